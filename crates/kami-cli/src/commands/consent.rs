@@ -0,0 +1,85 @@
+//! Operator consent to the capabilities a tool requests.
+//!
+//! `kami install` and `kami update` list what a manifest asks for beyond
+//! the tool's grant (or, for a tool without one, beyond what every tool
+//! gets) and, once approved interactively or with `--yes`, record the
+//! manifest's security config as the new grant together with the tool.
+
+use std::io::{BufRead, IsTerminal, Write};
+
+use kami_config::SandboxConfig;
+use kami_registry::ToolPolicyStore;
+use kami_types::{capability_diff, CapabilityChange, ResourceLimits, SecurityConfig, ToolId};
+
+use crate::output;
+
+/// Asks for the capabilities `requested` adds to the grant of `id`, or to
+/// `fallback` if it has none. Nothing is recorded: the caller saves the
+/// grant with the tool once approved.
+///
+/// # Errors
+/// Returns an error if the grant or the answer cannot be read.
+pub(crate) async fn approve(
+    store: &dyn ToolPolicyStore,
+    id: &ToolId,
+    fallback: &SecurityConfig,
+    requested: &SecurityConfig,
+    yes: bool,
+) -> anyhow::Result<bool> {
+    let granted = store.granted(id).await.map_err(store_err)?;
+    let changes = capability_diff(granted.as_ref().unwrap_or(fallback), requested);
+    if !changes.is_empty() {
+        output::print_info(&format!("{id} requests:"));
+        for line in describe(&changes) {
+            println!("{line}");
+        }
+        if !yes && !confirm()? {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// The security config of a new tool that requests nothing: no access and
+/// the host's default limits.
+pub(crate) fn baseline(defaults: &SandboxConfig) -> SecurityConfig {
+    SecurityConfig {
+        limits: ResourceLimits {
+            max_memory_mb: defaults.default_max_memory_mb,
+            max_fuel: defaults.default_max_fuel,
+            ..ResourceLimits::default()
+        },
+        ..SecurityConfig::default()
+    }
+}
+
+/// One line per change.
+fn describe(changes: &[CapabilityChange]) -> Vec<String> {
+    changes.iter().map(|c| format!("  + {c}")).collect()
+}
+
+/// Prompts on the terminal; without one, approval needs `--yes`.
+fn confirm() -> anyhow::Result<bool> {
+    let stdin = std::io::stdin();
+    if !stdin.is_terminal() {
+        output::print_warning("no terminal to ask for approval: pass --yes");
+        return Ok(false);
+    }
+    print!("Approve? [y/N] ");
+    std::io::stdout().flush()?;
+    let mut answer = String::new();
+    stdin.lock().read_line(&mut answer)?;
+    Ok(is_yes(&answer))
+}
+
+fn is_yes(answer: &str) -> bool {
+    matches!(answer.trim().to_ascii_lowercase().as_str(), "y" | "yes")
+}
+
+fn store_err(e: kami_registry::RepositoryError) -> anyhow::Error {
+    anyhow::anyhow!("registry error: {e}")
+}
+
+#[cfg(test)]
+#[path = "consent_tests.rs"]
+mod tests;
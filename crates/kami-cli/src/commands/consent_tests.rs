@@ -0,0 +1,71 @@
+//! Tests for capability consent.
+
+use super::*;
+
+use kami_store_sqlite::SqliteToolRepository;
+
+fn id() -> ToolId {
+    ToolId::new("dev.consent.tool").expect("id")
+}
+
+fn networked() -> SecurityConfig {
+    SecurityConfig {
+        net_allow_list: vec!["api.example.com".into()],
+        ..SecurityConfig::default()
+    }
+}
+
+#[test]
+fn only_explicit_yes_approves() {
+    assert!(is_yes("y\n"));
+    assert!(is_yes(" YES "));
+    assert!(!is_yes("\n"));
+    assert!(!is_yes("no"));
+}
+
+#[test]
+fn baseline_uses_host_defaults() {
+    let defaults = SandboxConfig {
+        default_max_memory_mb: 16,
+        default_max_fuel: 10,
+        ..SandboxConfig::default()
+    };
+    let base = baseline(&defaults);
+    assert_eq!(base.limits.max_memory_mb, 16);
+    assert_eq!(base.limits.max_fuel, 10);
+    assert!(base.net_allow_list.is_empty());
+}
+
+#[tokio::test]
+async fn yes_approves_without_recording_a_grant() {
+    let store = SqliteToolRepository::open_in_memory().expect("db");
+    let base = SecurityConfig::default();
+
+    let approved = approve(&store, &id(), &base, &networked(), true).await;
+
+    assert!(approved.expect("approve"));
+    assert!(store.granted(&id()).await.expect("read").is_none());
+}
+
+#[tokio::test]
+async fn nothing_new_needs_no_approval() {
+    let store = SqliteToolRepository::open_in_memory().expect("db");
+    store.grant(&id(), &networked()).await.expect("grant");
+
+    let approved = approve(
+        &store,
+        &id(),
+        &SecurityConfig::default(),
+        &networked(),
+        false,
+    )
+    .await;
+
+    assert!(approved.expect("approve"));
+}
+
+#[test]
+fn changes_are_listed_one_per_line() {
+    let changes = capability_diff(&SecurityConfig::default(), &networked());
+    assert_eq!(describe(&changes), ["  + network: api.example.com"]);
+}
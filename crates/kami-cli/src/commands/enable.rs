@@ -0,0 +1,123 @@
+//! `kami enable` and `kami disable` commands.
+//!
+//! Toggles whether an installed tool can be listed and called. A disabled
+//! tool stays installed, with its grant, overrides and data.
+
+use clap::Args;
+
+use kami_types::ToolId;
+
+use crate::{output, shared};
+
+/// Enable or disable an installed tool.
+#[derive(Debug, Args)]
+pub struct EnableArgs {
+    /// Tool ID (e.g. dev.example.fetch-url).
+    pub tool: String,
+    /// Database path (defaults to .kami/registry.db).
+    #[arg(long)]
+    pub db: Option<String>,
+}
+
+/// Executes `kami enable` (`enabled = true`) or `kami disable`.
+pub async fn execute(args: &EnableArgs, enabled: bool) -> anyhow::Result<()> {
+    let id = ToolId::new(&args.tool).map_err(|e| anyhow::anyhow!("invalid tool ID: {e}"))?;
+    let repo = shared::open_repository(&args.db)?;
+
+    let mut tool = repo
+        .find_by_id(&id)
+        .await
+        .map_err(|e| anyhow::anyhow!("registry error: {e}"))?
+        .ok_or_else(|| anyhow::anyhow!("tool not found: {id}"))?;
+
+    let state = if enabled { "enabled" } else { "disabled" };
+    if tool.enabled == enabled {
+        output::print_info(&format!("{id} is already {state}"));
+        return Ok(());
+    }
+    tool.enabled = enabled;
+    repo.update(&tool)
+        .await
+        .map_err(|e| anyhow::anyhow!("registry error: {e}"))?;
+    output::print_success(&format!("{id} {state}"));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kami_types::{SecurityConfig, Tool, ToolManifest, ToolVersion};
+
+    async fn install(db: &str) {
+        let repo = shared::open_repository(&Some(db.into())).expect("r");
+        repo.insert(&Tool {
+            manifest: ToolManifest {
+                id: ToolId::new("dev.t.toggle").expect("id"),
+                name: "toggle".into(),
+                version: ToolVersion::new(1, 0, 0),
+                wasm: "toggle.wasm".into(),
+                description: "toggle test".into(),
+                arguments: vec![],
+                output: vec![],
+                security: SecurityConfig::default(),
+                wasm_sha256: None,
+                signature: None,
+                signer_public_key: None,
+            },
+            install_path: "/toggle".into(),
+            enabled: true,
+            pinned_version: None,
+            updated_at: None,
+        })
+        .await
+        .expect("insert");
+    }
+
+    async fn is_enabled(db: &str) -> bool {
+        let repo = shared::open_repository(&Some(db.into())).expect("r");
+        let id = ToolId::new("dev.t.toggle").expect("id");
+        repo.find_by_id(&id)
+            .await
+            .expect("find")
+            .expect("tool")
+            .enabled
+    }
+
+    #[tokio::test]
+    async fn disable_and_enable_tool() {
+        let dir = tempfile::tempdir().expect("tmp");
+        let db = dir
+            .path()
+            .join("toggle.db")
+            .to_str()
+            .expect("u")
+            .to_string();
+        install(&db).await;
+        let args = EnableArgs {
+            tool: "dev.t.toggle".into(),
+            db: Some(db.clone()),
+        };
+
+        execute(&args, false).await.expect("disable");
+        assert!(!is_enabled(&db).await);
+        execute(&args, false).await.expect("already disabled");
+        execute(&args, true).await.expect("enable");
+        assert!(is_enabled(&db).await);
+    }
+
+    #[tokio::test]
+    async fn toggling_missing_tool_fails() {
+        let dir = tempfile::tempdir().expect("tmp");
+        let db = dir
+            .path()
+            .join("toggle2.db")
+            .to_str()
+            .expect("u")
+            .to_string();
+        let args = EnableArgs {
+            tool: "dev.t.nope".into(),
+            db: Some(db),
+        };
+        assert!(execute(&args, false).await.is_err());
+    }
+}
@@ -35,10 +35,14 @@ pub struct ExecArgs {
 pub async fn execute(args: &ExecArgs) -> anyhow::Result<()> {
     let resolved_input = input::resolve_input(&args.input, args.input_file.as_deref())?;
 
-    let repo = shared::open_repository(&args.db)?;
+    let store = shared::open_store(&args.db)?;
     let tool_id = ToolId::new(&args.tool).map_err(|e| anyhow::anyhow!("invalid tool ID: {e}"))?;
 
-    let runtime = shared::create_runtime(repo, args.concurrency, args.cache_size)?;
+    let secrets = shared::open_secret_store(&store, None)?;
+    let runtime = shared::create_runtime(store.clone(), args.concurrency, args.cache_size)?
+        .with_key_value_store(store.clone())
+        .with_tool_policies(store.clone())
+        .with_secrets(secrets, store);
 
     tracing::info!(
         tool = %args.tool,
@@ -50,6 +54,9 @@ pub async fn execute(args: &ExecArgs) -> anyhow::Result<()> {
         .execute(&tool_id, &resolved_input)
         .await
         .map_err(|e| {
+            if let Some(logs) = e.logs() {
+                output::print_guest_logs(logs);
+            }
             if let Some(hint) = e.hint() {
                 eprintln!("\n  Cause: {hint}");
             }
@@ -59,8 +66,10 @@ pub async fn execute(args: &ExecArgs) -> anyhow::Result<()> {
             anyhow::anyhow!("execution failed: {e}")
         })?;
 
+    output::print_guest_logs(&result.logs);
     if result.success {
         println!("{}", result.content);
+        output::print_other_parts(&result.parts);
     } else {
         output::print_error(&result.content);
     }
@@ -68,6 +77,8 @@ pub async fn execute(args: &ExecArgs) -> anyhow::Result<()> {
     tracing::debug!(
         duration_ms = result.duration_ms,
         fuel_consumed = result.fuel_consumed,
+        http_requests = result.egress.requests,
+        egress_bytes = result.egress.bytes_sent,
         success = result.success,
         "Execution complete"
     );
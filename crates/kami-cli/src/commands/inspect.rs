@@ -1,10 +1,15 @@
 //! `kami inspect` command.
 //!
-//! Displays detailed information about an installed tool.
+//! Displays detailed information about an installed tool, with the
+//! security config it runs with: its manifest narrowed by the host policy
+//! and its `kami policy` overrides.
 
-use clap::Args;
+use std::sync::Arc;
 
-use kami_types::ToolId;
+use clap::Args;
+use kami_registry::ToolRepository;
+use kami_runtime::SecurityPolicy;
+use kami_types::{ResourceLimits, ToolId};
 
 use crate::{output, shared};
 
@@ -20,7 +25,7 @@ pub struct InspectArgs {
 
 /// Executes the inspect command.
 pub async fn execute(args: &InspectArgs) -> anyhow::Result<()> {
-    let repo = shared::open_repository(&args.db)?;
+    let repo = shared::open_store(&args.db)?;
 
     let id = ToolId::new(&args.tool).map_err(|e| anyhow::anyhow!("invalid tool ID: {e}"))?;
 
@@ -38,7 +43,13 @@ pub async fn execute(args: &InspectArgs) -> anyhow::Result<()> {
     };
 
     let m = &tool.manifest;
-    let sec = &m.security;
+    let checker = Arc::new(shared::host_policy_checker()?);
+    let policy = SecurityPolicy::new(checker).with_store(repo.clone());
+    let signer = m.signature.as_ref().and(m.signer_public_key.as_deref());
+    let sec = &policy
+        .effective(&id, &m.security, signer)
+        .await
+        .map_err(|e| anyhow::anyhow!("{e}"))?;
 
     println!("Tool: {} v{}", m.id, m.version);
     println!("Name: {}", m.name);
@@ -56,17 +67,36 @@ pub async fn execute(args: &InspectArgs) -> anyhow::Result<()> {
         println!("  Network: deny-all");
     } else {
         println!("  Network: {}", sec.net_allow_list.join(", "));
+        if sec.allow_private_networks {
+            println!("  Private networks: allowed");
+        }
     }
     if sec.env_allow_list.is_empty() {
         println!("  Env vars: deny-all");
     } else {
         println!("  Env vars: {}", sec.env_allow_list.join(", "));
     }
+    if sec.key_value {
+        println!("  Key/value: {} bytes", sec.limits.max_kv_bytes);
+    } else {
+        println!("  Key/value: deny");
+    }
+    if sec.secrets.is_empty() {
+        println!("  Secrets: none");
+    } else {
+        let mode = if sec.secrets_as_env {
+            " (as env vars)"
+        } else {
+            ""
+        };
+        println!("  Secrets: {}{mode}", sec.secrets.join(", "));
+    }
 
     println!("\nResource limits:");
-    println!("  Memory: {} MB", sec.limits.max_memory_mb);
-    println!("  Timeout: {} ms", sec.limits.max_execution_ms);
-    println!("  Fuel: {}", sec.limits.max_fuel);
+    let network = !sec.net_allow_list.is_empty();
+    for line in limit_lines(&sec.limits, &m.security.limits, network) {
+        println!("  {line}");
+    }
 
     if !m.arguments.is_empty() {
         println!("\nArguments:");
@@ -82,63 +112,28 @@ pub async fn execute(args: &InspectArgs) -> anyhow::Result<()> {
     Ok(())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[tokio::test]
-    async fn inspect_missing_tool() {
-        let dir = tempfile::tempdir().expect("tmp");
-        let db = dir.path().join("insp.db").to_str().expect("u").to_string();
-        let args = InspectArgs {
-            tool: "dev.test.missing".into(),
-            db: Some(db),
-        };
-        // Returns Ok but prints "tool not found"
-        assert!(execute(&args).await.is_ok());
-    }
-
-    #[tokio::test]
-    async fn inspect_invalid_id() {
-        let dir = tempfile::tempdir().expect("tmp");
-        let db = dir.path().join("insp2.db").to_str().expect("u").to_string();
-        let args = InspectArgs {
-            tool: "bad".into(),
-            db: Some(db),
-        };
-        assert!(execute(&args).await.is_err());
-    }
-
-    #[tokio::test]
-    async fn inspect_existing_tool() {
-        use kami_types::*;
-        let dir = tempfile::tempdir().expect("tmp");
-        let db_path = dir.path().join("insp3.db");
-        let db = db_path.to_str().expect("u").to_string();
-        let repo = crate::shared::open_repository(&Some(db.clone())).expect("r");
-        let tool = Tool {
-            manifest: ToolManifest {
-                id: ToolId::new("dev.t.x").expect("id"),
-                name: "x".into(),
-                version: ToolVersion::new(1, 0, 0),
-                wasm: "x.wasm".into(),
-                description: "x tool".into(),
-                arguments: vec![],
-                security: SecurityConfig::default(),
-                wasm_sha256: None,
-                signature: None,
-                signer_public_key: None,
-            },
-            install_path: "/x".into(),
-            enabled: true,
-            pinned_version: None,
-            updated_at: None,
-        };
-        repo.insert(&tool).await.expect("ins");
-        let args = InspectArgs {
-            tool: "dev.t.x".into(),
-            db: Some(db),
-        };
-        assert!(execute(&args).await.is_ok());
+/// Formats the effective limits, with the manifest's value where narrowed.
+fn limit_lines(lim: &ResourceLimits, req: &ResourceLimits, network: bool) -> Vec<String> {
+    let line = |label: &str, get: fn(&ResourceLimits) -> u64, unit: &str| {
+        let (value, requested) = (get(lim), get(req));
+        if value == requested {
+            format!("{label}: {value}{unit}")
+        } else {
+            format!("{label}: {value}{unit} (manifest: {requested}{unit})")
+        }
+    };
+    let mut lines = vec![
+        line("Memory", |l| l.max_memory_mb.into(), " MB"),
+        line("Timeout", |l| l.max_execution_ms, " ms"),
+        line("Fuel", |l| l.max_fuel, ""),
+    ];
+    if network {
+        lines.push(line("HTTP requests", |l| l.max_http_requests.into(), ""));
+        lines.push(line("Egress", |l| l.max_egress_bytes, " bytes"));
     }
+    lines
 }
+
+#[cfg(test)]
+#[path = "inspect_tests.rs"]
+mod tests;
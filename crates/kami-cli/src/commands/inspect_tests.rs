@@ -0,0 +1,85 @@
+//! Tests for the `kami inspect` command.
+
+use super::*;
+
+#[tokio::test]
+async fn inspect_missing_tool() {
+    let dir = tempfile::tempdir().expect("tmp");
+    let db = dir.path().join("insp.db").to_str().expect("u").to_string();
+    let args = InspectArgs {
+        tool: "dev.test.missing".into(),
+        db: Some(db),
+    };
+    // Returns Ok but prints "tool not found"
+    assert!(execute(&args).await.is_ok());
+}
+
+#[tokio::test]
+async fn inspect_invalid_id() {
+    let dir = tempfile::tempdir().expect("tmp");
+    let db = dir.path().join("insp2.db").to_str().expect("u").to_string();
+    let args = InspectArgs {
+        tool: "bad".into(),
+        db: Some(db),
+    };
+    assert!(execute(&args).await.is_err());
+}
+
+#[tokio::test]
+async fn inspect_existing_tool() {
+    use kami_types::*;
+    let dir = tempfile::tempdir().expect("tmp");
+    let db_path = dir.path().join("insp3.db");
+    let db = db_path.to_str().expect("u").to_string();
+    let repo = crate::shared::open_store(&Some(db.clone())).expect("r");
+    let tool = Tool {
+        manifest: ToolManifest {
+            id: ToolId::new("dev.t.x").expect("id"),
+            name: "x".into(),
+            version: ToolVersion::new(1, 0, 0),
+            wasm: "x.wasm".into(),
+            description: "x tool".into(),
+            arguments: vec![],
+            output: vec![],
+            security: SecurityConfig::default(),
+            wasm_sha256: None,
+            signature: None,
+            signer_public_key: None,
+        },
+        install_path: "/x".into(),
+        enabled: true,
+        pinned_version: None,
+        updated_at: None,
+    };
+    repo.insert_granted(&tool).await.expect("ins");
+    let args = InspectArgs {
+        tool: "dev.t.x".into(),
+        db: Some(db),
+    };
+    assert!(execute(&args).await.is_ok());
+}
+
+#[test]
+fn narrowed_limits_show_the_manifest_value() {
+    let requested = ResourceLimits::default();
+    let effective = ResourceLimits {
+        max_memory_mb: 32,
+        ..ResourceLimits::default()
+    };
+
+    let lines = limit_lines(&effective, &requested, false);
+
+    assert_eq!(lines[0], "Memory: 32 MB (manifest: 64 MB)");
+    assert_eq!(lines[1], "Timeout: 5000 ms");
+    assert_eq!(lines.len(), 3);
+}
+
+#[test]
+fn network_tools_show_http_quotas() {
+    let limits = ResourceLimits::default();
+
+    let lines = limit_lines(&limits, &limits, true);
+
+    assert!(lines[3].starts_with("HTTP requests: "));
+    assert!(lines[4].starts_with("Egress: "));
+}
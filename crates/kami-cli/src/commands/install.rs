@@ -1,21 +1,27 @@
 //! `kami install` command.
 //!
-//! Installs tools from local paths, URLs, or GitHub shorthand.
+//! Installs tools from local paths, URLs, or GitHub shorthand, once the
+//! operator approves the capabilities the tool requests.
 
 use std::path::{Path, PathBuf};
 
 use clap::Args;
-use kami_config::parse_tool_manifest_file;
+use kami_config::{parse_tool_manifest_file, parse_tool_manifest_file_with_defaults};
+use kami_registry::ToolRepository;
 use kami_runtime::compute_file_hash;
 use kami_types::Tool;
 
-use crate::{commands::download, output, shared};
+use crate::commands::{consent, download};
+use crate::{output, shared};
 
 /// Install a WASM tool into the registry.
 #[derive(Debug, Args)]
 pub struct InstallArgs {
     /// Source: local path, URL, or GitHub shorthand (owner/repo@tag).
     pub source: String,
+    /// Approve the requested capabilities without prompting.
+    #[arg(short, long)]
+    pub yes: bool,
     /// Database path (defaults to .kami/registry.db).
     #[arg(long)]
     pub db: Option<String>,
@@ -24,7 +30,7 @@ pub struct InstallArgs {
 /// Executes the install command.
 pub async fn execute(args: &InstallArgs) -> anyhow::Result<()> {
     let tool_dir = resolve_source(&args.source).await?;
-    install_from_dir(&tool_dir, &args.db).await
+    install_from_dir(&tool_dir, &args.db, args.yes).await
 }
 
 /// Resolves the source to a local directory (downloading if remote).
@@ -61,7 +67,7 @@ async fn download_to_plugins(url: &str) -> anyhow::Result<PathBuf> {
 }
 
 /// Installs a tool from a local directory containing tool.toml.
-async fn install_from_dir(path: &Path, db: &Option<String>) -> anyhow::Result<()> {
+async fn install_from_dir(path: &Path, db: &Option<String>, yes: bool) -> anyhow::Result<()> {
     let manifest_path = if path.is_dir() {
         path.join("tool.toml")
     } else if path.extension().is_some_and(|e| e == "toml") {
@@ -74,7 +80,8 @@ async fn install_from_dir(path: &Path, db: &Option<String>) -> anyhow::Result<()
     }
     tracing::info!(path = %manifest_path.display(), "Parsing tool manifest");
 
-    let mut manifest = parse_tool_manifest_file(&manifest_path)
+    let defaults = shared::load_settings().sandbox;
+    let mut manifest = parse_tool_manifest_file_with_defaults(&manifest_path, &defaults)
         .map_err(|e| anyhow::anyhow!("manifest error: {e}"))?;
 
     let tool_dir = manifest_path.parent().unwrap_or_else(|| Path::new("."));
@@ -88,7 +95,7 @@ async fn install_from_dir(path: &Path, db: &Option<String>) -> anyhow::Result<()
     tracing::info!(hash = %wasm_hash, "SHA-256 computed");
     manifest.wasm_sha256 = Some(wasm_hash);
 
-    let repo = shared::open_repository(db)?;
+    let repo = shared::open_store(db)?;
     if let Some(existing) = repo
         .find_by_id(&manifest.id)
         .await
@@ -101,6 +108,11 @@ async fn install_from_dir(path: &Path, db: &Option<String>) -> anyhow::Result<()
         );
     }
 
+    let baseline = consent::baseline(&defaults);
+    if !consent::approve(&*repo, &manifest.id, &baseline, &manifest.security, yes).await? {
+        anyhow::bail!("installation of {} cancelled", manifest.id);
+    }
+
     let install_path = tool_dir
         .canonicalize()
         .unwrap_or_else(|_| tool_dir.to_path_buf())
@@ -114,7 +126,7 @@ async fn install_from_dir(path: &Path, db: &Option<String>) -> anyhow::Result<()
         pinned_version: None,
         updated_at: None,
     };
-    repo.insert(&tool)
+    repo.insert_granted(&tool)
         .await
         .map_err(|e| anyhow::anyhow!("insert error: {e}"))?;
     output::print_success(&format!(
@@ -3,8 +3,10 @@
 pub mod build;
 pub(crate) mod build_package;
 pub(crate) mod build_pipeline;
+pub(crate) mod consent;
 pub mod dev;
 pub mod download;
+pub mod enable;
 pub mod exec;
 pub mod init;
 pub mod inspect;
@@ -12,9 +14,13 @@ pub mod install;
 pub mod keygen;
 pub mod list;
 pub mod pin;
+pub mod policy;
+pub(crate) mod policy_caps;
 pub mod publish;
+pub(crate) mod rebuild;
 pub mod run;
 pub mod search;
+pub mod secret;
 pub mod serve;
 pub mod sign;
 pub mod status;
@@ -98,6 +98,7 @@ mod tests {
                 wasm: "pn.wasm".into(),
                 description: "pin test".into(),
                 arguments: vec![],
+                output: vec![],
                 security: SecurityConfig::default(),
                 wasm_sha256: None,
                 signature: None,
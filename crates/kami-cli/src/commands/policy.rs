@@ -0,0 +1,102 @@
+//! `kami policy` command.
+//!
+//! Manages the operator's per-tool limit overrides: `set`, `show` and
+//! `clear`. Overrides are ceilings applied on top of the tool's manifest
+//! and the host policy in `<data_dir>/policy.toml`, from the tool's next
+//! call on. `kami inspect` shows the limits a tool ends up with.
+
+use clap::{Parser, Subcommand};
+use kami_registry::{ToolPolicyStore, ToolRepository};
+use kami_store_sqlite::SqliteToolRepository;
+use kami_types::{LimitCaps, ToolId};
+
+use super::policy_caps::{describe, CapArgs};
+use crate::{output, shared};
+
+/// Manage per-tool limit overrides.
+#[derive(Debug, Parser)]
+pub struct PolicyArgs {
+    #[command(subcommand)]
+    command: PolicyCommand,
+    /// Database path (defaults to .kami/registry.db).
+    #[arg(long, global = true)]
+    pub db: Option<String>,
+}
+
+#[derive(Debug, Subcommand)]
+enum PolicyCommand {
+    /// Cap the limits of an installed tool; other overrides are kept.
+    Set {
+        /// Tool ID.
+        tool: String,
+        #[command(flatten)]
+        caps: CapArgs,
+    },
+    /// Print the overrides of a tool.
+    Show {
+        /// Tool ID.
+        tool: String,
+    },
+    /// Remove every override of a tool.
+    Clear {
+        /// Tool ID.
+        tool: String,
+    },
+}
+
+/// Dispatch to the appropriate policy subcommand.
+///
+/// # Errors
+/// Returns an error if the tool ID is invalid, the tool is not installed
+/// (for `set`), or the registry cannot be read or written.
+pub async fn execute(args: &PolicyArgs) -> anyhow::Result<()> {
+    let repo = shared::open_store(&args.db)?;
+    match &args.command {
+        PolicyCommand::Set { tool, caps } => {
+            let id = parse_id(tool)?;
+            set(&repo, &id, caps).await?;
+            output::print_success(&format!("Policy of {id} updated"));
+        }
+        PolicyCommand::Show { tool } => {
+            let id = parse_id(tool)?;
+            let caps = repo.overrides(&id).await.map_err(store_err)?;
+            match caps.map(|c| describe(&c)).filter(|l| !l.is_empty()) {
+                Some(lines) => lines.iter().for_each(|line| println!("{line}")),
+                None => println!("{id}: no overrides"),
+            }
+        }
+        PolicyCommand::Clear { tool } => {
+            let id = parse_id(tool)?;
+            if !repo.clear_overrides(&id).await.map_err(store_err)? {
+                anyhow::bail!("no overrides set for {id}");
+            }
+            output::print_success(&format!("Policy of {id} cleared"));
+        }
+    }
+    Ok(())
+}
+
+/// Merges `caps` into the overrides of the installed tool `id`.
+async fn set(repo: &SqliteToolRepository, id: &ToolId, caps: &CapArgs) -> anyhow::Result<()> {
+    if describe(&caps.merge(LimitCaps::default())).is_empty() {
+        anyhow::bail!("nothing to set: pass at least one limit, e.g. --max-memory 32");
+    }
+    if repo.find_by_id(id).await.map_err(store_err)?.is_none() {
+        anyhow::bail!("tool not found: {id}");
+    }
+    let current = repo.overrides(id).await.map_err(store_err)?;
+    let merged = caps.merge(current.unwrap_or_default());
+    repo.set_overrides(id, &merged).await.map_err(store_err)
+}
+
+fn parse_id(tool: &str) -> anyhow::Result<ToolId> {
+    ToolId::new(tool).map_err(|e| anyhow::anyhow!("invalid tool ID: {e}"))
+}
+
+fn store_err(e: kami_registry::RepositoryError) -> anyhow::Error {
+    anyhow::anyhow!("registry error: {e}")
+}
+
+#[cfg(test)]
+#[path = "policy_tests.rs"]
+mod tests;
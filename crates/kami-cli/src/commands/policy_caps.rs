@@ -0,0 +1,61 @@
+//! Limit flags of `kami policy set` and their `key = value` listing.
+
+use kami_types::LimitCaps;
+
+/// Limits `kami policy set` can cap; unset flags keep the current value.
+#[derive(Debug, Default, clap::Args)]
+pub(crate) struct CapArgs {
+    /// Maximum memory in MB.
+    #[arg(long = "max-memory", value_name = "MB")]
+    pub(crate) max_memory_mb: Option<u32>,
+    /// Execution timeout in milliseconds.
+    #[arg(long = "timeout-ms", value_name = "MS")]
+    pub(crate) max_execution_ms: Option<u64>,
+    /// Fuel (instruction budget).
+    #[arg(long)]
+    pub(crate) max_fuel: Option<u64>,
+    /// Outgoing HTTP requests per execution.
+    #[arg(long)]
+    pub(crate) max_http_requests: Option<u32>,
+    /// Bytes sent over HTTP per execution.
+    #[arg(long, value_name = "BYTES")]
+    pub(crate) max_egress_bytes: Option<u64>,
+    /// Bytes of stdout and of stderr kept per execution.
+    #[arg(long, value_name = "BYTES")]
+    pub(crate) max_log_bytes: Option<u64>,
+    /// Bytes the tool may keep in its key/value store.
+    #[arg(long, value_name = "BYTES")]
+    pub(crate) max_kv_bytes: Option<u64>,
+}
+
+impl CapArgs {
+    /// Returns `current` with the given flags replacing its values.
+    pub(crate) fn merge(&self, current: LimitCaps) -> LimitCaps {
+        LimitCaps {
+            max_memory_mb: self.max_memory_mb.or(current.max_memory_mb),
+            max_execution_ms: self.max_execution_ms.or(current.max_execution_ms),
+            max_fuel: self.max_fuel.or(current.max_fuel),
+            max_http_requests: self.max_http_requests.or(current.max_http_requests),
+            max_egress_bytes: self.max_egress_bytes.or(current.max_egress_bytes),
+            max_log_bytes: self.max_log_bytes.or(current.max_log_bytes),
+            max_kv_bytes: self.max_kv_bytes.or(current.max_kv_bytes),
+        }
+    }
+}
+
+/// Lists the overrides that are set, one `key = value` per line.
+pub(crate) fn describe(caps: &LimitCaps) -> Vec<String> {
+    let entries = [
+        ("max_memory_mb", caps.max_memory_mb.map(u64::from)),
+        ("max_execution_ms", caps.max_execution_ms),
+        ("max_fuel", caps.max_fuel),
+        ("max_http_requests", caps.max_http_requests.map(u64::from)),
+        ("max_egress_bytes", caps.max_egress_bytes),
+        ("max_log_bytes", caps.max_log_bytes),
+        ("max_kv_bytes", caps.max_kv_bytes),
+    ];
+    entries
+        .into_iter()
+        .filter_map(|(key, value)| value.map(|v| format!("{key} = {v}")))
+        .collect()
+}
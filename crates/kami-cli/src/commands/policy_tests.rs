@@ -0,0 +1,100 @@
+//! Tests for the `kami policy` command.
+
+use super::*;
+
+use kami_types::{SecurityConfig, Tool, ToolManifest, ToolVersion};
+
+const TOOL: &str = "dev.policy.tool";
+
+fn db(dir: &tempfile::TempDir) -> String {
+    dir.path()
+        .join("policy.db")
+        .to_str()
+        .expect("utf8")
+        .to_string()
+}
+
+fn args(dir: &tempfile::TempDir, command: PolicyCommand) -> PolicyArgs {
+    PolicyArgs {
+        command,
+        db: Some(db(dir)),
+    }
+}
+
+fn set(memory: Option<u32>, fuel: Option<u64>) -> PolicyCommand {
+    PolicyCommand::Set {
+        tool: TOOL.into(),
+        caps: CapArgs {
+            max_memory_mb: memory,
+            max_fuel: fuel,
+            ..CapArgs::default()
+        },
+    }
+}
+
+async fn install(dir: &tempfile::TempDir) -> std::sync::Arc<SqliteToolRepository> {
+    let repo = shared::open_store(&Some(db(dir))).expect("db");
+    let tool = Tool {
+        manifest: ToolManifest {
+            id: ToolId::new(TOOL).expect("id"),
+            name: "tool".into(),
+            version: ToolVersion::new(1, 0, 0),
+            wasm: "tool.wasm".into(),
+            description: "policy test".into(),
+            arguments: vec![],
+            output: vec![],
+            security: SecurityConfig::default(),
+            wasm_sha256: None,
+            signature: None,
+            signer_public_key: None,
+        },
+        install_path: "/tool".into(),
+        enabled: true,
+        pinned_version: None,
+        updated_at: None,
+    };
+    repo.insert(&tool).await.expect("insert");
+    repo
+}
+
+#[tokio::test]
+async fn set_merges_with_previous_overrides() {
+    let dir = tempfile::tempdir().expect("tmp");
+    let repo = install(&dir).await;
+
+    execute(&args(&dir, set(Some(32), None)))
+        .await
+        .expect("memory");
+    execute(&args(&dir, set(None, Some(1_000))))
+        .await
+        .expect("fuel");
+
+    let caps = repo.overrides(&parse_id(TOOL).expect("id")).await;
+    let caps = caps.expect("read").expect("overrides");
+    assert_eq!(describe(&caps), ["max_memory_mb = 32", "max_fuel = 1000"]);
+}
+
+#[tokio::test]
+async fn set_requires_an_installed_tool_and_a_limit() {
+    let dir = tempfile::tempdir().expect("tmp");
+    assert!(execute(&args(&dir, set(Some(32), None))).await.is_err());
+
+    install(&dir).await;
+    assert!(execute(&args(&dir, set(None, None))).await.is_err());
+}
+
+#[tokio::test]
+async fn clear_removes_overrides_once() {
+    let dir = tempfile::tempdir().expect("tmp");
+    install(&dir).await;
+    execute(&args(&dir, set(Some(32), None)))
+        .await
+        .expect("set");
+    let clear = || PolicyCommand::Clear { tool: TOOL.into() };
+
+    execute(&args(&dir, clear())).await.expect("clear");
+
+    assert!(execute(&args(&dir, clear())).await.is_err());
+    let show = PolicyCommand::Show { tool: TOOL.into() };
+    assert!(execute(&args(&dir, show)).await.is_ok());
+}
@@ -0,0 +1,54 @@
+//! Rebuilding a registry entry from a tool's source directory.
+
+use std::path::Path;
+
+use kami_config::parse_tool_manifest_file_with_defaults;
+use kami_runtime::compute_file_hash;
+use kami_types::Tool;
+
+use crate::shared;
+
+/// Re-reads tool.toml and recomputes the WASM hash.
+pub(crate) fn rebuild_tool(existing: &Tool) -> anyhow::Result<Tool> {
+    let install = Path::new(&existing.install_path);
+    let manifest_path = install.join("tool.toml");
+    if !manifest_path.exists() {
+        anyhow::bail!("tool.toml not found at {}", manifest_path.display());
+    }
+    let defaults = shared::load_settings().sandbox;
+    let mut manifest = parse_tool_manifest_file_with_defaults(&manifest_path, &defaults)
+        .map_err(|e| anyhow::anyhow!("manifest error: {e}"))?;
+
+    let wasm_path = install.join(&manifest.wasm);
+    if wasm_path.exists() {
+        let hash = compute_file_hash(&wasm_path).map_err(|e| anyhow::anyhow!("hash error: {e}"))?;
+        manifest.wasm_sha256 = Some(hash);
+    }
+
+    let now = chrono_now();
+    Ok(Tool {
+        manifest,
+        install_path: existing.install_path.clone(),
+        enabled: existing.enabled,
+        pinned_version: existing.pinned_version.clone(),
+        updated_at: Some(now),
+    })
+}
+
+/// Returns the current UTC time in ISO 8601 format (no chrono dep).
+fn chrono_now() -> String {
+    let dur = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default();
+    let secs = dur.as_secs();
+    format!("{secs}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn chrono_now_is_numeric_epoch_secs() {
+        assert!(chrono_now().parse::<u64>().is_ok());
+    }
+}
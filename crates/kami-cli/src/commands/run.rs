@@ -8,7 +8,7 @@ use kami_engine::{create_engine, create_linker, load_component_from_file, Instan
 use kami_runtime::{ToolExecutor, WasmToolExecutor};
 use kami_types::{ResourceLimits, SecurityConfig};
 
-use crate::input;
+use crate::{input, output};
 
 /// Run a WASM component directly from a file.
 #[derive(Debug, Args)]
@@ -60,6 +60,7 @@ pub async fn execute(args: &RunArgs) -> anyhow::Result<()> {
             max_fuel: args.fuel,
             max_memory_mb: args.max_memory_mb,
             max_execution_ms: args.timeout_ms,
+            ..ResourceLimits::default()
         },
         ..SecurityConfig::default()
     };
@@ -78,17 +79,26 @@ pub async fn execute(args: &RunArgs) -> anyhow::Result<()> {
     let result = executor
         .execute(&component, &resolved_input, &security)
         .await
-        .map_err(|e| anyhow::anyhow!("execution failed: {e}"))?;
+        .map_err(|e| {
+            if let Some(logs) = e.logs() {
+                output::print_guest_logs(logs);
+            }
+            anyhow::anyhow!("execution failed: {e}")
+        })?;
 
     // 5. Output result
+    output::print_guest_logs(&result.logs);
     if result.success {
         println!("{}", result.content);
+        output::print_other_parts(&result.parts);
     } else {
         eprintln!("[ERROR] {}", result.content);
     }
     tracing::debug!(
         duration_ms = result.duration_ms,
         fuel_consumed = result.fuel_consumed,
+        http_requests = result.egress.requests,
+        egress_bytes = result.egress.bytes_sent,
         success = result.success,
         "Execution complete"
     );
@@ -0,0 +1,142 @@
+//! `kami secret` command.
+//!
+//! Manages the encrypted secret store that tools read through their
+//! `[security] secrets` declaration: `set`, `get`, `rm` and `list`.
+//! Every change or read of a value is recorded in the audit log.
+
+use std::io::Read;
+use std::sync::Arc;
+
+use clap::{Parser, Subcommand};
+use kami_registry::{AuditLog, SecretStore};
+use kami_store_sqlite::{SqliteSecretStore, SqliteToolRepository};
+use kami_types::{DomainEvent, SecretAccess};
+
+use crate::{output, shared};
+
+/// Manage secrets injected into tools.
+#[derive(Debug, Parser)]
+pub struct SecretArgs {
+    #[command(subcommand)]
+    command: SecretCommand,
+    /// Database path (defaults to .kami/registry.db).
+    #[arg(long, global = true)]
+    pub db: Option<String>,
+    /// Encryption key file (defaults to <data_dir>/keys/secrets.key).
+    #[arg(long, global = true)]
+    pub key: Option<String>,
+}
+
+#[derive(Debug, Subcommand)]
+enum SecretCommand {
+    /// Store a secret; the value is read from stdin when omitted.
+    Set {
+        /// Secret name, e.g. GITHUB_TOKEN.
+        name: String,
+        /// Secret value (prefer stdin to keep it out of shell history).
+        value: Option<String>,
+    },
+    /// Print the value of a secret.
+    Get {
+        /// Secret name.
+        name: String,
+    },
+    /// Remove a secret.
+    Rm {
+        /// Secret name.
+        name: String,
+    },
+    /// List secret names (never values).
+    List,
+}
+
+/// Dispatch to the appropriate secret subcommand.
+///
+/// # Errors
+/// Returns an error if the store cannot be opened or the operation fails.
+pub async fn execute(args: &SecretArgs) -> anyhow::Result<()> {
+    let repo = shared::open_store(&args.db)?;
+    let store = shared::open_secret_store(&repo, args.key.as_deref())?;
+    match &args.command {
+        SecretCommand::Set { name, value } => {
+            validate_name(name)?;
+            let value = match value {
+                Some(v) => v.clone(),
+                None => read_stdin()?,
+            };
+            store.set(name, &value).await.map_err(store_err)?;
+            audit(&repo, name, SecretAccess::Write).await;
+            output::print_success(&format!("Secret {name} stored"));
+        }
+        SecretCommand::Get { name } => {
+            let value = get(&store, &repo, name).await?;
+            println!("{value}");
+        }
+        SecretCommand::Rm { name } => {
+            if !store.remove(name).await.map_err(store_err)? {
+                anyhow::bail!("secret not found: {name}");
+            }
+            audit(&repo, name, SecretAccess::Remove).await;
+            output::print_success(&format!("Secret {name} removed"));
+        }
+        SecretCommand::List => {
+            for name in store.list().await.map_err(store_err)? {
+                println!("{name}");
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Reads the secret `name`, recording the access first.
+///
+/// Fails closed like the runtime: a read that cannot be audited is not
+/// performed.
+async fn get(
+    store: &Arc<SqliteSecretStore>,
+    repo: &SqliteToolRepository,
+    name: &str,
+) -> anyhow::Result<String> {
+    let event = DomainEvent::secret_accessed(None, name, SecretAccess::Read);
+    repo.record(&event)
+        .await
+        .map_err(|e| anyhow::anyhow!("audit log unavailable, secret not read: {e}"))?;
+    let value = store.get(name).await.map_err(store_err)?;
+    value.ok_or_else(|| anyhow::anyhow!("secret not found: {name}"))
+}
+
+/// Secret names double as environment variable names.
+fn validate_name(name: &str) -> anyhow::Result<()> {
+    let mut chars = name.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !valid {
+        anyhow::bail!("invalid secret name '{name}': use letters, digits and '_'");
+    }
+    Ok(())
+}
+
+/// Reads a value from stdin, without its trailing newline.
+fn read_stdin() -> anyhow::Result<String> {
+    let mut buffer = String::new();
+    std::io::stdin().read_to_string(&mut buffer)?;
+    Ok(buffer.trim_end_matches(['\r', '\n']).to_string())
+}
+
+/// Records an operator access; a failure is reported but not fatal.
+async fn audit(repo: &SqliteToolRepository, name: &str, access: SecretAccess) {
+    let event = DomainEvent::secret_accessed(None, name, access);
+    if let Err(e) = repo.record(&event).await {
+        output::print_warning(&format!("audit log unavailable: {e}"));
+    }
+}
+
+fn store_err(e: kami_registry::RepositoryError) -> anyhow::Error {
+    anyhow::anyhow!("secret store error: {e}")
+}
+
+#[cfg(test)]
+#[path = "secret_tests.rs"]
+mod tests;
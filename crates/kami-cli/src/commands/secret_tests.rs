@@ -0,0 +1,90 @@
+//! Tests for the `kami secret` command.
+
+use super::*;
+
+use kami_types::DomainEvent;
+
+/// Returns `(db, key)` paths inside `dir`.
+fn paths(dir: &tempfile::TempDir) -> (String, String) {
+    let path = |f: &str| dir.path().join(f).to_str().expect("utf8").to_string();
+    (path("secrets.db"), path("secrets.key"))
+}
+
+fn args(dir: &tempfile::TempDir, command: SecretCommand) -> SecretArgs {
+    let (db, key) = paths(dir);
+    SecretArgs {
+        command,
+        db: Some(db),
+        key: Some(key),
+    }
+}
+
+#[test]
+fn secret_names_must_be_env_compatible() {
+    assert!(validate_name("GITHUB_TOKEN").is_ok());
+    assert!(validate_name("_private2").is_ok());
+    assert!(validate_name("").is_err());
+    assert!(validate_name("2FA").is_err());
+    assert!(validate_name("API-KEY").is_err());
+}
+
+#[tokio::test]
+async fn set_get_rm_roundtrip_is_audited() {
+    let dir = tempfile::tempdir().expect("tmp");
+    let set = SecretCommand::Set {
+        name: "API_TOKEN".into(),
+        value: Some("t0ken".into()),
+    };
+    execute(&args(&dir, set)).await.expect("set");
+
+    let (db, key) = paths(&dir);
+    let repo = shared::open_store(&Some(db)).expect("db");
+    let store = shared::open_secret_store(&repo, Some(&key)).expect("store");
+    assert_eq!(get(&store, &repo, "API_TOKEN").await.expect("get"), "t0ken");
+
+    let rm = SecretCommand::Rm {
+        name: "API_TOKEN".into(),
+    };
+    execute(&args(&dir, rm)).await.expect("rm");
+    assert!(get(&store, &repo, "API_TOKEN").await.is_err());
+
+    let events = repo.recent(10).await.expect("audit");
+    let accesses: Vec<SecretAccess> = events
+        .iter()
+        .filter_map(|e| match e {
+            DomainEvent::SecretAccessed {
+                tool_id: None,
+                access,
+                ..
+            } => Some(*access),
+            _ => None,
+        })
+        .collect();
+    let expected = [
+        SecretAccess::Read,
+        SecretAccess::Remove,
+        SecretAccess::Read,
+        SecretAccess::Write,
+    ];
+    assert_eq!(accesses, expected);
+}
+
+#[tokio::test]
+async fn removing_missing_secret_fails() {
+    let dir = tempfile::tempdir().expect("tmp");
+    let rm = SecretCommand::Rm {
+        name: "NOPE".into(),
+    };
+    assert!(execute(&args(&dir, rm)).await.is_err());
+}
+
+#[tokio::test]
+async fn invalid_name_is_rejected_before_storing() {
+    let dir = tempfile::tempdir().expect("tmp");
+    let set = SecretCommand::Set {
+        name: "bad name".into(),
+        value: Some("v".into()),
+    };
+    assert!(execute(&args(&dir, set)).await.is_err());
+    assert!(execute(&args(&dir, SecretCommand::List)).await.is_ok());
+}
@@ -7,6 +7,7 @@ use std::sync::Arc;
 
 use clap::Args;
 
+use kami_registry::ToolRepository;
 use kami_transport_http::HttpServer;
 use kami_transport_stdio::{McpHandler, McpServer, StdioTransport};
 
@@ -33,16 +34,23 @@ pub struct ServeArgs {
     /// Database path (defaults to .kami/registry.db).
     #[arg(long)]
     pub db: Option<String>,
+    /// Forward guest stdout/stderr to the client as MCP log notifications
+    /// (stdio transport only).
+    #[arg(long)]
+    pub forward_logs: bool,
 }
 
 /// Executes the serve command.
 pub async fn execute(args: &ServeArgs) -> anyhow::Result<()> {
-    let repo = shared::open_repository(&args.db)?;
-    let runtime = Arc::new(shared::create_runtime(
-        repo.clone(),
-        args.concurrency,
-        args.cache_size,
-    )?);
+    let store = shared::open_store(&args.db)?;
+    let repo: Arc<dyn ToolRepository> = store.clone();
+    let secrets = shared::open_secret_store(&store, None)?;
+    let runtime = Arc::new(
+        shared::create_runtime(repo.clone(), args.concurrency, args.cache_size)?
+            .with_key_value_store(store.clone())
+            .with_tool_policies(store.clone())
+            .with_secrets(secrets, store),
+    );
 
     match args.transport.as_str() {
         "http" => {
@@ -59,7 +67,7 @@ pub async fn execute(args: &ServeArgs) -> anyhow::Result<()> {
             }
         }
         _ => {
-            let handler = McpHandler::new(runtime.clone(), repo);
+            let handler = McpHandler::new(runtime.clone(), repo).with_guest_logs(args.forward_logs);
             let transport = StdioTransport::new(tokio::io::stdin(), tokio::io::stdout());
             let mut server = McpServer::new(transport, handler);
             tracing::info!("KAMI MCP server ready on stdio");
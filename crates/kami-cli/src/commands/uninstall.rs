@@ -91,6 +91,7 @@ mod tests {
                     wasm: "del.wasm".into(),
                     description: "d".into(),
                     arguments: vec![],
+                    output: vec![],
                     security: SecurityConfig::default(),
                     wasm_sha256: None,
                     signature: None,
@@ -1,17 +1,17 @@
 //! `kami update` command.
 //!
 //! Re-reads tool.toml from the install path and updates the registry
-//! entry with new manifest data and a fresh WASM hash.
-
-use std::path::Path;
+//! entry with new manifest data and a fresh WASM hash. Capabilities the
+//! new manifest adds to the tool's grant need the operator's approval.
 
 use clap::Args;
 
-use kami_config::parse_tool_manifest_file;
 use kami_registry::ToolRepository;
-use kami_runtime::compute_file_hash;
+use kami_store_sqlite::SqliteToolRepository;
 use kami_types::Tool;
 
+use crate::commands::consent;
+use crate::commands::rebuild::rebuild_tool;
 use crate::{output, shared};
 
 /// Update one or all installed tools from their source directories.
@@ -22,6 +22,9 @@ pub struct UpdateArgs {
     /// Update all installed tools.
     #[arg(long)]
     pub all: bool,
+    /// Approve new capabilities without prompting.
+    #[arg(short, long)]
+    pub yes: bool,
     /// Database path (defaults to .kami/registry.db).
     #[arg(long)]
     pub db: Option<String>,
@@ -29,21 +32,25 @@ pub struct UpdateArgs {
 
 /// Executes the update command.
 pub async fn execute(args: &UpdateArgs) -> anyhow::Result<()> {
-    let repo = shared::open_repository(&args.db)?;
+    let repo = shared::open_store(&args.db)?;
 
     if args.all {
-        return update_all(&*repo).await;
+        return update_all(&repo, args.yes).await;
     }
     let id_str = args
         .tool_id
         .as_deref()
         .ok_or_else(|| anyhow::anyhow!("provide a tool ID or use --all"))?;
     let id = id_str.parse().map_err(|e| anyhow::anyhow!("{e}"))?;
-    update_one(&*repo, &id).await
+    update_one(&repo, &id, args.yes).await
 }
 
 /// Updates a single tool by re-reading its source manifest.
-async fn update_one(repo: &dyn ToolRepository, id: &kami_types::ToolId) -> anyhow::Result<()> {
+async fn update_one(
+    repo: &SqliteToolRepository,
+    id: &kami_types::ToolId,
+    yes: bool,
+) -> anyhow::Result<()> {
     let existing = repo
         .find_by_id(id)
         .await
@@ -60,7 +67,10 @@ async fn update_one(repo: &dyn ToolRepository, id: &kami_types::ToolId) -> anyho
     }
 
     let updated = rebuild_tool(&existing)?;
-    repo.update(&updated)
+    if !approve(repo, &updated, yes).await? {
+        anyhow::bail!("update of {id} cancelled");
+    }
+    repo.update_granted(&updated)
         .await
         .map_err(|e| anyhow::anyhow!("{e}"))?;
     output::print_success(&format!(
@@ -70,43 +80,10 @@ async fn update_one(repo: &dyn ToolRepository, id: &kami_types::ToolId) -> anyho
     Ok(())
 }
 
-/// Re-reads tool.toml and recomputes the WASM hash.
-fn rebuild_tool(existing: &Tool) -> anyhow::Result<Tool> {
-    let install = Path::new(&existing.install_path);
-    let manifest_path = install.join("tool.toml");
-    if !manifest_path.exists() {
-        anyhow::bail!("tool.toml not found at {}", manifest_path.display());
-    }
-    let mut manifest = parse_tool_manifest_file(&manifest_path)
-        .map_err(|e| anyhow::anyhow!("manifest error: {e}"))?;
-
-    let wasm_path = install.join(&manifest.wasm);
-    if wasm_path.exists() {
-        let hash = compute_file_hash(&wasm_path).map_err(|e| anyhow::anyhow!("hash error: {e}"))?;
-        manifest.wasm_sha256 = Some(hash);
-    }
-
-    let now = chrono_now();
-    Ok(Tool {
-        manifest,
-        install_path: existing.install_path.clone(),
-        enabled: existing.enabled,
-        pinned_version: existing.pinned_version.clone(),
-        updated_at: Some(now),
-    })
-}
-
-/// Returns the current UTC time in ISO 8601 format (no chrono dep).
-fn chrono_now() -> String {
-    let dur = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .unwrap_or_default();
-    let secs = dur.as_secs();
-    format!("{secs}")
-}
-
 /// Updates every installed tool that is not pinned.
-async fn update_all(repo: &dyn ToolRepository) -> anyhow::Result<()> {
+///
+/// Tools whose new capabilities are not approved are skipped.
+async fn update_all(repo: &SqliteToolRepository, yes: bool) -> anyhow::Result<()> {
     let tools = repo
         .find_all(kami_registry::ToolQuery::all())
         .await
@@ -124,7 +101,11 @@ async fn update_all(repo: &dyn ToolRepository) -> anyhow::Result<()> {
         }
         match rebuild_tool(tool) {
             Ok(new_tool) => {
-                repo.update(&new_tool)
+                if !approve(repo, &new_tool, yes).await? {
+                    skipped += 1;
+                    continue;
+                }
+                repo.update_granted(&new_tool)
                     .await
                     .map_err(|e| anyhow::anyhow!("{e}"))?;
                 updated += 1;
@@ -139,11 +120,10 @@ async fn update_all(repo: &dyn ToolRepository) -> anyhow::Result<()> {
     Ok(())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    #[test]
-    fn chrono_now_is_numeric_epoch_secs() {
-        assert!(chrono_now().parse::<u64>().is_ok());
-    }
+/// Asks for the capabilities `updated` adds to the tool's grant, or to what
+/// every tool gets if it was never granted any.
+async fn approve(repo: &SqliteToolRepository, updated: &Tool, yes: bool) -> anyhow::Result<bool> {
+    let baseline = consent::baseline(&shared::load_settings().sandbox);
+    let requested = &updated.manifest.security;
+    consent::approve(repo, &updated.manifest.id, &baseline, requested, yes).await
 }
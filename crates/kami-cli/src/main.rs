@@ -50,8 +50,14 @@ enum Commands {
     Keygen(commands::keygen::KeygenArgs),
     /// Sign a WASM plugin with your Ed25519 key.
     Sign(commands::sign::SignArgs),
+    /// Allow a disabled tool to be listed and called again.
+    Enable(commands::enable::EnableArgs),
+    /// Keep an installed tool from being listed or called.
+    Disable(commands::enable::EnableArgs),
     /// Pin a tool to a specific version (prevents update).
     Pin(commands::pin::PinArgs),
+    /// Cap a tool's resource limits on this host.
+    Policy(commands::policy::PolicyArgs),
     /// List installed tools.
     List(commands::list::ListArgs),
     /// Inspect a tool's manifest.
@@ -68,6 +74,8 @@ enum Commands {
     Verify(commands::verify::VerifyArgs),
     /// Developer experience commands (watch, etc.).
     Dev(commands::dev::DevArgs),
+    /// Manage encrypted secrets injected into tools.
+    Secret(commands::secret::SecretArgs),
 }
 
 #[tokio::main]
@@ -103,7 +111,10 @@ async fn main() -> anyhow::Result<()> {
         Commands::Publish(args) => commands::publish::execute(args),
         Commands::Keygen(args) => commands::keygen::execute(args),
         Commands::Sign(args) => commands::sign::execute(args),
+        Commands::Enable(args) => commands::enable::execute(args, true).await,
+        Commands::Disable(args) => commands::enable::execute(args, false).await,
         Commands::Pin(args) => commands::pin::execute(args).await,
+        Commands::Policy(args) => commands::policy::execute(args).await,
         Commands::List(args) => commands::list::execute(args).await,
         Commands::Inspect(args) => commands::inspect::execute(args).await,
         Commands::Run(args) => commands::run::execute(args).await,
@@ -112,5 +123,6 @@ async fn main() -> anyhow::Result<()> {
         Commands::Status(args) => commands::status::execute(args).await,
         Commands::Verify(args) => commands::verify::execute(args).await,
         Commands::Dev(args) => commands::dev::execute(args).await,
+        Commands::Secret(args) => commands::secret::execute(args).await,
     }
 }
@@ -1,5 +1,8 @@
 //! Output formatting for CLI responses.
 
+use kami_runtime::GuestLogs;
+use kami_types::ContentPart;
+
 /// Prints a success message.
 pub fn print_success(message: &str) {
     println!("[OK] {message}");
@@ -20,6 +23,30 @@ pub fn print_info(message: &str) {
     println!("[INFO] {message}");
 }
 
+/// Prints captured guest stdout/stderr to stderr, keeping stdout for the result.
+pub fn print_guest_logs(logs: &GuestLogs) {
+    eprint!("{}{}", logs.stdout, logs.stderr);
+    if logs.truncated {
+        eprintln!("[WARN] guest output truncated (max_log_bytes reached)");
+    }
+}
+
+/// Lists the non-text parts of a result on stderr, which has no rendering
+/// for them.
+pub fn print_other_parts(parts: &[ContentPart]) {
+    for part in parts {
+        let summary = match part {
+            ContentPart::Text { .. } => continue,
+            ContentPart::Image { data, mime_type } | ContentPart::Audio { data, mime_type } => {
+                format!("{mime_type} ({} bytes base64)", data.len())
+            }
+            ContentPart::Resource { uri, .. } => format!("resource {uri}"),
+            ContentPart::ResourceLink { uri, .. } => format!("link to {uri}"),
+        };
+        eprintln!("[INFO] {summary}");
+    }
+}
+
 /// Default database path for the tool registry.
 pub fn default_db_path() -> String {
     let home = std::env::var("KAMI_DATA_DIR").unwrap_or_else(|_| ".kami".to_string());
@@ -50,6 +77,15 @@ mod tests {
         print_info("inf");
     }
 
+    #[test]
+    fn print_guest_logs_does_not_panic() {
+        print_guest_logs(&GuestLogs {
+            stdout: "out\n".into(),
+            stderr: String::new(),
+            truncated: true,
+        });
+    }
+
     #[test]
     fn default_db_path_contains_registry_db() {
         let path = default_db_path();
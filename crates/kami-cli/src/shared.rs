@@ -5,12 +5,18 @@
 
 use std::sync::Arc;
 
+use kami_config::{load_config, load_host_policy, KamiConfig};
 use kami_registry::ToolRepository;
 use kami_runtime::{KamiRuntime, RuntimeConfig};
-use kami_store_sqlite::SqliteToolRepository;
+use kami_sandbox::{PolicyChecker, SandboxDirMode};
+use kami_store_sqlite::{SecretKey, SqliteSecretStore, SqliteToolRepository};
 
 use crate::output;
 
+mod dirs;
+
+pub use dirs::{data_dir, plugins_dir, tool_data_dir};
+
 /// Opens the SQLite tool registry.
 ///
 /// Uses `$KAMI_DATA_DIR/registry.db` or `.kami/registry.db` by default.
@@ -19,6 +25,15 @@ use crate::output;
 ///
 /// Returns an error if the database file cannot be created or opened.
 pub fn open_repository(db: &Option<String>) -> anyhow::Result<Arc<dyn ToolRepository>> {
+    Ok(open_store(db)?)
+}
+
+/// Opens the SQLite registry, which also holds the tools' key/value data.
+///
+/// # Errors
+///
+/// Returns an error if the database file cannot be created or opened.
+pub fn open_store(db: &Option<String>) -> anyhow::Result<Arc<SqliteToolRepository>> {
     let path = db.clone().unwrap_or_else(output::default_db_path);
     if let Some(parent) = std::path::Path::new(&path).parent() {
         std::fs::create_dir_all(parent)?;
@@ -28,100 +43,91 @@ pub fn open_repository(db: &Option<String>) -> anyhow::Result<Arc<dyn ToolReposi
     Ok(Arc::new(repo))
 }
 
+/// Opens the encrypted secret store held in `repo`'s database.
+///
+/// The key is read from `key` or `<data_dir>/keys/secrets.key`, and
+/// generated on first use.
+///
+/// # Errors
+///
+/// Returns an error if the key file cannot be read or created.
+pub fn open_secret_store(
+    repo: &Arc<SqliteToolRepository>,
+    key: Option<&str>,
+) -> anyhow::Result<Arc<SqliteSecretStore>> {
+    let path = key.map_or_else(|| data_dir().join("keys").join("secrets.key"), Into::into);
+    let key = SecretKey::load_or_create(&path)
+        .map_err(|e| anyhow::anyhow!("secret key {}: {e}", path.display()))?;
+    Ok(Arc::new(SqliteSecretStore::new(Arc::clone(repo), key)))
+}
+
 /// Creates a `KamiRuntime` with the given concurrency and cache settings.
 ///
+/// Tools with filesystem access get a directory under `<data_dir>/data/`,
+/// per call if `[sandbox] scratch_dirs` is set, and compiled components
+/// persist under `<data_dir>/cache/components/`.
+/// The instance pool size and the number of dedicated execution threads
+/// come from `[runtime] pool_size` and `execution_threads` in
+/// [`load_settings`]. Every tool is narrowed by the host policy in
+/// `<data_dir>/policy.toml`, if present.
+///
 /// # Errors
 ///
-/// Returns an error if the runtime cannot be initialised.
+/// Returns an error if the host policy is invalid or the runtime cannot be
+/// initialised.
 pub fn create_runtime(
     repo: Arc<dyn ToolRepository>,
     concurrency: usize,
     cache_size: usize,
 ) -> anyhow::Result<KamiRuntime> {
+    let settings = load_settings();
     let config = RuntimeConfig {
         cache_size,
         max_concurrent: concurrency,
         epoch_interruption: true,
+        pool_size: settings.runtime.pool_size,
+        execution_threads: settings.runtime.execution_threads,
+        sandbox_root: Some(tool_data_dir()),
+        sandbox_mode: sandbox_mode(&settings),
+        artifact_cache_dir: Some(data_dir().join("cache").join("components")),
         ..RuntimeConfig::default()
     };
-    KamiRuntime::new(config, repo).map_err(|e| anyhow::anyhow!("runtime init error: {e}"))
+    let checker = host_policy_checker()?;
+    let runtime =
+        KamiRuntime::new(config, repo).map_err(|e| anyhow::anyhow!("runtime init error: {e}"))?;
+    Ok(runtime.with_capability_checker(Arc::new(checker)))
 }
 
-/// Returns the KAMI data directory (defaults to `~/.kami`).
+/// Returns a checker enforcing the host policy in `<data_dir>/policy.toml`.
 ///
-/// Uses `KAMI_DATA_DIR` if set, otherwise `$HOME/.kami`.
-pub fn data_dir() -> std::path::PathBuf {
-    if let Ok(dir) = std::env::var("KAMI_DATA_DIR") {
-        return std::path::PathBuf::from(dir);
-    }
-    dirs_or_fallback().join(".kami")
+/// # Errors
+///
+/// Returns an error if the policy file cannot be read or is invalid.
+pub fn host_policy_checker() -> anyhow::Result<PolicyChecker> {
+    let path = data_dir().join("policy.toml");
+    let policy = load_host_policy(&path)?;
+    PolicyChecker::new(policy).map_err(|e| anyhow::anyhow!("host policy {}: {e}", path.display()))
 }
 
-/// Returns the plugins directory (`<data_dir>/plugins/`).
-pub fn plugins_dir() -> std::path::PathBuf {
-    data_dir().join("plugins")
+/// Loads `<data_dir>/config.toml` merged with `KAMI_` env vars.
+///
+/// Falls back to defaults (with a warning) if the configuration is invalid.
+pub fn load_settings() -> KamiConfig {
+    let path = data_dir().join("config.toml");
+    load_config(path.to_str()).unwrap_or_else(|e| {
+        tracing::warn!(%e, "invalid configuration, using defaults");
+        KamiConfig::default()
+    })
 }
 
-/// Returns a home directory or a reasonable fallback.
-fn dirs_or_fallback() -> std::path::PathBuf {
-    std::env::var("HOME")
-        .or_else(|_| std::env::var("USERPROFILE"))
-        .map(std::path::PathBuf::from)
-        .unwrap_or_else(|_| std::path::PathBuf::from("."))
+/// Returns the sandbox directory mode selected by `[sandbox] scratch_dirs`.
+fn sandbox_mode(settings: &KamiConfig) -> SandboxDirMode {
+    match settings.sandbox.scratch_dirs {
+        true => SandboxDirMode::Scratch,
+        false => SandboxDirMode::Persistent,
+    }
 }
 
 #[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[tokio::test]
-    async fn open_repository_in_memory_equivalent() {
-        let repo = SqliteToolRepository::open_in_memory();
-        assert!(repo.is_ok());
-    }
-
-    #[test]
-    fn create_runtime_with_defaults() {
-        let repo = Arc::new(SqliteToolRepository::open_in_memory().expect("open"));
-        let runtime = create_runtime(repo, 4, 32);
-        assert!(runtime.is_ok());
-    }
-
-    #[test]
-    fn open_repository_with_temp_path() {
-        let dir = std::env::temp_dir().join("kami_cli_test");
-        let _ = std::fs::create_dir_all(&dir);
-        let db = dir.join("test_shared.db");
-        let _ = std::fs::remove_file(&db);
-        let path = db.to_str().expect("utf8").to_string();
-        let repo = open_repository(&Some(path));
-        assert!(repo.is_ok());
-        let _ = std::fs::remove_file(&db);
-    }
-
-    #[test]
-    fn open_repository_defaults_when_none() {
-        // Uses default path (.kami/registry.db)
-        let result = open_repository(&None);
-        // May fail on CI without permissions, but should not panic
-        let _ = result;
-    }
-
-    #[test]
-    fn data_dir_returns_a_path() {
-        let d = data_dir();
-        assert!(!d.as_os_str().is_empty());
-    }
-
-    #[test]
-    fn plugins_dir_is_within_data_dir() {
-        let p = plugins_dir();
-        assert_eq!(p.file_name().and_then(|s| s.to_str()), Some("plugins"));
-    }
-
-    #[test]
-    fn dirs_or_fallback_returns_nonempty() {
-        let d = dirs_or_fallback();
-        assert!(!d.as_os_str().is_empty());
-    }
-}
+#[path = "shared_tests.rs"]
+mod tests;
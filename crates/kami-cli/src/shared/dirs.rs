@@ -0,0 +1,29 @@
+//! Locations of the KAMI data directory and its subdirectories.
+
+/// Returns the KAMI data directory (defaults to `~/.kami`).
+///
+/// Uses `KAMI_DATA_DIR` if set, otherwise `$HOME/.kami`.
+pub fn data_dir() -> std::path::PathBuf {
+    if let Ok(dir) = std::env::var("KAMI_DATA_DIR") {
+        return std::path::PathBuf::from(dir);
+    }
+    dirs_or_fallback().join(".kami")
+}
+
+/// Returns the plugins directory (`<data_dir>/plugins/`).
+pub fn plugins_dir() -> std::path::PathBuf {
+    data_dir().join("plugins")
+}
+
+/// Returns the root of per-tool sandbox directories (`<data_dir>/data/`).
+pub fn tool_data_dir() -> std::path::PathBuf {
+    data_dir().join("data")
+}
+
+/// Returns a home directory or a reasonable fallback.
+pub(super) fn dirs_or_fallback() -> std::path::PathBuf {
+    std::env::var("HOME")
+        .or_else(|_| std::env::var("USERPROFILE"))
+        .map(std::path::PathBuf::from)
+        .unwrap_or_else(|_| std::path::PathBuf::from("."))
+}
@@ -0,0 +1,74 @@
+//! Tests for shared CLI helpers.
+
+use super::*;
+
+#[tokio::test]
+async fn open_repository_in_memory_equivalent() {
+    let repo = SqliteToolRepository::open_in_memory();
+    assert!(repo.is_ok());
+}
+
+#[test]
+fn create_runtime_with_defaults() {
+    let repo = Arc::new(SqliteToolRepository::open_in_memory().expect("open"));
+    let runtime = create_runtime(repo, 4, 32);
+    assert!(runtime.is_ok());
+}
+
+#[test]
+fn open_repository_with_temp_path() {
+    let dir = std::env::temp_dir().join("kami_cli_test");
+    let _ = std::fs::create_dir_all(&dir);
+    let db = dir.join("test_shared.db");
+    let _ = std::fs::remove_file(&db);
+    let path = db.to_str().expect("utf8").to_string();
+    let repo = open_repository(&Some(path));
+    assert!(repo.is_ok());
+    let _ = std::fs::remove_file(&db);
+}
+
+#[test]
+fn open_repository_defaults_when_none() {
+    // Uses default path (.kami/registry.db)
+    let result = open_repository(&None);
+    // May fail on CI without permissions, but should not panic
+    let _ = result;
+}
+
+#[test]
+fn data_dir_returns_a_path() {
+    let d = data_dir();
+    assert!(!d.as_os_str().is_empty());
+}
+
+#[test]
+fn plugins_dir_is_within_data_dir() {
+    let p = plugins_dir();
+    assert_eq!(p.file_name().and_then(|s| s.to_str()), Some("plugins"));
+}
+
+#[test]
+fn load_settings_returns_usable_defaults() {
+    let settings = load_settings();
+    assert!(settings.runtime.max_concurrent > 0);
+}
+
+#[test]
+fn scratch_dirs_setting_selects_scratch_mode() {
+    let mut settings = KamiConfig::default();
+    assert_eq!(sandbox_mode(&settings), SandboxDirMode::Persistent);
+    settings.sandbox.scratch_dirs = true;
+    assert_eq!(sandbox_mode(&settings), SandboxDirMode::Scratch);
+}
+
+#[test]
+fn tool_data_dir_is_within_data_dir() {
+    let d = tool_data_dir();
+    assert_eq!(d.file_name().and_then(|s| s.to_str()), Some("data"));
+}
+
+#[test]
+fn dirs_or_fallback_returns_nonempty() {
+    let d = dirs::dirs_or_fallback();
+    assert!(!d.as_os_str().is_empty());
+}
@@ -4,9 +4,16 @@
 //! Supports layered config: defaults -> file -> env vars.
 
 pub mod loader;
+mod manifest_arguments;
+pub mod manifest_defaults;
 pub mod manifest_loader;
+pub mod policy_loader;
 pub mod schema;
 
 pub use loader::{load_config, ConfigError};
+pub use manifest_defaults::{
+    parse_tool_manifest_file_with_defaults, parse_tool_manifest_with_defaults,
+};
 pub use manifest_loader::{parse_tool_manifest, parse_tool_manifest_file, ManifestError};
-pub use schema::KamiConfig;
+pub use policy_loader::{load_host_policy, parse_host_policy};
+pub use schema::{KamiConfig, SandboxConfig};
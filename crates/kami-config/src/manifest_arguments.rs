@@ -0,0 +1,63 @@
+//! Checks of `[[mcp.arguments]]` and `[[mcp.output]]` definitions that
+//! parse but can never be satisfied: an invalid `pattern` or a `minimum`
+//! above the `maximum`.
+//!
+//! A `default` its own schema rejects is only warned about: defaults were
+//! untyped strings before, so existing manifests declare e.g.
+//! `default = "10"` for an integer and must keep loading.
+
+use serde_json::{Map, Value};
+
+use kami_protocol::schema::{build_input_schema, CompiledSchema};
+use kami_types::{ArgumentConstraints, ToolArgument};
+
+use crate::manifest_loader::ManifestError;
+
+/// Checks the fields of the `section` (e.g. `mcp.arguments`) of a manifest.
+///
+/// # Errors
+///
+/// Returns `ManifestError::Parse` naming the first unsatisfiable field.
+pub(crate) fn check_fields(section: &str, fields: &[ToolArgument]) -> Result<(), ManifestError> {
+    let invalid = |message: String| ManifestError::Parse(format!("[[{section}]] {message}"));
+    CompiledSchema::compile(build_input_schema(fields)).map_err(|e| invalid(e.to_string()))?;
+    for field in fields {
+        check_field(field).map_err(|m| invalid(format!("'{}' {m}", field.name)))?;
+    }
+    Ok(())
+}
+
+fn check_field(field: &ToolArgument) -> Result<(), String> {
+    check_constraints(&field.constraints)?;
+    let Some(default) = &field.default else {
+        return Ok(());
+    };
+    let schema = build_input_schema(std::slice::from_ref(field));
+    let compiled = CompiledSchema::compile(schema).map_err(|e| e.to_string())?;
+    let mut value = Map::new();
+    value.insert(field.name.clone(), default.clone());
+    if let Some(violation) = compiled.validate(&Value::Object(value)).first() {
+        tracing::warn!(
+            field = %field.name,
+            %default,
+            "default does not match its schema: {}",
+            violation.message
+        );
+    }
+    Ok(())
+}
+
+fn check_constraints(constraints: &ArgumentConstraints) -> Result<(), String> {
+    if let (Some(min), Some(max)) = (&constraints.minimum, &constraints.maximum) {
+        if min.as_f64() > max.as_f64() {
+            return Err(format!("has minimum {min} greater than maximum {max}"));
+        }
+    }
+    if let Some(items) = &constraints.items {
+        check_constraints(&items.constraints).map_err(|m| format!("items {m}"))?;
+    }
+    for property in &constraints.properties {
+        check_field(property).map_err(|m| format!("property '{}' {m}", property.name))?;
+    }
+    Ok(())
+}
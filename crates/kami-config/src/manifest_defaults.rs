@@ -0,0 +1,60 @@
+//! Host defaults for the limits a `tool.toml` leaves out.
+//!
+//! A manifest that omits `max_memory_mb` or `max_fuel` gets the operator's
+//! `[sandbox] default_max_memory_mb` and `default_max_fuel` instead of the
+//! built-in values. Applied when a tool is installed or updated.
+
+use std::path::Path;
+
+use kami_types::ToolManifest;
+
+use crate::manifest_loader::{parse_tool_manifest, read_manifest, ManifestError};
+use crate::schema::SandboxConfig;
+
+/// Parses a `tool.toml` string, taking the memory and fuel limits its
+/// `[security]` section leaves out from `defaults`.
+///
+/// # Errors
+///
+/// Same as [`parse_tool_manifest`].
+pub fn parse_tool_manifest_with_defaults(
+    content: &str,
+    defaults: &SandboxConfig,
+) -> Result<ToolManifest, ManifestError> {
+    let mut manifest = parse_tool_manifest(content)?;
+    let raw: RawLimitKeys =
+        toml::from_str(content).map_err(|e| ManifestError::Parse(e.to_string()))?;
+    let limits = &mut manifest.security.limits;
+    limits.max_memory_mb = raw
+        .security
+        .max_memory_mb
+        .unwrap_or(defaults.default_max_memory_mb);
+    limits.max_fuel = raw.security.max_fuel.unwrap_or(defaults.default_max_fuel);
+    Ok(manifest)
+}
+
+/// Reads a `tool.toml` file, taking omitted limits from `defaults`.
+///
+/// # Errors
+///
+/// Returns `ManifestError::Io` if the file cannot be read.
+/// Returns `ManifestError::Parse` if the content is invalid.
+pub fn parse_tool_manifest_file_with_defaults(
+    path: &Path,
+    defaults: &SandboxConfig,
+) -> Result<ToolManifest, ManifestError> {
+    parse_tool_manifest_with_defaults(&read_manifest(path)?, defaults)
+}
+
+/// The limits a `[security]` section sets explicitly.
+#[derive(Debug, serde::Deserialize)]
+struct RawLimitKeys {
+    #[serde(default)]
+    security: RawLimits,
+}
+
+#[derive(Debug, Default, serde::Deserialize)]
+struct RawLimits {
+    max_memory_mb: Option<u32>,
+    max_fuel: Option<u64>,
+}
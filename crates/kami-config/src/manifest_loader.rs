@@ -8,6 +8,8 @@ use std::path::Path;
 
 use kami_types::{SecurityConfig, ToolArgument, ToolId, ToolManifest, ToolVersion};
 
+use crate::manifest_arguments::check_fields;
+
 /// Error type for manifest parsing failures.
 #[derive(Debug, thiserror::Error)]
 pub enum ManifestError {
@@ -30,7 +32,9 @@ pub enum ManifestError {
 /// # Errors
 ///
 /// Returns `ManifestError::Parse` if the TOML is malformed or missing
-/// required sections.
+/// required sections, or if an argument or output field can never be
+/// satisfied (invalid `pattern`, `minimum` above `maximum`, or a `default`
+/// not matching its type, bounds or `enum`).
 pub fn parse_tool_manifest(content: &str) -> Result<ToolManifest, ManifestError> {
     let raw: RawToolToml =
         toml::from_str(content).map_err(|e| ManifestError::Parse(e.to_string()))?;
@@ -42,6 +46,8 @@ pub fn parse_tool_manifest(content: &str) -> Result<ToolManifest, ManifestError>
         .version
         .parse()
         .map_err(|e: kami_types::KamiError| ManifestError::Parse(e.to_string()))?;
+    check_fields("mcp.arguments", &raw.mcp.arguments)?;
+    check_fields("mcp.output", &raw.mcp.output)?;
 
     Ok(ToolManifest {
         id,
@@ -50,6 +56,7 @@ pub fn parse_tool_manifest(content: &str) -> Result<ToolManifest, ManifestError>
         wasm: raw.tool.wasm,
         description: raw.mcp.description,
         arguments: raw.mcp.arguments,
+        output: raw.mcp.output,
         security: raw.security,
         // Populated at install time by hashing the WASM file on disk;
         // not present in the tool.toml source file itself.
@@ -66,11 +73,15 @@ pub fn parse_tool_manifest(content: &str) -> Result<ToolManifest, ManifestError>
 /// Returns `ManifestError::Io` if the file cannot be read.
 /// Returns `ManifestError::Parse` if the content is invalid.
 pub fn parse_tool_manifest_file(path: &Path) -> Result<ToolManifest, ManifestError> {
-    let content = std::fs::read_to_string(path).map_err(|e| ManifestError::Io {
+    parse_tool_manifest(&read_manifest(path)?)
+}
+
+/// Reads the content of a `tool.toml` file.
+pub(crate) fn read_manifest(path: &Path) -> Result<String, ManifestError> {
+    std::fs::read_to_string(path).map_err(|e| ManifestError::Io {
         path: path.display().to_string(),
         source: e,
-    })?;
-    parse_tool_manifest(&content)
+    })
 }
 
 // ---------------------------------------------------------------------------
@@ -104,4 +115,6 @@ struct RawMcpSection {
     description: String,
     #[serde(default)]
     arguments: Vec<ToolArgument>,
+    #[serde(default)]
+    output: Vec<ToolArgument>,
 }
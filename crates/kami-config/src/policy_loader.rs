@@ -0,0 +1,43 @@
+//! Host policy loader — reads the operator's `policy.toml`.
+//!
+//! ```toml
+//! deny_hosts = ["*.internal", "169.254.0.0/16"]
+//! deny_env = ["AWS_SECRET_ACCESS_KEY"]
+//! max_fs_access = "read-only"
+//! trusted_keys = ["3b6a27bcceb6a42d62a3a8d02a6f0d73653215771de243a63ac048a18b59da29"]
+//!
+//! [unsigned]
+//! max_memory_mb = 128
+//! ```
+
+use std::path::Path;
+
+use kami_types::HostPolicy;
+
+use crate::loader::ConfigError;
+
+/// Parses a `policy.toml` string. Unknown keys are rejected, so a typo
+/// cannot silently weaken the policy.
+///
+/// # Errors
+///
+/// Returns `ConfigError::Load` if the TOML is malformed.
+pub fn parse_host_policy(content: &str) -> Result<HostPolicy, ConfigError> {
+    toml::from_str(content).map_err(|e| ConfigError::Load(format!("invalid host policy: {e}")))
+}
+
+/// Loads the host policy at `path`; a missing file is an empty policy.
+///
+/// # Errors
+///
+/// Returns `ConfigError::Load` if the file cannot be read or parsed.
+pub fn load_host_policy(path: &Path) -> Result<HostPolicy, ConfigError> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => parse_host_policy(&content),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(HostPolicy::default()),
+        Err(e) => Err(ConfigError::Load(format!(
+            "cannot read host policy '{}': {e}",
+            path.display()
+        ))),
+    }
+}
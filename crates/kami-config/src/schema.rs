@@ -33,6 +33,9 @@ pub struct RuntimeConfig {
     /// Default execution timeout in seconds.
     #[serde(default = "default_timeout_secs")]
     pub default_timeout_secs: u64,
+    /// Worker threads dedicated to tool execution (0 = share the host runtime).
+    #[serde(default)]
+    pub execution_threads: usize,
 }
 
 impl RuntimeConfig {
@@ -48,6 +51,7 @@ impl Default for RuntimeConfig {
             max_concurrent: default_max_concurrent(),
             pool_size: default_pool_size(),
             default_timeout_secs: default_timeout_secs(),
+            execution_threads: 0,
         }
     }
 }
@@ -65,12 +69,16 @@ fn default_timeout_secs() -> u64 {
 /// Sandbox default settings.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SandboxConfig {
-    /// Default maximum memory per tool (MB).
+    /// Maximum memory (MB) of tools whose manifest sets none.
     #[serde(default = "default_max_memory")]
     pub default_max_memory_mb: u32,
-    /// Default maximum fuel per tool.
+    /// Maximum fuel of tools whose manifest sets none.
     #[serde(default = "default_max_fuel")]
     pub default_max_fuel: u64,
+    /// Give each call a fresh directory, deleted afterwards, instead of
+    /// one directory per tool kept across calls.
+    #[serde(default)]
+    pub scratch_dirs: bool,
 }
 
 impl Default for SandboxConfig {
@@ -78,6 +86,7 @@ impl Default for SandboxConfig {
         Self {
             default_max_memory_mb: default_max_memory(),
             default_max_fuel: default_max_fuel(),
+            scratch_dirs: false,
         }
     }
 }
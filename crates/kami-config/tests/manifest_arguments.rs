@@ -0,0 +1,142 @@
+//! Integration tests for constrained and nested `[[mcp.arguments]]` and
+//! `[[mcp.output]]` fields.
+
+use kami_config::manifest_loader::parse_tool_manifest;
+use serde_json::json;
+
+const RICH: &str = r#"
+[tool]
+id = "dev.example.search"
+name = "search"
+version = "1.0.0"
+wasm = "search.wasm"
+
+[mcp]
+description = "Searches documents"
+
+[[mcp.arguments]]
+name = "mode"
+type = "string"
+description = "Search mode"
+enum = ["exact", "fuzzy"]
+default = "exact"
+
+[[mcp.arguments]]
+name = "limit"
+type = "integer"
+description = "Maximum results"
+minimum = 1
+maximum = 100
+default = 10
+
+[[mcp.arguments]]
+name = "tags"
+type = "array"
+description = "Tags to match"
+
+[mcp.arguments.items]
+type = "string"
+pattern = "^[a-z]+$"
+
+[[mcp.arguments]]
+name = "filter"
+type = "object"
+description = "Extra filters"
+required = true
+
+[[mcp.arguments.properties]]
+name = "since"
+type = "string"
+format = "date-time"
+required = true
+
+[[mcp.output]]
+name = "hits"
+type = "array"
+description = "Matching document IDs"
+required = true
+
+[mcp.output.items]
+type = "string"
+"#;
+
+#[test]
+fn parse_constrained_arguments() {
+    let m = parse_tool_manifest(RICH).expect("should parse");
+    assert_eq!(m.arguments.len(), 4);
+
+    let mode = &m.arguments[0];
+    assert_eq!(
+        mode.constraints.allowed,
+        Some(vec![json!("exact"), json!("fuzzy")])
+    );
+    assert_eq!(mode.default, Some(json!("exact")));
+
+    let limit = &m.arguments[1];
+    assert_eq!(limit.default, Some(json!(10)));
+    assert_eq!(limit.constraints.minimum, Some(1.into()));
+    assert_eq!(limit.constraints.maximum, Some(100.into()));
+}
+
+#[test]
+fn parse_array_items_and_nested_properties() {
+    let m = parse_tool_manifest(RICH).expect("should parse");
+
+    let items = m.arguments[2].constraints.items.as_ref().expect("items");
+    assert_eq!(items.item_type, "string");
+    assert_eq!(items.constraints.pattern.as_deref(), Some("^[a-z]+$"));
+
+    let filter = &m.arguments[3];
+    assert!(filter.required);
+    let since = &filter.constraints.properties[0];
+    assert_eq!(since.name, "since");
+    assert!(since.required);
+    assert!(since.description.is_empty());
+    assert_eq!(since.constraints.format.as_deref(), Some("date-time"));
+}
+
+#[test]
+fn parse_output_fields() {
+    let m = parse_tool_manifest(RICH).expect("should parse");
+    assert_eq!(m.output.len(), 1);
+    assert_eq!(m.output[0].name, "hits");
+    let items = m.output[0].constraints.items.as_ref().expect("items");
+    assert_eq!(items.item_type, "string");
+}
+
+/// Parses a manifest declaring the single argument `field` and returns the
+/// error message.
+fn rejected(field: &str) -> String {
+    let toml = format!(
+        "[tool]\nid = \"dev.example.x\"\nname = \"x\"\nversion = \"1.0.0\"\nwasm = \"x.wasm\"\n\
+         [mcp]\ndescription = \"x\"\n[[mcp.arguments]]\nname = \"a\"\n{field}\n"
+    );
+    match parse_tool_manifest(&toml) {
+        Ok(_) => panic!("{field} must be rejected"),
+        Err(e) => e.to_string(),
+    }
+}
+
+#[test]
+fn invalid_pattern_is_rejected() {
+    let err = rejected("type = \"string\"\npattern = \"(\"");
+    assert!(err.contains("invalid pattern ("), "{err}");
+}
+
+#[test]
+fn minimum_above_maximum_is_rejected() {
+    let err = rejected("type = \"integer\"\nminimum = 10\nmaximum = 1");
+    assert!(
+        err.contains("'a' has minimum 10 greater than maximum 1"),
+        "{err}"
+    );
+}
+
+#[test]
+fn string_default_of_older_manifests_still_loads() {
+    let toml = "[tool]\nid = \"dev.example.x\"\nname = \"x\"\nversion = \"1.0.0\"\n\
+                wasm = \"x.wasm\"\n[mcp]\ndescription = \"x\"\n[[mcp.arguments]]\n\
+                name = \"a\"\ntype = \"integer\"\ndefault = \"10\"\n";
+    let m = parse_tool_manifest(toml).expect("mismatched default only warns");
+    assert_eq!(m.arguments[0].default, Some(json!("10")));
+}
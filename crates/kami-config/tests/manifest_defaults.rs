@@ -0,0 +1,60 @@
+//! Host `[sandbox]` defaults fill the limits a manifest leaves out.
+
+use kami_config::{parse_tool_manifest, parse_tool_manifest_with_defaults, SandboxConfig};
+
+const HEADER: &str = r#"
+[tool]
+id = "dev.example.defaults"
+name = "defaults"
+version = "1.0.0"
+wasm = "defaults.wasm"
+
+[mcp]
+description = "Uses host defaults"
+"#;
+
+fn host() -> SandboxConfig {
+    SandboxConfig {
+        default_max_memory_mb: 16,
+        default_max_fuel: 5_000,
+        ..SandboxConfig::default()
+    }
+}
+
+#[test]
+fn omitted_limits_take_host_defaults() {
+    let content = format!("{HEADER}\n[security]\nfs_access = \"none\"\n");
+
+    let manifest = parse_tool_manifest_with_defaults(&content, &host()).expect("parse");
+
+    assert_eq!(manifest.security.limits.max_memory_mb, 16);
+    assert_eq!(manifest.security.limits.max_fuel, 5_000);
+}
+
+#[test]
+fn manifest_without_security_section_takes_host_defaults() {
+    let manifest = parse_tool_manifest_with_defaults(HEADER, &host()).expect("parse");
+
+    assert_eq!(manifest.security.limits.max_memory_mb, 16);
+}
+
+#[test]
+fn explicit_limits_are_kept() {
+    let content = format!("{HEADER}\n[security]\nmax_memory_mb = 128\nmax_fuel = 42\n");
+
+    let manifest = parse_tool_manifest_with_defaults(&content, &host()).expect("parse");
+
+    assert_eq!(manifest.security.limits.max_memory_mb, 128);
+    assert_eq!(manifest.security.limits.max_fuel, 42);
+}
+
+#[test]
+fn built_in_defaults_match_the_plain_parser() {
+    let plain = parse_tool_manifest(HEADER).expect("plain");
+    let defaulted =
+        parse_tool_manifest_with_defaults(HEADER, &SandboxConfig::default()).expect("defaulted");
+
+    let (plain, defaulted) = (plain.security.limits, defaulted.security.limits);
+    assert_eq!(plain.max_memory_mb, defaulted.max_memory_mb);
+    assert_eq!(plain.max_fuel, defaulted.max_fuel);
+}
@@ -50,6 +50,7 @@ description = "A minimal tool"
 "#;
     let m = parse_tool_manifest(toml).expect("should parse");
     assert!(m.arguments.is_empty());
+    assert!(m.output.is_empty());
     assert!(m.security.net_allow_list.is_empty());
 }
 
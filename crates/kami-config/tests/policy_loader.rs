@@ -0,0 +1,51 @@
+//! Integration tests for policy_loader.
+
+use std::path::Path;
+
+use kami_config::{load_host_policy, parse_host_policy};
+use kami_types::FsAccess;
+
+const SAMPLE: &str = r#"
+deny_hosts = ["*.internal", "10.0.0.0/8"]
+deny_private_networks = true
+max_fs_access = "read-only"
+deny_env = ["AWS_SECRET_ACCESS_KEY"]
+
+[limits]
+max_execution_ms = 10000
+
+[unsigned]
+max_memory_mb = 128
+"#;
+
+#[test]
+fn parse_full_policy() {
+    let policy = parse_host_policy(SAMPLE).expect("parse");
+    assert_eq!(policy.deny_hosts, ["*.internal", "10.0.0.0/8"]);
+    assert!(policy.deny_private_networks);
+    assert_eq!(policy.max_fs_access, Some(FsAccess::ReadOnly));
+    assert_eq!(policy.deny_env, ["AWS_SECRET_ACCESS_KEY"]);
+    assert_eq!(policy.limits.max_execution_ms, Some(10_000));
+    assert_eq!(policy.limits.max_memory_mb, None);
+    assert_eq!(policy.unsigned.max_memory_mb, Some(128));
+}
+
+#[test]
+fn empty_policy_restricts_nothing() {
+    let policy = parse_host_policy("").expect("parse");
+    assert!(policy.deny_hosts.is_empty());
+    assert_eq!(policy.max_fs_access, None);
+    assert_eq!(policy.unsigned.max_memory_mb, None);
+}
+
+#[test]
+fn unknown_keys_are_rejected() {
+    assert!(parse_host_policy("deny_host = [\"*.internal\"]").is_err());
+    assert!(parse_host_policy("[unsigned]\nmax_memory = 1").is_err());
+}
+
+#[test]
+fn missing_file_is_empty_policy() {
+    let policy = load_host_policy(Path::new("/nonexistent/kami/policy.toml")).expect("load");
+    assert!(policy.deny_hosts.is_empty());
+}
@@ -11,6 +11,7 @@ fn kami_config_default_values() {
     assert_eq!(config.runtime.max_concurrent, 10);
     assert_eq!(config.runtime.pool_size, 5);
     assert_eq!(config.runtime.default_timeout_secs, 30);
+    assert_eq!(config.runtime.execution_threads, 0);
     assert_eq!(config.sandbox.default_max_memory_mb, 64);
     assert_eq!(config.sandbox.default_max_fuel, 1_000_000);
     assert_eq!(config.registry.database_path, "kami.db");
@@ -35,6 +36,7 @@ fn runtime_timeout_returns_correct_duration() {
         max_concurrent: 1,
         pool_size: 1,
         default_timeout_secs: 45,
+        execution_threads: 0,
     };
     assert_eq!(rt.timeout(), Duration::from_secs(45));
 }
@@ -62,6 +64,14 @@ fn sandbox_default_values() {
     let sb = SandboxConfig::default();
     assert_eq!(sb.default_max_memory_mb, 64);
     assert_eq!(sb.default_max_fuel, 1_000_000);
+    assert!(!sb.scratch_dirs);
+}
+
+#[test]
+fn sandbox_scratch_dirs_parses() {
+    let json = r#"{"sandbox":{"scratch_dirs":true}}"#;
+    let config: KamiConfig = serde_json::from_str(json).expect("parse");
+    assert!(config.sandbox.scratch_dirs);
 }
 
 #[test]
@@ -0,0 +1,147 @@
+//! Serialized (pre-compiled) component artifacts.
+//!
+//! Lets callers persist Cranelift output and reload it without
+//! recompiling. Artifacts are only valid for the exact wasmtime build and
+//! engine configuration that produced them; [`engine_fingerprint`]
+//! captures both so cache keys change whenever either does.
+
+use std::hash::{Hash, Hasher};
+
+use sha2::{Digest, Sha256};
+use wasmtime::component::Component;
+use wasmtime::Engine;
+
+use crate::error::EngineError;
+
+/// Version of the fingerprint encoding; bump it to invalidate every key.
+const FINGERPRINT_FORMAT: u32 = 1;
+
+/// Fingerprint of the wasmtime version and compilation settings of `engine`.
+///
+/// SHA-256 over a versioned encoding: the format number, the target
+/// architecture and OS, then wasmtime's compatibility data (its version,
+/// compiler flags, tunables and features) with every integer written
+/// little-endian. Unlike `DefaultHasher`, this does not change between
+/// Rust releases.
+pub fn engine_fingerprint(engine: &Engine) -> String {
+    let mut hasher = Sha256Hasher(Sha256::new());
+    hasher.write_u32(FINGERPRINT_FORMAT);
+    std::env::consts::ARCH.hash(&mut hasher);
+    std::env::consts::OS.hash(&mut hasher);
+    engine.precompile_compatibility_hash().hash(&mut hasher);
+    let digest = hasher.0.finalize();
+    digest.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Feeds `Hash` input into SHA-256 with a fixed byte order.
+struct Sha256Hasher(Sha256);
+
+impl Hasher for Sha256Hasher {
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.update(bytes);
+    }
+
+    fn write_u16(&mut self, n: u16) {
+        self.write(&n.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, n: u32) {
+        self.write(&n.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, n: u64) {
+        self.write(&n.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, n: usize) {
+        self.write_u64(n as u64);
+    }
+
+    /// Unused: the digest is read from the inner state.
+    fn finish(&self) -> u64 {
+        0
+    }
+}
+
+/// Serializes a compiled component to bytes.
+///
+/// # Errors
+///
+/// Returns `EngineError::Compilation` if serialization fails.
+pub fn serialize_component(component: &Component) -> Result<Vec<u8>, EngineError> {
+    component.serialize().map_err(|e| EngineError::Compilation {
+        reason: "failed to serialize component".to_string(),
+        source: e,
+    })
+}
+
+/// Loads a component from bytes produced by [`serialize_component`].
+///
+/// Callers must only pass bytes they produced themselves with an engine of
+/// the same [`engine_fingerprint`], read from storage no other user can
+/// write, and should verify a checksum first: wasmtime rejects
+/// incompatible headers but trusts the machine code.
+///
+/// # Errors
+///
+/// Returns `EngineError::Compilation` if the artifact is rejected.
+pub fn deserialize_component(engine: &Engine, bytes: &[u8]) -> Result<Component, EngineError> {
+    // SAFETY: `Component::deserialize` maps `bytes` as executable code, so
+    // they must be the unmodified output of `serialize_component`. The
+    // only caller, `kami_runtime::ArtifactCache::load`, reads them from a
+    // directory it has checked is owned by the current user and not
+    // writable by group or others, so only this user can have written
+    // them; the SHA-256 prefix it checks catches truncation and
+    // corruption, and wasmtime rejects artifacts whose version/config
+    // header does not match `engine`.
+    let component = unsafe { Component::deserialize(engine, bytes) };
+    component.map_err(|e| EngineError::Compilation {
+        reason: "failed to load precompiled component".to_string(),
+        source: e,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasmtime::Config;
+
+    fn engine(fuel: bool) -> Engine {
+        let mut config = Config::new();
+        config.wasm_component_model(true).consume_fuel(fuel);
+        Engine::new(&config).expect("engine")
+    }
+
+    #[test]
+    fn serialize_roundtrip() {
+        let engine = engine(true);
+        let component = Component::new(&engine, "(component)").expect("compile");
+        let bytes = serialize_component(&component).expect("serialize");
+        assert!(deserialize_component(&engine, &bytes).is_ok());
+    }
+
+    #[test]
+    fn fingerprint_depends_on_config() {
+        assert_eq!(
+            engine_fingerprint(&engine(true)),
+            engine_fingerprint(&engine(true))
+        );
+        assert_ne!(
+            engine_fingerprint(&engine(true)),
+            engine_fingerprint(&engine(false))
+        );
+    }
+
+    #[test]
+    fn fingerprint_is_a_sha256_hex_digest() {
+        let fingerprint = engine_fingerprint(&engine(true));
+        assert_eq!(fingerprint.len(), 64);
+        assert!(fingerprint.chars().all(|c| c.is_ascii_hexdigit()));
+    }
+
+    #[test]
+    fn garbage_bytes_are_rejected() {
+        let result = deserialize_component(&engine(true), b"not an artifact");
+        assert!(matches!(result, Err(EngineError::Compilation { .. })));
+    }
+}
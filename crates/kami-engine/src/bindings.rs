@@ -1,7 +1,12 @@
 //! Host-side bindings for the `kami-tool` WIT world.
 //!
 //! Uses `wasmtime::component::bindgen!` to generate typed accessors
-//! for tool exports and host import implementations.
+//! for tool exports and host import implementations. The host services
+//! of the `kami-tool-ext` world are bound in [`ext`], and the
+//! `kami-tool-v2` world, adding `run-v2`, in [`v2`].
+
+pub mod ext;
+pub mod v2;
 
 use core::pin::Pin;
 
@@ -10,6 +15,7 @@ use wasmtime::Store;
 
 use crate::error::EngineError;
 use crate::state::HostState;
+use crate::trap::classify_trap;
 
 wasmtime::component::bindgen!({
     world: "kami-tool",
@@ -64,7 +70,9 @@ pub async fn instantiate_tool(
 ///
 /// # Errors
 ///
-/// Returns `EngineError::Trap` if execution fails.
+/// Returns `EngineError::FuelExhausted`, `EngineError::MemoryLimitExceeded`
+/// or `EngineError::EpochDeadline` if a limit stops execution, and
+/// `EngineError::Trap` for any other failure.
 pub async fn call_run(
     store: &mut Store<HostState>,
     tool: &KamiTool,
@@ -73,9 +81,7 @@ pub async fn call_run(
     tool.kami_tool_tool()
         .call_run(&mut *store, input)
         .await
-        .map_err(|e: wasmtime::Error| EngineError::Trap {
-            message: e.to_string(),
-        })
+        .map_err(classify_trap)
 }
 
 /// Calls the `describe` export on a kami-tool component.
@@ -93,7 +99,5 @@ pub async fn call_describe(
     tool.kami_tool_tool()
         .call_describe(&mut *store)
         .await
-        .map_err(|e: wasmtime::Error| EngineError::Trap {
-            message: e.to_string(),
-        })
+        .map_err(classify_trap)
 }
@@ -0,0 +1,73 @@
+//! Host-side bindings for the `kami-tool-ext` WIT world.
+//!
+//! Adds the `progress`, `kv` and `secrets` imports to those of
+//! `kami-tool`, whose `host` interface is shared. Its linker serves
+//! components of every KAMI world.
+
+use core::future::Future;
+use core::pin::Pin;
+
+use crate::progress::Progress;
+use crate::secret::SecretError;
+use crate::state::HostState;
+
+wasmtime::component::bindgen!({
+    world: "kami-tool-ext",
+    path: "../../wit",
+    async: true,
+    with: {
+        "kami:tool/host": super::kami::tool::host,
+    },
+});
+
+/// Implements the `kami:tool/progress` interface for guest→host calls.
+impl kami::tool::progress::Host for HostState {
+    fn report_progress<'a, 'b>(
+        &'a mut self,
+        progress: f64,
+        total: Option<f64>,
+        message: Option<String>,
+    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'b>>
+    where
+        Self: 'b,
+        'a: 'b,
+    {
+        Box::pin(async move {
+            let update = Progress {
+                progress,
+                total,
+                message,
+            };
+            if !update.is_valid() {
+                tracing::debug!(target: "kami_guest", ?update, "invalid progress dropped");
+                return;
+            }
+            tracing::trace!(target: "kami_guest", progress, ?total, "progress");
+            if let Some(sink) = self.progress_sink() {
+                sink.report(update);
+            }
+        })
+    }
+}
+
+/// Implements the `kami:tool/secrets` interface for guest→host calls.
+impl kami::tool::secrets::Host for HostState {
+    fn get_secret<'a, 'b>(
+        &'a mut self,
+        name: String,
+    ) -> Pin<Box<dyn Future<Output = Result<String, String>> + Send + 'b>>
+    where
+        Self: 'b,
+        'a: 'b,
+    {
+        let source = self.secret_source().cloned();
+        Box::pin(async move {
+            let source = source.ok_or_else(|| SecretError::NotGranted { name: name.clone() });
+            let result = async { source?.get(&name).await };
+            result.await.map_err(|e| {
+                tracing::debug!(target: "kami_guest", error = %e, "get-secret failed");
+                e.to_string()
+            })
+        })
+    }
+}
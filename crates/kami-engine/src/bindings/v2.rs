@@ -0,0 +1,107 @@
+//! Host-side bindings for the `kami-tool-v2` WIT world.
+//!
+//! Imports are shared with the `kami-tool` and `kami-tool-ext` bindings,
+//! so the same linker serves every world. Parts returned by `run-v2` are
+//! mapped to [`ContentPart`]s here.
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine as _;
+use wasmtime::Store;
+
+use kami_types::{ContentPart, ResourceContents};
+
+use self::exports::kami::tool::content::{self as wit, Blob, Embedded, Link};
+use crate::error::EngineError;
+use crate::state::HostState;
+use crate::trap::classify_trap;
+
+wasmtime::component::bindgen!({
+    world: "kami-tool-v2",
+    path: "../../wit",
+    async: true,
+    with: {
+        "kami:tool/host": super::kami::tool::host,
+        "kami:tool/progress": super::ext::kami::tool::progress,
+        "kami:tool/kv": super::ext::kami::tool::kv,
+        "kami:tool/secrets": super::ext::kami::tool::secrets,
+    },
+});
+
+/// Calls the `run-v2` export and maps the returned parts.
+///
+/// A blob whose MIME type is neither `image/*` nor `audio/*`, or blob data
+/// that is not valid base64, turns the result into a tool error.
+///
+/// # Errors
+///
+/// Same as [`call_run`](super::call_run).
+pub async fn call_run_v2(
+    store: &mut Store<HostState>,
+    tool: &KamiToolV2,
+    input: &str,
+) -> Result<Result<Vec<ContentPart>, String>, EngineError> {
+    let result = tool
+        .kami_tool_content()
+        .call_run_v2(&mut *store, input)
+        .await
+        .map_err(classify_trap)?;
+    Ok(result.and_then(|parts| parts.into_iter().map(content_part).collect()))
+}
+
+fn content_part(part: wit::ContentPart) -> Result<ContentPart, String> {
+    Ok(match part {
+        wit::ContentPart::Text(text) => ContentPart::Text { text },
+        wit::ContentPart::Blob(Blob { mime_type, data }) => match mime_type.split('/').next() {
+            Some("image") => ContentPart::Image {
+                data: base64(data, &mime_type)?,
+                mime_type,
+            },
+            Some("audio") => ContentPart::Audio {
+                data: base64(data, &mime_type)?,
+                mime_type,
+            },
+            _ => {
+                return Err(format!(
+                    "run-v2 returned a blob of type '{mime_type}': only image/* and \
+                     audio/* blobs are supported, return other data as a resource"
+                ))
+            }
+        },
+        wit::ContentPart::Embedded(Embedded {
+            uri,
+            mime_type,
+            contents,
+        }) => {
+            let contents = match contents {
+                wit::ResourceContents::Text(text) => ResourceContents::Text(text),
+                wit::ResourceContents::Blob(blob) => ResourceContents::Blob(base64(blob, &uri)?),
+            };
+            ContentPart::Resource {
+                uri,
+                mime_type,
+                contents,
+            }
+        }
+        wit::ContentPart::Link(Link {
+            uri,
+            name,
+            description,
+            mime_type,
+        }) => ContentPart::ResourceLink {
+            uri,
+            name,
+            description,
+            mime_type,
+        },
+    })
+}
+
+/// Returns `data` if it is valid standard base64, as MCP clients decode it.
+fn base64(data: String, what: &str) -> Result<String, String> {
+    match STANDARD.decode(&data) {
+        Ok(_) => Ok(data),
+        Err(e) => Err(format!(
+            "run-v2 returned blob data for '{what}' that is not valid base64: {e}"
+        )),
+    }
+}
@@ -7,6 +7,7 @@ use wasmtime::{Engine, Store};
 
 use crate::error::EngineError;
 use crate::state::HostState;
+use crate::trap::classify_trap;
 
 /// Loads a WASM component from raw bytes.
 pub fn load_component(engine: &Engine, bytes: &[u8]) -> Result<Component, EngineError> {
@@ -29,7 +30,8 @@ pub fn load_component_from_file(engine: &Engine, path: &Path) -> Result<Componen
 /// Registers:
 /// - WASI standard interfaces (stdio, filesystem, clocks, etc.)
 /// - WASI HTTP outgoing-handler (for tools that make HTTP requests)
-/// - KAMI host imports (log function)
+/// - KAMI host imports of the `kami-tool-ext` world (`host`, `progress`,
+///   `kv`, `secrets`), a superset of those of `kami-tool`
 ///
 /// This linker is reusable across multiple instantiations.
 pub fn create_linker(engine: &Engine) -> Result<Linker<HostState>, EngineError> {
@@ -38,7 +40,7 @@ pub fn create_linker(engine: &Engine) -> Result<Linker<HostState>, EngineError>
         .map_err(|e| EngineError::Config(format!("WASI linker: {e}")))?;
     wasmtime_wasi_http::add_only_http_to_linker_async(&mut linker)
         .map_err(|e| EngineError::Config(format!("WASI HTTP linker: {e}")))?;
-    crate::bindings::KamiTool::add_to_linker(&mut linker, |s| s)
+    crate::bindings::ext::KamiToolExt::add_to_linker(&mut linker, |s| s)
         .map_err(|e| EngineError::Config(format!("host linker: {e}")))?;
     Ok(linker)
 }
@@ -75,9 +77,7 @@ pub async fn call_tool_run(
     let (result,) = run_func
         .call_async(&mut *store, (input.to_string(),))
         .await
-        .map_err(|e| EngineError::Trap {
-            message: e.to_string(),
-        })?;
+        .map_err(classify_trap)?;
 
     run_func
         .post_return_async(&mut *store)
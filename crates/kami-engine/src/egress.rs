@@ -0,0 +1,140 @@
+//! Outgoing HTTP accounting and quotas for one execution.
+//!
+//! `HostState` charges every request the allow list lets through against
+//! `max_http_requests`, and every byte sent (request line, headers and
+//! body) against `max_egress_bytes`. Received bytes are only counted.
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use thiserror::Error;
+
+/// Traffic exchanged with one host.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HostTraffic {
+    /// Requests charged to this host.
+    pub requests: u64,
+    /// Bytes sent to this host.
+    pub bytes_sent: u64,
+    /// Response body bytes received from this host.
+    pub bytes_received: u64,
+}
+
+/// Outgoing HTTP traffic of one execution.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EgressStats {
+    /// Requests charged against `max_http_requests`.
+    pub requests: u64,
+    /// Requests refused by the allow list, the network policy or a quota.
+    pub refused: u64,
+    /// Bytes sent, all hosts together.
+    pub bytes_sent: u64,
+    /// Response body bytes received, all hosts together.
+    pub bytes_received: u64,
+    /// Breakdown by lower-case host name.
+    pub hosts: BTreeMap<String, HostTraffic>,
+}
+
+/// An egress quota was reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum EgressError {
+    /// The execution already made `limit` requests.
+    #[error("max_http_requests ({limit}) exceeded")]
+    TooManyRequests { limit: u64 },
+    /// Sending would exceed `limit` bytes.
+    #[error("max_egress_bytes ({limit}) exceeded")]
+    TooManyBytes { limit: u64 },
+}
+
+/// Shared counter enforcing the egress quotas of one execution.
+#[derive(Debug, Clone)]
+pub struct EgressMeter {
+    stats: Arc<Mutex<EgressStats>>,
+    max_requests: u64,
+    max_bytes_sent: u64,
+}
+
+impl EgressMeter {
+    /// Creates a meter allowing `max_requests` requests and
+    /// `max_bytes_sent` bytes sent.
+    pub fn new(max_requests: u64, max_bytes_sent: u64) -> Self {
+        Self {
+            stats: Arc::default(),
+            max_requests,
+            max_bytes_sent,
+        }
+    }
+
+    /// Creates a meter that only counts.
+    pub fn unlimited() -> Self {
+        Self::new(u64::MAX, u64::MAX)
+    }
+
+    /// Charges a request to `host` whose line and headers take
+    /// `head_bytes`; refused requests are counted as such.
+    ///
+    /// # Errors
+    ///
+    /// Returns the quota the request would exceed.
+    pub fn start_request(&self, host: &str, head_bytes: u64) -> Result<(), EgressError> {
+        let mut stats = self.lock();
+        if stats.requests >= self.max_requests {
+            stats.refused += 1;
+            return Err(EgressError::TooManyRequests {
+                limit: self.max_requests,
+            });
+        }
+        stats.requests += 1;
+        host_entry(&mut stats, host).requests += 1;
+        drop(stats);
+        self.add_sent(host, head_bytes)
+            .inspect_err(|_| self.refuse())
+    }
+
+    /// Charges `bytes` sent to `host`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TooManyBytes` (without charging) if the quota would be exceeded.
+    pub fn add_sent(&self, host: &str, bytes: u64) -> Result<(), EgressError> {
+        let mut stats = self.lock();
+        let total = stats.bytes_sent.saturating_add(bytes);
+        if total > self.max_bytes_sent {
+            return Err(EgressError::TooManyBytes {
+                limit: self.max_bytes_sent,
+            });
+        }
+        stats.bytes_sent = total;
+        host_entry(&mut stats, host).bytes_sent += bytes;
+        Ok(())
+    }
+
+    /// Counts `bytes` received from `host`.
+    pub fn add_received(&self, host: &str, bytes: u64) {
+        let mut stats = self.lock();
+        stats.bytes_received = stats.bytes_received.saturating_add(bytes);
+        host_entry(&mut stats, host).bytes_received += bytes;
+    }
+
+    /// Counts a refused request.
+    pub fn refuse(&self) {
+        self.lock().refused += 1;
+    }
+
+    /// Returns the traffic counted so far.
+    pub fn stats(&self) -> EgressStats {
+        self.lock().clone()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, EgressStats> {
+        self.stats.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+fn host_entry<'a>(stats: &'a mut EgressStats, host: &str) -> &'a mut HostTraffic {
+    stats.hosts.entry(host.to_ascii_lowercase()).or_default()
+}
+
+#[cfg(test)]
+#[path = "egress_tests.rs"]
+mod tests;
@@ -0,0 +1,50 @@
+//! Tests for egress accounting and quotas.
+
+use super::*;
+
+#[test]
+fn counts_per_host() {
+    let meter = EgressMeter::unlimited();
+    meter
+        .start_request("API.example.com", 100)
+        .expect("request");
+    meter.add_sent("api.example.com", 50).expect("body");
+    meter.add_received("api.example.com", 300);
+    meter.start_request("other.net", 10).expect("request");
+    let stats = meter.stats();
+    assert_eq!(
+        (stats.requests, stats.bytes_sent, stats.bytes_received),
+        (2, 160, 300)
+    );
+    let api = stats.hosts["api.example.com"];
+    assert_eq!(
+        (api.requests, api.bytes_sent, api.bytes_received),
+        (1, 150, 300)
+    );
+}
+
+#[test]
+fn request_quota_refuses_and_counts() {
+    let meter = EgressMeter::new(1, u64::MAX);
+    meter.start_request("a.com", 0).expect("first");
+    let err = meter.start_request("a.com", 0).expect_err("second");
+    assert_eq!(err, EgressError::TooManyRequests { limit: 1 });
+    assert_eq!((meter.stats().requests, meter.stats().refused), (1, 1));
+}
+
+#[test]
+fn byte_quota_is_not_charged_when_exceeded() {
+    let meter = EgressMeter::new(10, 100);
+    meter.start_request("a.com", 60).expect("head");
+    let err = meter.add_sent("a.com", 41).expect_err("body");
+    assert_eq!(err, EgressError::TooManyBytes { limit: 100 });
+    assert_eq!(meter.stats().bytes_sent, 60);
+    meter.add_sent("a.com", 40).expect("fits exactly");
+}
+
+#[test]
+fn oversized_head_refuses_request() {
+    let meter = EgressMeter::new(10, 10);
+    assert!(meter.start_request("a.com", 11).is_err());
+    assert_eq!(meter.stats().refused, 1);
+}
@@ -0,0 +1,85 @@
+//! Shared epoch ticker for per-execution timeouts.
+//!
+//! A single background thread increments the engine epoch at a fixed
+//! interval. Each store checks its own tool's `max_execution_ms` at every
+//! tick (see [`crate::set_interruptible_epoch_deadline`]), so a short
+//! timeout on one execution never interrupts another execution running on
+//! the same `Engine`.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use wasmtime::Engine;
+
+use crate::error::EngineError;
+
+/// Interval between two epoch increments.
+pub const EPOCH_TICK: Duration = Duration::from_millis(10);
+
+/// Background thread incrementing an engine's epoch every `tick`.
+///
+/// The thread only holds a weak reference to the engine: it stops when
+/// the ticker is dropped or when the last `Engine` handle goes away.
+pub struct EpochTicker {
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+    tick: Duration,
+}
+
+impl EpochTicker {
+    /// Starts a ticker for `engine` with the given tick interval.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EngineError::Config` if the OS refuses to spawn the thread.
+    pub fn start(engine: &Engine, tick: Duration) -> Result<Self, EngineError> {
+        let stop = Arc::new(AtomicBool::new(false));
+        let weak = engine.weak();
+        let flag = Arc::clone(&stop);
+        let handle = std::thread::Builder::new()
+            .name("kami-epoch".to_string())
+            .spawn(move || {
+                while !flag.load(Ordering::Relaxed) {
+                    std::thread::sleep(tick);
+                    match weak.upgrade() {
+                        Some(engine) => engine.increment_epoch(),
+                        None => break,
+                    }
+                }
+            })
+            .map_err(|e| EngineError::Config(format!("epoch ticker: {e}")))?;
+        Ok(Self {
+            stop,
+            handle: Some(handle),
+            tick,
+        })
+    }
+
+    /// Returns the tick interval of this ticker.
+    pub fn tick(&self) -> Duration {
+        self.tick
+    }
+}
+
+impl Drop for EpochTicker {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        // Detach: the thread exits after at most one more tick.
+        drop(self.handle.take());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ticker_starts_and_stops() {
+        let engine = Engine::default();
+        let ticker = EpochTicker::start(&engine, Duration::from_millis(1)).expect("ticker");
+        assert_eq!(ticker.tick(), Duration::from_millis(1));
+        drop(ticker);
+    }
+}
@@ -23,6 +23,18 @@ pub enum EngineError {
     /// WASM instance trapped during execution.
     #[error("instance trapped: {message}")]
     Trap { message: String },
+    /// The instance used up its fuel budget.
+    #[error("instance ran out of fuel")]
+    FuelExhausted,
+    /// A linear memory grew past the store's memory cap.
+    #[error("memory limit of {limit_bytes} bytes exceeded")]
+    MemoryLimitExceeded { limit_bytes: u64 },
+    /// Execution was interrupted at its epoch deadline.
+    #[error("execution interrupted at its epoch deadline")]
+    EpochDeadline,
+    /// Execution was cancelled through its [`CancelFlag`](crate::CancelFlag).
+    #[error("execution cancelled")]
+    Cancelled,
     /// Exported function not found.
     #[error("export not found: {name}")]
     ExportNotFound { name: String },
@@ -37,7 +49,11 @@ pub enum EngineError {
 impl From<EngineError> for KamiError {
     fn from(e: EngineError) -> Self {
         let kind = match &e {
-            EngineError::ResourceExceeded { .. } => ErrorKind::ResourceExhausted,
+            EngineError::ResourceExceeded { .. }
+            | EngineError::FuelExhausted
+            | EngineError::MemoryLimitExceeded { .. } => ErrorKind::ResourceExhausted,
+            EngineError::EpochDeadline => ErrorKind::Timeout,
+            EngineError::Cancelled => ErrorKind::Cancelled,
             EngineError::ExportNotFound { .. } => ErrorKind::NotFound,
             _ => ErrorKind::Internal,
         };
@@ -64,6 +80,19 @@ impl DiagnosticError for EngineError {
             Self::ResourceExceeded { .. } => {
                 Some("The component exceeded its allocated resource budget.".into())
             }
+            Self::FuelExhausted => {
+                Some("The tool used up its fuel (instruction) budget before finishing.".into())
+            }
+            Self::MemoryLimitExceeded { limit_bytes } => Some(format!(
+                "The tool tried to grow its memory past the {} MiB limit.",
+                limit_bytes / (1024 * 1024)
+            )),
+            Self::EpochDeadline => {
+                Some("The tool was interrupted after running past its time limit.".into())
+            }
+            Self::Cancelled => {
+                Some("The store was stopped at an epoch tick after being cancelled.".into())
+            }
             Self::Trap { .. } => Some("The WASM instance trapped during execution.".into()),
             _ => None,
         }
@@ -82,37 +111,21 @@ impl DiagnosticError for EngineError {
             Self::ResourceExceeded { limit } => Some(format!(
                 "Increase the limit in tool.toml:\n  [security]\n  {limit}"
             )),
+            Self::FuelExhausted => {
+                Some("Raise max_fuel in tool.toml:\n  [security]\n  max_fuel = 10000000".into())
+            }
+            Self::MemoryLimitExceeded { .. } => Some(
+                "Raise max_memory_mb in tool.toml:\n  [security]\n  max_memory_mb = 128".into(),
+            ),
+            Self::EpochDeadline => Some(
+                "Raise max_execution_ms in tool.toml:\n  [security]\n  max_execution_ms = 10000"
+                    .into(),
+            ),
             _ => None,
         }
     }
 }
 
 #[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn resource_exceeded_maps_to_exhausted() {
-        let err: KamiError = EngineError::ResourceExceeded {
-            limit: "memory".into(),
-        }
-        .into();
-        assert_eq!(err.kind, ErrorKind::ResourceExhausted);
-    }
-
-    #[test]
-    fn export_not_found_maps_to_not_found() {
-        let err: KamiError = EngineError::ExportNotFound { name: "run".into() }.into();
-        assert_eq!(err.kind, ErrorKind::NotFound);
-    }
-
-    #[test]
-    fn compilation_hint_mentions_unknown_import() {
-        let e = EngineError::Compilation {
-            reason: "unknown import: wasi:http".into(),
-            source: wasmtime::Error::msg("test"),
-        };
-        let hint = e.hint().expect("should have hint");
-        assert!(hint.contains("does not provide"));
-    }
-}
+#[path = "error_tests.rs"]
+mod tests;
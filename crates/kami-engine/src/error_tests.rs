@@ -0,0 +1,33 @@
+use super::*;
+
+#[test]
+fn resource_exceeded_maps_to_exhausted() {
+    let err: KamiError = EngineError::ResourceExceeded {
+        limit: "memory".into(),
+    }
+    .into();
+    assert_eq!(err.kind, ErrorKind::ResourceExhausted);
+}
+
+#[test]
+fn export_not_found_maps_to_not_found() {
+    let err: KamiError = EngineError::ExportNotFound { name: "run".into() }.into();
+    assert_eq!(err.kind, ErrorKind::NotFound);
+}
+
+#[test]
+fn compilation_hint_mentions_unknown_import() {
+    let e = EngineError::Compilation {
+        reason: "unknown import: wasi:http".into(),
+        source: wasmtime::Error::msg("test"),
+    };
+    let hint = e.hint().expect("should have hint");
+    assert!(hint.contains("does not provide"));
+}
+
+#[test]
+fn cancelled_maps_to_cancelled() {
+    let err: KamiError = EngineError::Cancelled.into();
+    assert_eq!(err.kind, ErrorKind::Cancelled);
+    assert!(EngineError::Cancelled.fix().is_none());
+}
@@ -0,0 +1,145 @@
+//! Outgoing HTTP for guests (`wasi:http/outgoing-handler`).
+//!
+//! Every request a guest sends is checked against the store's
+//! `net_allow_list` rules — method, scheme, host, port and path — before it
+//! leaves the host. Its host is then resolved by the network policy, and
+//! the connection goes only to addresses passing deny rules and the
+//! non-public address check. Requests and bytes are charged to the store's
+//! `EgressMeter`.
+
+use std::sync::Arc;
+
+use http_body_util::BodyExt;
+use hyper::Request;
+use kami_sandbox::network::{is_request_allowed, HttpTarget, NetworkDenyReason};
+use kami_sandbox::SandboxError;
+use wasmtime::component::ResourceTable;
+use wasmtime_wasi_http::bindings::http::types::{DnsErrorPayload, ErrorCode};
+use wasmtime_wasi_http::body::HyperOutgoingBody;
+use wasmtime_wasi_http::types::{HostFutureIncomingResponse, OutgoingRequestConfig};
+use wasmtime_wasi_http::{HttpResult, WasiHttpCtx, WasiHttpView};
+
+use crate::egress::{EgressMeter, EgressStats};
+use crate::http_connect::send_to;
+use crate::metered::MeteredBody;
+use crate::state::HostState;
+
+impl HostState {
+    /// Enforces `max_requests` and `max_bytes_sent` on outgoing HTTP.
+    pub fn set_egress_limits(&mut self, max_requests: u64, max_bytes_sent: u64) {
+        self.egress = EgressMeter::new(max_requests, max_bytes_sent);
+    }
+
+    /// Returns the outgoing HTTP traffic counted so far.
+    pub fn egress_stats(&self) -> EgressStats {
+        self.egress.stats()
+    }
+}
+
+impl WasiHttpView for HostState {
+    fn ctx(&mut self) -> &mut WasiHttpCtx {
+        &mut self.http_ctx
+    }
+
+    fn table(&mut self) -> &mut ResourceTable {
+        &mut self.resource_table
+    }
+
+    /// Enforces `net_allow_list` before forwarding HTTP requests.
+    ///
+    /// Deny-all when `net_allow_list` is empty. Otherwise the request must
+    /// match every part of at least one rule and no deny rule, fit the
+    /// egress quotas, and its host must resolve to a permitted address.
+    fn send_request(
+        &mut self,
+        request: Request<HyperOutgoingBody>,
+        config: OutgoingRequestConfig,
+    ) -> HttpResult<HostFutureIncomingResponse> {
+        let target = target_of(&request, config.use_tls);
+        if !is_request_allowed(&target, self.net_policy.rules()) {
+            tracing::warn!(
+                method = target.method,
+                scheme = target.scheme,
+                host = target.host,
+                port = target.port,
+                path = target.path,
+                "outgoing HTTP denied by net_allow_list"
+            );
+            self.egress.refuse();
+            return Err(ErrorCode::ConnectionRefused.into());
+        }
+        let (host, port) = (target.host.to_ascii_lowercase(), target.port);
+        tracing::info!(method = target.method, %host, port, path = target.path, "outgoing HTTP");
+        if let Err(e) = self.egress.start_request(&host, head_bytes(&request)) {
+            tracing::warn!(%host, "outgoing HTTP refused: {e}");
+            return Err(ErrorCode::InternalError(Some(e.to_string())).into());
+        }
+        let meter = self.egress.clone();
+        let request = request.map(|body| MeteredBody::sent(body, meter.clone(), &host).boxed());
+        let policy = Arc::clone(&self.net_policy);
+        let handle = wasmtime_wasi::runtime::spawn(async move {
+            let addrs = match policy.resolve_http(&host, port).await {
+                Ok(addrs) => addrs,
+                Err(reason) => {
+                    meter.refuse();
+                    return Ok(Err(refused(SandboxError::NetworkDenied { host, reason })));
+                }
+            };
+            let response = send_to(request, config, addrs).await;
+            Ok(response.map(|mut incoming| {
+                let count = |body| MeteredBody::received(body, meter, &host).boxed();
+                incoming.resp = incoming.resp.map(count);
+                incoming
+            }))
+        });
+        Ok(HostFutureIncomingResponse::pending(handle))
+    }
+}
+
+/// Logs a denial after resolution and maps it to a `wasi:http` error.
+fn refused(denied: SandboxError) -> ErrorCode {
+    tracing::warn!(%denied, "outgoing HTTP refused after resolution");
+    match denied {
+        SandboxError::NetworkDenied {
+            reason: NetworkDenyReason::Unresolved,
+            ..
+        } => ErrorCode::DnsError(DnsErrorPayload {
+            rcode: Some("address not available".to_string()),
+            info_code: Some(0),
+        }),
+        _ => ErrorCode::ConnectionRefused,
+    }
+}
+
+/// Approximate size of the request line and headers on the wire.
+fn head_bytes<B>(request: &Request<B>) -> u64 {
+    let line = request.method().as_str().len() + request.uri().to_string().len() + 12;
+    let headers: usize = request
+        .headers()
+        .iter()
+        .map(|(name, value)| name.as_str().len() + value.len() + 4)
+        .sum();
+    (line + headers) as u64
+}
+
+/// Describes `request` for the allow-list check. The scheme follows
+/// `use_tls`, which decides how the connection is actually made.
+fn target_of<B>(request: &Request<B>, use_tls: bool) -> HttpTarget<'_> {
+    let uri = request.uri();
+    let (scheme, default_port) = if use_tls {
+        ("https", 443)
+    } else {
+        ("http", 80)
+    };
+    HttpTarget {
+        method: request.method().as_str(),
+        scheme,
+        host: uri.host().unwrap_or_default(),
+        port: uri.port_u16().unwrap_or(default_port),
+        path: uri.path(),
+    }
+}
+
+#[cfg(test)]
+#[path = "http_tests.rs"]
+mod tests;
@@ -0,0 +1,103 @@
+//! Connects outgoing guest HTTP requests to addresses already vetted by the
+//! network policy, so a second DNS answer cannot redirect the connection.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use http_body_util::BodyExt;
+use hyper::client::conn::http1::{handshake, SendRequest};
+use hyper::rt::{Read, Write};
+use hyper::{Request, Uri};
+use rustls::pki_types::ServerName;
+use tokio::net::TcpStream;
+use tokio::time::timeout;
+use tokio_rustls::{client::TlsStream, TlsConnector};
+use wasmtime_wasi::runtime::{spawn, AbortOnDropJoinHandle};
+use wasmtime_wasi_http::bindings::http::types::{DnsErrorPayload, ErrorCode};
+use wasmtime_wasi_http::body::HyperOutgoingBody;
+use wasmtime_wasi_http::hyper_request_error;
+use wasmtime_wasi_http::io::TokioIo;
+use wasmtime_wasi_http::types::{IncomingResponse, OutgoingRequestConfig};
+
+/// Sends `request` over a connection to the first reachable of `addrs`.
+///
+/// Mirrors `wasmtime_wasi_http::types::default_send_request_handler`,
+/// except that it never resolves the request's host itself.
+pub(crate) async fn send_to(
+    mut request: Request<HyperOutgoingBody>,
+    config: OutgoingRequestConfig,
+    addrs: Vec<SocketAddr>,
+) -> Result<IncomingResponse, ErrorCode> {
+    let tcp = timeout(config.connect_timeout, TcpStream::connect(&addrs[..]))
+        .await
+        .map_err(|_| ErrorCode::ConnectionTimeout)?
+        .map_err(|_| ErrorCode::ConnectionRefused)?;
+    let (mut sender, worker) = if config.use_tls {
+        let host = request.uri().host().unwrap_or_default();
+        let host = host.trim_start_matches('[').trim_end_matches(']');
+        let tls = tls_connect(host, tcp).await?;
+        connect_http1(TokioIo::new(tls), config.connect_timeout).await?
+    } else {
+        connect_http1(TokioIo::new(tcp), config.connect_timeout).await?
+    };
+    // Only a proxy expects scheme and authority in the request line.
+    let path = request.uri().path_and_query().map_or("/", |p| p.as_str());
+    *request.uri_mut() = Uri::builder()
+        .path_and_query(path)
+        .build()
+        .map_err(|_| ErrorCode::HttpRequestUriInvalid)?;
+    let resp = timeout(config.first_byte_timeout, sender.send_request(request))
+        .await
+        .map_err(|_| ErrorCode::ConnectionReadTimeout)?
+        .map_err(hyper_request_error)?
+        .map(|body| body.map_err(hyper_request_error).boxed());
+    Ok(IncomingResponse {
+        resp,
+        worker: Some(worker),
+        between_bytes_timeout: config.between_bytes_timeout,
+    })
+}
+
+/// Performs the TLS handshake, verifying the certificate against `host`.
+async fn tls_connect(host: &str, tcp: TcpStream) -> Result<TlsStream<TcpStream>, ErrorCode> {
+    let name = ServerName::try_from(host.to_string()).map_err(|_| {
+        ErrorCode::DnsError(DnsErrorPayload {
+            rcode: Some("invalid dns name".to_string()),
+            info_code: Some(0),
+        })
+    })?;
+    let roots = rustls::RootCertStore {
+        roots: webpki_roots::TLS_SERVER_ROOTS.into(),
+    };
+    let config = rustls::ClientConfig::builder()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+    TlsConnector::from(Arc::new(config))
+        .connect(name, tcp)
+        .await
+        .map_err(|e| {
+            tracing::warn!(error = %e, "TLS handshake failed");
+            ErrorCode::TlsProtocolError
+        })
+}
+
+/// Runs the HTTP/1 handshake and drives the connection on a worker task.
+async fn connect_http1<T>(
+    io: T,
+    limit: Duration,
+) -> Result<(SendRequest<HyperOutgoingBody>, AbortOnDropJoinHandle<()>), ErrorCode>
+where
+    T: Read + Write + Unpin + Send + 'static,
+{
+    let (sender, conn) = timeout(limit, handshake(io))
+        .await
+        .map_err(|_| ErrorCode::ConnectionTimeout)?
+        .map_err(hyper_request_error)?;
+    let worker = spawn(async move {
+        if let Err(e) = conn.await {
+            tracing::warn!(error = %e, "outgoing HTTP connection failed");
+        }
+    });
+    Ok((sender, worker))
+}
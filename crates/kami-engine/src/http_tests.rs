@@ -0,0 +1,29 @@
+//! Tests for outgoing HTTP request description.
+
+use super::*;
+
+fn request(method: &str, uri: &str) -> Request<()> {
+    Request::builder()
+        .method(method)
+        .uri(uri)
+        .body(())
+        .expect("request")
+}
+
+#[test]
+fn target_uses_scheme_default_port() {
+    let req = request("GET", "https://api.example.com/a?b=c");
+    let target = target_of(&req, true);
+    assert_eq!(target.scheme, "https");
+    assert_eq!(target.host, "api.example.com");
+    assert_eq!(target.port, 443);
+    assert_eq!(target.path, "/a");
+}
+
+#[test]
+fn target_keeps_explicit_port_and_method() {
+    let req = request("POST", "http://10.0.0.1:8080/");
+    let target = target_of(&req, false);
+    assert_eq!((target.method, target.scheme), ("POST", "http"));
+    assert_eq!((target.host, target.port), ("10.0.0.1", 8080));
+}
@@ -4,6 +4,7 @@ use wasmtime::{Config, Engine, Store};
 
 use crate::error::EngineError;
 use crate::state::HostState;
+use crate::yielding::YieldStrategy;
 
 /// Configuration for creating WASM instances.
 #[derive(Debug, Clone)]
@@ -16,6 +17,8 @@ pub struct InstanceConfig {
     pub async_support: bool,
     /// Enable epoch-based interruption for timeout enforcement.
     pub epoch_interruption: bool,
+    /// How running guests yield to the async executor.
+    pub yield_strategy: YieldStrategy,
 }
 
 impl Default for InstanceConfig {
@@ -25,6 +28,7 @@ impl Default for InstanceConfig {
             max_fuel: 1_000_000,
             async_support: true,
             epoch_interruption: false,
+            yield_strategy: YieldStrategy::default(),
         }
     }
 }
@@ -34,7 +38,24 @@ impl Default for InstanceConfig {
 /// The engine is reusable across all component compilations and
 /// should be created once at startup. Enables fuel metering,
 /// Component Model, and epoch interruption.
+///
+/// Yielding itself is configured per store (see [`crate::set_fuel_yield`]);
+/// the engine only checks that `yield_strategy` is supported.
 pub fn create_engine(config: &InstanceConfig) -> Result<Engine, EngineError> {
+    match config.yield_strategy {
+        YieldStrategy::Never => {}
+        _ if !config.async_support => {
+            return Err(EngineError::Config(
+                "yielding requires async support".into(),
+            ))
+        }
+        YieldStrategy::Epoch if !config.epoch_interruption => {
+            return Err(EngineError::Config(
+                "epoch yielding requires epoch interruption".into(),
+            ))
+        }
+        _ => {}
+    }
     let mut wasm_config = Config::new();
     wasm_config.async_support(config.async_support);
     wasm_config.consume_fuel(true);
@@ -48,7 +69,7 @@ pub fn create_engine(config: &InstanceConfig) -> Result<Engine, EngineError> {
 
 /// Creates a new `Store<HostState>` with fuel metering and resource limits.
 ///
-/// Connects `StoreLimits` from `HostState` via `store.limiter()` and sets
+/// Connects the `MemoryLimiter` of `HostState` via `store.limiter()` and sets
 /// the fuel budget for instruction-level metering.
 pub fn create_store(
     engine: &Engine,
@@ -58,7 +79,7 @@ pub fn create_store(
     let mut store = Store::new(engine, host_state);
 
     // Connect resource limiter (memory, tables)
-    store.limiter(|state| &mut state.store_limits);
+    store.limiter(|state| &mut state.limiter);
 
     // Set fuel budget
     store
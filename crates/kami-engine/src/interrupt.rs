@@ -0,0 +1,82 @@
+//! Wall-clock epoch deadlines that can also be cancelled.
+//!
+//! The store checks its deadline at every epoch tick: it traps with
+//! [`Trap::Interrupt`] once the timeout has elapsed, and with [`Cancelled`]
+//! as soon as its [`CancelFlag`] is raised, even if the guest never yields.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use wasmtime::{Store, Trap, UpdateDeadline};
+
+use crate::state::HostState;
+
+/// Error raised by the epoch callback of a cancelled store.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("execution cancelled")]
+pub struct Cancelled;
+
+/// Shared flag asking a running store to stop at its next epoch tick.
+#[derive(Debug, Clone, Default)]
+pub struct CancelFlag(Arc<AtomicBool>);
+
+impl CancelFlag {
+    /// Creates a flag that is not raised.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Raises the flag. Idempotent.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Release);
+    }
+
+    /// Returns `true` once [`cancel`](Self::cancel) has been called.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Acquire)
+    }
+}
+
+/// Sets an epoch deadline that traps once `timeout` has elapsed or `cancel`
+/// is raised, checked at every tick.
+///
+/// With `yield_each_tick` the guest also yields to the async executor at
+/// every tick (see [`crate::YieldStrategy::Epoch`]). Unlike
+/// [`crate::set_epoch_deadline`], the timeout is measured on the wall clock.
+pub fn set_interruptible_epoch_deadline(
+    store: &mut Store<HostState>,
+    timeout: Duration,
+    cancel: &CancelFlag,
+    yield_each_tick: bool,
+) {
+    let deadline = Instant::now().checked_add(timeout);
+    let cancel = cancel.clone();
+    store.epoch_deadline_callback(move |_| {
+        if cancel.is_cancelled() {
+            return Err(Cancelled.into());
+        }
+        match deadline {
+            Some(deadline) if Instant::now() >= deadline => Err(Trap::Interrupt.into()),
+            _ if yield_each_tick => Ok(UpdateDeadline::Yield(1)),
+            _ => Ok(UpdateDeadline::Continue(1)),
+        }
+    });
+    store.set_epoch_deadline(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flag_is_shared_between_clones() {
+        let flag = CancelFlag::new();
+        let observer = flag.clone();
+        assert!(!observer.is_cancelled());
+
+        flag.cancel();
+
+        assert!(observer.is_cancelled());
+    }
+}
@@ -0,0 +1,75 @@
+//! Key/value storage offered to guests through `kami:tool/kv`.
+//!
+//! The host forwards each call to the [`KeyValue`] installed on the store's
+//! `HostState`, already scoped to the running tool and its quota; without
+//! one, every call fails with [`KvError::NotGranted`].
+
+use async_trait::async_trait;
+
+/// Maximum length of a key, in bytes.
+pub const MAX_KEY_BYTES: usize = 256;
+
+/// Errors returned to the guest by `kami:tool/kv` calls.
+#[derive(Debug, thiserror::Error)]
+pub enum KvError {
+    /// The tool was not granted the key/value capability.
+    #[error("key/value storage is not granted to this tool")]
+    NotGranted,
+    /// The key is empty or too long.
+    #[error("invalid key: {reason}")]
+    InvalidKey { reason: String },
+    /// The write would exceed the tool's `max_kv_bytes`.
+    #[error("key/value quota of {limit} bytes exceeded")]
+    QuotaExceeded { limit: u64 },
+    /// The backing store failed.
+    #[error("key/value storage error: {message}")]
+    Storage { message: String },
+}
+
+/// Key/value store of one running tool.
+#[async_trait]
+pub trait KeyValue: Send + Sync {
+    /// Returns the value stored under `key`, if any.
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, KvError>;
+
+    /// Stores `value` under `key`, replacing any previous value.
+    async fn set(&self, key: &str, value: &[u8]) -> Result<(), KvError>;
+
+    /// Deletes `key`; returns whether it existed.
+    async fn delete(&self, key: &str) -> Result<bool, KvError>;
+
+    /// Lists the keys starting with `prefix`, in ascending order.
+    async fn list_keys(&self, prefix: &str) -> Result<Vec<String>, KvError>;
+}
+
+/// Checks that `key` is non-empty and at most [`MAX_KEY_BYTES`] long.
+pub fn validate_key(key: &str) -> Result<(), KvError> {
+    if key.is_empty() {
+        return Err(KvError::InvalidKey {
+            reason: "key is empty".to_string(),
+        });
+    }
+    if key.len() > MAX_KEY_BYTES {
+        return Err(KvError::InvalidKey {
+            reason: format!("key is longer than {MAX_KEY_BYTES} bytes"),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_key_bounds() {
+        assert!(validate_key("cache:page:1").is_ok());
+        assert!(validate_key(&"k".repeat(MAX_KEY_BYTES)).is_ok());
+        assert!(matches!(validate_key(""), Err(KvError::InvalidKey { .. })));
+        let long = "k".repeat(MAX_KEY_BYTES + 1);
+        assert!(matches!(
+            validate_key(&long),
+            Err(KvError::InvalidKey { .. })
+        ));
+    }
+}
@@ -0,0 +1,84 @@
+//! Implements the `kami:tool/kv` interface for guest→host calls.
+
+use core::future::Future;
+use core::pin::Pin;
+use std::sync::Arc;
+
+use crate::bindings::ext::kami::tool::kv::Host;
+use crate::kv::{validate_key, KeyValue, KvError};
+use crate::state::HostState;
+
+/// Boxed future returned by the generated async host functions.
+type HostFuture<'b, T> = Pin<Box<dyn Future<Output = Result<T, String>> + Send + 'b>>;
+
+/// Returns the tool's store, failing if it was not granted one.
+///
+/// The store is cloned out so that no borrow of `HostState`, which is not
+/// `Sync`, is held across the call.
+fn granted(state: &HostState) -> Result<Arc<dyn KeyValue>, KvError> {
+    state.key_value().cloned().ok_or(KvError::NotGranted)
+}
+
+/// Like [`granted`], after checking `key`.
+fn granted_for(state: &HostState, key: &str) -> Result<Arc<dyn KeyValue>, KvError> {
+    validate_key(key)?;
+    granted(state)
+}
+
+/// Reports a failed call to the guest as its error string.
+fn to_guest<T>(op: &str, result: Result<T, KvError>) -> Result<T, String> {
+    result.map_err(|e| {
+        tracing::debug!(target: "kami_guest", op, error = %e, "kv call failed");
+        e.to_string()
+    })
+}
+
+impl Host for HostState {
+    fn get<'a, 'b>(&'a mut self, key: String) -> HostFuture<'b, Option<Vec<u8>>>
+    where
+        Self: 'b,
+        'a: 'b,
+    {
+        Box::pin(async move {
+            let kv = granted_for(self, &key);
+            let result = async { kv?.get(&key).await };
+            to_guest("get", result.await)
+        })
+    }
+
+    fn set<'a, 'b>(&'a mut self, key: String, value: Vec<u8>) -> HostFuture<'b, ()>
+    where
+        Self: 'b,
+        'a: 'b,
+    {
+        Box::pin(async move {
+            let kv = granted_for(self, &key);
+            let result = async { kv?.set(&key, &value).await };
+            to_guest("set", result.await)
+        })
+    }
+
+    fn delete<'a, 'b>(&'a mut self, key: String) -> HostFuture<'b, bool>
+    where
+        Self: 'b,
+        'a: 'b,
+    {
+        Box::pin(async move {
+            let kv = granted_for(self, &key);
+            let result = async { kv?.delete(&key).await };
+            to_guest("delete", result.await)
+        })
+    }
+
+    fn list_keys<'a, 'b>(&'a mut self, prefix: String) -> HostFuture<'b, Vec<String>>
+    where
+        Self: 'b,
+        'a: 'b,
+    {
+        Box::pin(async move {
+            let kv = granted(self);
+            let result = async { kv?.list_keys(&prefix).await };
+            to_guest("list-keys", result.await)
+        })
+    }
+}
@@ -4,18 +4,44 @@
 //! Handles component compilation, instance creation, WASI linking,
 //! and async execution.
 
+pub mod artifact;
 pub mod bindings;
 pub mod component;
+pub mod egress;
+pub mod epoch;
 pub mod error;
+mod http;
+mod http_connect;
 pub mod instance;
+pub mod interrupt;
+pub mod kv;
+mod kv_host;
+pub mod limiter;
 pub mod memory;
+mod metered;
+pub mod prepared;
+pub mod progress;
+pub mod secret;
 pub mod state;
+pub mod trap;
+pub mod yielding;
 
+pub use artifact::{deserialize_component, engine_fingerprint, serialize_component};
 pub use bindings::{call_describe, call_run, instantiate_tool};
 pub use component::{
     call_tool_run, create_linker, instantiate_component, load_component, load_component_from_file,
 };
+pub use egress::{EgressError, EgressMeter, EgressStats, HostTraffic};
+pub use epoch::{EpochTicker, EPOCH_TICK};
 pub use error::EngineError;
 pub use instance::{create_engine, create_store, set_epoch_deadline, InstanceConfig};
+pub use interrupt::{set_interruptible_epoch_deadline, CancelFlag, Cancelled};
+pub use kv::{validate_key, KeyValue, KvError, MAX_KEY_BYTES};
+pub use limiter::MemoryLimiter;
 pub use memory::MemoryStats;
+pub use prepared::{call_prepared, prepare_tool, PreparedTool};
+pub use progress::{Progress, ProgressSink};
+pub use secret::{SecretError, SecretSource};
 pub use state::HostState;
+pub use trap::{classify_trap, resource_trap, MemoryLimitExceeded};
+pub use yielding::{set_fuel_yield, YieldStrategy, DEFAULT_YIELD_FUEL};
@@ -0,0 +1,133 @@
+//! Resource limiter that enforces `StoreLimits` and records usage.
+//!
+//! Wasmtime asks the limiter before every linear-memory or table growth
+//! (including the initial allocation at instantiation). Delegating the
+//! decision to `StoreLimits` keeps enforcement unchanged while the approved
+//! sizes feed [`MemoryStats`].
+
+use wasmtime::{ResourceLimiter, StoreLimits, StoreLimitsBuilder};
+
+use crate::memory::MemoryStats;
+use crate::trap::MemoryLimitExceeded;
+
+/// `ResourceLimiter` wrapping `StoreLimits` with usage accounting.
+pub struct MemoryLimiter {
+    limits: StoreLimits,
+    stats: MemoryStats,
+    /// Last approved growth, rolled back if the growth then fails.
+    pending_memory: u64,
+    pending_table: u64,
+}
+
+impl MemoryLimiter {
+    /// Creates a limiter without a memory cap.
+    pub fn unlimited() -> Self {
+        Self::from_limits(StoreLimitsBuilder::new().build(), 0)
+    }
+
+    /// Creates a limiter trapping when a memory grows past `max_memory_bytes`.
+    ///
+    /// The trap carries a [`MemoryLimitExceeded`] so callers can tell it
+    /// apart from other guest traps.
+    pub fn with_max_memory(max_memory_bytes: usize) -> Self {
+        let limits = StoreLimitsBuilder::new()
+            .memory_size(max_memory_bytes)
+            .trap_on_grow_failure(true)
+            .build();
+        Self::from_limits(limits, max_memory_bytes as u64)
+    }
+
+    fn from_limits(limits: StoreLimits, limit_bytes: u64) -> Self {
+        Self {
+            limits,
+            stats: MemoryStats {
+                limit_bytes,
+                ..MemoryStats::default()
+            },
+            pending_memory: 0,
+            pending_table: 0,
+        }
+    }
+
+    /// Returns usage recorded so far.
+    pub fn stats(&self) -> &MemoryStats {
+        &self.stats
+    }
+}
+
+impl ResourceLimiter for MemoryLimiter {
+    fn memory_growing(
+        &mut self,
+        current: usize,
+        desired: usize,
+        maximum: Option<usize>,
+    ) -> wasmtime::Result<bool> {
+        let limit_bytes = self.stats.limit_bytes;
+        let allowed = self
+            .limits
+            .memory_growing(current, desired, maximum)
+            .map_err(|e| {
+                let desired_bytes = desired as u64;
+                if limit_bytes > 0 && desired_bytes > limit_bytes {
+                    wasmtime::Error::new(MemoryLimitExceeded {
+                        desired_bytes,
+                        limit_bytes,
+                    })
+                } else {
+                    e
+                }
+            })?;
+        if allowed {
+            self.pending_memory = desired.saturating_sub(current) as u64;
+            self.stats.current_bytes += self.pending_memory;
+            self.stats.peak_bytes = self.stats.peak_bytes.max(self.stats.current_bytes);
+        }
+        Ok(allowed)
+    }
+
+    fn memory_grow_failed(&mut self, error: wasmtime::Error) -> wasmtime::Result<()> {
+        self.stats.current_bytes = self.stats.current_bytes.saturating_sub(self.pending_memory);
+        self.pending_memory = 0;
+        self.limits.memory_grow_failed(error)
+    }
+
+    fn table_growing(
+        &mut self,
+        current: usize,
+        desired: usize,
+        maximum: Option<usize>,
+    ) -> wasmtime::Result<bool> {
+        let allowed = self.limits.table_growing(current, desired, maximum)?;
+        if allowed {
+            self.pending_table = desired.saturating_sub(current) as u64;
+            self.stats.table_elements += self.pending_table;
+            self.stats.peak_table_elements = self
+                .stats
+                .peak_table_elements
+                .max(self.stats.table_elements);
+        }
+        Ok(allowed)
+    }
+
+    fn table_grow_failed(&mut self, error: wasmtime::Error) -> wasmtime::Result<()> {
+        self.stats.table_elements = self.stats.table_elements.saturating_sub(self.pending_table);
+        self.pending_table = 0;
+        self.limits.table_grow_failed(error)
+    }
+
+    fn instances(&self) -> usize {
+        self.limits.instances()
+    }
+
+    fn tables(&self) -> usize {
+        self.limits.tables()
+    }
+
+    fn memories(&self) -> usize {
+        self.limits.memories()
+    }
+}
+
+#[cfg(test)]
+#[path = "limiter_tests.rs"]
+mod tests;
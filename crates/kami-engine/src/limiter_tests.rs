@@ -0,0 +1,53 @@
+//! Tests for `MemoryLimiter`.
+
+use super::*;
+
+const MIB: usize = 1024 * 1024;
+
+#[test]
+fn approved_growth_is_recorded() {
+    let mut limiter = MemoryLimiter::with_max_memory(4 * MIB);
+    assert!(limiter.memory_growing(0, MIB, None).expect("grow"));
+    assert!(limiter.memory_growing(MIB, 2 * MIB, None).expect("grow"));
+    assert_eq!(limiter.stats().current_bytes, 2 * MIB as u64);
+    assert_eq!(limiter.stats().peak_bytes, 2 * MIB as u64);
+    assert_eq!(limiter.stats().limit_bytes, 4 * MIB as u64);
+}
+
+#[test]
+fn growth_past_cap_traps_and_is_not_recorded() {
+    let mut limiter = MemoryLimiter::with_max_memory(MIB);
+    assert!(limiter.memory_growing(0, 2 * MIB, None).is_err());
+    assert_eq!(limiter.stats().current_bytes, 0);
+}
+
+#[test]
+fn failed_growth_is_rolled_back() {
+    let mut limiter = MemoryLimiter::unlimited();
+    assert!(limiter.memory_growing(0, MIB, None).expect("grow"));
+    let _ = limiter.memory_grow_failed(wasmtime::Error::msg("mmap failed"));
+    assert_eq!(limiter.stats().current_bytes, 0);
+    assert_eq!(limiter.stats().peak_bytes, MIB as u64);
+}
+
+#[test]
+fn table_growth_is_recorded() {
+    let mut limiter = MemoryLimiter::unlimited();
+    assert!(limiter.table_growing(0, 10, None).expect("grow"));
+    assert!(limiter.table_growing(10, 12, None).expect("grow"));
+    assert_eq!(limiter.stats().table_elements, 12);
+    assert_eq!(limiter.stats().peak_table_elements, 12);
+}
+
+#[test]
+fn growth_past_cap_reports_memory_limit() {
+    let mut limiter = MemoryLimiter::with_max_memory(MIB);
+    let err = limiter
+        .memory_growing(MIB, 2 * MIB, None)
+        .expect_err("past cap");
+    let limit = err
+        .downcast_ref::<MemoryLimitExceeded>()
+        .expect("typed error");
+    assert_eq!(limit.limit_bytes, MIB as u64);
+    assert_eq!(limit.desired_bytes, 2 * MIB as u64);
+}
@@ -9,6 +9,10 @@ pub struct MemoryStats {
     pub peak_bytes: u64,
     /// Memory limit in bytes.
     pub limit_bytes: u64,
+    /// Current number of table elements.
+    pub table_elements: u64,
+    /// Peak number of table elements.
+    pub peak_table_elements: u64,
 }
 
 impl MemoryStats {
@@ -19,6 +23,14 @@ impl MemoryStats {
         }
         (self.current_bytes as f64 / self.limit_bytes as f64) * 100.0
     }
+
+    /// Returns the peak usage as a percentage of the limit.
+    pub fn peak_percent(&self) -> f64 {
+        if self.limit_bytes == 0 {
+            return 0.0;
+        }
+        (self.peak_bytes as f64 / self.limit_bytes as f64) * 100.0
+    }
 }
 
 #[cfg(test)]
@@ -31,6 +43,7 @@ mod tests {
             current_bytes: 1024,
             peak_bytes: 2048,
             limit_bytes: 0,
+            ..MemoryStats::default()
         };
         assert!((stats.usage_percent() - 0.0).abs() < f64::EPSILON);
     }
@@ -41,10 +54,22 @@ mod tests {
             current_bytes: 50,
             peak_bytes: 75,
             limit_bytes: 100,
+            ..MemoryStats::default()
         };
         assert!((stats.usage_percent() - 50.0).abs() < f64::EPSILON);
     }
 
+    #[test]
+    fn peak_percent_uses_peak_bytes() {
+        let stats = MemoryStats {
+            current_bytes: 10,
+            peak_bytes: 90,
+            limit_bytes: 100,
+            ..MemoryStats::default()
+        };
+        assert!((stats.peak_percent() - 90.0).abs() < f64::EPSILON);
+    }
+
     #[test]
     fn default_stats_are_zeroed() {
         let stats = MemoryStats::default();
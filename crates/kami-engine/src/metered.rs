@@ -0,0 +1,111 @@
+//! HTTP bodies that report their bytes to an [`EgressMeter`].
+
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+
+use hyper::body::{Body, Bytes, Frame, SizeHint};
+use wasmtime_wasi_http::bindings::http::types::ErrorCode;
+use wasmtime_wasi_http::body::HyperOutgoingBody;
+
+use crate::egress::EgressMeter;
+
+/// Which counter a [`MeteredBody`] feeds.
+#[derive(Debug, Clone, Copy)]
+enum Direction {
+    Sent,
+    Received,
+}
+
+/// Wraps a body, charging each data frame to `host`.
+pub(crate) struct MeteredBody {
+    inner: HyperOutgoingBody,
+    meter: EgressMeter,
+    host: String,
+    direction: Direction,
+}
+
+impl MeteredBody {
+    /// A request body; fails once `max_egress_bytes` would be exceeded.
+    pub(crate) fn sent(inner: HyperOutgoingBody, meter: EgressMeter, host: &str) -> Self {
+        Self::new(inner, meter, host, Direction::Sent)
+    }
+
+    /// A response body; only counted.
+    pub(crate) fn received(inner: HyperOutgoingBody, meter: EgressMeter, host: &str) -> Self {
+        Self::new(inner, meter, host, Direction::Received)
+    }
+
+    fn new(inner: HyperOutgoingBody, meter: EgressMeter, host: &str, direction: Direction) -> Self {
+        Self {
+            inner,
+            meter,
+            host: host.to_string(),
+            direction,
+        }
+    }
+}
+
+impl Body for MeteredBody {
+    type Data = Bytes;
+    type Error = ErrorCode;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Bytes>, ErrorCode>>> {
+        let this = &mut *self;
+        let frame = ready!(Pin::new(&mut this.inner).poll_frame(cx));
+        if let Some(data) = frame.as_ref().and_then(|f| f.as_ref().ok()?.data_ref()) {
+            let len = data.len() as u64;
+            match this.direction {
+                Direction::Sent => {
+                    if let Err(e) = this.meter.add_sent(&this.host, len) {
+                        tracing::warn!(host = %this.host, "outgoing HTTP body stopped: {e}");
+                        return Poll::Ready(Some(Err(ErrorCode::InternalError(Some(
+                            e.to_string(),
+                        )))));
+                    }
+                }
+                Direction::Received => this.meter.add_received(&this.host, len),
+            }
+        }
+        Poll::Ready(frame)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http_body_util::{BodyExt, Full};
+
+    fn body(text: &'static str) -> HyperOutgoingBody {
+        Full::new(Bytes::from(text))
+            .map_err(|never| match never {})
+            .boxed()
+    }
+
+    #[tokio::test]
+    async fn received_bytes_are_counted() {
+        let meter = EgressMeter::unlimited();
+        let metered = MeteredBody::received(body("hello"), meter.clone(), "a.com");
+        let collected = metered.collect().await.expect("body");
+        assert_eq!(collected.to_bytes(), "hello");
+        assert_eq!(meter.stats().hosts["a.com"].bytes_received, 5);
+    }
+
+    #[tokio::test]
+    async fn sent_body_fails_past_quota() {
+        let meter = EgressMeter::new(1, 4);
+        let metered = MeteredBody::sent(body("hello"), meter.clone(), "a.com");
+        assert!(metered.collect().await.is_err());
+        assert_eq!(meter.stats().bytes_sent, 0);
+    }
+}
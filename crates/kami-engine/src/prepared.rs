@@ -0,0 +1,103 @@
+//! Pre-instantiated components.
+//!
+//! Linking a component against the host `Linker` (import resolution and
+//! type-checking) only depends on the component, not on the store. A
+//! `PreparedTool` does that work once so each call only has to
+//! instantiate into a fresh `Store`.
+
+use wasmtime::component::{Component, InstancePre, Linker};
+use wasmtime::Store;
+
+use kami_types::ContentPart;
+
+use crate::bindings::v2::{call_run_v2, KamiToolV2Pre};
+use crate::bindings::{call_run, KamiToolPre};
+use crate::component::call_tool_run;
+use crate::error::EngineError;
+use crate::state::HostState;
+use crate::trap::resource_trap;
+
+/// A component already linked against the host imports.
+#[derive(Clone)]
+pub enum PreparedTool {
+    /// Component implementing the `kami-tool-v2` WIT world.
+    TypedV2(KamiToolV2Pre<HostState>),
+    /// Component implementing the `kami-tool` WIT world.
+    Typed(KamiToolPre<HostState>),
+    /// Component exporting a flat `run` function.
+    Flat(InstancePre<HostState>),
+}
+
+/// Links `component` against `linker` ahead of instantiation.
+///
+/// Uses typed bindings when the component matches the `kami-tool-v2` or
+/// `kami-tool` world and falls back to the flat `run` export otherwise.
+///
+/// # Errors
+///
+/// Returns `EngineError::Instantiation` if an import cannot be satisfied.
+pub fn prepare_tool(
+    linker: &Linker<HostState>,
+    component: &Component,
+) -> Result<PreparedTool, EngineError> {
+    let pre = linker
+        .instantiate_pre(component)
+        .map_err(|e| EngineError::Instantiation {
+            reason: "failed to link component imports".to_string(),
+            source: e,
+        })?;
+    if let Ok(typed) = KamiToolV2Pre::new(pre.clone()) {
+        return Ok(PreparedTool::TypedV2(typed));
+    }
+    Ok(match KamiToolPre::new(pre.clone()) {
+        Ok(typed) => PreparedTool::Typed(typed),
+        Err(_) => PreparedTool::Flat(pre),
+    })
+}
+
+/// Instantiates a prepared tool into `store` and calls its `run-v2` export,
+/// or `run` for tools without one, whose output becomes a single text part.
+///
+/// # Errors
+///
+/// Returns `EngineError::Instantiation` if instantiation fails, a
+/// resource-limit variant if a limit stops it (e.g. an initial memory above
+/// the cap), or the call's error as classified by [`classify_trap`].
+///
+/// [`classify_trap`]: crate::trap::classify_trap
+pub async fn call_prepared(
+    store: &mut Store<HostState>,
+    prepared: &PreparedTool,
+    input: &str,
+) -> Result<Result<Vec<ContentPart>, String>, EngineError> {
+    let instantiation = |e: wasmtime::Error| {
+        resource_trap(&e).unwrap_or_else(|| EngineError::Instantiation {
+            reason: "failed to instantiate prepared component".to_string(),
+            source: e,
+        })
+    };
+    let output = match prepared {
+        PreparedTool::TypedV2(pre) => {
+            let tool = pre
+                .instantiate_async(&mut *store)
+                .await
+                .map_err(instantiation)?;
+            return call_run_v2(store, &tool, input).await;
+        }
+        PreparedTool::Typed(pre) => {
+            let tool = pre
+                .instantiate_async(&mut *store)
+                .await
+                .map_err(instantiation)?;
+            call_run(store, &tool, input).await
+        }
+        PreparedTool::Flat(pre) => {
+            let instance = pre
+                .instantiate_async(&mut *store)
+                .await
+                .map_err(instantiation)?;
+            call_tool_run(store, &instance, input).await
+        }
+    };
+    Ok(output?.map(|text| vec![ContentPart::text(text)]))
+}
@@ -0,0 +1,55 @@
+//! Progress reported by guests through `kami:tool/progress.report-progress`.
+//!
+//! The host forwards each valid update to the [`ProgressSink`] installed
+//! on the store's `HostState`; without a sink, reports are only traced.
+
+/// One progress update of a running call.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Progress {
+    /// Work done so far.
+    pub progress: f64,
+    /// Total amount of work, if known.
+    pub total: Option<f64>,
+    /// Human-readable status.
+    pub message: Option<String>,
+}
+
+impl Progress {
+    /// Returns `true` if `progress` and `total` are finite and non-negative.
+    pub fn is_valid(&self) -> bool {
+        let valid = |v: f64| v.is_finite() && v >= 0.0;
+        valid(self.progress) && self.total.is_none_or(valid)
+    }
+}
+
+/// Receives the progress updates of one execution.
+pub trait ProgressSink: Send + Sync {
+    /// Called for every valid update, in the order the guest reported them.
+    fn report(&self, progress: Progress);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn progress(progress: f64, total: Option<f64>) -> Progress {
+        Progress {
+            progress,
+            total,
+            message: None,
+        }
+    }
+
+    #[test]
+    fn finite_non_negative_values_are_valid() {
+        assert!(progress(0.0, None).is_valid());
+        assert!(progress(3.0, Some(10.0)).is_valid());
+    }
+
+    #[test]
+    fn nan_infinite_or_negative_values_are_invalid() {
+        assert!(!progress(f64::NAN, None).is_valid());
+        assert!(!progress(1.0, Some(f64::INFINITY)).is_valid());
+        assert!(!progress(-1.0, Some(2.0)).is_valid());
+    }
+}
@@ -0,0 +1,28 @@
+//! Secrets offered to guests through `kami:tool/secrets.get-secret`.
+//!
+//! The host forwards each call to the [`SecretSource`] installed on the
+//! store's `HostState`, which checks the tool's grants and audits the
+//! access; without one, every call fails with [`SecretError::NotGranted`].
+
+use async_trait::async_trait;
+
+/// Errors returned to the guest by `get-secret` calls.
+#[derive(Debug, thiserror::Error)]
+pub enum SecretError {
+    /// The tool did not declare the secret in its `[security]` section.
+    #[error("secret '{name}' is not granted to this tool")]
+    NotGranted { name: String },
+    /// The secret is declared but was never set by the operator.
+    #[error("secret '{name}' is not set")]
+    NotFound { name: String },
+    /// The backing store failed.
+    #[error("secret storage error: {message}")]
+    Storage { message: String },
+}
+
+/// Secrets readable by one running tool.
+#[async_trait]
+pub trait SecretSource: Send + Sync {
+    /// Returns the value of the secret `name`.
+    async fn get(&self, name: &str) -> Result<String, SecretError>;
+}
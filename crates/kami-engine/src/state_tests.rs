@@ -0,0 +1,27 @@
+//! Tests for `HostState`.
+
+use super::*;
+use wasmtime_wasi::WasiCtxBuilder;
+
+#[test]
+fn host_state_creation() {
+    let ctx = WasiCtxBuilder::new().build();
+    let state = HostState::new(ctx);
+    assert_eq!(state.fuel_consumed(), 0);
+}
+
+#[test]
+fn host_state_with_memory_limits() {
+    let ctx = WasiCtxBuilder::new().build();
+    let state = HostState::with_limits(ctx, 32 * 1024 * 1024);
+    assert_eq!(state.fuel_consumed(), 0);
+    assert_eq!(state.memory_stats().limit_bytes, 32 * 1024 * 1024);
+}
+
+#[test]
+fn record_fuel_updates_consumed() {
+    let ctx = WasiCtxBuilder::new().build();
+    let mut state = HostState::new(ctx);
+    state.record_fuel(500);
+    assert_eq!(state.fuel_consumed(), 500);
+}
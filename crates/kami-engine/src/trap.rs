@@ -0,0 +1,96 @@
+//! Classification of execution failures into resource-specific errors.
+//!
+//! Wasmtime reports fuel exhaustion and epoch interruption as a
+//! [`wasmtime::Trap`] somewhere in the error chain, and a memory growth
+//! refused by [`MemoryLimiter`](crate::MemoryLimiter) as a
+//! [`MemoryLimitExceeded`], and a cancelled store as [`Cancelled`].
+//! Everything else is a genuine guest trap.
+
+use wasmtime::Trap;
+
+use crate::error::EngineError;
+use crate::interrupt::Cancelled;
+
+/// Error raised by the limiter when a memory grows past its cap.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("memory growth to {desired_bytes} bytes exceeds the {limit_bytes} byte limit")]
+pub struct MemoryLimitExceeded {
+    /// Size the guest asked for.
+    pub desired_bytes: u64,
+    /// Configured cap.
+    pub limit_bytes: u64,
+}
+
+/// Returns the resource-specific error behind `error`, if any.
+///
+/// Recognises fuel exhaustion, epoch interruption, the memory cap and
+/// cancellation; returns `None` for any other failure.
+pub fn resource_trap(error: &wasmtime::Error) -> Option<EngineError> {
+    error.chain().find_map(|cause| {
+        if cause.is::<Cancelled>() {
+            return Some(EngineError::Cancelled);
+        }
+        if let Some(limit) = cause.downcast_ref::<MemoryLimitExceeded>() {
+            return Some(EngineError::MemoryLimitExceeded {
+                limit_bytes: limit.limit_bytes,
+            });
+        }
+        match cause.downcast_ref::<Trap>()? {
+            Trap::OutOfFuel => Some(EngineError::FuelExhausted),
+            Trap::Interrupt => Some(EngineError::EpochDeadline),
+            _ => None,
+        }
+    })
+}
+
+/// Maps an error raised while a guest export runs to an `EngineError`.
+///
+/// Resource exhaustion gets its own variant; anything else becomes
+/// `EngineError::Trap`.
+pub fn classify_trap(error: wasmtime::Error) -> EngineError {
+    resource_trap(&error).unwrap_or_else(|| EngineError::Trap {
+        message: error.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn out_of_fuel_is_fuel_exhausted() {
+        let err = wasmtime::Error::new(Trap::OutOfFuel).context("wasm backtrace");
+        assert!(matches!(classify_trap(err), EngineError::FuelExhausted));
+    }
+
+    #[test]
+    fn interrupt_is_epoch_deadline() {
+        let err = wasmtime::Error::new(Trap::Interrupt);
+        assert!(matches!(classify_trap(err), EngineError::EpochDeadline));
+    }
+
+    #[test]
+    fn limiter_error_is_memory_limit() {
+        let err = wasmtime::Error::new(MemoryLimitExceeded {
+            desired_bytes: 2048,
+            limit_bytes: 1024,
+        });
+        assert!(matches!(
+            classify_trap(err),
+            EngineError::MemoryLimitExceeded { limit_bytes: 1024 }
+        ));
+    }
+
+    #[test]
+    fn cancelled_is_cancelled() {
+        let err = wasmtime::Error::new(Cancelled).context("wasm backtrace");
+        assert!(matches!(classify_trap(err), EngineError::Cancelled));
+    }
+
+    #[test]
+    fn other_traps_stay_guest_traps() {
+        let err = wasmtime::Error::new(Trap::UnreachableCodeReached);
+        assert!(matches!(classify_trap(err), EngineError::Trap { .. }));
+        assert!(resource_trap(&wasmtime::Error::msg("boom")).is_none());
+    }
+}
@@ -0,0 +1,89 @@
+//! Cooperative yielding of long-running guests.
+//!
+//! A guest future only returns `Pending` when it awaits a host call, so a
+//! CPU-bound tool would otherwise keep its tokio worker busy until it
+//! finishes or times out. Wasmtime can suspend the guest periodically,
+//! either after a fixed amount of fuel or at every epoch tick, which gives
+//! the executor a chance to run other tasks in between.
+
+use wasmtime::Store;
+
+use crate::error::EngineError;
+use crate::state::HostState;
+
+/// Fuel consumed between two yields with the default strategy.
+pub const DEFAULT_YIELD_FUEL: u64 = 10_000;
+
+/// When a running guest hands its worker thread back to the executor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YieldStrategy {
+    /// Never yield; the guest runs until it returns, traps or times out.
+    Never,
+    /// Yield every `interval` units of fuel consumed.
+    Fuel {
+        /// Fuel consumed between two yields.
+        interval: u64,
+    },
+    /// Yield at every epoch tick. Requires epoch interruption; see
+    /// [`crate::set_interruptible_epoch_deadline`].
+    Epoch,
+}
+
+impl Default for YieldStrategy {
+    fn default() -> Self {
+        Self::Fuel {
+            interval: DEFAULT_YIELD_FUEL,
+        }
+    }
+}
+
+/// Enables fuel-based yielding on `store` if `strategy` asks for it.
+///
+/// # Errors
+///
+/// Returns `EngineError::Config` if the interval is zero or the store's
+/// engine lacks async support.
+pub fn set_fuel_yield(
+    store: &mut Store<HostState>,
+    strategy: YieldStrategy,
+) -> Result<(), EngineError> {
+    match strategy {
+        YieldStrategy::Fuel { interval: 0 } => Err(EngineError::Config(
+            "fuel yield interval must be greater than zero".to_string(),
+        )),
+        YieldStrategy::Fuel { interval } => store
+            .fuel_async_yield_interval(Some(interval))
+            .map_err(|e| EngineError::Config(format!("fuel yield: {e}"))),
+        YieldStrategy::Never | YieldStrategy::Epoch => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instance::{create_engine, create_store, InstanceConfig};
+    use wasmtime_wasi::WasiCtxBuilder;
+
+    fn store() -> Store<HostState> {
+        let engine = create_engine(&InstanceConfig::default()).expect("engine");
+        let state = HostState::new(WasiCtxBuilder::new().build());
+        create_store(&engine, state, 1_000).expect("store")
+    }
+
+    #[test]
+    fn default_yields_on_fuel() {
+        assert_eq!(
+            YieldStrategy::default(),
+            YieldStrategy::Fuel {
+                interval: DEFAULT_YIELD_FUEL
+            }
+        );
+        assert!(set_fuel_yield(&mut store(), YieldStrategy::default()).is_ok());
+    }
+
+    #[test]
+    fn zero_fuel_interval_is_rejected() {
+        let result = set_fuel_yield(&mut store(), YieldStrategy::Fuel { interval: 0 });
+        assert!(matches!(result, Err(EngineError::Config(_))));
+    }
+}
@@ -0,0 +1,136 @@
+//! Multi-part results from components exporting `run-v2`.
+
+use kami_engine::{
+    call_prepared, create_engine, create_linker, create_store, load_component, prepare_tool,
+    HostState, InstanceConfig, PreparedTool,
+};
+use kami_sandbox::{build_wasi_ctx, WasiConfig};
+use kami_types::{ContentPart, SecurityConfig};
+use wasmtime::Store;
+
+/// A `kami-tool-v2` component whose `run-v2` returns a text part, a blob of
+/// type `mime` holding `data` (at most 16 bytes) and a resource link, laid
+/// out in static data.
+///
+/// Each `content-part` takes 44 bytes: a discriminant, then a payload of
+/// up to 40 bytes (the `link` record). `run` echoes its input.
+fn v2_component(mime: &str, data: &str) -> String {
+    format!(
+        r#"
+(component
+  (core module $m
+    (memory (export "memory") 1)
+    (data (i32.const 512) "hello")
+    (data (i32.const 528) "{mime}")
+    (data (i32.const 544) "{data}")
+    (data (i32.const 560) "file:///c.svg")
+    (data (i32.const 576) "chart")
+    (data (i32.const 1024) "\00\00\00\00\00\02\00\00\05\00\00\00")
+    (data (i32.const 1068) "\01\00\00\00\10\02\00\00\{len:02x}\00\00\00\20\02\00\00\{data_len:02x}\00\00\00")
+    (data (i32.const 1112) "\03\00\00\00\30\02\00\00\0d\00\00\00\40\02\00\00\05\00\00\00")
+    (data (i32.const 4096) "\00\00\00\00\00\04\00\00\03\00\00\00")
+    (data (i32.const 4112) "\40\02\00\00\05\00\00\00")
+    (func (export "cabi_realloc") (param i32 i32 i32 i32) (result i32) i32.const 8192)
+    (func (export "run") (param $ptr i32) (param $len i32) (result i32)
+      (i32.store (i32.const 4128) (i32.const 0))
+      (i32.store (i32.const 4132) (local.get $ptr))
+      (i32.store (i32.const 4136) (local.get $len))
+      i32.const 4128)
+    (func (export "describe") (result i32) i32.const 4112)
+    (func (export "run-v2") (param i32 i32) (result i32) i32.const 4096)
+  )
+  (core instance $i (instantiate $m))
+
+  (type $blob (record (field "mime-type" string) (field "data" string)))
+  (type $contents (variant (case "text" string) (case "blob" string)))
+  (type $embedded (record
+    (field "uri" string) (field "mime-type" (option string)) (field "contents" $contents)))
+  (type $link (record
+    (field "uri" string) (field "name" string)
+    (field "description" (option string)) (field "mime-type" (option string))))
+  (type $part (variant
+    (case "text" string) (case "blob" $blob) (case "embedded" $embedded) (case "link" $link)))
+
+  (func $run (param "input" string) (result (result string (error string)))
+    (canon lift (core func $i "run") (memory $i "memory") (realloc (func $i "cabi_realloc"))))
+  (func $describe (result string)
+    (canon lift (core func $i "describe") (memory $i "memory")))
+  (func $run-v2 (param "input" string) (result (result (list $part) (error string)))
+    (canon lift (core func $i "run-v2") (memory $i "memory") (realloc (func $i "cabi_realloc"))))
+
+  (instance $tool (export "run" (func $run)) (export "describe" (func $describe)))
+  (instance $content
+    (export "blob" (type $blob)) (export "resource-contents" (type $contents))
+    (export "embedded" (type $embedded)) (export "link" (type $link))
+    (export "content-part" (type $part)) (export "run-v2" (func $run-v2)))
+  (export "kami:tool/tool@0.1.0" (instance $tool))
+  (export "kami:tool/content@0.1.0" (instance $content))
+)
+"#,
+        len = mime.len(),
+        data_len = data.len()
+    )
+}
+
+async fn run(mime: &str, data: &str) -> (PreparedTool, Result<Vec<ContentPart>, String>) {
+    let engine = create_engine(&InstanceConfig::default()).expect("engine");
+    let linker = create_linker(&engine).expect("linker");
+    let component =
+        load_component(&engine, v2_component(mime, data).as_bytes()).expect("component");
+    let prepared = prepare_tool(&linker, &component).expect("prepare");
+    let ctx =
+        build_wasi_ctx(&SecurityConfig::default(), &WasiConfig::default(), None).expect("wasi ctx");
+    let mut store: Store<HostState> =
+        create_store(&engine, HostState::new(ctx), 1_000_000).expect("store");
+    let result = call_prepared(&mut store, &prepared, "{}")
+        .await
+        .expect("call");
+    (prepared, result)
+}
+
+#[tokio::test]
+async fn run_v2_parts_are_mapped_to_content() {
+    let (prepared, result) = run("image/png", "iVBO").await;
+
+    assert!(matches!(prepared, PreparedTool::TypedV2(_)));
+    assert_eq!(
+        result.expect("parts"),
+        vec![
+            ContentPart::text("hello"),
+            ContentPart::Image {
+                data: "iVBO".into(),
+                mime_type: "image/png".into(),
+            },
+            ContentPart::ResourceLink {
+                uri: "file:///c.svg".into(),
+                name: "chart".into(),
+                description: None,
+                mime_type: None,
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn audio_blobs_become_audio_parts() {
+    let (_, result) = run("audio/wav", "UklG").await;
+
+    let parts = result.expect("parts");
+    assert!(matches!(&parts[1], ContentPart::Audio { mime_type, .. } if mime_type == "audio/wav"));
+}
+
+#[tokio::test]
+async fn blobs_of_other_types_are_a_tool_error() {
+    let (_, result) = run("application/pdf", "JVBE").await;
+
+    let error = result.expect_err("unsupported blob");
+    assert!(error.contains("'application/pdf'"), "{error}");
+}
+
+#[tokio::test]
+async fn blobs_that_are_not_base64_are_a_tool_error() {
+    let (_, result) = run("image/png", "not base64!").await;
+
+    let error = result.expect_err("invalid data");
+    assert!(error.contains("not valid base64"), "{error}");
+}
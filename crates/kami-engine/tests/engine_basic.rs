@@ -4,7 +4,7 @@ mod common;
 
 use kami_engine::{
     call_tool_run, create_engine, create_linker, create_store, instantiate_component,
-    load_component, HostState, InstanceConfig,
+    load_component, EngineError, HostState, InstanceConfig, YieldStrategy,
 };
 use kami_sandbox::{build_wasi_ctx, WasiConfig};
 use kami_types::SecurityConfig;
@@ -115,3 +115,29 @@ async fn fuel_metering_works() {
         "fuel should be consumed after execution"
     );
 }
+
+#[test]
+fn epoch_yield_requires_epoch_interruption() {
+    let config = InstanceConfig {
+        yield_strategy: YieldStrategy::Epoch,
+        ..InstanceConfig::default()
+    };
+    assert!(matches!(
+        create_engine(&config),
+        Err(EngineError::Config(_))
+    ));
+}
+
+#[test]
+fn yielding_requires_async_support() {
+    let config = InstanceConfig {
+        async_support: false,
+        ..InstanceConfig::default()
+    };
+    assert!(create_engine(&config).is_err());
+    let config = InstanceConfig {
+        yield_strategy: YieldStrategy::Never,
+        ..config
+    };
+    assert!(create_engine(&config).is_ok());
+}
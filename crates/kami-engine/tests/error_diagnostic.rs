@@ -90,3 +90,34 @@ fn config_error_has_no_fix() {
     let e = EngineError::Config("bad".into());
     assert!(e.fix().is_none());
 }
+
+#[test]
+fn fuel_exhausted_maps_to_resource_exhausted() {
+    let err: KamiError = EngineError::FuelExhausted.into();
+    assert_eq!(err.kind, ErrorKind::ResourceExhausted);
+}
+
+#[test]
+fn fuel_exhausted_fix_mentions_max_fuel() {
+    let fix = EngineError::FuelExhausted.fix().expect("has fix");
+    assert!(fix.contains("max_fuel"));
+}
+
+#[test]
+fn memory_limit_maps_to_resource_exhausted() {
+    let e = EngineError::MemoryLimitExceeded {
+        limit_bytes: 16 * 1024 * 1024,
+    };
+    assert!(e.hint().expect("has hint").contains("16 MiB"));
+    assert!(e.fix().expect("has fix").contains("max_memory_mb"));
+    let err: KamiError = e.into();
+    assert_eq!(err.kind, ErrorKind::ResourceExhausted);
+}
+
+#[test]
+fn epoch_deadline_maps_to_timeout() {
+    let fix = EngineError::EpochDeadline.fix().expect("has fix");
+    assert!(fix.contains("max_execution_ms"));
+    let err: KamiError = EngineError::EpochDeadline.into();
+    assert_eq!(err.kind, ErrorKind::Timeout);
+}
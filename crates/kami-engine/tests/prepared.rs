@@ -0,0 +1,73 @@
+//! Pre-instantiation tests: link once, instantiate per store.
+
+mod common;
+
+use kami_engine::{
+    call_prepared, create_engine, create_linker, create_store, load_component, prepare_tool,
+    HostState, InstanceConfig, PreparedTool,
+};
+use kami_sandbox::{build_wasi_ctx, WasiConfig};
+use kami_types::{ContentPart, SecurityConfig};
+use wasmtime::{Engine, Store};
+
+use common::ECHO_COMPONENT_WAT;
+
+fn fresh_store(engine: &Engine) -> Store<HostState> {
+    let ctx =
+        build_wasi_ctx(&SecurityConfig::default(), &WasiConfig::default(), None).expect("wasi ctx");
+    create_store(engine, HostState::new(ctx), 1_000_000).expect("store")
+}
+
+#[tokio::test]
+async fn flat_component_is_prepared_as_flat() {
+    let engine = create_engine(&InstanceConfig::default()).expect("engine");
+    let linker = create_linker(&engine).expect("linker");
+    let component = load_component(&engine, ECHO_COMPONENT_WAT.as_bytes()).expect("component");
+
+    let prepared = prepare_tool(&linker, &component).expect("prepare");
+
+    assert!(matches!(prepared, PreparedTool::Flat(_)));
+}
+
+#[tokio::test]
+async fn prepared_tool_runs_in_successive_stores() {
+    let engine = create_engine(&InstanceConfig::default()).expect("engine");
+    let linker = create_linker(&engine).expect("linker");
+    let component = load_component(&engine, ECHO_COMPONENT_WAT.as_bytes()).expect("component");
+    let prepared = prepare_tool(&linker, &component).expect("prepare");
+
+    for input in ["first", "second"] {
+        let mut store = fresh_store(&engine);
+        let result = call_prepared(&mut store, &prepared, input)
+            .await
+            .expect("call");
+        assert_eq!(result, Ok(vec![ContentPart::text(input)]));
+    }
+}
+
+#[tokio::test]
+async fn unsatisfied_import_fails_to_prepare() {
+    let engine = create_engine(&InstanceConfig::default()).expect("engine");
+    let linker = create_linker(&engine).expect("linker");
+    let wat = r#"(component (import "unknown:pkg/missing" (instance (export "f" (func)))))"#;
+    let component = load_component(&engine, wat.as_bytes()).expect("component");
+
+    let result = prepare_tool(&linker, &component);
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn published_host_interface_is_unchanged() {
+    let engine = create_engine(&InstanceConfig::default()).expect("engine");
+    let linker = create_linker(&engine).expect("linker");
+    let reporter = include_str!("../../../tests/fixtures/wat/reporter.wat");
+    let prepare = |wat: &str| {
+        let component = load_component(&engine, wat.as_bytes()).expect("component");
+        prepare_tool(&linker, &component).map(|_| ())
+    };
+
+    assert!(prepare(reporter).is_ok());
+    let in_host = reporter.replace("kami:tool/progress@", "kami:tool/host@");
+    assert!(prepare(&in_host).is_err(), "host@0.1.0 only has log");
+}
@@ -35,6 +35,30 @@
 //!
 //! Build with: `cargo build --target wasm32-wasip2 --release`
 //!
+//! # Multi-Part Results
+//!
+//! Tools returning images, audio or resources implement the
+//! `kami-tool-v2` world from [`v2`]: its `ContentGuest::run_v2` returns a
+//! list of `ContentPart`s, next to the usual `Guest` (`run`, `describe`).
+//!
+//! ```ignore
+//! use kami_guest::bindings::v2::{self, Blob, ContentPart};
+//!
+//! impl v2::ContentGuest for MyTool {
+//!     fn run_v2(input: String) -> Result<Vec<ContentPart>, String> {
+//!         Ok(vec![
+//!             ContentPart::Text("Monthly sales".into()),
+//!             ContentPart::Blob(Blob {
+//!                 mime_type: "image/png".into(),
+//!                 data: render_chart_base64(&input)?,
+//!             }),
+//!         ])
+//!     }
+//! }
+//!
+//! v2::export!(MyTool with_types_in v2);
+//! ```
+//!
 //! # Native Testing
 //!
 //! For native tests (no WASM), use `kami_tool!` macro instead:
@@ -56,3 +80,30 @@ wit_bindgen::generate!({
 
 #[cfg(feature = "component-model")]
 pub use exports::kami::tool::tool::Guest;
+
+/// Bindings for the `kami-tool-ext` world, which adds the `progress`,
+/// `kv` and `secrets` host imports.
+#[cfg(feature = "component-model")]
+pub mod ext {
+    wit_bindgen::generate!({
+        world: "kami-tool-ext",
+        path: "../../wit",
+    });
+
+    pub use exports::kami::tool::tool::Guest;
+}
+
+/// Bindings for the `kami-tool-v2` world, which adds `run-v2` to
+/// `kami-tool-ext`.
+#[cfg(feature = "component-model")]
+pub mod v2 {
+    wit_bindgen::generate!({
+        world: "kami-tool-v2",
+        path: "../../wit",
+    });
+
+    pub use exports::kami::tool::content::{
+        Blob, ContentPart, Embedded, Guest as ContentGuest, Link, ResourceContents,
+    };
+    pub use exports::kami::tool::tool::Guest;
+}
@@ -0,0 +1,82 @@
+//! Validation of `tools/call` arguments against the tool's input schema.
+//!
+//! Runs before any WASM is instantiated, so an agent sending malformed
+//! arguments gets every violation back without spending the tool's fuel.
+
+use std::sync::Arc;
+
+use serde_json::{json, Value};
+
+use kami_protocol::{error_codes, JsonRpcErrorResponse, RequestId};
+use kami_registry::ToolRepository;
+use kami_types::ToolId;
+
+use crate::handler::JsonRpcOutput;
+use crate::schemas::{SchemaCache, ToolSchemas};
+
+/// Where `tools/call` finds a tool and its compiled schemas.
+pub(crate) struct ToolLookup<'a> {
+    /// Registry the tool is installed in.
+    pub(crate) repository: &'a dyn ToolRepository,
+    /// Schemas compiled by earlier calls.
+    pub(crate) schemas: &'a SchemaCache,
+}
+
+/// Looks `tool_id` up and checks `arguments` against its input schema.
+///
+/// Omitted arguments are checked as an empty object. Returns the tool's
+/// schemas, or `None` for a tool not in the repository: the runtime
+/// reports those on resolve.
+///
+/// # Errors
+/// Returns an `INVALID_PARAMS` error whose `data.violations` lists each
+/// violation with its JSON Pointer `path`, `keyword` and `message`, or an
+/// `INTERNAL_ERROR` if the repository fails or the tool's own schema is
+/// invalid.
+pub(crate) async fn check_arguments(
+    id: &RequestId,
+    tool_id: &ToolId,
+    lookup: &ToolLookup<'_>,
+    arguments: &Value,
+) -> Result<Option<Arc<ToolSchemas>>, JsonRpcOutput> {
+    let internal = |message: String| {
+        JsonRpcOutput::Error(JsonRpcErrorResponse::error(
+            id.clone(),
+            error_codes::INTERNAL_ERROR,
+            message,
+        ))
+    };
+    let tool = match lookup.repository.find_by_id(tool_id).await {
+        Ok(Some(tool)) => tool,
+        Ok(None) => return Ok(None),
+        Err(e) => return Err(internal(format!("cannot look up {tool_id}: {e}"))),
+    };
+    let schemas = lookup
+        .schemas
+        .get(&tool.manifest)
+        .map_err(|e| internal(format!("{tool_id} declares an {e}")))?;
+
+    let empty = json!({});
+    let violations = schemas.input.validate(if arguments.is_null() {
+        &empty
+    } else {
+        arguments
+    });
+    if violations.is_empty() {
+        return Ok(Some(schemas));
+    }
+    let summary: Vec<String> = violations
+        .iter()
+        .map(|v| format!("arguments{}: {}", v.path, v.message))
+        .collect();
+    let message = format!("invalid arguments for {tool_id}: {}", summary.join("; "));
+    let data = json!({ "tool": tool_id.as_str(), "violations": violations });
+    Err(JsonRpcOutput::Error(
+        JsonRpcErrorResponse::error(id.clone(), error_codes::INVALID_PARAMS, message)
+            .with_data(data),
+    ))
+}
+
+#[cfg(test)]
+#[path = "arguments_tests.rs"]
+mod tests;
@@ -0,0 +1,107 @@
+//! Tests for the lookup ahead of argument validation.
+
+use super::*;
+use async_trait::async_trait;
+use kami_registry::{RepositoryError, ToolQuery};
+use kami_store_sqlite::SqliteToolRepository;
+use kami_types::{
+    ArgumentConstraints, SecurityConfig, Tool, ToolArgument, ToolManifest, ToolVersion,
+};
+
+struct FailRepo;
+#[async_trait]
+impl ToolRepository for FailRepo {
+    async fn find_by_id(&self, _: &ToolId) -> Result<Option<Tool>, RepositoryError> {
+        Err(RepositoryError::Storage {
+            message: "disk gone".into(),
+        })
+    }
+    async fn find_all(&self, _: ToolQuery) -> Result<Vec<Tool>, RepositoryError> {
+        Ok(vec![])
+    }
+    async fn insert(&self, _: &Tool) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+    async fn update(&self, _: &Tool) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+    async fn delete(&self, _: &ToolId) -> Result<bool, RepositoryError> {
+        Ok(false)
+    }
+}
+
+/// A tool whose `code` argument must match `pattern`.
+fn tool(pattern: &str) -> Tool {
+    Tool {
+        manifest: ToolManifest {
+            id: ToolId::new("dev.test.code").expect("id"),
+            name: "code".into(),
+            version: ToolVersion::new(1, 0, 0),
+            wasm: "code.wasm".into(),
+            description: "code".into(),
+            arguments: vec![ToolArgument {
+                name: "code".into(),
+                arg_type: "string".into(),
+                constraints: ArgumentConstraints {
+                    pattern: Some(pattern.into()),
+                    ..ArgumentConstraints::default()
+                },
+                ..ToolArgument::default()
+            }],
+            output: vec![],
+            security: SecurityConfig::default(),
+            wasm_sha256: None,
+            signature: None,
+            signer_public_key: None,
+        },
+        install_path: "/nowhere".into(),
+        enabled: true,
+        pinned_version: None,
+        updated_at: None,
+    }
+}
+
+async fn check(repository: &dyn ToolRepository, code: &str) -> Result<(), i32> {
+    let lookup = ToolLookup {
+        repository,
+        schemas: &SchemaCache::default(),
+    };
+    let id = ToolId::new("dev.test.code").expect("id");
+    match check_arguments(
+        &RequestId::Number(1),
+        &id,
+        &lookup,
+        &json!({ "code": code }),
+    )
+    .await
+    {
+        Ok(_) => Ok(()),
+        Err(JsonRpcOutput::Error(e)) => Err(e.error.code),
+        Err(other) => panic!("unexpected output {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn repository_errors_are_not_skipped() {
+    assert_eq!(
+        check(&FailRepo, "x").await,
+        Err(error_codes::INTERNAL_ERROR)
+    );
+}
+
+#[tokio::test]
+async fn invalid_pattern_is_a_server_error() {
+    let repo = SqliteToolRepository::open_in_memory().expect("db");
+    repo.insert(&tool("(")).await.expect("insert");
+
+    assert_eq!(check(&repo, "x").await, Err(error_codes::INTERNAL_ERROR));
+}
+
+#[tokio::test]
+async fn arguments_are_checked_against_the_pattern() {
+    let repo = SqliteToolRepository::open_in_memory().expect("db");
+    repo.insert(&tool("^[a-z]+$")).await.expect("insert");
+
+    assert_eq!(check(&repo, "abc").await, Ok(()));
+    assert_eq!(check(&repo, "ABC").await, Err(error_codes::INVALID_PARAMS));
+}
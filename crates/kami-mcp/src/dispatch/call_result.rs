@@ -0,0 +1,77 @@
+//! `tools/call` results: content parts, and structured output for tools
+//! declaring an output schema.
+//!
+//! A tool with `[[mcp.output]]` fields promises JSON matching its
+//! `outputSchema`. Its output is parsed and validated, then returned as
+//! `structuredContent` with the raw text kept as the `content` fallback.
+
+use serde_json::Value;
+
+use kami_protocol::mcp::tools::{ToolContent, ToolsCallResult};
+use kami_protocol::schema::CompiledSchema;
+use kami_types::{ContentPart, ToolId};
+
+/// Builds the `tools/call` result for a call's `parts`, or its text
+/// `output` when there are none (failed calls).
+///
+/// Given the output schema of `tool_id`, `output` is parsed and validated:
+/// output that is not JSON or violates the schema turns the result into an
+/// error describing what is wrong.
+pub(crate) fn call_result(
+    tool_id: &ToolId,
+    output_schema: Option<&CompiledSchema>,
+    output: String,
+    parts: Vec<ContentPart>,
+    is_error: bool,
+) -> ToolsCallResult {
+    let Some(schema) = output_schema.filter(|_| !is_error) else {
+        return parts_result(output, parts, is_error, None);
+    };
+    let value: Value = match serde_json::from_str(&output) {
+        Ok(value) => value,
+        Err(e) => {
+            tracing::warn!(%tool_id, error = %e, "tool output is not JSON");
+            return text_result(format!("output of {tool_id} is not valid JSON: {e}"), true);
+        }
+    };
+    let violations = schema.validate(&value);
+    if !violations.is_empty() {
+        let summary: Vec<String> = violations
+            .iter()
+            .map(|v| format!("output{}: {}", v.path, v.message))
+            .collect();
+        tracing::warn!(%tool_id, "tool output does not match its output schema");
+        let message = format!(
+            "output of {tool_id} does not match its output schema: {}",
+            summary.join("; ")
+        );
+        return text_result(message, true);
+    }
+    parts_result(output, parts, false, Some(value))
+}
+
+fn text_result(text: String, is_error: bool) -> ToolsCallResult {
+    parts_result(text, Vec::new(), is_error, None)
+}
+
+fn parts_result(
+    text: String,
+    parts: Vec<ContentPart>,
+    is_error: bool,
+    structured_content: Option<Value>,
+) -> ToolsCallResult {
+    let content = if parts.is_empty() {
+        vec![ToolContent::Text { text }]
+    } else {
+        parts.into_iter().map(ToolContent::from).collect()
+    };
+    ToolsCallResult {
+        content,
+        structured_content,
+        is_error,
+    }
+}
+
+#[cfg(test)]
+#[path = "call_result_tests.rs"]
+mod tests;
@@ -0,0 +1,123 @@
+//! Tests for `tools/call` results.
+
+use super::*;
+use kami_protocol::schema::build_output_schema;
+use kami_types::{SecurityConfig, ToolArgument, ToolManifest, ToolVersion};
+use serde_json::json;
+
+fn manifest(output: Vec<ToolArgument>) -> ToolManifest {
+    ToolManifest {
+        id: ToolId::new("dev.test.count").expect("id"),
+        name: "count".into(),
+        version: ToolVersion::new(1, 0, 0),
+        wasm: "count.wasm".into(),
+        description: "count".into(),
+        arguments: vec![],
+        output,
+        security: SecurityConfig::default(),
+        wasm_sha256: None,
+        signature: None,
+        signer_public_key: None,
+    }
+}
+
+fn counted() -> ToolManifest {
+    manifest(vec![ToolArgument {
+        name: "count".into(),
+        arg_type: "integer".into(),
+        required: true,
+        ..ToolArgument::default()
+    }])
+}
+
+/// Result of a call to `manifest`'s tool, with its output schema compiled.
+fn result(
+    manifest: Option<&ToolManifest>,
+    output: &str,
+    parts: Vec<ContentPart>,
+    is_error: bool,
+) -> ToolsCallResult {
+    let schema = manifest.and_then(|m| build_output_schema(&m.output));
+    let schema = schema.map(|s| CompiledSchema::compile(s).expect("compile"));
+    let id = ToolId::new("dev.test.count").expect("id");
+    call_result(&id, schema.as_ref(), output.into(), parts, is_error)
+}
+
+/// Result of a successful call returning `output` from `run`.
+fn ok(manifest: Option<&ToolManifest>, output: &str) -> ToolsCallResult {
+    result(manifest, output, vec![ContentPart::text(output)], false)
+}
+
+fn text(result: &ToolsCallResult) -> &str {
+    match &result.content[..] {
+        [ToolContent::Text { text }] => text,
+        other => panic!("expected one text item, got {other:?}"),
+    }
+}
+
+#[test]
+fn valid_json_output_becomes_structured_content() {
+    let result = ok(Some(&counted()), r#"{"count":3}"#);
+
+    assert!(!result.is_error);
+    assert_eq!(result.structured_content, Some(json!({"count": 3})));
+    assert_eq!(text(&result), r#"{"count":3}"#);
+}
+
+#[test]
+fn tools_without_output_schema_return_text() {
+    let plain = manifest(vec![]);
+    for tool in [Some(&plain), None] {
+        let result = ok(tool, r#"{"count":3}"#);
+        assert!(result.structured_content.is_none());
+        assert!(!result.is_error);
+    }
+}
+
+#[test]
+fn failed_calls_are_not_parsed() {
+    let result = result(Some(&counted()), "boom", vec![], true);
+
+    assert!(result.is_error);
+    assert!(result.structured_content.is_none());
+    assert_eq!(text(&result), "boom");
+}
+
+#[test]
+fn non_json_output_is_an_error() {
+    let result = ok(Some(&counted()), "three");
+
+    assert!(result.is_error);
+    assert!(text(&result).starts_with("output of dev.test.count is not valid JSON"));
+}
+
+#[test]
+fn output_violating_schema_is_an_error() {
+    let result = ok(Some(&counted()), r#"{"count":"3"}"#);
+
+    assert!(result.is_error);
+    assert!(result.structured_content.is_none());
+    assert_eq!(
+        text(&result),
+        "output of dev.test.count does not match its output schema: \
+         output/count: expected integer, got string"
+    );
+}
+
+#[test]
+fn every_part_becomes_a_content_item() {
+    let parts = vec![
+        ContentPart::text("chart"),
+        ContentPart::Image {
+            data: "iVBO".into(),
+            mime_type: "image/png".into(),
+        },
+    ];
+
+    let result = result(None, "chart", parts, false);
+
+    assert_eq!(result.content.len(), 2);
+    assert!(
+        matches!(&result.content[1], ToolContent::Image { mime_type, .. } if mime_type == "image/png")
+    );
+}
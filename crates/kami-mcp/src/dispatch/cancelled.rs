@@ -0,0 +1,41 @@
+//! Handles the `notifications/cancelled` MCP notification.
+
+use serde_json::Value;
+
+use kami_protocol::mcp::CancelledParams;
+use kami_runtime::KamiRuntime;
+
+use crate::inflight::InFlight;
+
+/// Cancels the execution behind the request named in `params`.
+///
+/// Unknown or already finished requests are ignored, as the spec allows.
+/// The cancelled `tools/call` gets no response.
+pub(crate) fn handle_cancelled(
+    params: &Option<Value>,
+    in_flight: &InFlight,
+    runtime: &KamiRuntime,
+) {
+    let parsed = params
+        .clone()
+        .map(serde_json::from_value::<CancelledParams>);
+    let params = match parsed {
+        Some(Ok(p)) => p,
+        Some(Err(e)) => {
+            tracing::debug!(error = %e, "invalid notifications/cancelled params");
+            return;
+        }
+        None => {
+            tracing::debug!("notifications/cancelled without params");
+            return;
+        }
+    };
+    let request_id = params.request_id;
+    let reason = params.reason.as_deref().unwrap_or("none");
+    match in_flight.cancel(&request_id) {
+        Some(execution) if runtime.cancel(execution) => {
+            tracing::info!(?request_id, %execution, reason, "tools/call cancelled");
+        }
+        _ => tracing::debug!(?request_id, "cancelled request is not running"),
+    }
+}
@@ -4,33 +4,56 @@ use serde_json::Value;
 
 use kami_protocol::mcp::initialize::{
     InitializeParams, InitializeResult, PromptsCapability, ResourcesCapability, ServerCapabilities,
-    ServerInfo, ToolCapability, PROTOCOL_VERSION,
+    ServerInfo, ToolCapability,
 };
+use kami_protocol::mcp::logging::LoggingCapability;
+use kami_protocol::mcp::ProtocolVersion;
 use kami_protocol::{error_codes, JsonRpcErrorResponse, JsonRpcResponse, RequestId};
 
 use crate::handler::JsonRpcOutput;
+use crate::session::{Session, SessionState};
 
 /// Handles the `initialize` request and returns the server capabilities.
-pub(crate) fn handle_initialize(id: RequestId, params: &Option<Value>) -> JsonRpcOutput {
-    if let Some(p) = params {
-        if let Err(e) = serde_json::from_value::<InitializeParams>(p.clone()) {
-            return JsonRpcOutput::Error(JsonRpcErrorResponse::error(
-                id,
-                error_codes::INVALID_PARAMS,
-                format!("invalid initialize params: {e}"),
-            ));
-        }
-    }
+///
+/// The protocol revision is negotiated from the client's
+/// `protocolVersion` and recorded in `session`. `logging` advertises the
+/// logging capability when log notifications can actually be delivered.
+pub(crate) fn handle_initialize(
+    id: RequestId,
+    params: &Option<Value>,
+    logging: bool,
+    session: &SessionState,
+) -> JsonRpcOutput {
+    let negotiated = match params {
+        Some(p) => match serde_json::from_value::<InitializeParams>(p.clone()) {
+            Ok(params) => Session {
+                protocol: ProtocolVersion::negotiate(&params.protocol_version),
+            },
+            Err(e) => {
+                return JsonRpcOutput::Error(JsonRpcErrorResponse::error(
+                    id,
+                    error_codes::INVALID_PARAMS,
+                    format!("invalid initialize params: {e}"),
+                ));
+            }
+        },
+        None => Session::default(),
+    };
+    session.set(negotiated);
+    let protocol = negotiated.protocol;
+    tracing::debug!(%protocol, "MCP protocol negotiated");
 
     let result = InitializeResult {
-        protocol_version: PROTOCOL_VERSION.to_string(),
+        protocol_version: protocol.to_string(),
         capabilities: ServerCapabilities {
             tools: Some(ToolCapability {}),
             prompts: Some(PromptsCapability {}),
             resources: Some(ResourcesCapability {}),
+            logging: logging.then_some(LoggingCapability {}),
         },
         server_info: ServerInfo {
             name: "kami".to_string(),
+            title: protocol.has_titles().then(|| "KAMI".to_string()),
             version: env!("CARGO_PKG_VERSION").to_string(),
         },
     };
@@ -52,7 +75,7 @@ mod tests {
     #[test]
     fn initialize_no_params_returns_success() {
         let id = RequestId::Number(1);
-        let result = handle_initialize(id, &None);
+        let result = handle_initialize(id, &None, false, &SessionState::default());
         assert!(matches!(result, JsonRpcOutput::Success(_)));
     }
 
@@ -64,7 +87,7 @@ mod tests {
             "capabilities": {},
             "clientInfo": {"name": "test", "version": "1.0"}
         });
-        let result = handle_initialize(id, &Some(params));
+        let result = handle_initialize(id, &Some(params), false, &SessionState::default());
         assert!(matches!(result, JsonRpcOutput::Success(_)));
     }
 
@@ -72,7 +95,21 @@ mod tests {
     fn initialize_with_non_object_params_returns_error() {
         let id = RequestId::Number(3);
         let params = serde_json::json!(42);
-        let result = handle_initialize(id, &Some(params));
+        let result = handle_initialize(id, &Some(params), false, &SessionState::default());
         assert!(matches!(result, JsonRpcOutput::Error(_)));
     }
+
+    #[test]
+    fn negotiated_version_is_recorded() {
+        let session = SessionState::default();
+        for version in ["2025-03-26", "2025-06-18"] {
+            let params = serde_json::json!({
+                "protocolVersion": version,
+                "capabilities": {"elicitation": {}},
+                "clientInfo": {"name": "test", "version": "1.0"}
+            });
+            handle_initialize(RequestId::Number(4), &Some(params), false, &session);
+            assert_eq!(session.get().protocol.as_str(), version);
+        }
+    }
 }
@@ -0,0 +1,134 @@
+//! Handles `logging/setLevel` and forwards guest output as
+//! `notifications/message`.
+
+use std::sync::Mutex;
+
+use serde_json::{json, Value};
+
+use kami_protocol::mcp::logging::{LoggingLevel, LoggingMessageParams, SetLevelParams};
+use kami_protocol::mcp::methods;
+use kami_protocol::{error_codes, JsonRpcErrorResponse, JsonRpcResponse, RequestId};
+use kami_runtime::GuestLogs;
+use kami_types::ToolId;
+
+use crate::handler::JsonRpcOutput;
+use crate::notify::Notifier;
+
+/// Handles the `logging/setLevel` request by updating `level`.
+pub(crate) fn handle_set_level(
+    id: RequestId,
+    params: &Option<Value>,
+    level: &Mutex<LoggingLevel>,
+) -> JsonRpcOutput {
+    let parsed = params
+        .clone()
+        .ok_or_else(|| "logging/setLevel requires params".to_string())
+        .and_then(|p| {
+            serde_json::from_value::<SetLevelParams>(p)
+                .map_err(|e| format!("invalid logging/setLevel params: {e}"))
+        });
+    match parsed {
+        Ok(p) => {
+            *level.lock().unwrap_or_else(|e| e.into_inner()) = p.level;
+            JsonRpcOutput::Success(JsonRpcResponse::success(id, json!({})))
+        }
+        Err(message) => JsonRpcOutput::Error(JsonRpcErrorResponse::error(
+            id,
+            error_codes::INVALID_PARAMS,
+            message,
+        )),
+    }
+}
+
+/// Destination of forwarded guest output for one `tools/call`.
+pub(crate) struct GuestLogSink<'a> {
+    /// Channel to the client.
+    pub notifier: &'a Notifier,
+    /// Minimum level requested via `logging/setLevel`.
+    pub min_level: LoggingLevel,
+}
+
+impl GuestLogSink<'_> {
+    /// Sends stdout (`info`) and stderr (`warning`) of a call to the client.
+    pub(crate) fn forward(&self, tool_id: &ToolId, logs: &GuestLogs) {
+        let streams = [
+            ("stdout", LoggingLevel::Info, &logs.stdout),
+            ("stderr", LoggingLevel::Warning, &logs.stderr),
+        ];
+        for (stream, level, text) in streams {
+            if text.is_empty() || level < self.min_level {
+                continue;
+            }
+            let params = LoggingMessageParams {
+                level,
+                logger: Some(tool_id.to_string()),
+                data: json!({ "stream": stream, "text": text, "truncated": logs.truncated }),
+            };
+            match serde_json::to_value(params) {
+                Ok(v) => self.notifier.notify(methods::NOTIFICATIONS_MESSAGE, v),
+                Err(e) => tracing::warn!(%e, "failed to serialize log notification"),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn logs(stdout: &str, stderr: &str) -> GuestLogs {
+        GuestLogs {
+            stdout: stdout.to_string(),
+            stderr: stderr.to_string(),
+            truncated: false,
+        }
+    }
+
+    #[test]
+    fn set_level_updates_minimum() {
+        let level = Mutex::new(LoggingLevel::Info);
+        let params = Some(json!({"level": "error"}));
+        let result = handle_set_level(RequestId::Number(1), &params, &level);
+        assert!(matches!(result, JsonRpcOutput::Success(_)));
+        assert_eq!(*level.lock().expect("lock"), LoggingLevel::Error);
+    }
+
+    #[test]
+    fn set_level_rejects_unknown_level() {
+        let level = Mutex::new(LoggingLevel::Info);
+        let params = Some(json!({"level": "loud"}));
+        let result = handle_set_level(RequestId::Number(2), &params, &level);
+        assert!(matches!(result, JsonRpcOutput::Error(_)));
+    }
+
+    #[test]
+    fn forward_sends_one_message_per_non_empty_stream() {
+        let (notifier, mut rx) = Notifier::channel();
+        let sink = GuestLogSink {
+            notifier: &notifier,
+            min_level: LoggingLevel::Info,
+        };
+        let id = ToolId::new("dev.test.logs").expect("id");
+        sink.forward(&id, &logs("out\n", ""));
+        let n = rx.try_recv().expect("stdout message");
+        assert_eq!(n.method, methods::NOTIFICATIONS_MESSAGE);
+        let params = n.params.expect("params");
+        assert_eq!(params["logger"], "dev.test.logs");
+        assert_eq!(params["data"]["text"], "out\n");
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn forward_respects_minimum_level() {
+        let (notifier, mut rx) = Notifier::channel();
+        let sink = GuestLogSink {
+            notifier: &notifier,
+            min_level: LoggingLevel::Warning,
+        };
+        let id = ToolId::new("dev.test.logs").expect("id");
+        sink.forward(&id, &logs("out", "err"));
+        let n = rx.try_recv().expect("stderr message");
+        assert_eq!(n.params.expect("params")["level"], "warning");
+        assert!(rx.try_recv().is_err());
+    }
+}
@@ -3,7 +3,11 @@
 //! Each sub-module handles one family of MCP methods as free functions,
 //! keeping `McpHandler` itself thin (struct + routing only).
 
+pub(crate) mod arguments;
+pub(crate) mod call_result;
+pub(crate) mod cancelled;
 pub(crate) mod initialize;
+pub(crate) mod logging;
 pub(crate) mod prompts_list;
 pub(crate) mod resources_list;
 pub(crate) mod tools_call;
@@ -1,19 +1,49 @@
 //! Handles the `tools/call` MCP method.
 
+use std::sync::Arc;
+
 use serde_json::Value;
 
-use kami_protocol::mcp::tools::{ToolContent, ToolsCallParams, ToolsCallResult};
+use kami_protocol::mcp::tools::ToolsCallParams;
+use kami_protocol::mcp::ProtocolVersion;
 use kami_protocol::{error_codes, JsonRpcErrorResponse, JsonRpcResponse, RequestId};
-use kami_runtime::KamiRuntime;
+use kami_runtime::{ExecutionId, KamiRuntime};
 use kami_types::ToolId;
 
+use crate::dispatch::arguments::{check_arguments, ToolLookup};
+use crate::dispatch::call_result::call_result;
+use crate::dispatch::logging::GuestLogSink;
 use crate::handler::JsonRpcOutput;
+use crate::notify::Notifier;
+use crate::progress::ProgressNotifier;
+
+/// Where a running call reports to.
+#[derive(Default)]
+pub(crate) struct CallReporting<'a> {
+    /// Channel for `notifications/progress`.
+    pub(crate) notifier: Option<&'a Notifier>,
+    /// Destination of guest stdout/stderr.
+    pub(crate) log_sink: Option<GuestLogSink<'a>>,
+}
 
 /// Handles the `tools/call` request.
+///
+/// The arguments are first validated against the tool's input schema,
+/// found through `lookup`. The result carries every content part the tool returned,
+/// and JSON output of a tool declaring an output schema is returned as
+/// `structuredContent`, as far as `protocol` defines them. The tool runs as
+/// `execution`, so it can be cancelled while it runs. Given a notifier and
+/// a `_meta.progressToken`, guest progress reports become
+/// `notifications/progress`; given a log sink, guest stdout/stderr are
+/// sent as log notifications ahead of the response.
 pub(crate) async fn handle_tools_call(
     id: RequestId,
     params: &Option<Value>,
+    lookup: ToolLookup<'_>,
     runtime: &KamiRuntime,
+    execution: ExecutionId,
+    protocol: ProtocolVersion,
+    reporting: CallReporting<'_>,
 ) -> JsonRpcOutput {
     // 1. Parse params
     let call_params = match params {
@@ -48,19 +78,42 @@ pub(crate) async fn handle_tools_call(
         }
     };
 
-    // 3. Execute via runtime
+    // 3. Validate arguments before any WASM runs. Tools missing from the
+    // repository are let through: the runtime reports them on resolve.
+    let schemas = match check_arguments(&id, &tool_id, &lookup, &call_params.arguments).await {
+        Ok(schemas) => schemas,
+        Err(failed) => return failed,
+    };
+
+    // 4. Execute via runtime
     let input = call_params.arguments.to_string();
     tracing::debug!(%tool_id, "executing tool via MCP");
 
-    let (content, is_error) = match runtime.execute(&tool_id, &input).await {
-        Ok(result) => (result.content, !result.success),
-        Err(e) => (e.to_string(), true),
+    let token = call_params.meta.and_then(|m| m.progress_token);
+    let outcome = match (reporting.notifier, token) {
+        (Some(notifier), Some(token)) => {
+            let sink = Arc::new(ProgressNotifier::new(notifier.clone(), token));
+            runtime
+                .execute_with_progress(execution, &tool_id, &input, sink)
+                .await
+        }
+        _ => runtime.execute_as(execution, &tool_id, &input).await,
     };
-
-    let call_result = ToolsCallResult {
-        content: vec![ToolContent::Text { text: content }],
-        is_error,
+    let logs = match &outcome {
+        Ok(result) => Some(&result.logs),
+        Err(e) => e.logs(),
     };
+    if let (Some(sink), Some(logs)) = (&reporting.log_sink, logs) {
+        sink.forward(&tool_id, logs);
+    }
+    let (content, parts, is_error) = match outcome {
+        Ok(result) => (result.content, result.parts, !result.success),
+        Err(e) => (e.to_string(), Vec::new(), true),
+    };
+
+    let output_schema = schemas.as_ref().and_then(|s| s.output.as_ref());
+    let call_result =
+        call_result(&tool_id, output_schema, content, parts, is_error).for_version(protocol);
 
     match serde_json::to_value(call_result) {
         Ok(v) => JsonRpcOutput::Success(JsonRpcResponse::success(id, v)),
@@ -73,43 +126,5 @@ pub(crate) async fn handle_tools_call(
 }
 
 #[cfg(test)]
-mod tests {
-    use super::*;
-    use kami_runtime::{KamiRuntime, RuntimeConfig};
-    use kami_store_sqlite::SqliteToolRepository;
-    use std::sync::Arc;
-
-    fn make_runtime() -> KamiRuntime {
-        let repo = Arc::new(SqliteToolRepository::open_in_memory().expect("db"));
-        KamiRuntime::new(
-            RuntimeConfig {
-                cache_size: 2,
-                max_concurrent: 1,
-                epoch_interruption: false,
-                ..RuntimeConfig::default()
-            },
-            repo,
-        )
-        .expect("runtime")
-    }
-
-    #[tokio::test]
-    async fn tools_call_no_params_returns_error() {
-        let result = handle_tools_call(RequestId::Number(1), &None, &make_runtime()).await;
-        assert!(matches!(result, JsonRpcOutput::Error(_)));
-    }
-
-    #[tokio::test]
-    async fn tools_call_invalid_params_type_returns_error() {
-        let params = serde_json::json!(42);
-        let result = handle_tools_call(RequestId::Number(2), &Some(params), &make_runtime()).await;
-        assert!(matches!(result, JsonRpcOutput::Error(_)));
-    }
-
-    #[tokio::test]
-    async fn tools_call_invalid_tool_name_returns_error() {
-        let params = serde_json::json!({"name": "bad", "arguments": {}});
-        let result = handle_tools_call(RequestId::Number(3), &Some(params), &make_runtime()).await;
-        assert!(matches!(result, JsonRpcOutput::Error(_)));
-    }
-}
+#[path = "tools_call_tests.rs"]
+mod tests;
@@ -0,0 +1,125 @@
+//! Tests for the `tools/call` handler.
+
+use super::*;
+use crate::schemas::SchemaCache;
+use kami_registry::ToolRepository;
+use kami_runtime::RuntimeConfig;
+use kami_store_sqlite::SqliteToolRepository;
+use kami_types::{SecurityConfig, Tool, ToolArgument, ToolManifest, ToolVersion};
+
+fn setup() -> (Arc<SqliteToolRepository>, KamiRuntime) {
+    let repo = Arc::new(SqliteToolRepository::open_in_memory().expect("db"));
+    let runtime = KamiRuntime::new(
+        RuntimeConfig {
+            cache_size: 2,
+            max_concurrent: 1,
+            epoch_interruption: false,
+            ..RuntimeConfig::default()
+        },
+        repo.clone(),
+    )
+    .expect("runtime");
+    (repo, runtime)
+}
+
+async fn call(params: Option<Value>) -> JsonRpcOutput {
+    let (repo, rt) = setup();
+    let tool = Tool {
+        manifest: ToolManifest {
+            id: ToolId::new("dev.test.fetch").expect("id"),
+            name: "fetch".into(),
+            version: ToolVersion::new(1, 0, 0),
+            wasm: "missing.wasm".into(),
+            description: "fetch".into(),
+            arguments: vec![ToolArgument {
+                name: "url".into(),
+                arg_type: "string".into(),
+                description: "The URL".into(),
+                required: true,
+                ..ToolArgument::default()
+            }],
+            output: vec![],
+            security: SecurityConfig::default(),
+            wasm_sha256: None,
+            signature: None,
+            signer_public_key: None,
+        },
+        install_path: "/nowhere".into(),
+        enabled: true,
+        pinned_version: None,
+        updated_at: None,
+    };
+    repo.insert(&tool).await.expect("insert");
+    let exec = rt.next_execution_id();
+    let protocol = ProtocolVersion::LATEST;
+    let reporting = CallReporting::default();
+    handle_tools_call(
+        RequestId::Number(9),
+        &params,
+        ToolLookup {
+            repository: &*repo,
+            schemas: &SchemaCache::default(),
+        },
+        &rt,
+        exec,
+        protocol,
+        reporting,
+    )
+    .await
+}
+
+fn error_data(output: JsonRpcOutput) -> Value {
+    let JsonRpcOutput::Error(e) = output else {
+        panic!("expected an error");
+    };
+    assert_eq!(e.error.code, error_codes::INVALID_PARAMS);
+    e.error.data.expect("data")
+}
+
+#[tokio::test]
+async fn tools_call_no_params_returns_error() {
+    assert!(matches!(call(None).await, JsonRpcOutput::Error(_)));
+}
+
+#[tokio::test]
+async fn tools_call_invalid_params_type_returns_error() {
+    let params = serde_json::json!(42);
+    assert!(matches!(call(Some(params)).await, JsonRpcOutput::Error(_)));
+}
+
+#[tokio::test]
+async fn tools_call_invalid_tool_name_returns_error() {
+    let params = serde_json::json!({"name": "bad", "arguments": {}});
+    assert!(matches!(call(Some(params)).await, JsonRpcOutput::Error(_)));
+}
+
+#[tokio::test]
+async fn wrong_argument_type_is_rejected_before_execution() {
+    let params = serde_json::json!({"name": "dev.test.fetch", "arguments": {"url": 5}});
+
+    let data = error_data(call(Some(params)).await);
+
+    assert_eq!(data["tool"], "dev.test.fetch");
+    assert_eq!(data["violations"][0]["path"], "/url");
+    assert_eq!(data["violations"][0]["keyword"], "type");
+}
+
+#[tokio::test]
+async fn omitted_arguments_are_checked_as_empty_object() {
+    let params = serde_json::json!({"name": "dev.test.fetch"});
+
+    let data = error_data(call(Some(params)).await);
+
+    assert_eq!(data["violations"][0]["keyword"], "required");
+}
+
+#[tokio::test]
+async fn valid_arguments_reach_the_runtime() {
+    let params = serde_json::json!({"name": "dev.test.fetch", "arguments": {"url": "x"}});
+
+    let JsonRpcOutput::Success(resp) = call(Some(params)).await else {
+        panic!("expected a tools/call result");
+    };
+
+    assert_eq!(resp.result["isError"], true);
+}
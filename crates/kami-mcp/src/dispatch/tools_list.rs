@@ -1,18 +1,20 @@
 //! Handles the `tools/list` MCP method.
 
-use serde_json::Value;
-
-use kami_protocol::mcp::tools::{McpToolDefinition, ToolsListResult};
+use kami_protocol::mcp::tools::{McpToolDefinition, ToolAnnotations, ToolsListResult};
+use kami_protocol::mcp::ProtocolVersion;
+use kami_protocol::schema::{build_input_schema, build_output_schema};
 use kami_protocol::{error_codes, JsonRpcErrorResponse, JsonRpcResponse, RequestId};
 use kami_registry::{ToolQuery, ToolRepository};
-use kami_types::ToolArgument;
+use kami_types::{FsAccess, ToolManifest};
 
 use crate::handler::JsonRpcOutput;
 
-/// Handles the `tools/list` request.
+/// Handles the `tools/list` request, describing each tool with the
+/// fields `protocol` defines.
 pub(crate) async fn handle_tools_list(
     id: RequestId,
     repository: &dyn ToolRepository,
+    protocol: ProtocolVersion,
 ) -> JsonRpcOutput {
     let tools = match repository.find_all(ToolQuery::all()).await {
         Ok(t) => t,
@@ -28,14 +30,7 @@ pub(crate) async fn handle_tools_list(
     let definitions: Vec<McpToolDefinition> = tools
         .iter()
         .filter(|t| t.enabled)
-        .map(|t| {
-            let m = &t.manifest;
-            McpToolDefinition {
-                name: m.id.to_string(),
-                description: Some(m.description.clone()),
-                input_schema: build_input_schema(&m.arguments),
-            }
-        })
+        .map(|t| definition(&t.manifest).for_version(protocol))
         .collect();
 
     let result = ToolsListResult {
@@ -53,32 +48,30 @@ pub(crate) async fn handle_tools_list(
     }
 }
 
-/// Builds a JSON Schema `inputSchema` from tool arguments.
-pub(crate) fn build_input_schema(arguments: &[ToolArgument]) -> Value {
-    let mut properties = serde_json::Map::new();
-    let mut required = Vec::new();
-
-    for arg in arguments {
-        let mut prop = serde_json::Map::new();
-        prop.insert("type".to_string(), Value::String(arg.arg_type.clone()));
-        prop.insert(
-            "description".to_string(),
-            Value::String(arg.description.clone()),
-        );
-        properties.insert(arg.name.clone(), Value::Object(prop));
-        if arg.required {
-            required.push(Value::String(arg.name.clone()));
-        }
+fn definition(m: &ToolManifest) -> McpToolDefinition {
+    McpToolDefinition {
+        name: m.id.to_string(),
+        title: Some(m.name.clone()),
+        description: Some(m.description.clone()),
+        input_schema: build_input_schema(&m.arguments),
+        output_schema: build_output_schema(&m.output),
+        annotations: Some(annotations(m)),
     }
+}
 
-    let mut schema = serde_json::Map::new();
-    schema.insert("type".to_string(), Value::String("object".to_string()));
-    schema.insert("properties".to_string(), Value::Object(properties));
-    if !required.is_empty() {
-        schema.insert("required".to_string(), Value::Array(required));
+/// Derives behaviour hints from the tool's sandbox: a tool that can reach
+/// no network, key/value store or writable directory cannot change
+/// anything outside the call.
+fn annotations(m: &ToolManifest) -> ToolAnnotations {
+    let security = &m.security;
+    let networked = !security.net_allow_list.is_empty();
+    let read_only = !networked && !security.key_value && security.fs_access != FsAccess::Sandbox;
+    ToolAnnotations {
+        title: Some(m.name.clone()),
+        read_only_hint: Some(read_only),
+        open_world_hint: Some(networked),
+        ..ToolAnnotations::default()
     }
-
-    Value::Object(schema)
 }
 
 #[cfg(test)]
@@ -86,7 +79,7 @@ mod tests {
     use super::*;
     use async_trait::async_trait;
     use kami_registry::{RepositoryError, ToolQuery, ToolRepository};
-    use kami_types::{Tool, ToolArgument, ToolId};
+    use kami_types::{Tool, ToolId};
 
     struct FailRepo;
     #[async_trait]
@@ -114,32 +107,8 @@ mod tests {
 
     #[tokio::test]
     async fn registry_error_returns_mcp_error() {
-        let result = handle_tools_list(RequestId::Number(1), &FailRepo).await;
+        let result =
+            handle_tools_list(RequestId::Number(1), &FailRepo, ProtocolVersion::LATEST).await;
         assert!(matches!(result, JsonRpcOutput::Error(_)));
     }
-
-    #[test]
-    fn build_input_schema_empty_args() {
-        let schema = build_input_schema(&[]);
-        assert_eq!(schema["type"], "object");
-        assert!(schema["properties"].as_object().expect("obj").is_empty());
-        assert!(schema.get("required").is_none());
-    }
-
-    #[test]
-    fn build_input_schema_with_args() {
-        let arg = ToolArgument {
-            name: "url".into(),
-            arg_type: "string".into(),
-            description: "The URL".into(),
-            required: true,
-            default: None,
-        };
-        let schema = build_input_schema(&[arg]);
-        assert_eq!(schema["type"], "object");
-        assert_eq!(schema["properties"]["url"]["type"], "string");
-        let req = schema["required"].as_array().expect("arr");
-        assert_eq!(req.len(), 1);
-        assert_eq!(req[0], "url");
-    }
 }
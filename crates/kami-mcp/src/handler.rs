@@ -3,18 +3,25 @@
 //! `McpHandler` routes incoming JSON-RPC requests to the method-specific
 //! handlers in the `dispatch` sub-modules and returns a typed response.
 
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use tracing::debug;
 
-use kami_protocol::mcp::methods;
-use kami_protocol::{
-    error_codes, JsonRpcErrorResponse, JsonRpcNotification, JsonRpcRequest, JsonRpcResponse,
-};
+use kami_protocol::mcp::{methods, LoggingLevel};
+use kami_protocol::{error_codes, JsonRpcErrorResponse, JsonRpcRequest};
 use kami_registry::ToolRepository;
 use kami_runtime::KamiRuntime;
 
 use crate::dispatch;
+use crate::dispatch::logging::GuestLogSink;
+use crate::notify::Notifier;
+use crate::schemas::SchemaCache;
+use crate::session::SessionState;
+
+mod calls;
+mod notifications;
+
+pub use crate::output::JsonRpcOutput;
 
 /// Handles MCP method dispatch over JSON-RPC.
 ///
@@ -23,29 +30,10 @@ use crate::dispatch;
 pub struct McpHandler {
     runtime: Arc<KamiRuntime>,
     repository: Arc<dyn ToolRepository>,
-}
-
-/// Enum representing either a success or error JSON-RPC response.
-#[derive(Debug)]
-pub enum JsonRpcOutput {
-    /// Success response.
-    Success(JsonRpcResponse),
-    /// Error response.
-    Error(JsonRpcErrorResponse),
-}
-
-impl JsonRpcOutput {
-    /// Serializes the output to a JSON string.
-    ///
-    /// # Errors
-    ///
-    /// Returns a `serde_json::Error` if serialization fails (extremely rare).
-    pub fn to_json(&self) -> Result<String, serde_json::Error> {
-        match self {
-            Self::Success(r) => serde_json::to_string(r),
-            Self::Error(r) => serde_json::to_string(r),
-        }
-    }
+    notifier: Option<Notifier>,
+    forward_guest_logs: bool,
+    log_level: Mutex<LoggingLevel>,
+    schemas: SchemaCache,
 }
 
 impl McpHandler {
@@ -54,65 +42,82 @@ impl McpHandler {
         Self {
             runtime,
             repository,
+            notifier: None,
+            forward_guest_logs: false,
+            log_level: Mutex::new(LoggingLevel::default()),
+            schemas: SchemaCache::default(),
         }
     }
 
-    /// Dispatches a JSON-RPC request to the appropriate method handler.
-    #[tracing::instrument(skip(self, request), fields(method = %request.method))]
-    pub async fn dispatch(&self, request: &JsonRpcRequest) -> JsonRpcOutput {
+    /// Sets the channel used for server-to-client notifications.
+    pub fn with_notifier(mut self, notifier: Notifier) -> Self {
+        self.notifier = Some(notifier);
+        self
+    }
+
+    /// Forwards guest stdout/stderr to the client as `notifications/message`.
+    ///
+    /// Only takes effect on transports that deliver notifications.
+    pub fn with_guest_logs(mut self, enabled: bool) -> Self {
+        self.forward_guest_logs = enabled;
+        self
+    }
+
+    /// Returns where guest output of a `tools/call` should be forwarded.
+    fn guest_log_sink(&self) -> Option<GuestLogSink<'_>> {
+        let notifier = self.notifier.as_ref().filter(|_| self.forward_guest_logs)?;
+        let min_level = *self.log_level.lock().unwrap_or_else(|e| e.into_inner());
+        Some(GuestLogSink {
+            notifier,
+            min_level,
+        })
+    }
+
+    /// Dispatches a JSON-RPC request received on the connection whose
+    /// negotiated state is `session`: `initialize` records into it, and
+    /// other responses are shaped for its protocol revision.
+    ///
+    /// Returns `None` for a `tools/call` the client cancelled while it ran:
+    /// MCP asks receivers not to answer cancelled requests.
+    #[tracing::instrument(skip_all, fields(method = %request.method))]
+    pub async fn dispatch(
+        &self,
+        request: &JsonRpcRequest,
+        session: &SessionState,
+    ) -> Option<JsonRpcOutput> {
         debug!(method = %request.method, "dispatching MCP request");
+        let protocol = session.get().protocol;
+        let id = request.id.clone();
 
-        match request.method.as_str() {
-            methods::INITIALIZE => {
-                dispatch::initialize::handle_initialize(request.id.clone(), &request.params)
-            }
+        let output = match request.method.as_str() {
+            methods::INITIALIZE => dispatch::initialize::handle_initialize(
+                id,
+                &request.params,
+                self.guest_log_sink().is_some(),
+                session,
+            ),
             methods::TOOLS_LIST => {
-                dispatch::tools_list::handle_tools_list(
-                    request.id.clone(),
-                    self.repository.as_ref(),
-                )
-                .await
-            }
-            methods::TOOLS_CALL => {
-                dispatch::tools_call::handle_tools_call(
-                    request.id.clone(),
-                    &request.params,
-                    &self.runtime,
-                )
-                .await
-            }
-            methods::PROMPTS_LIST => {
-                dispatch::prompts_list::handle_prompts_list(request.id.clone())
-            }
-            methods::RESOURCES_LIST => {
-                dispatch::resources_list::handle_resources_list(request.id.clone())
+                let repository = self.repository.as_ref();
+                dispatch::tools_list::handle_tools_list(id, repository, protocol).await
             }
+            methods::TOOLS_CALL => return self.call_tool(request, session).await,
+            methods::PROMPTS_LIST => dispatch::prompts_list::handle_prompts_list(id),
+            methods::RESOURCES_LIST => dispatch::resources_list::handle_resources_list(id),
             methods::RESOURCES_READ => {
-                dispatch::resources_list::handle_resources_read(request.id.clone(), &request.params)
+                dispatch::resources_list::handle_resources_read(id, &request.params)
+            }
+            methods::LOGGING_SET_LEVEL => {
+                dispatch::logging::handle_set_level(id, &request.params, &self.log_level)
             }
             other => {
                 tracing::warn!(method = other, "unknown MCP method");
                 JsonRpcOutput::Error(JsonRpcErrorResponse::error(
-                    request.id.clone(),
+                    id,
                     error_codes::METHOD_NOT_FOUND,
                     format!("unknown method: {other}"),
                 ))
             }
-        }
-    }
-
-    /// Handles a JSON-RPC notification silently (no response is sent).
-    ///
-    /// Per the MCP spec, `notifications/initialized` must be accepted
-    /// without generating a response.
-    pub fn handle_notification(&self, notification: &JsonRpcNotification) {
-        match notification.method.as_str() {
-            methods::NOTIFICATIONS_INITIALIZED => {
-                debug!("MCP session initialized by client");
-            }
-            other => {
-                debug!(method = other, "notification ignored");
-            }
-        }
+        };
+        Some(output)
     }
 }
@@ -0,0 +1,55 @@
+//! `tools/call` requests, tracked in their session while they run.
+
+use tracing::debug;
+
+use kami_protocol::{error_codes, JsonRpcErrorResponse, JsonRpcRequest};
+
+use super::McpHandler;
+use crate::dispatch;
+use crate::dispatch::arguments::ToolLookup;
+use crate::dispatch::tools_call::CallReporting;
+use crate::output::JsonRpcOutput;
+use crate::session::SessionState;
+
+impl McpHandler {
+    /// Runs a `tools/call` that `session` can cancel by its request id.
+    ///
+    /// Returns `None` if it was cancelled, and an `INVALID_REQUEST` error if
+    /// the session already has a call running under the same id.
+    pub(super) async fn call_tool(
+        &self,
+        request: &JsonRpcRequest,
+        session: &SessionState,
+    ) -> Option<JsonRpcOutput> {
+        let id = request.id.clone();
+        let execution = self.runtime.next_execution_id();
+        let Some(tracked) = session.in_flight().track(id.clone(), execution) else {
+            return Some(JsonRpcOutput::Error(JsonRpcErrorResponse::error(
+                id,
+                error_codes::INVALID_REQUEST,
+                "a request with this id is already running",
+            )));
+        };
+        let output = dispatch::tools_call::handle_tools_call(
+            id,
+            &request.params,
+            ToolLookup {
+                repository: self.repository.as_ref(),
+                schemas: &self.schemas,
+            },
+            &self.runtime,
+            execution,
+            session.get().protocol,
+            CallReporting {
+                notifier: self.notifier.as_ref(),
+                log_sink: self.guest_log_sink(),
+            },
+        )
+        .await;
+        if tracked.cancelled() {
+            debug!(id = ?request.id, "tools/call cancelled, no response sent");
+            return None;
+        }
+        Some(output)
+    }
+}
@@ -0,0 +1,35 @@
+//! Notifications received from the client.
+
+use tracing::debug;
+
+use kami_protocol::mcp::methods;
+use kami_protocol::JsonRpcNotification;
+
+use super::McpHandler;
+use crate::dispatch;
+use crate::session::SessionState;
+
+impl McpHandler {
+    /// Handles a JSON-RPC notification silently (no response is sent).
+    ///
+    /// Per the MCP spec, `notifications/initialized` must be accepted
+    /// without generating a response. `notifications/cancelled` cancels the
+    /// `tools/call` with the given request id running in `session`; a
+    /// transport without session identity passes a fresh state, so its
+    /// cancellations are ignored.
+    pub fn handle_notification(&self, notification: &JsonRpcNotification, session: &SessionState) {
+        match notification.method.as_str() {
+            methods::NOTIFICATIONS_INITIALIZED => {
+                debug!("MCP session initialized by client");
+            }
+            methods::NOTIFICATIONS_CANCELLED => dispatch::cancelled::handle_cancelled(
+                &notification.params,
+                session.in_flight(),
+                &self.runtime,
+            ),
+            other => {
+                debug!(method = other, "notification ignored");
+            }
+        }
+    }
+}
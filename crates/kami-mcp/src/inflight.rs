@@ -0,0 +1,80 @@
+//! Running `tools/call` requests of one session, keyed by JSON-RPC id.
+//!
+//! Lets `notifications/cancelled` find the runtime execution behind the
+//! request it names. Each [`SessionState`](crate::SessionState) keeps its
+//! own map, so a client can only cancel its own calls.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+
+use kami_protocol::RequestId;
+use kami_runtime::ExecutionId;
+
+/// A running request.
+#[derive(Debug, Clone, Copy)]
+struct Call {
+    execution: ExecutionId,
+    cancelled: bool,
+}
+
+/// Map of in-flight requests to their executions.
+#[derive(Debug, Default)]
+pub(crate) struct InFlight {
+    calls: Mutex<HashMap<RequestId, Call>>,
+}
+
+impl InFlight {
+    /// Records `request` as running `execution` until the guard is dropped.
+    ///
+    /// Returns `None` if a request with the same id is already running:
+    /// JSON-RPC ids must be unique among a client's pending requests.
+    pub(crate) fn track(&self, request: RequestId, execution: ExecutionId) -> Option<Tracked<'_>> {
+        let mut calls = self.lock();
+        if calls.contains_key(&request) {
+            return None;
+        }
+        let call = Call {
+            execution,
+            cancelled: false,
+        };
+        calls.insert(request.clone(), call);
+        Some(Tracked {
+            owner: self,
+            request,
+        })
+    }
+
+    /// Marks `request` cancelled and returns the execution running it.
+    pub(crate) fn cancel(&self, request: &RequestId) -> Option<ExecutionId> {
+        let mut calls = self.lock();
+        let call = calls.get_mut(request)?;
+        call.cancelled = true;
+        Some(call.execution)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<RequestId, Call>> {
+        self.calls.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Removes a request from [`InFlight`] once it completes.
+pub(crate) struct Tracked<'a> {
+    owner: &'a InFlight,
+    request: RequestId,
+}
+
+impl Tracked<'_> {
+    /// Returns `true` if the client cancelled the request.
+    pub(crate) fn cancelled(&self) -> bool {
+        self.owner
+            .lock()
+            .get(&self.request)
+            .is_some_and(|call| call.cancelled)
+    }
+}
+
+impl Drop for Tracked<'_> {
+    fn drop(&mut self) {
+        self.owner.lock().remove(&self.request);
+    }
+}
@@ -7,5 +7,13 @@
 
 mod dispatch;
 pub mod handler;
+mod inflight;
+pub mod notify;
+mod output;
+mod progress;
+mod schemas;
+mod session;
 
 pub use handler::{JsonRpcOutput, McpHandler};
+pub use notify::Notifier;
+pub use session::{Session, SessionState};
@@ -0,0 +1,57 @@
+//! Server-to-client notifications.
+//!
+//! Handlers push JSON-RPC notifications (e.g. `notifications/message`)
+//! into a channel; the transport drains it and writes them out before the
+//! response of the request that produced them.
+
+use serde_json::Value;
+use tokio::sync::mpsc;
+use tracing::debug;
+
+use kami_protocol::JsonRpcNotification;
+
+/// Sending half of the notification channel.
+#[derive(Debug, Clone)]
+pub struct Notifier {
+    tx: mpsc::UnboundedSender<JsonRpcNotification>,
+}
+
+impl Notifier {
+    /// Creates a notifier and the receiver the transport drains.
+    pub fn channel() -> (Self, mpsc::UnboundedReceiver<JsonRpcNotification>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        (Self { tx }, rx)
+    }
+
+    /// Queues a notification; dropped if the transport has gone away.
+    pub fn notify(&self, method: &str, params: Value) {
+        if self
+            .tx
+            .send(JsonRpcNotification::new(method, Some(params)))
+            .is_err()
+        {
+            debug!(method, "notification dropped, transport closed");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn notify_queues_notification() {
+        let (notifier, mut rx) = Notifier::channel();
+        notifier.notify("notifications/message", serde_json::json!({"x": 1}));
+        let n = rx.try_recv().expect("queued");
+        assert_eq!(n.method, "notifications/message");
+        assert_eq!(n.params, Some(serde_json::json!({"x": 1})));
+    }
+
+    #[test]
+    fn notify_after_receiver_dropped_does_not_fail() {
+        let (notifier, rx) = Notifier::channel();
+        drop(rx);
+        notifier.notify("notifications/message", Value::Null);
+    }
+}
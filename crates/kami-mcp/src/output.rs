@@ -0,0 +1,26 @@
+//! JSON-RPC output produced by the MCP handler.
+
+use kami_protocol::{JsonRpcErrorResponse, JsonRpcResponse};
+
+/// Enum representing either a success or error JSON-RPC response.
+#[derive(Debug)]
+pub enum JsonRpcOutput {
+    /// Success response.
+    Success(JsonRpcResponse),
+    /// Error response.
+    Error(JsonRpcErrorResponse),
+}
+
+impl JsonRpcOutput {
+    /// Serializes the output to a JSON string.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `serde_json::Error` if serialization fails (extremely rare).
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        match self {
+            Self::Success(r) => serde_json::to_string(r),
+            Self::Error(r) => serde_json::to_string(r),
+        }
+    }
+}
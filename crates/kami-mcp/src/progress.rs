@@ -0,0 +1,99 @@
+//! Forwards guest progress reports as `notifications/progress`.
+
+use std::sync::Mutex;
+
+use kami_protocol::mcp::{methods, ProgressParams, ProgressToken};
+use kami_runtime::{Progress, ProgressSink};
+
+use crate::notify::Notifier;
+
+/// Sends the progress of one `tools/call` under the client's token.
+///
+/// MCP requires progress to increase with every notification, so updates
+/// that do not are dropped.
+pub(crate) struct ProgressNotifier {
+    notifier: Notifier,
+    token: ProgressToken,
+    last: Mutex<Option<f64>>,
+}
+
+impl ProgressNotifier {
+    /// Creates a sink reporting under `token`.
+    pub(crate) fn new(notifier: Notifier, token: ProgressToken) -> Self {
+        Self {
+            notifier,
+            token,
+            last: Mutex::new(None),
+        }
+    }
+}
+
+impl ProgressSink for ProgressNotifier {
+    fn report(&self, progress: Progress) {
+        {
+            let mut last = self.last.lock().unwrap_or_else(|e| e.into_inner());
+            if last.is_some_and(|previous| progress.progress <= previous) {
+                tracing::debug!(
+                    progress = progress.progress,
+                    "non-increasing progress dropped"
+                );
+                return;
+            }
+            *last = Some(progress.progress);
+        }
+        let params = ProgressParams {
+            progress_token: self.token.clone(),
+            progress: progress.progress,
+            total: progress.total,
+            message: progress.message,
+        };
+        match serde_json::to_value(params) {
+            Ok(v) => self.notifier.notify(methods::NOTIFICATIONS_PROGRESS, v),
+            Err(e) => tracing::warn!(%e, "failed to serialize progress notification"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn update(progress: f64) -> Progress {
+        Progress {
+            progress,
+            total: Some(4.0),
+            message: None,
+        }
+    }
+
+    #[test]
+    fn report_sends_progress_under_token() {
+        let (notifier, mut rx) = Notifier::channel();
+        let sink = ProgressNotifier::new(notifier, ProgressToken::String("t".into()));
+        sink.report(update(1.0));
+        let n = rx.try_recv().expect("notification");
+        assert_eq!(n.method, methods::NOTIFICATIONS_PROGRESS);
+        let params = n.params.expect("params");
+        assert_eq!(params["progressToken"], "t");
+        assert_eq!(params["progress"], 1.0);
+        assert_eq!(params["total"], 4.0);
+    }
+
+    #[test]
+    fn report_drops_non_increasing_progress() {
+        let (notifier, mut rx) = Notifier::channel();
+        let sink = ProgressNotifier::new(notifier, ProgressToken::Number(1));
+        for p in [1.0, 1.0, 0.5, 2.0] {
+            sink.report(update(p));
+        }
+        assert_eq!(
+            rx.try_recv().expect("first").params.expect("p")["progress"],
+            1.0
+        );
+        assert_eq!(
+            rx.try_recv().expect("last").params.expect("p")["progress"],
+            2.0
+        );
+        assert!(rx.try_recv().is_err());
+    }
+}
@@ -0,0 +1,100 @@
+//! Compiled input and output schemas of called tools.
+//!
+//! A tool's schemas are compiled, `pattern` regexes included, the first
+//! time it is called, and reused for as long as its manifest declares the
+//! same arguments and output.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use serde_json::Value;
+
+use kami_protocol::schema::{build_input_schema, build_output_schema, CompiledSchema, SchemaError};
+use kami_types::{ToolId, ToolManifest};
+
+/// The schemas of one tool.
+#[derive(Debug)]
+pub(crate) struct ToolSchemas {
+    /// Schema `tools/call` arguments are validated against.
+    pub(crate) input: CompiledSchema,
+    /// Schema of the tool's structured output, if it declares one.
+    pub(crate) output: Option<CompiledSchema>,
+}
+
+impl ToolSchemas {
+    fn built_from(&self, input: &Value, output: Option<&Value>) -> bool {
+        self.input.schema() == input && self.output.as_ref().map(CompiledSchema::schema) == output
+    }
+}
+
+/// Compiled schemas keyed by tool id.
+#[derive(Default)]
+pub(crate) struct SchemaCache {
+    compiled: Mutex<HashMap<ToolId, Arc<ToolSchemas>>>,
+}
+
+impl SchemaCache {
+    /// Returns the compiled schemas of `manifest`, compiling them if the
+    /// tool is new or its manifest changed.
+    ///
+    /// # Errors
+    /// Returns [`SchemaError::InvalidSchema`] if the manifest declares an
+    /// invalid `pattern`.
+    pub(crate) fn get(&self, manifest: &ToolManifest) -> Result<Arc<ToolSchemas>, SchemaError> {
+        let input = build_input_schema(&manifest.arguments);
+        let output = build_output_schema(&manifest.output);
+        if let Some(cached) = self.lock().get(&manifest.id) {
+            if cached.built_from(&input, output.as_ref()) {
+                return Ok(Arc::clone(cached));
+            }
+        }
+        let schemas = Arc::new(ToolSchemas {
+            input: CompiledSchema::compile(input)?,
+            output: output.map(CompiledSchema::compile).transpose()?,
+        });
+        self.lock()
+            .insert(manifest.id.clone(), Arc::clone(&schemas));
+        Ok(schemas)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<ToolId, Arc<ToolSchemas>>> {
+        self.compiled.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kami_types::{SecurityConfig, ToolArgument, ToolVersion};
+
+    fn manifest(arg_type: &str) -> ToolManifest {
+        ToolManifest {
+            id: ToolId::new("dev.test.cached").expect("id"),
+            name: "cached".into(),
+            version: ToolVersion::new(1, 0, 0),
+            wasm: "cached.wasm".into(),
+            description: "cached".into(),
+            arguments: vec![ToolArgument {
+                name: "a".into(),
+                arg_type: arg_type.into(),
+                ..ToolArgument::default()
+            }],
+            output: vec![],
+            security: SecurityConfig::default(),
+            wasm_sha256: None,
+            signature: None,
+            signer_public_key: None,
+        }
+    }
+
+    #[test]
+    fn schemas_are_compiled_once_per_manifest() {
+        let cache = SchemaCache::default();
+        let first = cache.get(&manifest("string")).expect("compile");
+        let again = cache.get(&manifest("string")).expect("cached");
+        let changed = cache.get(&manifest("integer")).expect("recompile");
+
+        assert!(Arc::ptr_eq(&first, &again));
+        assert!(!Arc::ptr_eq(&first, &changed));
+    }
+}
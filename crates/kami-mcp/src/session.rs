@@ -0,0 +1,53 @@
+//! What client and server agreed on in `initialize`.
+
+use std::sync::Mutex;
+
+use kami_protocol::mcp::ProtocolVersion;
+
+use crate::inflight::InFlight;
+
+/// Negotiated state of an MCP session.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Session {
+    /// Protocol revision responses are shaped for.
+    pub protocol: ProtocolVersion,
+}
+
+/// The [`Session`] of one client connection, replaced by its `initialize`,
+/// and the `tools/call` requests it has running.
+///
+/// Transports keep one per connection (or per request when they have no
+/// connections), so clients negotiating different revisions with the same
+/// [`McpHandler`](crate::McpHandler) do not affect each other, and a
+/// `notifications/cancelled` only reaches calls of its own connection.
+/// Before `initialize` the latest revision is assumed.
+#[derive(Debug, Default)]
+pub struct SessionState {
+    current: Mutex<Session>,
+    in_flight: InFlight,
+}
+
+impl SessionState {
+    /// Starts with `session` already agreed, e.g. from a transport header.
+    pub fn new(session: Session) -> Self {
+        Self {
+            current: Mutex::new(session),
+            in_flight: InFlight::default(),
+        }
+    }
+
+    /// Returns the current session.
+    pub fn get(&self) -> Session {
+        *self.current.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Replaces the current session.
+    pub fn set(&self, session: Session) {
+        *self.current.lock().unwrap_or_else(|e| e.into_inner()) = session;
+    }
+
+    /// Returns the requests running in this session.
+    pub(crate) fn in_flight(&self) -> &InFlight {
+        &self.in_flight
+    }
+}
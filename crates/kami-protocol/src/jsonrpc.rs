@@ -99,6 +99,17 @@ impl JsonRpcRequest {
     }
 }
 
+impl JsonRpcNotification {
+    /// Creates a new JSON-RPC 2.0 notification.
+    pub fn new(method: impl Into<String>, params: Option<Value>) -> Self {
+        Self {
+            jsonrpc: "2.0".to_string(),
+            method: method.into(),
+            params,
+        }
+    }
+}
+
 impl JsonRpcResponse {
     /// Creates a success response.
     pub fn success(id: RequestId, result: Value) -> Self {
@@ -123,4 +134,10 @@ impl JsonRpcErrorResponse {
             },
         }
     }
+
+    /// Attaches structured `data` to the error.
+    pub fn with_data(mut self, data: Value) -> Self {
+        self.error.data = Some(data);
+        self
+    }
 }
@@ -0,0 +1,34 @@
+//! MCP cancellation types (`notifications/cancelled`).
+
+use serde::{Deserialize, Serialize};
+
+use crate::jsonrpc::RequestId;
+
+/// Params of a `notifications/cancelled` notification.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelledParams {
+    /// Id of the request to cancel.
+    pub request_id: RequestId,
+    /// Why the request was cancelled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn params_parse_with_and_without_reason() {
+        let json = r#"{"requestId":7,"reason":"user aborted"}"#;
+        let params: CancelledParams = serde_json::from_str(json).expect("parse");
+        assert_eq!(params.request_id, RequestId::Number(7));
+        assert_eq!(params.reason.as_deref(), Some("user aborted"));
+
+        let params: CancelledParams =
+            serde_json::from_str(r#"{"requestId":"abc"}"#).expect("parse");
+        assert_eq!(params.request_id, RequestId::String("abc".into()));
+        assert!(params.reason.is_none());
+    }
+}
@@ -0,0 +1,113 @@
+//! MCP content items returned by `tools/call`.
+
+use serde::{Deserialize, Serialize};
+
+use kami_types::{ContentPart, ResourceContents};
+
+use super::version::ProtocolVersion;
+
+/// Content item in a tool call response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ToolContent {
+    /// Text content.
+    Text { text: String },
+    /// Image content (base64).
+    Image {
+        data: String,
+        #[serde(rename = "mimeType")]
+        mime_type: String,
+    },
+    /// Audio content (base64).
+    Audio {
+        data: String,
+        #[serde(rename = "mimeType")]
+        mime_type: String,
+    },
+    /// A resource embedded with its contents.
+    Resource { resource: EmbeddedResource },
+    /// A link to a resource the client can read.
+    ResourceLink {
+        uri: String,
+        name: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        #[serde(rename = "mimeType", default, skip_serializing_if = "Option::is_none")]
+        mime_type: Option<String>,
+    },
+}
+
+/// Contents of an embedded resource: `text` or base64 `blob`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmbeddedResource {
+    /// Resource URI.
+    pub uri: String,
+    /// MIME type.
+    #[serde(rename = "mimeType", default, skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+    /// Text contents.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// Base64-encoded binary contents.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blob: Option<String>,
+}
+
+impl ToolContent {
+    /// Rewrites an item `version` does not define as a text item
+    /// describing it.
+    pub fn for_version(self, version: ProtocolVersion) -> Self {
+        match self {
+            Self::Audio { mime_type, .. } if !version.has_audio_content() => Self::Text {
+                text: format!("[{mime_type} audio omitted: requires MCP 2025-03-26]"),
+            },
+            Self::ResourceLink { uri, name, .. } if !version.has_resource_links() => Self::Text {
+                text: format!("{name}: {uri}"),
+            },
+            other => other,
+        }
+    }
+}
+
+impl From<ContentPart> for ToolContent {
+    fn from(part: ContentPart) -> Self {
+        match part {
+            ContentPart::Text { text } => Self::Text { text },
+            ContentPart::Image { data, mime_type } => Self::Image { data, mime_type },
+            ContentPart::Audio { data, mime_type } => Self::Audio { data, mime_type },
+            ContentPart::Resource {
+                uri,
+                mime_type,
+                contents,
+            } => {
+                let (text, blob) = match contents {
+                    ResourceContents::Text(text) => (Some(text), None),
+                    ResourceContents::Blob(blob) => (None, Some(blob)),
+                };
+                Self::Resource {
+                    resource: EmbeddedResource {
+                        uri,
+                        mime_type,
+                        text,
+                        blob,
+                    },
+                }
+            }
+            ContentPart::ResourceLink {
+                uri,
+                name,
+                description,
+                mime_type,
+            } => Self::ResourceLink {
+                uri,
+                name,
+                description,
+                mime_type,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "content_tests.rs"]
+mod tests;
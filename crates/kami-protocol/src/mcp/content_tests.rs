@@ -0,0 +1,103 @@
+//! Tests for MCP content items.
+
+use super::*;
+use serde_json::json;
+
+fn wire(part: ContentPart) -> serde_json::Value {
+    serde_json::to_value(ToolContent::from(part)).expect("ser")
+}
+
+#[test]
+fn media_parts_use_mcp_field_names() {
+    let image = wire(ContentPart::Image {
+        data: "iVBO".into(),
+        mime_type: "image/png".into(),
+    });
+    assert_eq!(
+        image,
+        json!({"type": "image", "data": "iVBO", "mimeType": "image/png"})
+    );
+    let audio = wire(ContentPart::Audio {
+        data: "UklG".into(),
+        mime_type: "audio/wav".into(),
+    });
+    assert_eq!(audio["type"], "audio");
+}
+
+#[test]
+fn embedded_resource_carries_text_or_blob() {
+    let text = wire(ContentPart::Resource {
+        uri: "file:///report.md".into(),
+        mime_type: Some("text/markdown".into()),
+        contents: ResourceContents::Text("# Report".into()),
+    });
+    assert_eq!(
+        text,
+        json!({
+            "type": "resource",
+            "resource": {"uri": "file:///report.md", "mimeType": "text/markdown", "text": "# Report"}
+        })
+    );
+    let blob = wire(ContentPart::Resource {
+        uri: "file:///a.bin".into(),
+        mime_type: None,
+        contents: ResourceContents::Blob("AAEC".into()),
+    });
+    assert_eq!(
+        blob["resource"],
+        json!({"uri": "file:///a.bin", "blob": "AAEC"})
+    );
+}
+
+#[test]
+fn resource_link_is_snake_case_tagged() {
+    let link = wire(ContentPart::ResourceLink {
+        uri: "https://example.com/chart.svg".into(),
+        name: "chart".into(),
+        description: None,
+        mime_type: Some("image/svg+xml".into()),
+    });
+    assert_eq!(
+        link,
+        json!({
+            "type": "resource_link",
+            "uri": "https://example.com/chart.svg",
+            "name": "chart",
+            "mimeType": "image/svg+xml"
+        })
+    );
+}
+
+#[test]
+fn content_roundtrips() {
+    let item = ToolContent::Text { text: "hi".into() };
+    let s = serde_json::to_string(&item).expect("ser");
+    let back: ToolContent = serde_json::from_str(&s).expect("de");
+    assert_eq!(back, item);
+}
+
+#[test]
+fn newer_items_become_text_for_older_revisions() {
+    let audio = ToolContent::Audio {
+        data: "UklG".into(),
+        mime_type: "audio/wav".into(),
+    };
+    let link = ToolContent::ResourceLink {
+        uri: "file:///c.svg".into(),
+        name: "chart".into(),
+        description: None,
+        mime_type: None,
+    };
+    let as_text = |c: ToolContent, v| match c.for_version(v) {
+        ToolContent::Text { text } => Some(text),
+        _ => None,
+    };
+    let omitted = as_text(audio.clone(), ProtocolVersion::V2024_11_05).expect("text");
+    assert!(omitted.contains("audio/wav"), "{omitted}");
+    assert!(as_text(audio, ProtocolVersion::V2025_03_26).is_none());
+    assert_eq!(
+        as_text(link.clone(), ProtocolVersion::V2025_03_26).as_deref(),
+        Some("chart: file:///c.svg")
+    );
+    assert!(as_text(link, ProtocolVersion::V2025_06_18).is_none());
+}
@@ -5,14 +5,24 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::logging::LoggingCapability;
+use super::version::ProtocolVersion;
+
 /// Client capabilities declared during initialization.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ClientCapabilities {
     /// Whether the client supports tool execution.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tools: Option<ToolCapability>,
+    /// Whether the client can answer `elicitation/create` (2025-06-18).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub elicitation: Option<ElicitationCapability>,
 }
 
+/// Elicitation capability: the server may ask the user for input.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ElicitationCapability {}
+
 /// Tool-related capability.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ToolCapability {}
@@ -37,6 +47,9 @@ pub struct ServerCapabilities {
     /// Resources capability (present if server exposes resources).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub resources: Option<ResourcesCapability>,
+    /// Logging capability (present if server sends log notifications).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logging: Option<LoggingCapability>,
 }
 
 /// Client info sent during initialization.
@@ -53,6 +66,9 @@ pub struct ClientInfo {
 pub struct ServerInfo {
     /// Server name.
     pub name: String,
+    /// Display name (2025-06-18).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
     /// Server version.
     pub version: String,
 }
@@ -83,68 +99,9 @@ pub struct InitializeResult {
     pub server_info: ServerInfo,
 }
 
-/// MCP protocol version supported by this implementation.
-pub const PROTOCOL_VERSION: &str = "2024-11-05";
+/// Latest MCP protocol version supported by this implementation.
+pub const PROTOCOL_VERSION: &str = ProtocolVersion::LATEST.as_str();
 
 #[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn initialize_params_roundtrip() {
-        let params = InitializeParams {
-            protocol_version: PROTOCOL_VERSION.to_string(),
-            capabilities: ClientCapabilities {
-                tools: Some(ToolCapability {}),
-            },
-            client_info: ClientInfo {
-                name: "test-client".to_string(),
-                version: "1.0.0".to_string(),
-            },
-        };
-        let json = serde_json::to_string(&params).expect("serialize");
-        let back: InitializeParams = serde_json::from_str(&json).expect("deserialize");
-        assert_eq!(back.protocol_version, PROTOCOL_VERSION);
-        assert_eq!(back.client_info.name, "test-client");
-    }
-
-    #[test]
-    fn initialize_result_roundtrip() {
-        let result = InitializeResult {
-            protocol_version: PROTOCOL_VERSION.to_string(),
-            capabilities: ServerCapabilities {
-                tools: Some(ToolCapability {}),
-                prompts: Some(PromptsCapability {}),
-                resources: Some(ResourcesCapability {}),
-            },
-            server_info: ServerInfo {
-                name: "kami".to_string(),
-                version: "0.1.0".to_string(),
-            },
-        };
-        let json = serde_json::to_string(&result).expect("serialize");
-        let back: InitializeResult = serde_json::from_str(&json).expect("deserialize");
-        assert_eq!(back.server_info.name, "kami");
-        assert!(back.capabilities.prompts.is_some());
-        assert!(back.capabilities.resources.is_some());
-    }
-
-    #[test]
-    fn client_capabilities_default_has_no_tools() {
-        let caps = ClientCapabilities::default();
-        assert!(caps.tools.is_none());
-    }
-
-    #[test]
-    fn server_capabilities_empty_roundtrip() {
-        let caps = ServerCapabilities::default();
-        let json = serde_json::to_string(&caps).expect("ser");
-        let back: ServerCapabilities = serde_json::from_str(&json).expect("de");
-        assert!(back.tools.is_none());
-    }
-
-    #[test]
-    fn protocol_version_constant() {
-        assert_eq!(PROTOCOL_VERSION, "2024-11-05");
-    }
-}
+#[path = "initialize_tests.rs"]
+mod tests;
@@ -0,0 +1,83 @@
+//! Tests for MCP initialize types.
+
+use super::*;
+
+#[test]
+fn initialize_params_roundtrip() {
+    let params = InitializeParams {
+        protocol_version: PROTOCOL_VERSION.to_string(),
+        capabilities: ClientCapabilities {
+            tools: Some(ToolCapability {}),
+            elicitation: None,
+        },
+        client_info: ClientInfo {
+            name: "test-client".to_string(),
+            version: "1.0.0".to_string(),
+        },
+    };
+    let json = serde_json::to_string(&params).expect("serialize");
+    let back: InitializeParams = serde_json::from_str(&json).expect("deserialize");
+    assert_eq!(back.protocol_version, PROTOCOL_VERSION);
+    assert_eq!(back.client_info.name, "test-client");
+}
+
+#[test]
+fn initialize_result_roundtrip() {
+    let result = InitializeResult {
+        protocol_version: PROTOCOL_VERSION.to_string(),
+        capabilities: ServerCapabilities {
+            tools: Some(ToolCapability {}),
+            prompts: Some(PromptsCapability {}),
+            resources: Some(ResourcesCapability {}),
+            logging: None,
+        },
+        server_info: ServerInfo {
+            name: "kami".to_string(),
+            title: None,
+            version: "0.1.0".to_string(),
+        },
+    };
+    let json = serde_json::to_string(&result).expect("serialize");
+    let back: InitializeResult = serde_json::from_str(&json).expect("deserialize");
+    assert_eq!(back.server_info.name, "kami");
+    assert!(back.capabilities.prompts.is_some());
+    assert!(back.capabilities.resources.is_some());
+}
+
+#[test]
+fn client_capabilities_default_has_no_tools() {
+    let caps = ClientCapabilities::default();
+    assert!(caps.tools.is_none());
+}
+
+#[test]
+fn server_capabilities_empty_roundtrip() {
+    let caps = ServerCapabilities::default();
+    let json = serde_json::to_string(&caps).expect("ser");
+    let back: ServerCapabilities = serde_json::from_str(&json).expect("de");
+    assert!(back.tools.is_none());
+}
+
+#[test]
+fn protocol_version_constant() {
+    assert_eq!(PROTOCOL_VERSION, "2025-06-18");
+}
+
+#[test]
+fn elicitation_capability_is_read_from_client_capabilities() {
+    let caps: ClientCapabilities =
+        serde_json::from_str(r#"{"elicitation":{},"roots":{"listChanged":true}}"#).expect("de");
+    assert!(caps.elicitation.is_some());
+    let caps: ClientCapabilities = serde_json::from_str("{}").expect("de");
+    assert!(caps.elicitation.is_none());
+}
+
+#[test]
+fn server_capabilities_logging_serializes_when_present() {
+    let caps = ServerCapabilities {
+        logging: Some(LoggingCapability {}),
+        ..ServerCapabilities::default()
+    };
+    let json = serde_json::to_string(&caps).expect("ser");
+    assert_eq!(json, r#"{"logging":{}}"#);
+}
@@ -0,0 +1,78 @@
+//! MCP logging types (`logging/setLevel`, `notifications/message`).
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Syslog severity levels used by MCP, from least to most severe.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LoggingLevel {
+    /// Detailed debugging information.
+    Debug,
+    /// General informational messages.
+    #[default]
+    Info,
+    /// Normal but significant events.
+    Notice,
+    /// Warning conditions.
+    Warning,
+    /// Error conditions.
+    Error,
+    /// Critical conditions.
+    Critical,
+    /// Action must be taken immediately.
+    Alert,
+    /// System is unusable.
+    Emergency,
+}
+
+/// Logging capability (present if the server emits log notifications).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LoggingCapability {}
+
+/// Request params for `logging/setLevel`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetLevelParams {
+    /// Minimum level the client wants to receive.
+    pub level: LoggingLevel,
+}
+
+/// Params of a `notifications/message` notification.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoggingMessageParams {
+    /// Severity of the message.
+    pub level: LoggingLevel,
+    /// Name of the logger that emitted the message.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logger: Option<String>,
+    /// Arbitrary JSON payload.
+    pub data: Value,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn levels_are_ordered_by_severity() {
+        assert!(LoggingLevel::Debug < LoggingLevel::Info);
+        assert!(LoggingLevel::Warning < LoggingLevel::Emergency);
+    }
+
+    #[test]
+    fn level_serializes_lowercase() {
+        let json = serde_json::to_string(&LoggingLevel::Warning).expect("serialize");
+        assert_eq!(json, "\"warning\"");
+    }
+
+    #[test]
+    fn message_params_omit_missing_logger() {
+        let params = LoggingMessageParams {
+            level: LoggingLevel::Info,
+            logger: None,
+            data: serde_json::json!("hi"),
+        };
+        let json = serde_json::to_string(&params).expect("serialize");
+        assert_eq!(json, r#"{"level":"info","data":"hi"}"#);
+    }
+}
@@ -1,14 +1,23 @@
 //! MCP (Model Context Protocol) type definitions.
 
+pub mod cancellation;
+pub mod content;
 pub mod initialize;
+pub mod logging;
+pub mod progress;
 pub mod prompts;
 pub mod resources;
 pub mod tools;
+pub mod version;
 
+pub use cancellation::*;
 pub use initialize::*;
+pub use logging::*;
+pub use progress::*;
 pub use prompts::*;
 pub use resources::*;
 pub use tools::*;
+pub use version::ProtocolVersion;
 
 /// MCP protocol method names.
 pub mod methods {
@@ -26,4 +35,12 @@ pub mod methods {
     pub const RESOURCES_LIST: &str = "resources/list";
     /// Read a resource.
     pub const RESOURCES_READ: &str = "resources/read";
+    /// Set the minimum level of log notifications.
+    pub const LOGGING_SET_LEVEL: &str = "logging/setLevel";
+    /// Server log message notification.
+    pub const NOTIFICATIONS_MESSAGE: &str = "notifications/message";
+    /// Either side cancels a request it sent earlier.
+    pub const NOTIFICATIONS_CANCELLED: &str = "notifications/cancelled";
+    /// Progress of a request that carried a `progressToken`.
+    pub const NOTIFICATIONS_PROGRESS: &str = "notifications/progress";
 }
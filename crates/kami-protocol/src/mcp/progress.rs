@@ -0,0 +1,66 @@
+//! MCP progress types (`_meta.progressToken`, `notifications/progress`).
+
+use serde::{Deserialize, Serialize};
+
+/// Token a client attaches to a request to receive progress for it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ProgressToken {
+    /// Numeric token.
+    Number(i64),
+    /// String token.
+    String(String),
+}
+
+/// The `_meta` object of a request.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestMeta {
+    /// Token to tie `notifications/progress` to this request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub progress_token: Option<ProgressToken>,
+}
+
+/// Params of a `notifications/progress` notification.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProgressParams {
+    /// Token from the request being reported on.
+    pub progress_token: ProgressToken,
+    /// Work done so far; increases with every notification.
+    pub progress: f64,
+    /// Total amount of work, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total: Option<f64>,
+    /// Human-readable status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn meta_parses_string_or_number_token() {
+        let meta: RequestMeta = serde_json::from_str(r#"{"progressToken":"abc"}"#).expect("parse");
+        assert_eq!(
+            meta.progress_token,
+            Some(ProgressToken::String("abc".into()))
+        );
+        let meta: RequestMeta = serde_json::from_str(r#"{"progressToken":5}"#).expect("parse");
+        assert_eq!(meta.progress_token, Some(ProgressToken::Number(5)));
+    }
+
+    #[test]
+    fn progress_params_omit_missing_fields() {
+        let params = ProgressParams {
+            progress_token: ProgressToken::Number(1),
+            progress: 2.0,
+            total: None,
+            message: None,
+        };
+        let json = serde_json::to_string(&params).expect("serialize");
+        assert_eq!(json, r#"{"progressToken":1,"progress":2.0}"#);
+    }
+}
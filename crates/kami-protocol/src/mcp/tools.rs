@@ -3,6 +3,11 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use super::progress::RequestMeta;
+use super::version::ProtocolVersion;
+
+pub use super::content::{EmbeddedResource, ToolContent};
+
 /// Request params for `tools/list`.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ToolsListParams {
@@ -16,12 +21,62 @@ pub struct ToolsListParams {
 pub struct McpToolDefinition {
     /// Tool name.
     pub name: String,
+    /// Display name (2025-06-18).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
     /// Tool description.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// JSON Schema for input parameters.
     #[serde(rename = "inputSchema")]
     pub input_schema: Value,
+    /// JSON Schema of the structured result, if the tool declares one.
+    #[serde(
+        rename = "outputSchema",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub output_schema: Option<Value>,
+    /// Hints about the tool's behaviour (2025-03-26).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<ToolAnnotations>,
+}
+
+impl McpToolDefinition {
+    /// Drops the fields `version` does not define.
+    pub fn for_version(mut self, version: ProtocolVersion) -> Self {
+        if !version.has_titles() {
+            self.title = None;
+        }
+        if !version.has_structured_content() {
+            self.output_schema = None;
+        }
+        if !version.has_tool_annotations() {
+            self.annotations = None;
+        }
+        self
+    }
+}
+
+/// Behaviour hints for clients; never relied upon for security.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolAnnotations {
+    /// Display name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The tool does not modify its environment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read_only_hint: Option<bool>,
+    /// The tool may perform destructive updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub destructive_hint: Option<bool>,
+    /// Repeated calls with the same arguments have no further effect.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idempotent_hint: Option<bool>,
+    /// The tool interacts with external entities.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub open_world_hint: Option<bool>,
 }
 
 /// Response for `tools/list`.
@@ -42,16 +97,9 @@ pub struct ToolsCallParams {
     /// Arguments to pass.
     #[serde(default)]
     pub arguments: Value,
-}
-
-/// Content item in a tool call response.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(tag = "type", rename_all = "lowercase")]
-pub enum ToolContent {
-    /// Text content.
-    Text { text: String },
-    /// Image content (base64).
-    Image { data: String, mime_type: String },
+    /// Request metadata, e.g. the progress token.
+    #[serde(rename = "_meta", default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<RequestMeta>,
 }
 
 /// Response for `tools/call`.
@@ -59,76 +107,34 @@ pub enum ToolContent {
 pub struct ToolsCallResult {
     /// Content items returned by the tool.
     pub content: Vec<ToolContent>,
+    /// The result as JSON, matching the tool's `outputSchema`.
+    #[serde(
+        rename = "structuredContent",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub structured_content: Option<Value>,
     /// Whether the tool call resulted in an error.
     #[serde(default, rename = "isError")]
     pub is_error: bool,
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use serde_json::json;
-
-    #[test]
-    fn tools_list_params_default() {
-        let p = ToolsListParams::default();
-        assert!(p.cursor.is_none());
-    }
-
-    #[test]
-    fn tool_definition_serde_roundtrip() {
-        let def = McpToolDefinition {
-            name: "my-tool".into(),
-            description: Some("A tool".into()),
-            input_schema: json!({"type": "object"}),
-        };
-        let s = serde_json::to_string(&def).expect("ser");
-        assert!(s.contains("inputSchema"));
-        let back: McpToolDefinition = serde_json::from_str(&s).expect("de");
-        assert_eq!(back.name, "my-tool");
-    }
-
-    #[test]
-    fn tools_list_result_with_tools() {
-        let res = ToolsListResult {
-            tools: vec![McpToolDefinition {
-                name: "t".into(),
-                description: None,
-                input_schema: json!({}),
-            }],
-            next_cursor: None,
-        };
-        let s = serde_json::to_string(&res).expect("ser");
-        let back: ToolsListResult = serde_json::from_str(&s).expect("de");
-        assert_eq!(back.tools.len(), 1);
-    }
-
-    #[test]
-    fn tools_call_params_serde() {
-        let j = r#"{"name":"echo","arguments":{"x":1}}"#;
-        let p: ToolsCallParams = serde_json::from_str(j).expect("de");
-        assert_eq!(p.name, "echo");
-        assert_eq!(p.arguments["x"], 1);
-    }
-
-    #[test]
-    fn tool_content_text_variant() {
-        let c = ToolContent::Text {
-            text: "hello".into(),
-        };
-        let s = serde_json::to_string(&c).expect("ser");
-        assert!(s.contains("\"type\":\"text\""));
-    }
-
-    #[test]
-    fn tools_call_result_roundtrip() {
-        let r = ToolsCallResult {
-            content: vec![ToolContent::Text { text: "ok".into() }],
-            is_error: false,
-        };
-        let s = serde_json::to_string(&r).expect("ser");
-        let back: ToolsCallResult = serde_json::from_str(&s).expect("de");
-        assert!(!back.is_error);
-        assert_eq!(back.content.len(), 1);
+impl ToolsCallResult {
+    /// Drops `structuredContent` and rewrites content items `version`
+    /// does not define.
+    pub fn for_version(mut self, version: ProtocolVersion) -> Self {
+        if !version.has_structured_content() {
+            self.structured_content = None;
+        }
+        self.content = self
+            .content
+            .into_iter()
+            .map(|c| c.for_version(version))
+            .collect();
+        self
     }
 }
+
+#[cfg(test)]
+#[path = "tools_tests.rs"]
+mod tests;
@@ -0,0 +1,145 @@
+//! Tests for MCP tools/* types.
+
+use super::*;
+use crate::mcp::progress::ProgressToken;
+use serde_json::json;
+
+#[test]
+fn tools_list_params_default() {
+    let p = ToolsListParams::default();
+    assert!(p.cursor.is_none());
+}
+
+#[test]
+fn tool_definition_serde_roundtrip() {
+    let def = McpToolDefinition {
+        name: "my-tool".into(),
+        title: None,
+        description: Some("A tool".into()),
+        input_schema: json!({"type": "object"}),
+        output_schema: Some(json!({"type": "object"})),
+        annotations: None,
+    };
+    let s = serde_json::to_string(&def).expect("ser");
+    assert!(s.contains("inputSchema"));
+    assert!(s.contains("outputSchema"));
+    let back: McpToolDefinition = serde_json::from_str(&s).expect("de");
+    assert_eq!(back.name, "my-tool");
+}
+
+#[test]
+fn tools_list_result_with_tools() {
+    let res = ToolsListResult {
+        tools: vec![McpToolDefinition {
+            name: "t".into(),
+            title: None,
+            description: None,
+            input_schema: json!({}),
+            output_schema: None,
+            annotations: None,
+        }],
+        next_cursor: None,
+    };
+    let s = serde_json::to_string(&res).expect("ser");
+    let back: ToolsListResult = serde_json::from_str(&s).expect("de");
+    assert_eq!(back.tools.len(), 1);
+}
+
+#[test]
+fn tools_call_params_serde() {
+    let j = r#"{"name":"echo","arguments":{"x":1}}"#;
+    let p: ToolsCallParams = serde_json::from_str(j).expect("de");
+    assert_eq!(p.name, "echo");
+    assert_eq!(p.arguments["x"], 1);
+    assert!(p.meta.is_none());
+}
+
+#[test]
+fn tools_call_params_read_progress_token() {
+    let j = r#"{"name":"echo","_meta":{"progressToken":"t1"}}"#;
+    let p: ToolsCallParams = serde_json::from_str(j).expect("de");
+    let token = p.meta.and_then(|m| m.progress_token);
+    assert_eq!(token, Some(ProgressToken::String("t1".into())));
+}
+
+#[test]
+fn tool_content_text_variant() {
+    let c = ToolContent::Text {
+        text: "hello".into(),
+    };
+    let s = serde_json::to_string(&c).expect("ser");
+    assert!(s.contains("\"type\":\"text\""));
+}
+
+#[test]
+fn tools_call_result_roundtrip() {
+    let r = ToolsCallResult {
+        content: vec![ToolContent::Text { text: "ok".into() }],
+        structured_content: None,
+        is_error: false,
+    };
+    let s = serde_json::to_string(&r).expect("ser");
+    assert!(!s.contains("structuredContent"));
+    let back: ToolsCallResult = serde_json::from_str(&s).expect("de");
+    assert!(!back.is_error);
+    assert_eq!(back.content.len(), 1);
+}
+
+#[test]
+fn tools_call_result_with_structured_content() {
+    let r = ToolsCallResult {
+        content: vec![ToolContent::Text {
+            text: r#"{"n":1}"#.into(),
+        }],
+        structured_content: Some(json!({"n": 1})),
+        is_error: false,
+    };
+    let v = serde_json::to_value(&r).expect("ser");
+    assert_eq!(v["structuredContent"], json!({"n": 1}));
+    assert_eq!(v["content"][0]["text"], r#"{"n":1}"#);
+}
+
+fn full_definition() -> McpToolDefinition {
+    McpToolDefinition {
+        name: "t".into(),
+        title: Some("T".into()),
+        description: None,
+        input_schema: json!({"type": "object"}),
+        output_schema: Some(json!({"type": "object"})),
+        annotations: Some(ToolAnnotations {
+            read_only_hint: Some(true),
+            ..ToolAnnotations::default()
+        }),
+    }
+}
+
+#[test]
+fn tool_definition_keeps_only_fields_of_the_revision() {
+    let old = serde_json::to_value(full_definition().for_version(ProtocolVersion::V2024_11_05));
+    assert_eq!(
+        old.expect("ser"),
+        json!({"name": "t", "inputSchema": {"type": "object"}})
+    );
+    let mid = full_definition().for_version(ProtocolVersion::V2025_03_26);
+    assert!(mid.title.is_none() && mid.output_schema.is_none());
+    let annotations = serde_json::to_value(mid.annotations).expect("ser");
+    assert_eq!(annotations, json!({"readOnlyHint": true}));
+    let new = full_definition().for_version(ProtocolVersion::V2025_06_18);
+    assert!(new.title.is_some() && new.output_schema.is_some());
+}
+
+#[test]
+fn tools_call_result_drops_structured_content_before_2025_06_18() {
+    let r = ToolsCallResult {
+        content: vec![ToolContent::Text { text: "1".into() }],
+        structured_content: Some(json!(1)),
+        is_error: false,
+    };
+    let old = r.clone().for_version(ProtocolVersion::V2025_03_26);
+    assert!(old.structured_content.is_none());
+    assert_eq!(old.content, r.content);
+    assert!(r
+        .for_version(ProtocolVersion::LATEST)
+        .structured_content
+        .is_some());
+}
@@ -0,0 +1,124 @@
+//! MCP protocol revisions and version negotiation.
+//!
+//! The client proposes a revision in `initialize`; the server answers with
+//! the same revision if it speaks it, or with its latest one otherwise.
+//! The agreed revision decides which fields and content types the server
+//! may send for the rest of the session.
+
+use std::fmt;
+
+/// A revision of the MCP specification this server speaks.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ProtocolVersion {
+    /// `2024-11-05`: the first public revision.
+    V2024_11_05,
+    /// `2025-03-26`: adds tool annotations and audio content.
+    V2025_03_26,
+    /// `2025-06-18`: adds titles, structured tool output, resource links
+    /// and elicitation.
+    #[default]
+    V2025_06_18,
+}
+
+impl ProtocolVersion {
+    /// Every supported revision, oldest first.
+    pub const SUPPORTED: [Self; 3] = [Self::V2024_11_05, Self::V2025_03_26, Self::V2025_06_18];
+
+    /// The newest supported revision.
+    pub const LATEST: Self = Self::V2025_06_18;
+
+    /// Returns the revision's date string, as sent on the wire.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::V2024_11_05 => "2024-11-05",
+            Self::V2025_03_26 => "2025-03-26",
+            Self::V2025_06_18 => "2025-06-18",
+        }
+    }
+
+    /// Parses a revision date string, or returns `None` if unsupported.
+    pub fn parse(version: &str) -> Option<Self> {
+        Self::SUPPORTED.into_iter().find(|v| v.as_str() == version)
+    }
+
+    /// Picks the revision to answer a client requesting `requested` with:
+    /// the requested one if supported, otherwise [`LATEST`](Self::LATEST).
+    pub fn negotiate(requested: &str) -> Self {
+        Self::parse(requested).unwrap_or(Self::LATEST)
+    }
+
+    /// Whether tools carry `annotations` (behaviour hints).
+    pub fn has_tool_annotations(self) -> bool {
+        self >= Self::V2025_03_26
+    }
+
+    /// Whether `audio` content items are understood.
+    pub fn has_audio_content(self) -> bool {
+        self >= Self::V2025_03_26
+    }
+
+    /// Whether tools and implementations carry a display `title`.
+    pub fn has_titles(self) -> bool {
+        self >= Self::V2025_06_18
+    }
+
+    /// Whether tools carry an `outputSchema` and results `structuredContent`.
+    pub fn has_structured_content(self) -> bool {
+        self >= Self::V2025_06_18
+    }
+
+    /// Whether `resource_link` content items are understood.
+    pub fn has_resource_links(self) -> bool {
+        self >= Self::V2025_06_18
+    }
+
+    /// Whether the client may declare the `elicitation` capability.
+    pub fn has_elicitation(self) -> bool {
+        self >= Self::V2025_06_18
+    }
+}
+
+impl fmt::Display for ProtocolVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn supported_revisions_parse_back() {
+        for version in ProtocolVersion::SUPPORTED {
+            assert_eq!(ProtocolVersion::parse(version.as_str()), Some(version));
+        }
+        assert_eq!(ProtocolVersion::parse("2025-01-01"), None);
+    }
+
+    #[test]
+    fn negotiation_falls_back_to_latest() {
+        assert_eq!(
+            ProtocolVersion::negotiate("2024-11-05"),
+            ProtocolVersion::V2024_11_05
+        );
+        assert_eq!(
+            ProtocolVersion::negotiate("1999-01-01"),
+            ProtocolVersion::LATEST
+        );
+        assert_eq!(ProtocolVersion::negotiate(""), ProtocolVersion::LATEST);
+    }
+
+    #[test]
+    fn features_follow_revisions() {
+        let old = ProtocolVersion::V2024_11_05;
+        let mid = ProtocolVersion::V2025_03_26;
+        let new = ProtocolVersion::V2025_06_18;
+        assert!(!old.has_tool_annotations() && mid.has_tool_annotations());
+        assert!(!old.has_audio_content() && mid.has_audio_content());
+        assert!(!mid.has_titles() && new.has_titles());
+        assert!(!mid.has_structured_content() && new.has_structured_content());
+        assert!(!mid.has_resource_links() && new.has_resource_links());
+        assert!(!mid.has_elicitation() && new.has_elicitation());
+    }
+}
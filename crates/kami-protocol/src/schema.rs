@@ -1,8 +1,17 @@
-//! JSON Schema helpers for MCP tool input validation.
+//! JSON Schema helpers for MCP tool input and output validation.
+
+mod arguments;
+mod compiled;
+mod keywords;
+mod validate;
 
 use serde_json::Value;
 use thiserror::Error;
 
+pub use arguments::{build_input_schema, build_output_schema};
+pub use compiled::CompiledSchema;
+pub use validate::Violation;
+
 /// Schema validation errors.
 #[derive(Debug, Error)]
 pub enum SchemaError {
@@ -16,7 +25,8 @@ pub enum SchemaError {
 
 /// Checks that a JSON value contains required properties from a schema.
 ///
-/// This is a minimal validator - checks required fields and basic types.
+/// This is a minimal validator - checks required fields only. Use
+/// [`CompiledSchema`] for full validation.
 pub fn validate_required_fields(schema: &Value, input: &Value) -> Result<(), SchemaError> {
     let required = match schema.get("required") {
         Some(Value::Array(arr)) => arr,
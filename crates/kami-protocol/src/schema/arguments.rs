@@ -0,0 +1,83 @@
+//! MCP `inputSchema` and `outputSchema` generation from a tool's argument
+//! and output field definitions.
+
+use serde_json::{json, Map, Value};
+
+use kami_types::{ArgumentConstraints, ToolArgument};
+
+/// Builds a JSON Schema `inputSchema` from tool arguments.
+///
+/// The result is an object schema; nested objects and array items carry
+/// their own constraints.
+pub fn build_input_schema(arguments: &[ToolArgument]) -> Value {
+    Value::Object(object_schema(arguments))
+}
+
+/// Builds a JSON Schema `outputSchema` from the fields of a tool's result.
+///
+/// Returns `None` when the tool declares no output fields.
+pub fn build_output_schema(fields: &[ToolArgument]) -> Option<Value> {
+    (!fields.is_empty()).then(|| build_input_schema(fields))
+}
+
+fn object_schema(arguments: &[ToolArgument]) -> Map<String, Value> {
+    let properties: Map<String, Value> = arguments
+        .iter()
+        .map(|arg| (arg.name.clone(), Value::Object(property(arg))))
+        .collect();
+    let required: Vec<&str> = arguments
+        .iter()
+        .filter(|arg| arg.required)
+        .map(|arg| arg.name.as_str())
+        .collect();
+
+    let mut schema = Map::new();
+    schema.insert("type".into(), json!("object"));
+    schema.insert("properties".into(), Value::Object(properties));
+    if !required.is_empty() {
+        schema.insert("required".into(), json!(required));
+    }
+    schema
+}
+
+fn property(arg: &ToolArgument) -> Map<String, Value> {
+    let mut prop = typed(&arg.arg_type, &arg.constraints);
+    prop.insert("description".into(), json!(arg.description));
+    if let Some(default) = &arg.default {
+        prop.insert("default".into(), default.clone());
+    }
+    prop
+}
+
+fn typed(ty: &str, constraints: &ArgumentConstraints) -> Map<String, Value> {
+    let mut schema = if constraints.properties.is_empty() {
+        Map::new()
+    } else {
+        object_schema(&constraints.properties)
+    };
+    schema.insert("type".into(), json!(ty));
+    let c = constraints;
+    let keywords = [
+        ("enum", c.allowed.as_ref().map(|values| json!(values))),
+        ("minimum", c.minimum.clone().map(Value::Number)),
+        ("maximum", c.maximum.clone().map(Value::Number)),
+        ("pattern", c.pattern.as_ref().map(|p| json!(p))),
+        ("format", c.format.as_ref().map(|f| json!(f))),
+        (
+            "items",
+            c.items
+                .as_ref()
+                .map(|i| typed(&i.item_type, &i.constraints).into()),
+        ),
+    ];
+    for (keyword, value) in keywords {
+        if let Some(value) = value {
+            schema.insert(keyword.into(), value);
+        }
+    }
+    schema
+}
+
+#[cfg(test)]
+#[path = "arguments_tests.rs"]
+mod tests;
@@ -0,0 +1,94 @@
+//! Tests for `inputSchema` and `outputSchema` generation.
+
+use super::*;
+use kami_types::ArgumentItems;
+
+fn arg(name: &str, arg_type: &str) -> ToolArgument {
+    ToolArgument {
+        name: name.into(),
+        arg_type: arg_type.into(),
+        description: format!("The {name}"),
+        ..ToolArgument::default()
+    }
+}
+
+#[test]
+fn build_input_schema_empty_args() {
+    let schema = build_input_schema(&[]);
+    assert_eq!(schema["type"], "object");
+    assert!(schema["properties"].as_object().expect("obj").is_empty());
+    assert!(schema.get("required").is_none());
+}
+
+#[test]
+fn build_input_schema_with_args() {
+    let url = ToolArgument {
+        required: true,
+        ..arg("url", "string")
+    };
+    let schema = build_input_schema(&[url]);
+    assert_eq!(schema["type"], "object");
+    assert_eq!(schema["properties"]["url"]["type"], "string");
+    assert_eq!(schema["properties"]["url"]["description"], "The url");
+    assert_eq!(schema["required"], json!(["url"]));
+}
+
+#[test]
+fn constraints_and_typed_default_are_emitted() {
+    let mut mode = arg("mode", "string");
+    mode.constraints.allowed = Some(vec![json!("fast"), json!("safe")]);
+    mode.default = Some(json!("safe"));
+    let mut depth = arg("depth", "integer");
+    depth.constraints.minimum = Some(1.into());
+    depth.constraints.maximum = Some(5.into());
+    let mut url = arg("url", "string");
+    url.constraints.pattern = Some("^https://".into());
+    url.constraints.format = Some("uri".into());
+
+    let schema = build_input_schema(&[mode, depth, url]);
+
+    let props = &schema["properties"];
+    assert_eq!(props["mode"]["enum"], json!(["fast", "safe"]));
+    assert_eq!(props["mode"]["default"], "safe");
+    assert_eq!(props["depth"]["minimum"], 1);
+    assert_eq!(props["depth"]["maximum"], 5);
+    assert_eq!(props["url"]["pattern"], "^https://");
+    assert_eq!(props["url"]["format"], "uri");
+}
+
+#[test]
+fn nested_objects_and_array_items() {
+    let mut tags = arg("tags", "array");
+    let mut item = ArgumentItems {
+        item_type: "string".into(),
+        ..ArgumentItems::default()
+    };
+    item.constraints.pattern = Some("^[a-z]+$".into());
+    tags.constraints.items = Some(Box::new(item));
+    let mut opts = arg("opts", "object");
+    opts.constraints.properties = vec![ToolArgument {
+        required: true,
+        ..arg("depth", "integer")
+    }];
+
+    let schema = build_input_schema(&[tags, opts]);
+
+    let props = &schema["properties"];
+    assert_eq!(
+        props["tags"]["items"],
+        json!({"type": "string", "pattern": "^[a-z]+$"})
+    );
+    assert_eq!(props["opts"]["type"], "object");
+    assert_eq!(props["opts"]["properties"]["depth"]["type"], "integer");
+    assert_eq!(props["opts"]["required"], json!(["depth"]));
+}
+
+#[test]
+fn output_schema_only_when_fields_are_declared() {
+    assert!(build_output_schema(&[]).is_none());
+
+    let schema = build_output_schema(&[arg("count", "integer")]).expect("schema");
+
+    assert_eq!(schema["type"], "object");
+    assert_eq!(schema["properties"]["count"]["type"], "integer");
+}
@@ -0,0 +1,73 @@
+//! Schemas with their `pattern` regexes compiled ahead of validation.
+
+use std::collections::HashMap;
+
+use regex::Regex;
+use serde_json::Value;
+
+use super::validate::{check, Violation};
+use super::SchemaError;
+
+/// Compiled `pattern` regexes of a schema, keyed by their source.
+pub(super) type Patterns = HashMap<String, Regex>;
+
+/// A JSON Schema ready to validate instances against.
+///
+/// Every `pattern` is compiled once by [`compile`](Self::compile), so a
+/// schema kept across calls never recompiles its regexes.
+#[derive(Debug, Clone)]
+pub struct CompiledSchema {
+    schema: Value,
+    patterns: Patterns,
+}
+
+impl CompiledSchema {
+    /// Compiles the `pattern` keywords of `schema` and its subschemas.
+    ///
+    /// # Errors
+    /// Returns [`SchemaError::InvalidSchema`] if a `pattern` is not a valid
+    /// regular expression: the schema is at fault, not the instances.
+    pub fn compile(schema: Value) -> Result<Self, SchemaError> {
+        let mut patterns = Patterns::new();
+        collect_patterns(&schema, &mut patterns)?;
+        Ok(Self { schema, patterns })
+    }
+
+    /// Returns the schema this was compiled from.
+    pub fn schema(&self) -> &Value {
+        &self.schema
+    }
+
+    /// Validates `instance`, returning every violation.
+    ///
+    /// An empty list means `instance` is valid.
+    pub fn validate(&self, instance: &Value) -> Vec<Violation> {
+        let mut out = Vec::new();
+        check(&self.schema, instance, "", &self.patterns, &mut out);
+        out
+    }
+}
+
+fn collect_patterns(schema: &Value, patterns: &mut Patterns) -> Result<(), SchemaError> {
+    let Value::Object(schema) = schema else {
+        return Ok(());
+    };
+    if let Some(pattern) = schema.get("pattern").and_then(Value::as_str) {
+        if !patterns.contains_key(pattern) {
+            let regex = Regex::new(pattern).map_err(|e| SchemaError::InvalidSchema {
+                message: format!("invalid pattern {pattern}: {e}"),
+            })?;
+            patterns.insert(pattern.to_string(), regex);
+        }
+    }
+    let properties = schema.get("properties").and_then(Value::as_object);
+    let subschemas = properties
+        .into_iter()
+        .flat_map(|p| p.values())
+        .chain(schema.get("items"))
+        .chain(schema.get("additionalProperties"));
+    for subschema in subschemas {
+        collect_patterns(subschema, patterns)?;
+    }
+    Ok(())
+}
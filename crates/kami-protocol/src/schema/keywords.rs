@@ -0,0 +1,129 @@
+//! Keyword checks on a single value: `type`, `enum`, `const`, numeric
+//! bounds, string length and `pattern`.
+
+use serde_json::{Map, Value};
+
+use super::compiled::Patterns;
+
+/// A failed keyword and what is wrong.
+pub(super) type Failure = (&'static str, String);
+
+/// Checks the keywords of `schema` that apply to `value` itself.
+///
+/// A `type` mismatch is reported alone, as the only failure.
+pub(super) fn check_value(
+    schema: &Map<String, Value>,
+    value: &Value,
+    patterns: &Patterns,
+) -> Vec<Failure> {
+    if let Some(ty) = schema.get("type").filter(|ty| !type_matches(ty, value)) {
+        let message = format!("expected {}, got {}", names(ty), kind(value));
+        return vec![("type", message)];
+    }
+    let mut failed = Vec::new();
+    if let Some(Value::Array(options)) = schema.get("enum") {
+        if !options.iter().any(|option| equal(option, value)) {
+            let message = format!("must be one of {}", Value::Array(options.clone()));
+            failed.push(("enum", message));
+        }
+    }
+    if let Some(expected) = schema.get("const").filter(|c| !equal(c, value)) {
+        failed.push(("const", format!("must be {expected}")));
+    }
+    match value {
+        Value::Number(n) => check_number(schema, n.as_f64().unwrap_or(f64::NAN), &mut failed),
+        Value::String(s) => check_string(schema, s, patterns, &mut failed),
+        _ => {}
+    }
+    failed
+}
+
+/// JSON Schema equality: numbers compare by value, so `1` equals `1.0`.
+pub(super) fn equal(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        // Integers of equal value share a representation; only floats differ.
+        (Value::Number(x), Value::Number(y)) => {
+            x == y || ((x.is_f64() || y.is_f64()) && x.as_f64() == y.as_f64())
+        }
+        (Value::Array(x), Value::Array(y)) => {
+            x.len() == y.len() && x.iter().zip(y).all(|(x, y)| equal(x, y))
+        }
+        (Value::Object(x), Value::Object(y)) => {
+            x.len() == y.len() && x.iter().all(|(k, v)| y.get(k).is_some_and(|w| equal(v, w)))
+        }
+        _ => a == b,
+    }
+}
+
+fn check_number(schema: &Map<String, Value>, n: f64, failed: &mut Vec<Failure>) {
+    let bound = |keyword| schema.get(keyword).and_then(Value::as_f64);
+    if let Some(min) = bound("minimum").filter(|min| n < *min) {
+        failed.push(("minimum", format!("must be >= {min}")));
+    }
+    if let Some(max) = bound("maximum").filter(|max| n > *max) {
+        failed.push(("maximum", format!("must be <= {max}")));
+    }
+    if let Some(min) = bound("exclusiveMinimum").filter(|min| n <= *min) {
+        failed.push(("exclusiveMinimum", format!("must be > {min}")));
+    }
+    if let Some(max) = bound("exclusiveMaximum").filter(|max| n >= *max) {
+        failed.push(("exclusiveMaximum", format!("must be < {max}")));
+    }
+}
+
+fn check_string(
+    schema: &Map<String, Value>,
+    s: &str,
+    patterns: &Patterns,
+    failed: &mut Vec<Failure>,
+) {
+    let len = s.chars().count() as u64;
+    let bound = |keyword| schema.get(keyword).and_then(Value::as_u64);
+    if let Some(min) = bound("minLength").filter(|min| len < *min) {
+        failed.push(("minLength", format!("must be at least {min} characters")));
+    }
+    if let Some(max) = bound("maxLength").filter(|max| len > *max) {
+        failed.push(("maxLength", format!("must be at most {max} characters")));
+    }
+    // Patterns were all compiled with the schema; a missing one cannot match.
+    if let Some(pattern) = schema.get("pattern").and_then(Value::as_str) {
+        if !patterns.get(pattern).is_some_and(|re| re.is_match(s)) {
+            failed.push(("pattern", format!("must match pattern {pattern}")));
+        }
+    }
+}
+
+fn type_matches(ty: &Value, value: &Value) -> bool {
+    let is = |name: &str| match name {
+        "integer" => value.as_f64().is_some_and(|n| n.fract() == 0.0),
+        "number" | "string" | "boolean" | "array" | "object" | "null" => kind(value) == name,
+        _ => true,
+    };
+    match ty {
+        Value::String(name) => is(name),
+        Value::Array(names) => names.iter().filter_map(Value::as_str).any(is),
+        _ => true,
+    }
+}
+
+fn names(ty: &Value) -> String {
+    match ty {
+        Value::Array(names) => names
+            .iter()
+            .filter_map(Value::as_str)
+            .collect::<Vec<_>>()
+            .join(" or "),
+        other => other.as_str().unwrap_or_default().to_string(),
+    }
+}
+
+fn kind(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
@@ -0,0 +1,143 @@
+//! JSON Schema validation of tool arguments.
+//!
+//! Covers the keywords tool input schemas use: `type`, `enum`, `const`,
+//! numeric bounds, string length and `pattern`, `items` and array bounds,
+//! `properties`, `required` and `additionalProperties`. Annotations such as
+//! `description`, `default` and `format` are not checked, and unknown type
+//! names match any value.
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use super::compiled::Patterns;
+use super::keywords;
+
+/// One way a value fails its schema.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Violation {
+    /// JSON Pointer to the offending value (empty for the root).
+    pub path: String,
+    /// The schema keyword that failed.
+    pub keyword: &'static str,
+    /// What is wrong, e.g. "expected string, got number".
+    pub message: String,
+}
+
+/// Appends the violations of `value`, found at `path`, to `out`.
+pub(super) fn check(
+    schema: &Value,
+    value: &Value,
+    path: &str,
+    patterns: &Patterns,
+    out: &mut Vec<Violation>,
+) {
+    let schema = match schema {
+        Value::Object(schema) => schema,
+        Value::Bool(false) => return out.push(violation(path, "false", "no value allowed")),
+        _ => return,
+    };
+    let failed = keywords::check_value(schema, value, patterns);
+    let type_failed = failed
+        .first()
+        .is_some_and(|(keyword, _)| *keyword == "type");
+    out.extend(
+        failed
+            .into_iter()
+            .map(|(k, message)| violation(path, k, message)),
+    );
+    match value {
+        _ if type_failed => {}
+        Value::Array(items) => check_array(schema, items, path, patterns, out),
+        Value::Object(map) => check_object(schema, map, path, patterns, out),
+        _ => {}
+    }
+}
+
+fn check_array(
+    schema: &Map<String, Value>,
+    items: &[Value],
+    path: &str,
+    patterns: &Patterns,
+    out: &mut Vec<Violation>,
+) {
+    let len = items.len() as u64;
+    let bound = |keyword| schema.get(keyword).and_then(Value::as_u64);
+    if let Some(min) = bound("minItems").filter(|min| len < *min) {
+        out.push(violation(
+            path,
+            "minItems",
+            format!("must have at least {min} items"),
+        ));
+    }
+    if let Some(max) = bound("maxItems").filter(|max| len > *max) {
+        out.push(violation(
+            path,
+            "maxItems",
+            format!("must have at most {max} items"),
+        ));
+    }
+    let unique = schema.get("uniqueItems") == Some(&Value::Bool(true));
+    if unique && has_duplicates(items) {
+        out.push(violation(
+            path,
+            "uniqueItems",
+            "must not contain duplicates",
+        ));
+    }
+    if let Some(item_schema) = schema.get("items") {
+        for (i, item) in items.iter().enumerate() {
+            check(item_schema, item, &format!("{path}/{i}"), patterns, out);
+        }
+    }
+}
+
+fn check_object(
+    schema: &Map<String, Value>,
+    map: &Map<String, Value>,
+    path: &str,
+    patterns: &Patterns,
+    out: &mut Vec<Violation>,
+) {
+    let required = schema.get("required").and_then(Value::as_array);
+    for name in required.into_iter().flatten().filter_map(Value::as_str) {
+        if !map.contains_key(name) {
+            let message = format!("missing required property '{name}'");
+            out.push(violation(path, "required", message));
+        }
+    }
+    let properties = schema.get("properties").and_then(Value::as_object);
+    let additional = schema.get("additionalProperties");
+    for (key, value) in map {
+        let child = format!("{path}/{}", key.replace('~', "~0").replace('/', "~1"));
+        match (properties.and_then(|p| p.get(key)), additional) {
+            (Some(sub), _) => check(sub, value, &child, patterns, out),
+            (None, Some(Value::Bool(false))) => {
+                out.push(violation(
+                    &child,
+                    "additionalProperties",
+                    "unexpected property",
+                ));
+            }
+            (None, Some(sub)) => check(sub, value, &child, patterns, out),
+            (None, None) => {}
+        }
+    }
+}
+
+/// Whether any two items are equal by [`keywords::equal`].
+fn has_duplicates(items: &[Value]) -> bool {
+    let equal_later = |(i, item)| items[i + 1..].iter().any(|b| keywords::equal(item, b));
+    items.iter().enumerate().any(equal_later)
+}
+
+fn violation(path: &str, keyword: &'static str, message: impl Into<String>) -> Violation {
+    Violation {
+        path: path.to_string(),
+        keyword,
+        message: message.into(),
+    }
+}
+
+#[cfg(test)]
+#[path = "validate_tests.rs"]
+mod tests;
@@ -0,0 +1,143 @@
+//! Tests for JSON Schema validation.
+
+use super::*;
+use crate::schema::{CompiledSchema, SchemaError};
+use serde_json::json;
+
+fn validate(schema: &Value, instance: &Value) -> Vec<Violation> {
+    let compiled = CompiledSchema::compile(schema.clone()).expect("compile");
+    compiled.validate(instance)
+}
+
+fn keywords(schema: &Value, instance: &Value) -> Vec<(String, &'static str)> {
+    validate(schema, instance)
+        .into_iter()
+        .map(|v| (v.path, v.keyword))
+        .collect()
+}
+
+#[test]
+fn valid_instance_has_no_violations() {
+    let schema = json!({
+        "type": "object",
+        "properties": {
+            "url": {"type": "string", "pattern": "^https://"},
+            "count": {"type": "integer", "minimum": 1, "maximum": 10}
+        },
+        "required": ["url"]
+    });
+    let input = json!({"url": "https://example.com", "count": 3});
+    assert!(validate(&schema, &input).is_empty());
+}
+
+#[test]
+fn type_mismatch_is_reported_once() {
+    let schema = json!({"type": "string", "minLength": 3, "enum": ["abc"]});
+    let violations = validate(&schema, &json!(42));
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].message, "expected string, got number");
+}
+
+#[test]
+fn integer_accepts_whole_numbers_only() {
+    let schema = json!({"type": "integer"});
+    assert!(validate(&schema, &json!(4)).is_empty());
+    assert!(validate(&schema, &json!(4.0)).is_empty());
+    assert_eq!(validate(&schema, &json!(4.5)).len(), 1);
+}
+
+#[test]
+fn type_lists_and_unknown_types() {
+    let schema = json!({"type": ["string", "null"]});
+    assert!(validate(&schema, &json!(null)).is_empty());
+    assert_eq!(
+        validate(&schema, &json!(1))[0].message,
+        "expected string or null, got number"
+    );
+    assert!(validate(&json!({"type": "uri"}), &json!(1)).is_empty());
+}
+
+#[test]
+fn enum_const_and_bounds() {
+    let schema = json!({"enum": ["a", "b"]});
+    assert_eq!(keywords(&schema, &json!("c")), [(String::new(), "enum")]);
+    assert_eq!(keywords(&json!({"const": 1}), &json!(2))[0].1, "const");
+    let range = json!({"exclusiveMinimum": 0, "maximum": 5});
+    assert_eq!(keywords(&range, &json!(0))[0].1, "exclusiveMinimum");
+    assert_eq!(keywords(&range, &json!(6))[0].1, "maximum");
+}
+
+#[test]
+fn string_length_and_pattern() {
+    let schema = json!({"maxLength": 2, "pattern": "^[a-z]+$"});
+    assert_eq!(
+        keywords(&schema, &json!("ABC")),
+        [(String::new(), "maxLength"), (String::new(), "pattern")]
+    );
+}
+
+#[test]
+fn invalid_pattern_fails_compilation() {
+    let schema = json!({"properties": {"id": {"items": {"pattern": "("}}}});
+    let Err(SchemaError::InvalidSchema { message }) = CompiledSchema::compile(schema) else {
+        panic!("an invalid pattern must not compile");
+    };
+    assert!(message.starts_with("invalid pattern ("), "{message}");
+}
+
+#[test]
+fn unique_items_compares_whole_values() {
+    let schema = json!({"uniqueItems": true});
+    let same = json!([{"a": 1, "b": [2]}, {"b": [2], "a": 1}]);
+    assert_eq!(keywords(&schema, &same), [(String::new(), "uniqueItems")]);
+    assert!(validate(&schema, &json!([1, "1", [1], {"1": 1}])).is_empty());
+}
+
+#[test]
+fn numbers_compare_by_value() {
+    assert!(validate(&json!({"enum": [1, 2]}), &json!(1.0)).is_empty());
+    assert!(validate(&json!({"const": {"n": [1.0]}}), &json!({"n": [1]})).is_empty());
+    let unique = json!({"uniqueItems": true});
+    let duplicate = [(String::new(), "uniqueItems")];
+    assert_eq!(keywords(&unique, &json!([1, 1.0])), duplicate);
+    let beyond_f64 = json!([9007199254740992_u64, 9007199254740993_u64]);
+    assert!(validate(&unique, &beyond_f64).is_empty());
+}
+
+#[test]
+fn nested_violations_carry_their_path() {
+    let schema = json!({
+        "type": "object",
+        "properties": {
+            "tags": {"type": "array", "items": {"type": "string"}, "uniqueItems": true},
+            "opts": {
+                "type": "object",
+                "properties": {"depth": {"type": "integer"}},
+                "required": ["depth"],
+                "additionalProperties": false
+            }
+        }
+    });
+    let input = json!({"tags": ["a", 1, "a"], "opts": {"a/b": true}});
+
+    let found = keywords(&schema, &input);
+
+    assert_eq!(
+        found,
+        [
+            ("/opts".to_string(), "required"),
+            ("/opts/a~1b".to_string(), "additionalProperties"),
+            ("/tags".to_string(), "uniqueItems"),
+            ("/tags/1".to_string(), "type"),
+        ]
+    );
+}
+
+#[test]
+fn array_bounds_and_false_schema() {
+    let schema = json!({"minItems": 2, "items": false});
+    assert_eq!(
+        keywords(&schema, &json!([1])),
+        [(String::new(), "minItems"), ("/0".to_string(), "false")]
+    );
+}
@@ -0,0 +1,17 @@
+//! Abstract audit log trait (port) for security-relevant events.
+
+use async_trait::async_trait;
+
+use kami_types::DomainEvent;
+
+use crate::repository::RepositoryError;
+
+/// Append-only log of security-relevant domain events.
+#[async_trait]
+pub trait AuditLog: Send + Sync {
+    /// Appends an event to the log.
+    async fn record(&self, event: &DomainEvent) -> Result<(), RepositoryError>;
+
+    /// Returns up to `limit` of the most recent events, newest first.
+    async fn recent(&self, limit: usize) -> Result<Vec<DomainEvent>, RepositoryError>;
+}
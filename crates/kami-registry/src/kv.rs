@@ -0,0 +1,36 @@
+//! Abstract key/value store trait (port) for per-tool persistent state.
+
+use async_trait::async_trait;
+
+use kami_types::ToolId;
+
+use crate::repository::RepositoryError;
+
+/// Abstract trait for the key/value storage offered to tools.
+///
+/// Every entry belongs to one tool: a tool can never read or list the keys
+/// of another. Implementations live in adapter crates (e.g.,
+/// `kami-store-sqlite`).
+#[async_trait]
+pub trait KeyValueStore: Send + Sync {
+    /// Returns the value stored under `key` for `tool`.
+    async fn get(&self, tool: &ToolId, key: &str) -> Result<Option<Vec<u8>>, RepositoryError>;
+
+    /// Stores `value` under `key`, replacing any previous value.
+    ///
+    /// Returns `QuotaExceeded` if the tool's keys and values would then take
+    /// more than `max_bytes`; the store is left unchanged.
+    async fn set(
+        &self,
+        tool: &ToolId,
+        key: &str,
+        value: &[u8],
+        max_bytes: u64,
+    ) -> Result<(), RepositoryError>;
+
+    /// Removes `key`. Returns true if it existed.
+    async fn remove(&self, tool: &ToolId, key: &str) -> Result<bool, RepositoryError>;
+
+    /// Lists the keys of `tool` starting with `prefix`, in ascending order.
+    async fn list_keys(&self, tool: &ToolId, prefix: &str) -> Result<Vec<String>, RepositoryError>;
+}
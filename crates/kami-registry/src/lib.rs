@@ -3,8 +3,16 @@
 //! Port definitions (abstract traits) for the tool registry.
 //! Adapter crates implement these traits.
 
+pub mod audit;
+pub mod kv;
+pub mod policy;
 pub mod query;
 pub mod repository;
+pub mod secret;
 
+pub use audit::AuditLog;
+pub use kv::KeyValueStore;
+pub use policy::ToolPolicyStore;
 pub use query::ToolQuery;
 pub use repository::{RepositoryError, ToolRepository};
+pub use secret::SecretStore;
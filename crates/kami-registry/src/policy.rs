@@ -0,0 +1,35 @@
+//! Abstract store (port) of the operator's per-tool decisions: limit
+//! overrides and capability grants.
+
+use async_trait::async_trait;
+
+use kami_types::{LimitCaps, SecurityConfig, ToolId};
+
+use crate::repository::RepositoryError;
+
+/// Abstract trait for the operator's per-tool decisions.
+///
+/// Overrides, set with `kami policy`, are ceilings on a tool's resource
+/// limits applied on top of its manifest and the host policy: they can
+/// only tighten a tool. They belong to the operator, not to the tool, so
+/// reinstalling a tool keeps them.
+///
+/// Grants record the security config the operator approved at install or
+/// update. They are dropped when the tool is uninstalled.
+#[async_trait]
+pub trait ToolPolicyStore: Send + Sync {
+    /// Returns the overrides set for `tool`, if any.
+    async fn overrides(&self, tool: &ToolId) -> Result<Option<LimitCaps>, RepositoryError>;
+
+    /// Sets the overrides of `tool`, replacing any previous ones.
+    async fn set_overrides(&self, tool: &ToolId, caps: &LimitCaps) -> Result<(), RepositoryError>;
+
+    /// Removes the overrides of `tool`. Returns true if there were any.
+    async fn clear_overrides(&self, tool: &ToolId) -> Result<bool, RepositoryError>;
+
+    /// Returns the security config last approved for `tool`, if any.
+    async fn granted(&self, tool: &ToolId) -> Result<Option<SecurityConfig>, RepositoryError>;
+
+    /// Records `security` as approved for `tool`, replacing any grant.
+    async fn grant(&self, tool: &ToolId, security: &SecurityConfig) -> Result<(), RepositoryError>;
+}
@@ -23,6 +23,9 @@ pub enum RepositoryError {
     /// Stored data is corrupt or cannot be deserialized.
     #[error("data corruption for tool '{tool_id}': {message}")]
     DataCorruption { tool_id: String, message: String },
+    /// A write would exceed a storage quota.
+    #[error("quota of {limit} bytes exceeded")]
+    QuotaExceeded { limit: u64 },
 }
 
 /// Abstract trait for tool persistence.
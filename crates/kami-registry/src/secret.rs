@@ -0,0 +1,25 @@
+//! Abstract secret store trait (port) for host-mediated secrets.
+
+use async_trait::async_trait;
+
+use crate::repository::RepositoryError;
+
+/// Abstract trait for the operator-managed secret store.
+///
+/// Values are opaque strings kept encrypted at rest by the adapter. Tools
+/// never reach the store directly: the runtime reads it on their behalf
+/// after checking their declared `secrets`.
+#[async_trait]
+pub trait SecretStore: Send + Sync {
+    /// Returns the decrypted value of the secret `name`, if set.
+    async fn get(&self, name: &str) -> Result<Option<String>, RepositoryError>;
+
+    /// Stores `value` under `name`, replacing any previous value.
+    async fn set(&self, name: &str, value: &str) -> Result<(), RepositoryError>;
+
+    /// Removes the secret `name`. Returns true if it existed.
+    async fn remove(&self, name: &str) -> Result<bool, RepositoryError>;
+
+    /// Lists the names of all stored secrets, in ascending order.
+    async fn list(&self) -> Result<Vec<String>, RepositoryError>;
+}
@@ -17,6 +17,7 @@ fn make_entry(engine: &Engine, path: &str) -> CachedComponent {
     CachedComponent {
         component,
         security: SecurityConfig::default(),
+        signer: None,
         wasm_path: path.to_string(),
     }
 }
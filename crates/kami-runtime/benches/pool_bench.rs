@@ -0,0 +1,46 @@
+//! Benchmark comparing cold and warm execution latency.
+//!
+//! Cold: every call links the component against the host linker before
+//! instantiating. Warm: the `InstancePool` hands back the pre-instantiated
+//! component, so only instantiation into a fresh store remains.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use kami_engine::{create_engine, create_linker, load_component, InstanceConfig};
+use kami_runtime::{InstancePool, ToolExecutor, WasmToolExecutor};
+use kami_types::{SecurityConfig, ToolId};
+
+/// Flat component whose `run` echoes its input as `Ok(input)`.
+const ECHO_WAT: &str = include_str!("../../../tests/fixtures/wat/echo.wat");
+
+fn bench_cold_vs_warm(c: &mut Criterion) {
+    let rt = tokio::runtime::Runtime::new().expect("bench: tokio");
+    let engine = create_engine(&InstanceConfig::default()).expect("bench: engine");
+    let linker = create_linker(&engine).expect("bench: linker");
+    let component = load_component(&engine, ECHO_WAT.as_bytes()).expect("bench: component");
+    let executor = WasmToolExecutor::new(engine, linker).with_pool(InstancePool::new(4));
+    let security = SecurityConfig::default();
+    let id = ToolId::new("dev.bench.echo").expect("bench: id");
+
+    c.bench_function("execute_cold", |b| {
+        b.iter(|| {
+            rt.block_on(async {
+                let r = executor.execute(&component, "ping", &security).await;
+                black_box(r.expect("bench: cold"));
+            });
+        });
+    });
+
+    c.bench_function("execute_warm", |b| {
+        b.iter(|| {
+            rt.block_on(async {
+                let r = executor
+                    .execute_tool(&id, &component, "ping", &security)
+                    .await;
+                black_box(r.expect("bench: warm"));
+            });
+        });
+    });
+}
+
+criterion_group!(benches, bench_cold_vs_warm);
+criterion_main!(benches);
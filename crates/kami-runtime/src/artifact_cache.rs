@@ -0,0 +1,145 @@
+//! Persistent on-disk cache of compiled components.
+//!
+//! Entries are named `<wasm-sha256>-<engine-fingerprint>.cwasm`, so a
+//! changed WASM file, wasmtime upgrade or engine config change simply
+//! misses. Each file starts with the SHA-256 of the artifact that follows;
+//! a mismatch (truncation, corruption) deletes the entry. Total size is
+//! bounded by evicting the least recently used files.
+//!
+//! Artifacts are loaded as machine code, so the directory is created with
+//! mode 0700 and refused if another user owns it or may write to it, since
+//! such a user could replace an entry together with its checksum.
+
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use sha2::{Digest, Sha256};
+use tracing::{debug, warn};
+use wasmtime::component::Component;
+use wasmtime::Engine;
+
+use kami_engine::{deserialize_component, engine_fingerprint, serialize_component};
+
+mod private_dir;
+
+const EXTENSION: &str = "cwasm";
+const DIGEST_LEN: usize = 32;
+
+/// Size-bounded directory of serialized components.
+#[derive(Debug, Clone)]
+pub struct ArtifactCache {
+    dir: PathBuf,
+    max_bytes: u64,
+}
+
+impl ArtifactCache {
+    /// Creates a cache in `dir` holding at most `max_bytes` of artifacts.
+    pub fn new(dir: impl Into<PathBuf>, max_bytes: u64) -> Self {
+        Self {
+            dir: dir.into(),
+            max_bytes,
+        }
+    }
+
+    /// Returns the cache directory.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Loads the artifact for a WASM file with the given (verified) hash.
+    ///
+    /// Returns `None` on a miss, if the entry is unusable or if the
+    /// directory is not private to the current user.
+    pub fn load(&self, engine: &Engine, wasm_sha256: &str) -> Option<Component> {
+        let path = self.entry_path(engine, wasm_sha256);
+        if let Err(e) = private_dir::check(&self.dir) {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                warn!(error = %e, "ignoring artifact cache");
+            }
+            return None;
+        }
+        let bytes = std::fs::read(&path).ok()?;
+        let component = (bytes.len() > DIGEST_LEN)
+            .then(|| bytes.split_at(DIGEST_LEN))
+            .filter(|(digest, body)| Sha256::digest(body).as_slice() == *digest)
+            .and_then(|(_, body)| deserialize_component(engine, body).ok());
+        match component {
+            Some(component) => {
+                // Refresh mtime so eviction approximates LRU.
+                let _ = std::fs::File::options()
+                    .append(true)
+                    .open(&path)
+                    .and_then(|f| f.set_modified(SystemTime::now()));
+                debug!(path = %path.display(), "artifact cache hit");
+                Some(component)
+            }
+            None => {
+                warn!(path = %path.display(), "discarding corrupt artifact");
+                let _ = std::fs::remove_file(&path);
+                None
+            }
+        }
+    }
+
+    /// Serializes and stores `component`, then enforces the size bound.
+    ///
+    /// # Errors
+    ///
+    /// Returns `std::io::Error` if the artifact cannot be written, or with
+    /// `PermissionDenied` if the directory is not private to the current
+    /// user.
+    pub fn store(
+        &self,
+        engine: &Engine,
+        wasm_sha256: &str,
+        component: &Component,
+    ) -> std::io::Result<()> {
+        let body = serialize_component(component).map_err(std::io::Error::other)?;
+        private_dir::create(&self.dir)?;
+        let path = self.entry_path(engine, wasm_sha256);
+        let tmp = path.with_extension(format!("{EXTENSION}.tmp{}", std::process::id()));
+        let mut bytes = Sha256::digest(&body).to_vec();
+        bytes.extend_from_slice(&body);
+        std::fs::write(&tmp, &bytes)?;
+        std::fs::rename(&tmp, &path)?;
+        self.evict()
+    }
+
+    /// Removes least recently used entries until the total fits `max_bytes`.
+    fn evict(&self) -> std::io::Result<()> {
+        let mut entries = Vec::new();
+        for entry in std::fs::read_dir(&self.dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some(EXTENSION) {
+                continue;
+            }
+            let meta = entry.metadata()?;
+            let modified = meta.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+            entries.push((modified, meta.len(), path));
+        }
+        let mut total: u64 = entries.iter().map(|(_, len, _)| len).sum();
+        entries.sort_by_key(|(modified, _, _)| *modified);
+        for (_, len, path) in entries {
+            if total <= self.max_bytes {
+                break;
+            }
+            debug!(path = %path.display(), "evicting artifact");
+            match std::fs::remove_file(&path) {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e),
+                _ => {}
+            }
+            total = total.saturating_sub(len);
+        }
+        Ok(())
+    }
+
+    fn entry_path(&self, engine: &Engine, wasm_sha256: &str) -> PathBuf {
+        let key = format!("{wasm_sha256}-{}", engine_fingerprint(engine));
+        let key: String = key
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric() || *c == '-')
+            .collect();
+        self.dir.join(format!("{key}.{EXTENSION}"))
+    }
+}
@@ -0,0 +1,55 @@
+//! Owner-only cache directory.
+//!
+//! Artifacts are loaded as native code, so the directory holding them must
+//! not be writable by anyone but the user running KAMI. New directories
+//! are created owner-only (0700).
+
+use std::io;
+use std::path::Path;
+
+/// Creates `dir` (mode 0700) if missing, then checks it with [`check`].
+pub(super) fn create(dir: &Path) -> io::Result<()> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::DirBuilderExt;
+        std::fs::DirBuilder::new()
+            .recursive(true)
+            .mode(0o700)
+            .create(dir)?;
+    }
+    check(dir)
+}
+
+/// Fails unless `dir` is a real directory owned by the effective user that
+/// group and others cannot write to.
+#[cfg(unix)]
+pub(super) fn check(dir: &Path) -> io::Result<()> {
+    use std::os::unix::fs::MetadataExt;
+    let meta = std::fs::symlink_metadata(dir)?;
+    let refuse = |why: String| {
+        let message = format!("artifact cache {}: {why}", dir.display());
+        Err(io::Error::new(io::ErrorKind::PermissionDenied, message))
+    };
+    if !meta.is_dir() {
+        return refuse("not a directory".to_string());
+    }
+    let euid = rustix::process::geteuid().as_raw();
+    if meta.uid() != euid {
+        return refuse(format!("owned by uid {}, not {euid}", meta.uid()));
+    }
+    if meta.mode() & 0o022 != 0 {
+        return refuse(format!(
+            "mode {:o} lets group or others write",
+            meta.mode() & 0o777
+        ));
+    }
+    Ok(())
+}
+
+/// Without Unix ownership and modes privacy cannot be checked, so the
+/// cache is refused.
+#[cfg(not(unix))]
+pub(super) fn check(dir: &Path) -> io::Result<()> {
+    let message = format!("artifact cache {} needs a Unix host", dir.display());
+    Err(io::Error::new(io::ErrorKind::Unsupported, message))
+}
@@ -19,6 +19,8 @@ pub struct CachedComponent {
     pub component: Component,
     /// Security config from the tool manifest.
     pub security: SecurityConfig,
+    /// Public key the tool's Ed25519 signature was verified against.
+    pub signer: Option<String>,
     /// WASM file path for cache invalidation.
     pub wasm_path: String,
 }
@@ -0,0 +1,119 @@
+//! Cancellation of running executions.
+//!
+//! Every execution of [`KamiRuntime`](crate::KamiRuntime) is registered under
+//! an [`ExecutionId`] while it runs. Cancelling it raises the store's
+//! [`CancelFlag`], which traps the guest at its next epoch tick, and drops
+//! the execution future, which also covers executions still waiting for a
+//! permit or for their component.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard};
+
+use kami_engine::CancelFlag;
+use tokio::sync::watch;
+
+use crate::error::RuntimeError;
+
+/// Identifies one execution for [`KamiRuntime::cancel`](crate::KamiRuntime::cancel).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExecutionId(u64);
+
+impl fmt::Display for ExecutionId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Cancellation signals of one running execution.
+struct Signal {
+    flag: CancelFlag,
+    cancelled: watch::Sender<bool>,
+}
+
+/// Registry of the executions that can currently be cancelled.
+#[derive(Default)]
+pub(crate) struct Cancellations {
+    next: AtomicU64,
+    active: Mutex<HashMap<ExecutionId, Signal>>,
+}
+
+impl Cancellations {
+    /// Allocates a fresh execution id.
+    pub(crate) fn next_id(&self) -> ExecutionId {
+        ExecutionId(self.next.fetch_add(1, Ordering::Relaxed))
+    }
+
+    /// Registers `id` as running until the returned guard is dropped.
+    pub(crate) fn register(&self, id: ExecutionId) -> Registration<'_> {
+        let flag = CancelFlag::new();
+        let (cancelled, receiver) = watch::channel(false);
+        let signal = Signal {
+            flag: flag.clone(),
+            cancelled,
+        };
+        self.lock().insert(id, signal);
+        Registration {
+            id,
+            owner: self,
+            flag,
+            cancelled: receiver,
+        }
+    }
+
+    /// Cancels `id`; returns `false` if it is not running.
+    pub(crate) fn cancel(&self, id: ExecutionId) -> bool {
+        match self.lock().get(&id) {
+            Some(signal) => {
+                signal.flag.cancel();
+                signal.cancelled.send_replace(true);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<ExecutionId, Signal>> {
+        self.active.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Keeps an execution cancellable; unregisters it on drop.
+pub(crate) struct Registration<'a> {
+    id: ExecutionId,
+    owner: &'a Cancellations,
+    flag: CancelFlag,
+    cancelled: watch::Receiver<bool>,
+}
+
+impl Registration<'_> {
+    /// Returns the flag to hand to the execution's store.
+    pub(crate) fn flag(&self) -> &CancelFlag {
+        &self.flag
+    }
+
+    /// Runs `task` until it finishes or the execution is cancelled.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RuntimeError::Cancelled` if cancelled first, otherwise the
+    /// error of `task`.
+    pub(crate) async fn run<T>(
+        &self,
+        task: impl Future<Output = Result<T, RuntimeError>>,
+    ) -> Result<T, RuntimeError> {
+        let mut cancelled = self.cancelled.clone();
+        tokio::select! {
+            result = task => result,
+            Ok(_) = cancelled.wait_for(|c| *c) => Err(RuntimeError::Cancelled),
+        }
+    }
+}
+
+impl Drop for Registration<'_> {
+    fn drop(&mut self) {
+        self.owner.lock().remove(&self.id);
+    }
+}
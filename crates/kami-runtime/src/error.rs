@@ -1,26 +1,50 @@
 //! Runtime-specific error types.
 
-use kami_types::{DiagnosticError, ErrorKind, KamiError};
+use kami_engine::EngineError;
+use kami_types::{ErrorKind, KamiError};
 use thiserror::Error;
 
+use crate::types::GuestLogs;
+
+mod diagnostic;
+
 /// Errors from the runtime orchestrator.
 #[derive(Debug, Error)]
 pub enum RuntimeError {
     /// Tool not found in registry.
     #[error("tool not found: {name}")]
     ToolNotFound { name: String },
+    /// The tool is installed but disabled.
+    #[error("tool '{tool_id}' is disabled")]
+    ToolDisabled { tool_id: String },
     /// Engine error during execution.
     #[error("engine error: {0}")]
-    Engine(#[from] kami_engine::EngineError),
+    Engine(#[from] EngineError),
     /// Sandbox policy violation.
     #[error("sandbox error: {0}")]
     Sandbox(#[from] kami_sandbox::SandboxError),
-    /// Execution timed out.
+    /// Execution timed out; `logs` holds what the guest wrote until then.
     #[error("execution timed out after {timeout_ms}ms")]
-    Timeout { timeout_ms: u64 },
+    Timeout { timeout_ms: u64, logs: GuestLogs },
+    /// The tool used up its fuel budget; `logs` holds what it wrote.
+    #[error("fuel budget of {max_fuel} exhausted")]
+    FuelExhausted { max_fuel: u64, logs: GuestLogs },
+    /// The tool grew its memory past the configured cap; `logs` holds
+    /// what it wrote.
+    #[error("memory limit of {max_memory_mb} MiB exceeded")]
+    MemoryLimitExceeded { max_memory_mb: u32, logs: GuestLogs },
+    /// The guest trapped; `logs` holds what it wrote before.
+    #[error("instance trapped: {message}")]
+    Trapped { message: String, logs: GuestLogs },
+    /// The execution was cancelled by its caller.
+    #[error("execution cancelled")]
+    Cancelled,
     /// Pool exhausted (no available instances).
     #[error("instance pool exhausted")]
     PoolExhausted,
+    /// The dedicated execution runtime could not run the task.
+    #[error("execution worker unavailable: {reason}")]
+    WorkerUnavailable { reason: String },
     /// WASM file hash does not match the stored SHA-256 digest.
     #[error("integrity violation for tool '{tool_id}': {detail}")]
     IntegrityViolation { tool_id: String, detail: String },
@@ -33,111 +57,51 @@ pub enum RuntimeError {
     },
 }
 
+impl RuntimeError {
+    /// Returns the output the guest wrote before it was stopped, for the
+    /// errors that end a started execution.
+    pub fn logs(&self) -> Option<&GuestLogs> {
+        match self {
+            Self::Timeout { logs, .. }
+            | Self::FuelExhausted { logs, .. }
+            | Self::MemoryLimitExceeded { logs, .. }
+            | Self::Trapped { logs, .. } => Some(logs),
+            _ => None,
+        }
+    }
+}
+
 impl From<RuntimeError> for KamiError {
     fn from(e: RuntimeError) -> Self {
         let kind = match &e {
             RuntimeError::ToolNotFound { .. } => ErrorKind::NotFound,
+            RuntimeError::ToolDisabled { .. } => ErrorKind::PermissionDenied,
             RuntimeError::Sandbox(kami_sandbox::SandboxError::InvalidConfig { .. }) => {
                 ErrorKind::InvalidInput
             }
             RuntimeError::Sandbox(_) => ErrorKind::PermissionDenied,
             RuntimeError::Timeout { .. } => ErrorKind::Timeout,
-            RuntimeError::PoolExhausted => ErrorKind::ResourceExhausted,
+            RuntimeError::Cancelled | RuntimeError::Engine(EngineError::Cancelled) => {
+                ErrorKind::Cancelled
+            }
+            RuntimeError::FuelExhausted { .. }
+            | RuntimeError::MemoryLimitExceeded { .. }
+            | RuntimeError::PoolExhausted => ErrorKind::ResourceExhausted,
             RuntimeError::IntegrityViolation { .. } => ErrorKind::PermissionDenied,
+            RuntimeError::WorkerUnavailable { .. } | RuntimeError::Trapped { .. } => {
+                ErrorKind::Internal
+            }
             RuntimeError::RateLimited { .. } => ErrorKind::ResourceExhausted,
+            RuntimeError::Engine(
+                EngineError::FuelExhausted | EngineError::MemoryLimitExceeded { .. },
+            ) => ErrorKind::ResourceExhausted,
+            RuntimeError::Engine(EngineError::EpochDeadline) => ErrorKind::Timeout,
             RuntimeError::Engine(_) => ErrorKind::Internal,
         };
         KamiError::new(kind, e.to_string())
     }
 }
 
-impl DiagnosticError for RuntimeError {
-    fn hint(&self) -> Option<String> {
-        match self {
-            Self::ToolNotFound { name } => Some(format!(
-                "No tool with id '{name}' is registered in the local database."
-            )),
-            Self::Timeout { timeout_ms } => {
-                Some(format!("Execution exceeded the {timeout_ms}ms time limit."))
-            }
-            Self::PoolExhausted => {
-                Some("All execution slots are busy. The system is under heavy load.".into())
-            }
-            Self::IntegrityViolation { .. } => Some(
-                "The WASM file on disk does not match the SHA-256 hash recorded at install time."
-                    .into(),
-            ),
-            Self::RateLimited { .. } => {
-                Some("Too many requests for this tool in the current time window.".into())
-            }
-            Self::Engine(e) => e.hint(),
-            Self::Sandbox(e) => e.hint(),
-        }
-    }
-
-    fn fix(&self) -> Option<String> {
-        match self {
-            Self::ToolNotFound { .. } => {
-                Some("Install the tool first: kami install <path-to-tool>".into())
-            }
-            Self::Timeout { .. } => Some(
-                "Increase the timeout in tool.toml:\n  [security]\n  max_execution_ms = 10000"
-                    .into(),
-            ),
-            Self::PoolExhausted => {
-                Some("Increase runtime concurrency: kami serve --concurrency 16".into())
-            }
-            Self::IntegrityViolation { .. } => {
-                Some("Re-install the tool: kami uninstall <id> && kami install <path>".into())
-            }
-            Self::RateLimited { .. } => {
-                Some("Wait before retrying, or increase rate_limit_per_tool in config.".into())
-            }
-            Self::Engine(e) => e.fix(),
-            Self::Sandbox(e) => e.fix(),
-        }
-    }
-}
-
 #[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn tool_not_found_maps_to_not_found() {
-        let err: KamiError = RuntimeError::ToolNotFound { name: "x".into() }.into();
-        assert_eq!(err.kind, ErrorKind::NotFound);
-    }
-
-    #[test]
-    fn timeout_maps_to_timeout_kind() {
-        let err: KamiError = RuntimeError::Timeout { timeout_ms: 5000 }.into();
-        assert_eq!(err.kind, ErrorKind::Timeout);
-    }
-
-    #[test]
-    fn rate_limited_has_fix_suggestion() {
-        let e = RuntimeError::RateLimited {
-            tool_id: "t".into(),
-            limit: 10,
-            window_secs: 60,
-        };
-        assert!(e.fix().expect("has fix").contains("rate_limit"));
-    }
-
-    #[test]
-    fn pool_exhausted_maps_to_resource_exhausted() {
-        let err: KamiError = RuntimeError::PoolExhausted.into();
-        assert_eq!(err.kind, ErrorKind::ResourceExhausted);
-    }
-
-    #[test]
-    fn integrity_violation_maps_to_permission_denied() {
-        let err: KamiError = RuntimeError::IntegrityViolation {
-            tool_id: "t".into(),
-            detail: "mismatch".into(),
-        }
-        .into();
-        assert_eq!(err.kind, ErrorKind::PermissionDenied);
-    }
-}
+#[path = "error_tests.rs"]
+mod tests;
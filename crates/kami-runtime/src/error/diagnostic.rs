@@ -0,0 +1,80 @@
+//! Hints and fixes shown with each [`RuntimeError`].
+
+use kami_types::DiagnosticError;
+
+use super::RuntimeError;
+
+impl DiagnosticError for RuntimeError {
+    fn hint(&self) -> Option<String> {
+        match self {
+            Self::ToolNotFound { name } => Some(format!(
+                "No tool with id '{name}' is registered in the local database."
+            )),
+            Self::ToolDisabled { .. } => {
+                Some("The tool is installed but was disabled by the operator.".into())
+            }
+            Self::Timeout { timeout_ms, .. } => {
+                Some(format!("Execution exceeded the {timeout_ms}ms time limit."))
+            }
+            Self::FuelExhausted { max_fuel, .. } => Some(format!(
+                "The tool used up its budget of {max_fuel} fuel units before finishing."
+            )),
+            Self::MemoryLimitExceeded { max_memory_mb, .. } => Some(format!(
+                "The tool tried to grow its memory past the {max_memory_mb} MiB limit."
+            )),
+            Self::Trapped { .. } => Some("The WASM instance trapped during execution.".into()),
+            Self::Cancelled => Some("The execution was cancelled before it finished.".into()),
+            Self::PoolExhausted => {
+                Some("All execution slots are busy. The system is under heavy load.".into())
+            }
+            Self::WorkerUnavailable { .. } => {
+                Some("The dedicated execution runtime stopped or the task panicked.".into())
+            }
+            Self::IntegrityViolation { .. } => Some(
+                "The WASM file on disk does not match the SHA-256 hash recorded at install time."
+                    .into(),
+            ),
+            Self::RateLimited { .. } => {
+                Some("Too many requests for this tool in the current time window.".into())
+            }
+            Self::Engine(e) => e.hint(),
+            Self::Sandbox(e) => e.hint(),
+        }
+    }
+
+    fn fix(&self) -> Option<String> {
+        match self {
+            Self::ToolNotFound { .. } => {
+                Some("Install the tool first: kami install <path-to-tool>".into())
+            }
+            Self::ToolDisabled { tool_id } => Some(format!("Enable it: kami enable {tool_id}")),
+            Self::Timeout { .. } => Some(
+                "Increase the timeout in tool.toml:\n  [security]\n  max_execution_ms = 10000"
+                    .into(),
+            ),
+            Self::FuelExhausted { max_fuel, .. } => Some(format!(
+                "Raise max_fuel in tool.toml:\n  [security]\n  max_fuel = {}",
+                max_fuel.saturating_mul(10)
+            )),
+            Self::MemoryLimitExceeded { max_memory_mb, .. } => Some(format!(
+                "Raise max_memory_mb in tool.toml:\n  [security]\n  max_memory_mb = {}",
+                max_memory_mb.saturating_mul(2)
+            )),
+            Self::Trapped { .. } | Self::Cancelled => None,
+            Self::PoolExhausted => {
+                Some("Increase runtime concurrency: kami serve --concurrency 16".into())
+            }
+            Self::WorkerUnavailable { .. } => Some(
+                "Run tools on the caller's runtime:\n  [runtime]\n  execution_threads = 0".into(),
+            ),
+            Self::IntegrityViolation { .. } => {
+                Some("Re-install the tool: kami uninstall <id> && kami install <path>".into())
+            }
+            Self::RateLimited { .. } => {
+                Some("Wait before retrying, or increase rate_limit_per_tool in config.".into())
+            }
+            Self::Engine(e) => e.fix(),
+            Self::Sandbox(e) => e.fix(),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+//! Tests for `RuntimeError` mappings.
+
+use super::*;
+use kami_types::DiagnosticError;
+
+#[test]
+fn tool_not_found_maps_to_not_found() {
+    let err: KamiError = RuntimeError::ToolNotFound { name: "x".into() }.into();
+    assert_eq!(err.kind, ErrorKind::NotFound);
+}
+
+#[test]
+fn tool_disabled_maps_to_permission_denied() {
+    let e = RuntimeError::ToolDisabled {
+        tool_id: "dev.t.off".into(),
+    };
+    assert!(e.fix().expect("has fix").contains("kami enable dev.t.off"));
+    let err: KamiError = e.into();
+    assert_eq!(err.kind, ErrorKind::PermissionDenied);
+}
+
+#[test]
+fn timeout_maps_to_timeout_kind() {
+    let err: KamiError = RuntimeError::Timeout {
+        timeout_ms: 5000,
+        logs: GuestLogs::default(),
+    }
+    .into();
+    assert_eq!(err.kind, ErrorKind::Timeout);
+}
+
+#[test]
+fn rate_limited_has_fix_suggestion() {
+    let e = RuntimeError::RateLimited {
+        tool_id: "t".into(),
+        limit: 10,
+        window_secs: 60,
+    };
+    assert!(e.fix().expect("has fix").contains("rate_limit"));
+}
+
+#[test]
+fn pool_exhausted_maps_to_resource_exhausted() {
+    let err: KamiError = RuntimeError::PoolExhausted.into();
+    assert_eq!(err.kind, ErrorKind::ResourceExhausted);
+}
+
+#[test]
+fn integrity_violation_maps_to_permission_denied() {
+    let err: KamiError = RuntimeError::IntegrityViolation {
+        tool_id: "t".into(),
+        detail: "mismatch".into(),
+    }
+    .into();
+    assert_eq!(err.kind, ErrorKind::PermissionDenied);
+}
+
+#[test]
+fn cancelled_maps_to_cancelled_kind() {
+    let err: KamiError = RuntimeError::Cancelled.into();
+    assert_eq!(err.kind, ErrorKind::Cancelled);
+    let engine: KamiError = RuntimeError::Engine(EngineError::Cancelled).into();
+    assert_eq!(engine.kind, ErrorKind::Cancelled);
+}
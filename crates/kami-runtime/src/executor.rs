@@ -1,141 +1,141 @@
 //! Async tool executor with full isolation pipeline.
 //!
-//! Pipeline: validate config → build sandbox → apply limits → execute with timeout.
+//! Pipeline: validate config → link (pooled) → build sandbox → apply limits →
+//! execute with timeout.
+
+mod builder;
 
 use std::time::{Duration, Instant};
 
 use async_trait::async_trait;
-use tracing::{debug, warn};
+use tracing::debug;
 use wasmtime::component::{Component, Linker};
 use wasmtime::Engine;
 
-use kami_engine::{
-    call_tool_run, create_store, instantiate_component, instantiate_tool, set_epoch_deadline,
-    HostState,
-};
-use kami_sandbox::{build_wasi_ctx, validate_security_config, WasiConfig};
-use kami_types::SecurityConfig;
+use kami_engine::{prepare_tool, HostState};
+use kami_sandbox::{validate_security_config, SandboxDir, SandboxDirs};
+use kami_types::{SecurityConfig, ToolId};
 
 use crate::error::RuntimeError;
+use crate::invoke::{call_component, collect_logs, into_execution_result, Usage};
+use crate::pool::InstancePool;
+use crate::preemption::Preemption;
+use crate::store_setup::{build_store, prepare_sandbox_dir, CallHooks};
 use crate::types::{ExecutionResult, ToolExecutor};
 
 /// Concrete executor that runs WASM components through the
 /// engine + sandbox pipeline with full isolation enforcement.
+///
+/// Timeouts are enforced per store against a shared [`kami_engine::EpochTicker`].
 pub struct WasmToolExecutor {
     engine: Engine,
     linker: Linker<HostState>,
+    preemption: Preemption,
+    sandbox_dirs: Option<SandboxDirs>,
+    pool: InstancePool,
 }
 
 impl WasmToolExecutor {
-    /// Creates a new executor with a pre-configured engine and linker.
-    pub fn new(engine: Engine, linker: Linker<HostState>) -> Self {
-        Self { engine, linker }
-    }
-}
-
-#[async_trait]
-impl ToolExecutor for WasmToolExecutor {
-    /// Executes a component with full isolation pipeline.
+    /// Executes a component on behalf of `tool_id`, preopening its sandbox
+    /// dir and reusing its pooled pre-instance.
     ///
     /// # Errors
     ///
-    /// Returns `RuntimeError::Sandbox` if security config is invalid.
-    /// Returns `RuntimeError::Engine` if the component fails to execute.
-    /// Returns `RuntimeError::Timeout` if execution exceeds the deadline.
+    /// Same as [`ToolExecutor::execute`], plus `RuntimeError::Sandbox` if the
+    /// sandbox directory cannot be prepared.
+    pub async fn execute_tool(
+        &self,
+        tool_id: &ToolId,
+        component: &Component,
+        input: &str,
+        security: &SecurityConfig,
+    ) -> Result<ExecutionResult, RuntimeError> {
+        self.run(
+            Some(tool_id),
+            component,
+            input,
+            security,
+            &CallHooks::default(),
+        )
+        .await
+    }
+
+    /// Runs the pipeline with per-call cancellation and progress `hooks`.
     #[tracing::instrument(skip_all, fields(
         max_fuel = security.limits.max_fuel,
         timeout_ms = security.limits.max_execution_ms,
     ))]
-    async fn execute(
+    pub(crate) async fn run(
         &self,
+        tool_id: Option<&ToolId>,
         component: &Component,
         input: &str,
         security: &SecurityConfig,
+        hooks: &CallHooks,
     ) -> Result<ExecutionResult, RuntimeError> {
         let start = Instant::now();
 
         // 1. Validate security config
         validate_security_config(security)?;
 
-        let fuel = security.limits.max_fuel;
-        let max_memory = security.limits.max_memory_mb as usize * 1024 * 1024;
-        let timeout_duration = Duration::from_millis(security.limits.max_execution_ms);
-
+        let (fuel, timeout_ms) = (security.limits.max_fuel, security.limits.max_execution_ms);
         debug!(
             fuel,
             max_memory_mb = security.limits.max_memory_mb,
-            timeout_ms = security.limits.max_execution_ms,
-            "starting isolated execution"
+            timeout_ms,
+            "isolating"
         );
 
-        // 2. Build sandboxed WASI context
-        let wasi_config = WasiConfig {
-            inherit_stdout: true,
-            inherit_stderr: true,
-            ..WasiConfig::default()
-        };
-        let wasi_ctx = build_wasi_ctx(security, &wasi_config, None)?;
-
-        // 3. Create store with memory limits + fuel + HTTP allow-list
-        let mut host_state = HostState::with_limits(wasi_ctx, max_memory);
-        host_state.set_net_allow_list(security.net_allow_list.clone());
-        let mut store = create_store(&self.engine, host_state, fuel)?;
-
-        // 4. Set epoch deadline (1 tick = timeout reached)
-        set_epoch_deadline(&mut store, 1);
-
-        // 5. Spawn epoch ticker that increments epoch after timeout
-        let engine_clone = self.engine.clone();
-        let tick_handle = tokio::spawn(async move {
-            tokio::time::sleep(timeout_duration).await;
-            engine_clone.increment_epoch();
-        });
-
-        // 6. Instantiate and call: try typed API (WIT components), fallback to flat
-        let outer_timeout = timeout_duration + Duration::from_millis(500);
-        let call_result = tokio::time::timeout(outer_timeout, async {
-            match instantiate_tool(&self.linker, &mut store, component).await {
-                Ok(tool) => kami_engine::bindings::call_run(&mut store, &tool, input).await,
-                Err(_) => {
-                    let inst = instantiate_component(&self.linker, &mut store, component).await?;
-                    call_tool_run(&mut store, &inst, input).await
-                }
+        // 2. Link imports (warm calls reuse the pooled pre-instance)
+        let prepared = match tool_id {
+            Some(id) => {
+                self.pool
+                    .get_or_prepare(id, component, &self.linker)
+                    .await?
             }
-        })
-        .await;
-
-        tick_handle.abort();
+            None => prepare_tool(&self.linker, component)?,
+        };
 
-        let duration_ms = start.elapsed().as_millis() as u64;
-        let fuel_remaining = store.get_fuel().unwrap_or(0);
-        let fuel_consumed = fuel.saturating_sub(fuel_remaining);
+        // 3. Build sandboxed store: WASI ctx + limits + allow-list + deadline + yielding
+        let sandbox_dir = prepare_sandbox_dir(self.sandbox_dirs.as_ref(), tool_id, security)?;
+        let dir = sandbox_dir.as_ref().map(SandboxDir::path);
+        let (mut store, out) = build_store(&self.engine, security, dir, &self.preemption, hooks)?;
+
+        // 4. Instantiate and call, with the tokio timeout as a safety net
+        let outer_timeout = Duration::from_millis(timeout_ms.saturating_add(500));
+        let outcome = call_component(&mut store, &prepared, input, outer_timeout).await;
+
+        let usage = Usage::measure(&store, start, fuel);
+        into_execution_result(
+            outcome,
+            usage,
+            &security.limits,
+            collect_logs(tool_id, &out),
+        )
+    }
+}
 
-        match call_result {
-            Ok(Ok(Ok(output))) => Ok(ExecutionResult {
-                content: output,
-                duration_ms,
-                success: true,
-                fuel_consumed,
-            }),
-            Ok(Ok(Err(error))) => Ok(ExecutionResult {
-                content: error,
-                duration_ms,
-                success: false,
-                fuel_consumed,
-            }),
-            Ok(Err(engine_err)) => {
-                warn!(?engine_err, "engine error during execution");
-                Err(engine_err.into())
-            }
-            Err(_elapsed) => {
-                warn!(
-                    timeout_ms = security.limits.max_execution_ms,
-                    "execution timed out"
-                );
-                Err(RuntimeError::Timeout {
-                    timeout_ms: security.limits.max_execution_ms,
-                })
-            }
-        }
+#[async_trait]
+impl ToolExecutor for WasmToolExecutor {
+    /// Executes a component with full isolation pipeline.
+    ///
+    /// No sandbox directory is mounted; use [`WasmToolExecutor::execute_tool`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `RuntimeError::Sandbox` if security config is invalid.
+    /// Returns `RuntimeError::Engine` if the component fails to execute,
+    /// or `RuntimeError::Trapped` if it traps.
+    /// Returns `RuntimeError::Timeout` if execution exceeds the deadline.
+    /// Returns `RuntimeError::FuelExhausted` or
+    /// `RuntimeError::MemoryLimitExceeded` if the tool hits those limits.
+    async fn execute(
+        &self,
+        component: &Component,
+        input: &str,
+        security: &SecurityConfig,
+    ) -> Result<ExecutionResult, RuntimeError> {
+        self.run(None, component, input, security, &CallHooks::default())
+            .await
     }
 }
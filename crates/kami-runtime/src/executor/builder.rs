@@ -0,0 +1,47 @@
+//! Construction and configuration of [`WasmToolExecutor`].
+
+use kami_engine::{HostState, YieldStrategy};
+use kami_sandbox::SandboxDirs;
+use wasmtime::component::Linker;
+use wasmtime::Engine;
+
+use super::WasmToolExecutor;
+use crate::pool::InstancePool;
+use crate::preemption::Preemption;
+
+impl WasmToolExecutor {
+    /// Creates a new executor with a pre-configured engine and linker,
+    /// starting the shared epoch ticker and yielding on fuel by default.
+    pub fn new(engine: Engine, linker: Linker<HostState>) -> Self {
+        Self {
+            preemption: Preemption::start(&engine),
+            engine,
+            linker,
+            sandbox_dirs: None,
+            pool: InstancePool::new(0),
+        }
+    }
+
+    /// Reuses pre-instantiated components from `pool` for `execute_tool`.
+    pub fn with_pool(mut self, pool: InstancePool) -> Self {
+        self.pool = pool;
+        self
+    }
+
+    /// Sets how running guests yield to the async executor.
+    pub fn with_yield_strategy(mut self, strategy: YieldStrategy) -> Self {
+        self.preemption.yield_strategy = strategy;
+        self
+    }
+
+    /// Returns the pre-instantiation pool.
+    pub fn pool(&self) -> &InstancePool {
+        &self.pool
+    }
+
+    /// Mounts a per-tool directory for tools with `fs_access` other than `none`.
+    pub fn with_sandbox_dirs(mut self, dirs: SandboxDirs) -> Self {
+        self.sandbox_dirs = Some(dirs);
+        self
+    }
+}
@@ -16,9 +16,14 @@ use sha2::{Digest, Sha256};
 /// Returns `io::Error` if the file cannot be read.
 pub fn compute_file_hash(path: &Path) -> Result<String, io::Error> {
     let bytes = std::fs::read(path)?;
+    Ok(compute_hash(&bytes))
+}
+
+/// Computes the SHA-256 hash of in-memory bytes as a hex string.
+pub fn compute_hash(bytes: &[u8]) -> String {
     let mut hasher = Sha256::new();
-    hasher.update(&bytes);
-    Ok(hasher.finalize().encode_hex::<String>())
+    hasher.update(bytes);
+    hasher.finalize().encode_hex::<String>()
 }
 
 /// Verifies that a file matches an expected SHA-256 hex digest.
@@ -45,12 +50,40 @@ pub fn verify_hash(path: &Path, expected: &Option<String>) -> Result<(), io::Err
     Ok(())
 }
 
+/// Hashes `bytes` and checks them against an optional expected digest.
+///
+/// Returns the actual hash so callers can key caches by verified content.
+///
+/// # Errors
+///
+/// Returns `io::ErrorKind::InvalidData` if the hash differs from `expected`.
+pub fn verify_bytes(bytes: &[u8], expected: &Option<String>) -> Result<String, io::Error> {
+    let actual = compute_hash(bytes);
+    match expected {
+        Some(expected_hash) if *expected_hash != actual => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("integrity violation: expected {expected_hash}, got {actual}"),
+        )),
+        _ => Ok(actual),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::io::Write;
     use tempfile::NamedTempFile;
 
+    #[test]
+    fn verify_bytes_returns_hash_or_rejects_mismatch() {
+        let actual = verify_bytes(b"hello", &None).unwrap();
+        assert_eq!(
+            verify_bytes(b"hello", &Some(actual.clone())).unwrap(),
+            actual
+        );
+        assert!(verify_bytes(b"tampered", &Some(actual)).is_err());
+    }
+
     #[test]
     fn hash_deterministic() {
         let mut f = NamedTempFile::new().unwrap();
@@ -0,0 +1,131 @@
+//! Component invocation and result mapping for one execution.
+
+use std::time::{Duration, Instant};
+
+use tokio::time::error::Elapsed;
+use tracing::warn;
+use wasmtime::Store;
+
+use kami_engine::{call_prepared, EgressStats, EngineError, HostState, MemoryStats, PreparedTool};
+use kami_sandbox::{CapturePipe, GuestOutput};
+use kami_types::{joined_text, ContentPart, ResourceLimits, ToolId};
+
+use crate::error::RuntimeError;
+use crate::types::{ExecutionResult, GuestLogs};
+
+/// `tracing` target under which guest output is re-emitted.
+pub(crate) const GUEST_LOG_TARGET: &str = "kami_guest";
+
+/// Outcome of a guarded call: outer timeout, engine error, or tool result.
+pub(crate) type CallOutcome =
+    Result<Result<Result<Vec<ContentPart>, String>, EngineError>, Elapsed>;
+
+/// Instantiates `prepared` and calls `run-v2` or `run`, bounded by `outer_timeout`.
+pub(crate) async fn call_component(
+    store: &mut Store<HostState>,
+    prepared: &PreparedTool,
+    input: &str,
+    outer_timeout: Duration,
+) -> CallOutcome {
+    tokio::time::timeout(outer_timeout, call_prepared(store, prepared, input)).await
+}
+
+/// Reads the captured guest output and forwards each line to `tracing`.
+///
+/// Runs whatever the outcome, so output written before a trap is not lost.
+pub(crate) fn collect_logs(tool_id: Option<&ToolId>, output: &GuestOutput) -> GuestLogs {
+    let tool = tool_id.map_or("<anonymous>", ToolId::as_str);
+    let read = |pipe: &CapturePipe| String::from_utf8_lossy(&pipe.contents()).into_owned();
+    let logs = GuestLogs {
+        stdout: read(&output.stdout),
+        stderr: read(&output.stderr),
+        truncated: output.stdout.dropped() + output.stderr.dropped() > 0,
+    };
+    for line in logs.stdout.lines() {
+        tracing::info!(target: GUEST_LOG_TARGET, tool, stream = "stdout", "{line}");
+    }
+    for line in logs.stderr.lines() {
+        tracing::warn!(target: GUEST_LOG_TARGET, tool, stream = "stderr", "{line}");
+    }
+    if logs.truncated {
+        warn!(
+            tool,
+            "guest output exceeded max_log_bytes and was truncated"
+        );
+    }
+    logs
+}
+
+/// Resource usage of one execution.
+pub(crate) struct Usage {
+    duration_ms: u64,
+    fuel_consumed: u64,
+    memory: MemoryStats,
+    egress: EgressStats,
+}
+
+impl Usage {
+    /// Reads elapsed time, fuel, memory and HTTP usage from a finished store.
+    pub(crate) fn measure(store: &Store<HostState>, started: Instant, fuel_budget: u64) -> Self {
+        Self {
+            duration_ms: started.elapsed().as_millis() as u64,
+            fuel_consumed: fuel_budget.saturating_sub(store.get_fuel().unwrap_or(0)),
+            memory: store.data().memory_stats(),
+            egress: store.data().egress_stats(),
+        }
+    }
+}
+
+/// Maps a call outcome and its measurements to an `ExecutionResult`.
+pub(crate) fn into_execution_result(
+    outcome: CallOutcome,
+    usage: Usage,
+    limits: &ResourceLimits,
+    logs: GuestLogs,
+) -> Result<ExecutionResult, RuntimeError> {
+    let result = |content, parts, success, logs| ExecutionResult {
+        content,
+        parts,
+        duration_ms: usage.duration_ms,
+        success,
+        fuel_consumed: usage.fuel_consumed,
+        memory: usage.memory,
+        egress: usage.egress,
+        logs,
+    };
+    match outcome {
+        Ok(Ok(Ok(parts))) => Ok(result(joined_text(&parts), parts, true, logs)),
+        Ok(Ok(Err(error))) => Ok(result(error, Vec::new(), false, logs)),
+        Ok(Err(engine_err)) => {
+            warn!(?engine_err, "engine error during execution");
+            Err(limit_error(engine_err, limits, logs))
+        }
+        Err(_elapsed) => {
+            let timeout_ms = limits.max_execution_ms;
+            warn!(timeout_ms, "execution timed out");
+            Err(RuntimeError::Timeout { timeout_ms, logs })
+        }
+    }
+}
+
+/// Attaches the configured limit and the guest's output to errors that
+/// stop a running guest.
+fn limit_error(engine_err: EngineError, limits: &ResourceLimits, logs: GuestLogs) -> RuntimeError {
+    match engine_err {
+        EngineError::FuelExhausted => RuntimeError::FuelExhausted {
+            max_fuel: limits.max_fuel,
+            logs,
+        },
+        EngineError::MemoryLimitExceeded { .. } => RuntimeError::MemoryLimitExceeded {
+            max_memory_mb: limits.max_memory_mb,
+            logs,
+        },
+        EngineError::EpochDeadline => RuntimeError::Timeout {
+            timeout_ms: limits.max_execution_ms,
+            logs,
+        },
+        EngineError::Trap { message } => RuntimeError::Trapped { message, logs },
+        EngineError::Cancelled => RuntimeError::Cancelled,
+        other => other.into(),
+    }
+}
@@ -0,0 +1,73 @@
+//! Per-tool key/value storage backing the `kami:tool/kv` interface.
+//!
+//! [`KamiRuntime`](crate::KamiRuntime) scopes its [`KeyValueStore`] to the
+//! running tool and its `max_kv_bytes` quota, and only for tools granted
+//! [`Capability::KeyValue`].
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use kami_engine::{KeyValue, KvError};
+use kami_registry::{KeyValueStore, RepositoryError};
+use kami_sandbox::{CapabilityChecker, DefaultCapabilityChecker};
+use kami_types::{Capability, SecurityConfig, ToolId};
+
+/// The key/value store of one tool.
+pub(crate) struct ToolKeyValue {
+    store: Arc<dyn KeyValueStore>,
+    tool: ToolId,
+    max_bytes: u64,
+}
+
+impl ToolKeyValue {
+    /// Scopes `store` to `tool`, or returns `None` if `security` does not
+    /// grant the key/value capability.
+    pub(crate) fn scoped(
+        store: &Arc<dyn KeyValueStore>,
+        tool: &ToolId,
+        security: &SecurityConfig,
+    ) -> Option<Arc<dyn KeyValue>> {
+        DefaultCapabilityChecker
+            .check(&Capability::KeyValue, security)
+            .ok()?;
+        Some(Arc::new(Self {
+            store: Arc::clone(store),
+            tool: tool.clone(),
+            max_bytes: security.limits.max_kv_bytes,
+        }))
+    }
+}
+
+fn kv_error(e: RepositoryError) -> KvError {
+    match e {
+        RepositoryError::QuotaExceeded { limit } => KvError::QuotaExceeded { limit },
+        other => KvError::Storage {
+            message: other.to_string(),
+        },
+    }
+}
+
+#[async_trait]
+impl KeyValue for ToolKeyValue {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, KvError> {
+        self.store.get(&self.tool, key).await.map_err(kv_error)
+    }
+
+    async fn set(&self, key: &str, value: &[u8]) -> Result<(), KvError> {
+        self.store
+            .set(&self.tool, key, value, self.max_bytes)
+            .await
+            .map_err(kv_error)
+    }
+
+    async fn delete(&self, key: &str) -> Result<bool, KvError> {
+        self.store.remove(&self.tool, key).await.map_err(kv_error)
+    }
+
+    async fn list_keys(&self, prefix: &str) -> Result<Vec<String>, KvError> {
+        self.store
+            .list_keys(&self.tool, prefix)
+            .await
+            .map_err(kv_error)
+    }
+}
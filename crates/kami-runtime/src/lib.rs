@@ -7,34 +7,52 @@
 //! Use `KamiRuntime` for high-level tool execution by ID, or
 //! `WasmToolExecutor` directly for low-level component execution.
 
+pub mod artifact_cache;
 pub mod cache;
+pub mod cancel;
 pub mod error;
 pub mod executor;
 pub mod integrity;
+mod invoke;
+mod kv;
 pub mod metrics;
 pub mod orchestrator;
 pub mod pipeline;
+pub mod policy;
+pub mod pool;
+mod preemption;
 pub mod rate_limiter;
 pub mod resolver;
 pub mod runtime_config;
 pub mod scheduler;
+mod secrets;
 pub mod signature;
+mod store_setup;
 pub mod types;
+pub mod workers;
 
+pub use artifact_cache::ArtifactCache;
 pub use cache::{CachedComponent, ComponentCache};
+pub use cancel::ExecutionId;
 pub use error::RuntimeError;
 pub use executor::WasmToolExecutor;
-pub use integrity::{compute_file_hash, verify_hash};
-pub use metrics::{ExecutionMetrics, MetricsSnapshot};
+pub use integrity::{compute_file_hash, compute_hash, verify_hash};
+// Progress types appear in `KamiRuntime::execute_with_progress`.
+pub use kami_engine::{Progress, ProgressSink};
+pub use metrics::{ExecutionMetrics, MetricsSnapshot, MEMORY_ALERT_PERCENT};
 pub use orchestrator::KamiRuntime;
 pub use pipeline::{
     execute_pipeline, PipelineDefinition, PipelineError, PipelineResult, PipelineStep, StepResult,
 };
+pub use policy::SecurityPolicy;
+pub use pool::InstancePool;
 pub use rate_limiter::{RateLimitConfig, RateLimiter};
 pub use resolver::ToolResolver;
 pub use runtime_config::RuntimeConfig;
 pub use scheduler::{Scheduler, SchedulerConfig};
 pub use signature::{
-    generate_keypair, public_key_from_secret, sign_file, verify_file_signature, KeyPair,
+    generate_keypair, public_key_from_secret, sign_file, verify_file_signature, verify_signature,
+    KeyPair,
 };
-pub use types::{ExecutionResult, ToolExecutor};
+pub use types::{ExecutionResult, GuestLogs, ToolExecutor};
+pub use workers::ExecutionWorkers;
@@ -6,6 +6,13 @@
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
+use kami_engine::MemoryStats;
+use kami_types::ToolId;
+
+/// Peak memory usage (percent of `max_memory_mb`) at which an execution
+/// counts as close to its cap.
+pub const MEMORY_ALERT_PERCENT: f64 = 90.0;
+
 /// Shared, thread-safe execution metrics collected by [`super::KamiRuntime`].
 ///
 /// All fields are `AtomicU64` — incrementing from any async task is safe and fast.
@@ -23,6 +30,12 @@ pub struct ExecutionMetrics {
     pub cache_hits: AtomicU64,
     /// Number of times a component had to be compiled from scratch.
     pub cache_misses: AtomicU64,
+    /// Cumulative peak linear memory of successful executions, in bytes.
+    pub total_peak_memory_bytes: AtomicU64,
+    /// Highest peak linear memory seen in a single execution, in bytes.
+    pub max_peak_memory_bytes: AtomicU64,
+    /// Executions whose peak reached [`MEMORY_ALERT_PERCENT`] of their cap.
+    pub near_memory_limit: AtomicU64,
 }
 
 /// A point-in-time snapshot of [`ExecutionMetrics`].
@@ -42,6 +55,12 @@ pub struct MetricsSnapshot {
     pub cache_hits: u64,
     /// Cache misses (component compiled fresh).
     pub cache_misses: u64,
+    /// Cumulative peak linear memory, in bytes.
+    pub total_peak_memory_bytes: u64,
+    /// Highest single-execution peak linear memory, in bytes.
+    pub max_peak_memory_bytes: u64,
+    /// Executions close to their memory cap.
+    pub near_memory_limit: u64,
 }
 
 impl ExecutionMetrics {
@@ -62,6 +81,24 @@ impl ExecutionMetrics {
             .fetch_add(fuel_consumed, Ordering::Relaxed);
     }
 
+    /// Records the memory usage of a successful execution of `tool_id`.
+    ///
+    /// Warns and returns `true` if the peak reached [`MEMORY_ALERT_PERCENT`]
+    /// of the cap.
+    pub fn record_memory(&self, tool_id: &ToolId, stats: &MemoryStats) -> bool {
+        self.total_peak_memory_bytes
+            .fetch_add(stats.peak_bytes, Ordering::Relaxed);
+        self.max_peak_memory_bytes
+            .fetch_max(stats.peak_bytes, Ordering::Relaxed);
+        let near_limit = stats.peak_percent() >= MEMORY_ALERT_PERCENT;
+        if near_limit {
+            self.near_memory_limit.fetch_add(1, Ordering::Relaxed);
+            tracing::warn!(%tool_id, peak_bytes = stats.peak_bytes,
+                limit_bytes = stats.limit_bytes, "tool close to its memory cap");
+        }
+        near_limit
+    }
+
     /// Records a failed execution.
     pub fn record_failure(&self) {
         self.failed_executions.fetch_add(1, Ordering::Relaxed);
@@ -86,6 +123,9 @@ impl ExecutionMetrics {
             total_fuel_consumed: self.total_fuel_consumed.load(Ordering::Relaxed),
             cache_hits: self.cache_hits.load(Ordering::Relaxed),
             cache_misses: self.cache_misses.load(Ordering::Relaxed),
+            total_peak_memory_bytes: self.total_peak_memory_bytes.load(Ordering::Relaxed),
+            max_peak_memory_bytes: self.max_peak_memory_bytes.load(Ordering::Relaxed),
+            near_memory_limit: self.near_memory_limit.load(Ordering::Relaxed),
         }
     }
 }
@@ -0,0 +1,43 @@
+//! Tool administration on a live [`KamiRuntime`]: enabling, disabling
+//! and dropping what is cached for a tool.
+
+use kami_types::ToolId;
+
+use super::KamiRuntime;
+use crate::error::RuntimeError;
+
+impl KamiRuntime {
+    /// Invalidates the cached component and pooled pre-instance of a tool.
+    pub async fn invalidate_cache(&self, tool_id: &ToolId) {
+        self.resolver.invalidate(tool_id).await;
+        self.executor.pool().invalidate(tool_id).await;
+    }
+
+    /// Enables or disables a tool in the registry and invalidates its cache,
+    /// so the change applies from the next call. Returns `false` if the
+    /// tool was already in that state.
+    ///
+    /// # Errors
+    /// Returns `RuntimeError::ToolNotFound` if the tool is not installed or
+    /// the registry cannot be read or written.
+    pub async fn set_enabled(&self, tool_id: &ToolId, enabled: bool) -> Result<bool, RuntimeError> {
+        let repository = self.resolver.repository();
+        let not_found = |e| RuntimeError::ToolNotFound {
+            name: format!("{tool_id}: {e}"),
+        };
+        let mut tool = repository
+            .find_by_id(tool_id)
+            .await
+            .map_err(not_found)?
+            .ok_or_else(|| RuntimeError::ToolNotFound {
+                name: tool_id.to_string(),
+            })?;
+        self.invalidate_cache(tool_id).await;
+        if tool.enabled == enabled {
+            return Ok(false);
+        }
+        tool.enabled = enabled;
+        repository.update(&tool).await.map_err(not_found)?;
+        Ok(true)
+    }
+}
@@ -0,0 +1,150 @@
+//! Execution entry points of [`KamiRuntime`]: admission, resolution,
+//! scheduling, cancellation and progress reporting of a single tool call.
+
+use std::sync::Arc;
+
+use kami_engine::ProgressSink;
+use kami_types::ToolId;
+use tracing::{info, warn};
+
+use super::KamiRuntime;
+use crate::cancel::ExecutionId;
+use crate::error::RuntimeError;
+use crate::kv::ToolKeyValue;
+use crate::store_setup::CallHooks;
+use crate::types::ExecutionResult;
+
+impl KamiRuntime {
+    /// Executes a tool by its ID with the given JSON input.
+    ///
+    /// # Errors
+    /// Returns `RuntimeError::RateLimited` if the request exceeds rate limits.
+    /// Returns `RuntimeError::ToolNotFound` or `RuntimeError::PoolExhausted`.
+    pub async fn execute(
+        &self,
+        tool_id: &ToolId,
+        input: &str,
+    ) -> Result<ExecutionResult, RuntimeError> {
+        self.execute_as(self.next_execution_id(), tool_id, input)
+            .await
+    }
+
+    /// Executes a tool under `id`, so that [`cancel`](Self::cancel) can stop it.
+    ///
+    /// # Errors
+    /// Same as [`execute`](Self::execute), plus `RuntimeError::Cancelled`.
+    pub async fn execute_as(
+        &self,
+        id: ExecutionId,
+        tool_id: &ToolId,
+        input: &str,
+    ) -> Result<ExecutionResult, RuntimeError> {
+        self.execute_observed(id, tool_id, input, None).await
+    }
+
+    /// Like [`execute_as`](Self::execute_as), forwarding the guest's
+    /// `report-progress` calls to `progress` while the tool runs.
+    ///
+    /// # Errors
+    /// Same as [`execute_as`](Self::execute_as).
+    pub async fn execute_with_progress(
+        &self,
+        id: ExecutionId,
+        tool_id: &ToolId,
+        input: &str,
+        progress: Arc<dyn ProgressSink>,
+    ) -> Result<ExecutionResult, RuntimeError> {
+        self.execute_observed(id, tool_id, input, Some(progress))
+            .await
+    }
+
+    #[tracing::instrument(skip_all, fields(%tool_id, execution_id = %id))]
+    async fn execute_observed(
+        &self,
+        id: ExecutionId,
+        tool_id: &ToolId,
+        input: &str,
+        progress: Option<Arc<dyn ProgressSink>>,
+    ) -> Result<ExecutionResult, RuntimeError> {
+        info!(%tool_id, "executing tool");
+        self.metrics.record_attempt();
+        let registration = self.cancellations.register(id);
+        let hooks = CallHooks {
+            cancel: registration.flag().clone(),
+            progress,
+            ..CallHooks::default()
+        };
+        let result = registration.run(self.run(tool_id, input, hooks)).await;
+
+        match &result {
+            Ok(r) => {
+                self.metrics.record_success(r.fuel_consumed);
+                self.metrics.record_memory(tool_id, &r.memory);
+                info!(%tool_id, success = r.success, duration_ms = r.duration_ms,
+                    fuel = r.fuel_consumed, peak_memory = r.memory.peak_bytes,
+                    http_requests = r.egress.requests, egress_bytes = r.egress.bytes_sent,
+                    "execution complete");
+            }
+            Err(e) => {
+                self.metrics.record_failure();
+                warn!(%tool_id, error = %e, "execution failed");
+            }
+        }
+        result
+    }
+
+    /// Returns a fresh id for [`execute_as`](Self::execute_as).
+    pub fn next_execution_id(&self) -> ExecutionId {
+        self.cancellations.next_id()
+    }
+
+    /// Cancels a running execution; returns `false` if `id` is not running.
+    pub fn cancel(&self, id: ExecutionId) -> bool {
+        self.cancellations.cancel(id)
+    }
+
+    /// Admits, resolves and runs one execution.
+    async fn run(
+        &self,
+        tool_id: &ToolId,
+        input: &str,
+        mut hooks: CallHooks,
+    ) -> Result<ExecutionResult, RuntimeError> {
+        // Rate limit check — before any expensive work
+        self.rate_limiter.admit(tool_id)?;
+
+        if self.resolver.cache().get(tool_id).await.is_some() {
+            self.metrics.record_cache_hit();
+        } else {
+            self.metrics.record_cache_miss();
+            // A freshly resolved component must not reuse a stale pre-instance.
+            self.executor.pool().invalidate(tool_id).await;
+        }
+
+        let _permit = self.scheduler.acquire().await?;
+        let cached = self.resolver.resolve(tool_id).await?;
+        let security = self
+            .policy
+            .effective(tool_id, &cached.security, cached.signer.as_deref())
+            .await?;
+        if let Some(store) = &self.key_value {
+            hooks.key_value = ToolKeyValue::scoped(store, tool_id, &security);
+        }
+        if let Some(secrets) = &self.secrets {
+            hooks.secrets = Some(secrets.scoped(tool_id, &security));
+            if security.secrets_as_env {
+                hooks.secret_env = secrets.env(tool_id, &security).await;
+            }
+        }
+        hooks.capabilities = Some(Arc::clone(self.policy.checker()));
+
+        let executor = Arc::clone(&self.executor);
+        let (id, input) = (tool_id.clone(), input.to_owned());
+        let task = async move {
+            executor
+                .run(Some(&id), &cached.component, &input, &security, &hooks)
+                .await
+        };
+        self.scheduler.run(task).await.and_then(|r| r)
+    }
+}
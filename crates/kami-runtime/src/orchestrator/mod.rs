@@ -0,0 +1,138 @@
+//! Top-level runtime orchestrator — combines resolution, scheduling, and WASM execution.
+
+mod admin;
+mod execute;
+
+use std::sync::Arc;
+
+use kami_engine::{create_engine, create_linker, HostState, InstanceConfig};
+use kami_registry::{AuditLog, KeyValueStore, SecretStore, ToolPolicyStore, ToolRepository};
+use kami_sandbox::CapabilityChecker;
+use tracing::info;
+use wasmtime::{component::Linker, Engine};
+
+use crate::cancel::Cancellations;
+use crate::policy::SecurityPolicy;
+use crate::pool::InstancePool;
+use crate::rate_limiter::RateLimiter;
+use crate::scheduler::Scheduler;
+use crate::secrets::Secrets;
+use crate::{cache::ComponentCache, error::RuntimeError, executor::WasmToolExecutor};
+use crate::{metrics::ExecutionMetrics, resolver::ToolResolver, runtime_config::RuntimeConfig};
+
+/// Top-level runtime orchestrator.
+///
+/// Combines tool resolution, scheduling, rate limiting, and sandboxed WASM execution.
+/// Use `metrics()` to read live atomic counters.
+pub struct KamiRuntime {
+    executor: Arc<WasmToolExecutor>,
+    resolver: ToolResolver,
+    scheduler: Scheduler,
+    rate_limiter: RateLimiter,
+    metrics: Arc<ExecutionMetrics>,
+    cancellations: Cancellations,
+    key_value: Option<Arc<dyn KeyValueStore>>,
+    secrets: Option<Secrets>,
+    policy: SecurityPolicy,
+}
+
+impl KamiRuntime {
+    /// Creates a new runtime with the given configuration and repository.
+    ///
+    /// # Errors
+    /// Returns `RuntimeError` if the engine or linker cannot be created.
+    pub fn new(
+        config: RuntimeConfig,
+        repository: Arc<dyn ToolRepository>,
+    ) -> Result<Self, RuntimeError> {
+        let instance_config = InstanceConfig {
+            epoch_interruption: config.epoch_interruption,
+            yield_strategy: config.yield_strategy,
+            ..InstanceConfig::default()
+        };
+        let engine = create_engine(&instance_config)?;
+        let linker = create_linker(&engine)?;
+        Ok(Self::with_engine(engine, linker, config, repository))
+    }
+
+    /// Creates a runtime from an existing engine and linker.
+    pub fn with_engine(
+        engine: Engine,
+        linker: Linker<HostState>,
+        config: RuntimeConfig,
+        repository: Arc<dyn ToolRepository>,
+    ) -> Self {
+        let cache = ComponentCache::new(config.cache_size);
+        let mut executor = WasmToolExecutor::new(engine.clone(), linker)
+            .with_pool(InstancePool::new(config.pool_size))
+            .with_yield_strategy(config.yield_strategy);
+        if let Some(dirs) = config.sandbox_dirs() {
+            executor = executor.with_sandbox_dirs(dirs);
+        }
+        let mut resolver = ToolResolver::new(engine, cache, repository);
+        if let Some(artifacts) = config.artifact_cache() {
+            resolver = resolver.with_artifact_cache(artifacts);
+        }
+        Self {
+            executor: Arc::new(executor),
+            resolver,
+            scheduler: config.scheduler(),
+            rate_limiter: RateLimiter::new(&config.rate_limit),
+            metrics: ExecutionMetrics::new_shared(),
+            cancellations: Cancellations::default(),
+            key_value: None,
+            secrets: None,
+            policy: SecurityPolicy::default(),
+        }
+    }
+
+    /// Backs the `kami:tool/kv` interface with `store`, for tools granted
+    /// the key/value capability. Without a store, every call fails.
+    pub fn with_key_value_store(mut self, store: Arc<dyn KeyValueStore>) -> Self {
+        self.key_value = Some(store);
+        self
+    }
+
+    /// Serves tools' declared secrets from `store`, recording every access
+    /// in `audit`. Without a store, every `get-secret` call fails.
+    pub fn with_secrets(mut self, store: Arc<dyn SecretStore>, audit: Arc<dyn AuditLog>) -> Self {
+        self.secrets = Some(Secrets::new(store, audit));
+        self
+    }
+
+    /// Narrows every tool's security config with `checker` and consults it
+    /// on each connection, mount and environment variable of a call.
+    /// Defaults to [`DefaultCapabilityChecker`](kami_sandbox::DefaultCapabilityChecker).
+    pub fn with_capability_checker(mut self, checker: Arc<dyn CapabilityChecker>) -> Self {
+        self.policy = self.policy.with_checker(checker);
+        self
+    }
+
+    /// Refuses tools whose manifest exceeds their grant in `store`, and
+    /// caps every tool's limits with its overrides there.
+    pub fn with_tool_policies(mut self, store: Arc<dyn ToolPolicyStore>) -> Self {
+        self.policy = self.policy.with_store(store);
+        self
+    }
+
+    /// Gracefully shuts down the runtime by draining all in-flight executions.
+    pub async fn shutdown(&self) {
+        self.scheduler.drain().await;
+        info!("runtime shutdown complete");
+    }
+
+    /// Returns a shared handle to the runtime execution metrics.
+    pub fn metrics(&self) -> Arc<ExecutionMetrics> {
+        self.metrics.clone()
+    }
+
+    /// Returns a reference to the tool resolver.
+    pub fn resolver(&self) -> &ToolResolver {
+        &self.resolver
+    }
+
+    /// Returns a reference to the task scheduler.
+    pub fn scheduler(&self) -> &Scheduler {
+        &self.scheduler
+    }
+}
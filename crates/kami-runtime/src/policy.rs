@@ -0,0 +1,99 @@
+//! Effective security config of a tool call.
+//!
+//! A manifest says what a tool asks for. Each call runs with that request
+//! narrowed by the capability checker (the host policy) and by the
+//! operator's per-tool overrides, read when the tool is resolved so a
+//! `kami policy` change applies from the next call. A tool whose manifest
+//! asks for more than the operator granted it is refused.
+
+use std::sync::Arc;
+
+use kami_registry::ToolPolicyStore;
+use kami_sandbox::{CapabilityChecker, DefaultCapabilityChecker, SandboxError};
+use kami_types::{capability_diff, SecurityConfig, ToolId};
+
+use crate::error::RuntimeError;
+
+/// Host-side narrowing of tool manifests.
+#[derive(Clone)]
+pub struct SecurityPolicy {
+    checker: Arc<dyn CapabilityChecker>,
+    store: Option<Arc<dyn ToolPolicyStore>>,
+}
+
+impl Default for SecurityPolicy {
+    fn default() -> Self {
+        Self::new(Arc::new(DefaultCapabilityChecker))
+    }
+}
+
+impl SecurityPolicy {
+    /// Creates a policy narrowing manifests with `checker` only.
+    pub fn new(checker: Arc<dyn CapabilityChecker>) -> Self {
+        Self {
+            checker,
+            store: None,
+        }
+    }
+
+    /// Replaces the checker, keeping the store.
+    pub fn with_checker(mut self, checker: Arc<dyn CapabilityChecker>) -> Self {
+        self.checker = checker;
+        self
+    }
+
+    /// Also enforces the per-tool grants and overrides kept in `store`.
+    pub fn with_store(mut self, store: Arc<dyn ToolPolicyStore>) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// Returns the checker consulted during calls.
+    pub fn checker(&self) -> &Arc<dyn CapabilityChecker> {
+        &self.checker
+    }
+
+    /// Returns the config `tool` runs with: `manifest` restricted by the
+    /// checker, then capped by the tool's overrides. `signer` is the key the
+    /// tool's signature was verified against, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SandboxError::NotGranted` if the tool has no grant or
+    /// `manifest` asks for more than it, or `SandboxError::InvalidConfig` if
+    /// the store cannot be read: the call is refused rather than run
+    /// unchecked.
+    pub async fn effective(
+        &self,
+        tool: &ToolId,
+        manifest: &SecurityConfig,
+        signer: Option<&str>,
+    ) -> Result<SecurityConfig, RuntimeError> {
+        let mut security = self.checker.restrict(manifest, signer);
+        let Some(store) = &self.store else {
+            return Ok(security);
+        };
+        let unreadable = |e| SandboxError::InvalidConfig {
+            reason: format!("cannot read the policy of {tool}: {e}"),
+        };
+        let changes = match store.granted(tool).await.map_err(unreadable)? {
+            Some(grant) => capability_diff(&grant, manifest)
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", "),
+            None => "none were ever approved".to_string(),
+        };
+        if !changes.is_empty() {
+            return Err(SandboxError::NotGranted {
+                tool: tool.to_string(),
+                changes,
+            }
+            .into());
+        }
+        if let Some(caps) = store.overrides(tool).await.map_err(unreadable)? {
+            caps.apply(&mut security.limits);
+        }
+        Ok(security)
+    }
+}
@@ -0,0 +1,115 @@
+//! Pool of pre-instantiated components keyed by `ToolId`.
+//!
+//! Warm calls reuse the `PreparedTool` (imports already linked and
+//! type-checked) and only instantiate into a fresh `Store`. Entries are
+//! evicted LRU once `max_size` tools are pooled; a size of 0 disables
+//! pooling so every call links from scratch.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+use wasmtime::component::{Component, Linker};
+
+use kami_engine::{prepare_tool, HostState, PreparedTool};
+use kami_types::ToolId;
+
+use crate::error::RuntimeError;
+
+/// Thread-safe LRU pool of prepared components.
+#[derive(Clone)]
+pub struct InstancePool {
+    inner: Arc<Mutex<PoolInner>>,
+    max_size: usize,
+}
+
+/// Internal LRU state: `order` front = least recently used.
+struct PoolInner {
+    entries: HashMap<String, PreparedTool>,
+    order: Vec<String>,
+}
+
+impl InstancePool {
+    /// Creates a pool holding at most `max_size` tools (0 = disabled).
+    pub fn new(max_size: usize) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(PoolInner {
+                entries: HashMap::new(),
+                order: Vec::with_capacity(max_size),
+            })),
+            max_size,
+        }
+    }
+
+    /// Returns the pooled entry for `id`, preparing and pooling it on a miss.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RuntimeError::Engine` if the component cannot be linked.
+    pub async fn get_or_prepare(
+        &self,
+        id: &ToolId,
+        component: &Component,
+        linker: &Linker<HostState>,
+    ) -> Result<PreparedTool, RuntimeError> {
+        if self.max_size == 0 {
+            return Ok(prepare_tool(linker, component)?);
+        }
+        let key = id.as_str();
+        let mut inner = self.inner.lock().await;
+        if let Some(prepared) = inner.entries.get(key).cloned() {
+            inner.touch(key);
+            return Ok(prepared);
+        }
+        let prepared = prepare_tool(linker, component)?;
+        if inner.entries.len() >= self.max_size {
+            inner.evict_lru();
+        }
+        inner.order.push(key.to_string());
+        inner.entries.insert(key.to_string(), prepared.clone());
+        Ok(prepared)
+    }
+
+    /// Returns `true` if `id` currently has a pooled entry.
+    pub async fn contains(&self, id: &ToolId) -> bool {
+        self.inner.lock().await.entries.contains_key(id.as_str())
+    }
+
+    /// Drops the pooled entry for `id` (e.g. after an update).
+    pub async fn invalidate(&self, id: &ToolId) {
+        let mut inner = self.inner.lock().await;
+        inner.entries.remove(id.as_str());
+        inner.order.retain(|k| k != id.as_str());
+    }
+
+    /// Returns the number of pooled tools.
+    pub async fn len(&self) -> usize {
+        self.inner.lock().await.entries.len()
+    }
+
+    /// Returns true if no tool is pooled.
+    pub async fn is_empty(&self) -> bool {
+        self.len().await == 0
+    }
+
+    /// Returns the configured capacity.
+    pub fn max_size(&self) -> usize {
+        self.max_size
+    }
+}
+
+impl PoolInner {
+    /// Moves `key` to the back (most recently used).
+    fn touch(&mut self, key: &str) {
+        self.order.retain(|k| k != key);
+        self.order.push(key.to_string());
+    }
+
+    /// Evicts the least recently used entry.
+    fn evict_lru(&mut self) {
+        if !self.order.is_empty() {
+            let lru_key = self.order.remove(0);
+            self.entries.remove(&lru_key);
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! Interruption and preemption settings shared by an executor's stores.
+
+use std::time::Duration;
+
+use wasmtime::{Engine, Store};
+
+use kami_engine::{
+    set_fuel_yield, set_interruptible_epoch_deadline, CancelFlag, EngineError, EpochTicker,
+    HostState, YieldStrategy, EPOCH_TICK,
+};
+
+use crate::error::RuntimeError;
+
+/// How the stores of an executor are interrupted and preempted.
+pub(crate) struct Preemption {
+    /// Shared epoch ticker, or why it could not be started.
+    pub ticker: Result<EpochTicker, String>,
+    /// How running guests yield to the async executor.
+    pub yield_strategy: YieldStrategy,
+}
+
+impl Preemption {
+    /// Starts the shared epoch ticker of `engine` with the default strategy.
+    ///
+    /// If the ticker cannot be started, [`apply`](Self::apply) refuses every
+    /// store rather than run guests that no timeout or cancellation reaches.
+    pub(crate) fn start(engine: &Engine) -> Self {
+        let ticker = EpochTicker::start(engine, EPOCH_TICK)
+            .inspect_err(|e| tracing::warn!(%e, "epoch ticker unavailable"))
+            .map_err(|e| e.to_string());
+        Self {
+            ticker,
+            yield_strategy: YieldStrategy::default(),
+        }
+    }
+
+    /// Sets the yield interval of `store` and its cancellable epoch deadline.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RuntimeError::Engine` if fuel yielding cannot be configured
+    /// or the epoch ticker failed to start.
+    pub(crate) fn apply(
+        &self,
+        store: &mut Store<HostState>,
+        timeout: Duration,
+        cancel: &CancelFlag,
+    ) -> Result<(), RuntimeError> {
+        set_fuel_yield(store, self.yield_strategy)?;
+        if let Err(reason) = &self.ticker {
+            return Err(EngineError::Config(format!(
+                "epoch ticker unavailable, timeouts cannot be enforced: {reason}"
+            ))
+            .into());
+        }
+        let yield_each_tick = self.yield_strategy == YieldStrategy::Epoch;
+        set_interruptible_epoch_deadline(store, timeout, cancel, yield_each_tick);
+        Ok(())
+    }
+}
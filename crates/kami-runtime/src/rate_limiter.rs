@@ -9,6 +9,8 @@ use std::time::{Duration, Instant};
 
 use kami_types::ToolId;
 
+use crate::error::RuntimeError;
+
 /// Configuration for the rate limiter.
 #[derive(Debug, Clone)]
 pub struct RateLimitConfig {
@@ -117,4 +119,21 @@ impl RateLimiter {
         }
         true
     }
+
+    /// Like [`check`](Self::check), but returns `RuntimeError::RateLimited`
+    /// when the request is over the limit.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RuntimeError::RateLimited` if the request is rejected.
+    pub fn admit(&self, tool_id: &ToolId) -> Result<(), RuntimeError> {
+        if self.check(tool_id) {
+            return Ok(());
+        }
+        Err(RuntimeError::RateLimited {
+            tool_id: tool_id.to_string(),
+            limit: self.config.per_tool,
+            window_secs: self.config.window.as_secs(),
+        })
+    }
 }
@@ -1,18 +1,22 @@
 //! Tool resolution from registry to compiled component.
 //!
 //! Resolves a `ToolId` by looking it up in the registry, loading the
-//! WASM file, compiling it, and caching the result.
+//! WASM file, compiling it, and caching the result. With an
+//! `ArtifactCache`, compilation is skipped when a precompiled artifact for
+//! the verified WASM hash exists on disk.
+
+mod compile;
 
 use std::path::Path;
 use std::sync::Arc;
 
-use tracing::{debug, info};
+use tracing::debug;
 use wasmtime::Engine;
 
-use kami_engine::load_component_from_file;
 use kami_registry::ToolRepository;
 use kami_types::ToolId;
 
+use crate::artifact_cache::ArtifactCache;
 use crate::cache::{CachedComponent, ComponentCache};
 use crate::error::RuntimeError;
 use crate::integrity;
@@ -24,6 +28,7 @@ pub struct ToolResolver {
     engine: Engine,
     cache: ComponentCache,
     repository: Arc<dyn ToolRepository>,
+    artifacts: Option<ArtifactCache>,
 }
 
 impl ToolResolver {
@@ -33,22 +38,25 @@ impl ToolResolver {
             engine,
             cache,
             repository,
+            artifacts: None,
         }
     }
 
+    /// Persists compiled components in `artifacts` across processes.
+    pub fn with_artifact_cache(mut self, artifacts: ArtifactCache) -> Self {
+        self.artifacts = Some(artifacts);
+        self
+    }
+
     /// Resolves a tool ID to a compiled component.
     ///
-    /// Returns the cached component if available, otherwise loads from
-    /// the registry, compiles, and caches.
+    /// The registry is read on every call, so a tool disabled or removed
+    /// by another process is refused even while its component is cached:
+    /// disabled tools fail with `RuntimeError::ToolDisabled`. Otherwise
+    /// returns the cached component if available, or compiles and caches.
     #[tracing::instrument(skip(self), fields(tool_id = %id))]
     pub async fn resolve(&self, id: &ToolId) -> Result<CachedComponent, RuntimeError> {
-        // 1. Check cache first
-        if let Some(cached) = self.cache.get(id).await {
-            debug!(%id, "cache hit");
-            return Ok(cached);
-        }
-
-        // 2. Look up in registry
+        // 1. Look up in registry
         let tool = self
             .repository
             .find_by_id(id)
@@ -59,45 +67,56 @@ impl ToolResolver {
             .ok_or_else(|| RuntimeError::ToolNotFound {
                 name: id.to_string(),
             })?;
-
-        // 3. Resolve WASM file path
-        let wasm_path = Path::new(&tool.install_path).join(&tool.manifest.wasm);
-
-        if !wasm_path.exists() {
-            return Err(RuntimeError::ToolNotFound {
-                name: format!("WASM file missing: {}", wasm_path.display()),
+        if !tool.enabled {
+            return Err(RuntimeError::ToolDisabled {
+                tool_id: id.to_string(),
             });
         }
 
-        // 4. Verify WASM integrity (skipped if no hash stored at install time)
-        integrity::verify_hash(&wasm_path, &tool.manifest.wasm_sha256).map_err(|e| {
-            RuntimeError::IntegrityViolation {
-                tool_id: id.to_string(),
-                detail: e.to_string(),
-            }
+        // 2. Reuse the compiled component if cached
+        if let Some(cached) = self.cache.get(id).await {
+            debug!(%id, "cache hit");
+            return Ok(cached);
+        }
+
+        // 3. Read the WASM file
+        let wasm_path = Path::new(&tool.install_path).join(&tool.manifest.wasm);
+        let bytes = std::fs::read(&wasm_path).map_err(|e| RuntimeError::ToolNotFound {
+            name: format!("WASM file missing: {} ({e})", wasm_path.display()),
         })?;
 
-        // 5. Verify Ed25519 signature if stored
-        if let (Some(sig), Some(pk)) = (&tool.manifest.signature, &tool.manifest.signer_public_key)
-        {
-            crate::signature::verify_file_signature(&wasm_path, sig, pk).map_err(|e| {
+        // 4. Verify WASM integrity (skipped if no hash stored at install time).
+        //    The verified bytes are compiled, and their hash keys the disk cache.
+        let wasm_sha256 =
+            integrity::verify_bytes(&bytes, &tool.manifest.wasm_sha256).map_err(|e| {
+                RuntimeError::IntegrityViolation {
+                    tool_id: id.to_string(),
+                    detail: e.to_string(),
+                }
+            })?;
+
+        // 5. Verify Ed25519 signature if stored, over the same bytes
+        let mut signer = None;
+        let signing = (&tool.manifest.signature, &tool.manifest.signer_public_key);
+        if let (Some(sig), Some(pk)) = signing {
+            crate::signature::verify_signature(&bytes, sig, pk).map_err(|e| {
                 RuntimeError::IntegrityViolation {
                     tool_id: id.to_string(),
                     detail: format!("signature verification failed: {e}"),
                 }
             })?;
             debug!(%id, "signature verified");
+            signer = Some(pk.clone());
         }
 
-        info!(%id, path = %wasm_path.display(), "compiling component");
+        // 6. Load the precompiled artifact, or compile and persist it
+        let component = self.load_or_compile(&wasm_sha256, &bytes, &wasm_path)?;
 
-        // 5. Compile the component
-        let component = load_component_from_file(&self.engine, &wasm_path)?;
-
-        // 6. Cache it
+        // 7. Cache it
         let cached = CachedComponent {
             component,
             security: tool.manifest.security.clone(),
+            signer,
             wasm_path: wasm_path.display().to_string(),
         };
         self.cache.insert(id, cached.clone()).await;
@@ -110,6 +129,11 @@ impl ToolResolver {
         self.cache.invalidate(id).await;
     }
 
+    /// Returns the registry tools are resolved from.
+    pub fn repository(&self) -> &Arc<dyn ToolRepository> {
+        &self.repository
+    }
+
     /// Returns a reference to the component cache.
     pub fn cache(&self) -> &ComponentCache {
         &self.cache
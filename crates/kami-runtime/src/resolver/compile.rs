@@ -0,0 +1,38 @@
+//! Compilation step of [`ToolResolver`], backed by the artifact cache.
+
+use std::path::Path;
+
+use tracing::{info, warn};
+use wasmtime::component::Component;
+
+use kami_engine::load_component;
+
+use super::ToolResolver;
+use crate::error::RuntimeError;
+
+impl ToolResolver {
+    /// Loads the precompiled artifact for `wasm_sha256`, or compiles
+    /// `bytes` and persists the result.
+    pub(super) fn load_or_compile(
+        &self,
+        wasm_sha256: &str,
+        bytes: &[u8],
+        wasm_path: &Path,
+    ) -> Result<Component, RuntimeError> {
+        if let Some(component) = self
+            .artifacts
+            .as_ref()
+            .and_then(|a| a.load(&self.engine, wasm_sha256))
+        {
+            return Ok(component);
+        }
+        info!(path = %wasm_path.display(), "compiling component");
+        let component = load_component(&self.engine, bytes)?;
+        if let Some(artifacts) = &self.artifacts {
+            if let Err(e) = artifacts.store(&self.engine, wasm_sha256, &component) {
+                warn!(%e, dir = %artifacts.dir().display(), "failed to persist artifact");
+            }
+        }
+        Ok(component)
+    }
+}
@@ -1,6 +1,14 @@
 //! Configuration for the KAMI runtime.
 
+use std::path::PathBuf;
+
+use kami_engine::YieldStrategy;
+use kami_sandbox::{SandboxDirMode, SandboxDirs};
+
+use crate::artifact_cache::ArtifactCache;
 use crate::rate_limiter::RateLimitConfig;
+use crate::scheduler::{Scheduler, SchedulerConfig};
+use crate::workers::ExecutionWorkers;
 
 /// Configuration for the KAMI runtime.
 #[derive(Debug, Clone)]
@@ -9,10 +17,60 @@ pub struct RuntimeConfig {
     pub cache_size: usize,
     /// Scheduler concurrency limit.
     pub max_concurrent: usize,
+    /// Number of tools kept pre-instantiated for warm calls (0 = disabled).
+    pub pool_size: usize,
     /// Enable epoch interruption for timeout.
     pub epoch_interruption: bool,
+    /// How running guests yield to the async executor.
+    pub yield_strategy: YieldStrategy,
+    /// Worker threads of a dedicated execution runtime (0 = run executions
+    /// on the caller's runtime).
+    pub execution_threads: usize,
     /// Rate limiter configuration.
     pub rate_limit: RateLimitConfig,
+    /// Root under which per-tool sandbox directories are created.
+    /// `None` disables filesystem mounts even for tools requesting them.
+    pub sandbox_root: Option<PathBuf>,
+    /// Whether tools get a persistent directory or a scratch one per call.
+    pub sandbox_mode: SandboxDirMode,
+    /// Directory of precompiled component artifacts (`None` = disabled).
+    pub artifact_cache_dir: Option<PathBuf>,
+    /// Size bound of the artifact cache in bytes.
+    pub artifact_cache_max_bytes: u64,
+}
+
+impl RuntimeConfig {
+    /// Returns the sandbox directory allocator, if a root is configured.
+    pub fn sandbox_dirs(&self) -> Option<SandboxDirs> {
+        self.sandbox_root
+            .as_ref()
+            .map(|root| SandboxDirs::new(root, self.sandbox_mode))
+    }
+
+    /// Returns the on-disk artifact cache, if a directory is configured.
+    pub fn artifact_cache(&self) -> Option<ArtifactCache> {
+        self.artifact_cache_dir
+            .as_ref()
+            .map(|dir| ArtifactCache::new(dir, self.artifact_cache_max_bytes))
+    }
+
+    /// Returns the scheduler, with dedicated workers if `execution_threads`
+    /// is non-zero. If they cannot be started, executions run inline.
+    pub fn scheduler(&self) -> Scheduler {
+        let scheduler = Scheduler::new(&SchedulerConfig {
+            max_concurrent: self.max_concurrent,
+        });
+        if self.execution_threads == 0 {
+            return scheduler;
+        }
+        match ExecutionWorkers::start(self.execution_threads) {
+            Ok(workers) => scheduler.with_workers(workers),
+            Err(e) => {
+                tracing::warn!(%e, "dedicated execution runtime unavailable");
+                scheduler
+            }
+        }
+    }
 }
 
 impl Default for RuntimeConfig {
@@ -20,8 +78,15 @@ impl Default for RuntimeConfig {
         Self {
             cache_size: 32,
             max_concurrent: 4,
+            pool_size: 16,
             epoch_interruption: true,
+            yield_strategy: YieldStrategy::default(),
+            execution_threads: 0,
             rate_limit: RateLimitConfig::default(),
+            sandbox_root: None,
+            sandbox_mode: SandboxDirMode::Persistent,
+            artifact_cache_dir: None,
+            artifact_cache_max_bytes: 256 * 1024 * 1024,
         }
     }
 }
@@ -35,7 +100,10 @@ mod tests {
         let cfg = RuntimeConfig::default();
         assert_eq!(cfg.cache_size, 32);
         assert_eq!(cfg.max_concurrent, 4);
+        assert_eq!(cfg.pool_size, 16);
         assert!(cfg.epoch_interruption);
+        assert_eq!(cfg.yield_strategy, YieldStrategy::default());
+        assert_eq!(cfg.execution_threads, 0);
         assert_eq!(cfg.rate_limit.per_tool, 100);
     }
 
@@ -46,9 +114,34 @@ mod tests {
             max_concurrent: 8,
             epoch_interruption: false,
             rate_limit: RateLimitConfig::default(),
+            ..RuntimeConfig::default()
         };
         let copy = cfg.clone();
         assert_eq!(copy.cache_size, 64);
         assert!(!copy.epoch_interruption);
     }
+
+    #[test]
+    fn sandbox_dirs_follow_root_and_mode() {
+        let cfg = RuntimeConfig {
+            sandbox_root: Some(PathBuf::from("/data")),
+            sandbox_mode: SandboxDirMode::Scratch,
+            ..RuntimeConfig::default()
+        };
+        let dirs = cfg.sandbox_dirs().expect("dirs");
+        assert_eq!(dirs.root(), std::path::Path::new("/data"));
+        assert_eq!(dirs.mode(), SandboxDirMode::Scratch);
+        assert!(RuntimeConfig::default().sandbox_dirs().is_none());
+    }
+
+    #[test]
+    fn artifact_cache_disabled_by_default() {
+        assert!(RuntimeConfig::default().artifact_cache().is_none());
+        let cfg = RuntimeConfig {
+            artifact_cache_dir: Some(PathBuf::from("/cache")),
+            ..RuntimeConfig::default()
+        };
+        let cache = cfg.artifact_cache().expect("cache");
+        assert_eq!(cache.dir(), std::path::Path::new("/cache"));
+    }
 }
@@ -1,13 +1,17 @@
 //! Task scheduling with concurrency control and priorities.
 //!
 //! Uses a `tokio::sync::Semaphore` to limit concurrent WASM executions,
-//! preventing resource exhaustion on the host.
+//! preventing resource exhaustion on the host. Executions run inline on
+//! the caller's runtime unless [`ExecutionWorkers`] are attached.
 
+use std::future::Future;
 use std::sync::Arc;
 
 use tokio::sync::Semaphore;
 use tracing::debug;
 
+use crate::workers::ExecutionWorkers;
+
 /// Configuration for the task scheduler.
 #[derive(Debug, Clone)]
 pub struct SchedulerConfig {
@@ -29,6 +33,7 @@ impl Default for SchedulerConfig {
 pub struct Scheduler {
     semaphore: Arc<Semaphore>,
     max_concurrent: usize,
+    workers: Option<Arc<ExecutionWorkers>>,
 }
 
 impl Scheduler {
@@ -37,6 +42,35 @@ impl Scheduler {
         Self {
             semaphore: Arc::new(Semaphore::new(config.max_concurrent)),
             max_concurrent: config.max_concurrent,
+            workers: None,
+        }
+    }
+
+    /// Runs executions on `workers` instead of the caller's runtime.
+    pub fn with_workers(mut self, workers: ExecutionWorkers) -> Self {
+        self.workers = Some(Arc::new(workers));
+        self
+    }
+
+    /// Returns the number of dedicated worker threads (0 = run inline).
+    pub fn worker_threads(&self) -> usize {
+        self.workers.as_ref().map_or(0, |w| w.threads())
+    }
+
+    /// Runs `task` on the dedicated workers, or inline without them.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RuntimeError::WorkerUnavailable` if a dedicated worker
+    /// could not complete the task.
+    pub async fn run<F>(&self, task: F) -> Result<F::Output, crate::error::RuntimeError>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        match &self.workers {
+            Some(workers) => workers.run(task).await,
+            None => Ok(task.await),
         }
     }
 
@@ -93,34 +127,5 @@ pub struct SchedulerPermit {
 }
 
 #[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[tokio::test]
-    async fn scheduler_acquire_and_release() {
-        let scheduler = Scheduler::new(&SchedulerConfig { max_concurrent: 2 });
-
-        assert_eq!(scheduler.available_permits(), 2);
-
-        let _p1 = scheduler.acquire().await.expect("permit 1");
-        assert_eq!(scheduler.available_permits(), 1);
-
-        let _p2 = scheduler.acquire().await.expect("permit 2");
-        assert_eq!(scheduler.available_permits(), 0);
-
-        drop(_p1);
-        assert_eq!(scheduler.available_permits(), 1);
-    }
-
-    #[tokio::test]
-    async fn scheduler_blocks_at_capacity() {
-        let scheduler = Scheduler::new(&SchedulerConfig { max_concurrent: 1 });
-
-        let _p1 = scheduler.acquire().await.expect("permit");
-        assert_eq!(scheduler.available_permits(), 0);
-
-        // Second acquire should block; use try_acquire to test
-        let try_result = scheduler.semaphore.clone().try_acquire_owned();
-        assert!(try_result.is_err(), "should be at capacity");
-    }
-}
+#[path = "scheduler_tests.rs"]
+mod tests;
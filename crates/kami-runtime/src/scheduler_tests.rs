@@ -0,0 +1,31 @@
+//! Tests for `Scheduler`.
+
+use super::*;
+
+#[tokio::test]
+async fn scheduler_acquire_and_release() {
+    let scheduler = Scheduler::new(&SchedulerConfig { max_concurrent: 2 });
+
+    assert_eq!(scheduler.available_permits(), 2);
+
+    let _p1 = scheduler.acquire().await.expect("permit 1");
+    assert_eq!(scheduler.available_permits(), 1);
+
+    let _p2 = scheduler.acquire().await.expect("permit 2");
+    assert_eq!(scheduler.available_permits(), 0);
+
+    drop(_p1);
+    assert_eq!(scheduler.available_permits(), 1);
+}
+
+#[tokio::test]
+async fn scheduler_blocks_at_capacity() {
+    let scheduler = Scheduler::new(&SchedulerConfig { max_concurrent: 1 });
+
+    let _p1 = scheduler.acquire().await.expect("permit");
+    assert_eq!(scheduler.available_permits(), 0);
+
+    // Second acquire should block; use try_acquire to test
+    let try_result = scheduler.semaphore.clone().try_acquire_owned();
+    assert!(try_result.is_err(), "should be at capacity");
+}
@@ -0,0 +1,106 @@
+//! Host-mediated secrets backing `get-secret` and `secrets_as_env`.
+//!
+//! [`KamiRuntime`](crate::KamiRuntime) reads its [`SecretStore`] on behalf of
+//! the running tool, only for names granted by [`Capability::Secret`], and
+//! records every access — refused ones included — in its [`AuditLog`].
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use kami_engine::{SecretError, SecretSource};
+use kami_registry::{AuditLog, SecretStore};
+use kami_sandbox::{CapabilityChecker, DefaultCapabilityChecker};
+use kami_types::{Capability, DomainEvent, SecretAccess, SecurityConfig, ToolId};
+
+/// The runtime's secret store and the audit log of its accesses.
+#[derive(Clone)]
+pub(crate) struct Secrets {
+    store: Arc<dyn SecretStore>,
+    audit: Arc<dyn AuditLog>,
+}
+
+impl Secrets {
+    pub(crate) fn new(store: Arc<dyn SecretStore>, audit: Arc<dyn AuditLog>) -> Self {
+        Self { store, audit }
+    }
+
+    /// Scopes the store to `tool` and the secrets its `security` declares.
+    pub(crate) fn scoped(&self, tool: &ToolId, security: &SecurityConfig) -> Arc<dyn SecretSource> {
+        Arc::new(ToolSecrets {
+            secrets: self.clone(),
+            tool: tool.clone(),
+            security: security.clone(),
+        })
+    }
+
+    /// Resolves the declared secrets of `tool` for injection as env vars.
+    ///
+    /// Secrets that are not set are skipped with a warning.
+    pub(crate) async fn env(
+        &self,
+        tool: &ToolId,
+        security: &SecurityConfig,
+    ) -> Vec<(String, String)> {
+        let mut env = Vec::with_capacity(security.secrets.len());
+        for name in &security.secrets {
+            match self.read(tool, security, name).await {
+                Ok(value) => env.push((name.clone(), value)),
+                Err(e) => tracing::warn!(%tool, secret = %name, error = %e, "secret not injected"),
+            }
+        }
+        env
+    }
+
+    /// Reads `name` for `tool` after checking its grant, auditing the access.
+    async fn read(
+        &self,
+        tool: &ToolId,
+        security: &SecurityConfig,
+        name: &str,
+    ) -> Result<String, SecretError> {
+        let capability = Capability::Secret(name.to_string());
+        if DefaultCapabilityChecker
+            .check(&capability, security)
+            .is_err()
+        {
+            let event =
+                DomainEvent::secret_accessed(Some(tool.clone()), name, SecretAccess::Denied);
+            if let Err(e) = self.audit.record(&event).await {
+                tracing::warn!(%tool, secret = %name, error = %e, "failed to audit denied secret");
+            }
+            return Err(SecretError::NotGranted {
+                name: name.to_string(),
+            });
+        }
+        // Fail closed: a read that cannot be audited is not performed.
+        let event = DomainEvent::secret_accessed(Some(tool.clone()), name, SecretAccess::Read);
+        self.audit.record(&event).await.map_err(storage)?;
+        self.store
+            .get(name)
+            .await
+            .map_err(storage)?
+            .ok_or_else(|| SecretError::NotFound {
+                name: name.to_string(),
+            })
+    }
+}
+
+fn storage(e: kami_registry::RepositoryError) -> SecretError {
+    SecretError::Storage {
+        message: e.to_string(),
+    }
+}
+
+/// The secrets readable by one tool.
+struct ToolSecrets {
+    secrets: Secrets,
+    tool: ToolId,
+    security: SecurityConfig,
+}
+
+#[async_trait]
+impl SecretSource for ToolSecrets {
+    async fn get(&self, name: &str) -> Result<String, SecretError> {
+        self.secrets.read(&self.tool, &self.security, name).await
+    }
+}
@@ -59,6 +59,19 @@ pub fn verify_file_signature(
     path: &Path,
     signature_hex: &str,
     public_key_hex: &str,
+) -> Result<(), io::Error> {
+    verify_signature(&std::fs::read(path)?, signature_hex, public_key_hex)
+}
+
+/// Verifies the Ed25519 signature of `data` against a hex-encoded public key.
+///
+/// # Errors
+///
+/// Returns `io::Error` if keys are invalid or the signature does not match.
+pub fn verify_signature(
+    data: &[u8],
+    signature_hex: &str,
+    public_key_hex: &str,
 ) -> Result<(), io::Error> {
     let pk_bytes = hex::decode(public_key_hex).map_err(|e| {
         io::Error::new(
@@ -87,8 +100,7 @@ pub fn verify_file_signature(
         .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "signature must be 64 bytes"))?;
     let signature = Signature::from_bytes(&sig_array);
 
-    let data = std::fs::read(path)?;
-    verifying.verify(&data, &signature).map_err(|e| {
+    verifying.verify(data, &signature).map_err(|e| {
         io::Error::new(
             io::ErrorKind::InvalidData,
             format!("signature invalid: {e}"),
@@ -0,0 +1,134 @@
+//! Store construction for a single isolated execution.
+//!
+//! Builds the sandboxed WASI context and wraps it in a `Store` carrying the
+//! tool's memory, fuel and network limits.
+
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use wasmtime::{Engine, Store};
+
+use kami_engine::{create_store, CancelFlag, HostState, KeyValue, ProgressSink, SecretSource};
+use kami_sandbox::network::{NetworkPolicy, SystemResolver};
+use kami_sandbox::{
+    build_wasi_ctx, CapabilityChecker, CapabilityGuard, GuestOutput, SandboxDir, SandboxDirs,
+    SandboxError, WasiConfig,
+};
+use kami_types::{FsAccess, SecurityConfig, ToolId};
+
+use crate::error::RuntimeError;
+use crate::preemption::Preemption;
+
+/// Prepares the sandbox directory of `tool_id` if its policy grants
+/// filesystem access. Anonymous executions never get a directory.
+///
+/// # Errors
+///
+/// Returns `RuntimeError::Sandbox` if the directory cannot be prepared.
+pub(crate) fn prepare_sandbox_dir(
+    dirs: Option<&SandboxDirs>,
+    tool_id: Option<&ToolId>,
+    security: &SecurityConfig,
+) -> Result<Option<SandboxDir>, RuntimeError> {
+    let Some(tool_id) = tool_id else {
+        return Ok(None);
+    };
+    if matches!(security.fs_access, FsAccess::None) {
+        return Ok(None);
+    }
+    match dirs {
+        Some(dirs) => Ok(Some(dirs.prepare(tool_id.as_str())?)),
+        None => {
+            tracing::warn!(%tool_id, "no sandbox root configured, filesystem not mounted");
+            Ok(None)
+        }
+    }
+}
+
+/// Per-call hooks into a store: cancellation, progress reporting, the
+/// tool's key/value store, its secrets and the capability checker.
+#[derive(Default)]
+pub(crate) struct CallHooks {
+    /// Raised to trap the store at its next epoch tick.
+    pub cancel: CancelFlag,
+    /// Receives the guest's `report-progress` calls.
+    pub progress: Option<Arc<dyn ProgressSink>>,
+    /// Backs the guest's `kami:tool/kv` calls.
+    pub key_value: Option<Arc<dyn KeyValue>>,
+    /// Answers the guest's `get-secret` calls.
+    pub secrets: Option<Arc<dyn SecretSource>>,
+    /// Declared secrets injected as environment variables.
+    pub secret_env: Vec<(String, String)>,
+    /// Consulted on every connection, mount and environment variable.
+    pub capabilities: Option<Arc<dyn CapabilityChecker>>,
+}
+
+/// Builds a `Store` for one execution of a tool.
+///
+/// `sandbox_dir` is preopened as `.` when `fs_access` allows it. With an
+/// epoch ticker, the store traps once its own `max_execution_ms` has
+/// elapsed or `hooks.cancel` is raised, and it yields to the executor as
+/// `preemption` dictates. Progress reports go to `hooks.progress`,
+/// key/value calls to `hooks.key_value` and secret reads to `hooks.secrets`;
+/// `hooks.secret_env` is added to the guest's environment, and
+/// `hooks.capabilities` is bound to `security` as the context's guard.
+/// Guest stdout/stderr go to the returned capture, never the host's stdio.
+///
+/// # Errors
+///
+/// Returns `RuntimeError::Sandbox` if the WASI context cannot be built
+/// and `RuntimeError::Engine` if the store cannot be configured.
+pub(crate) fn build_store(
+    engine: &Engine,
+    security: &SecurityConfig,
+    sandbox_dir: Option<&Path>,
+    preemption: &Preemption,
+    hooks: &CallHooks,
+) -> Result<(Store<HostState>, GuestOutput), RuntimeError> {
+    let output = GuestOutput::new(security.limits.max_log_bytes as usize);
+    let resolver = Arc::new(SystemResolver);
+    let mut net_policy = NetworkPolicy::from_config(security, resolver);
+    let mut guard = None;
+    if let Some(checker) = &hooks.capabilities {
+        let bound = CapabilityGuard::new(Arc::clone(checker), security.clone());
+        net_policy = net_policy.with_guard(bound.clone());
+        guard = Some(bound);
+    }
+    let net_policy = Arc::new(net_policy);
+    let wasi_config = WasiConfig {
+        capture: Some(output.clone()),
+        secret_env: hooks.secret_env.clone(),
+        net_policy: Some(Arc::clone(&net_policy)),
+        guard,
+        ..WasiConfig::default()
+    };
+    let dir = sandbox_dir
+        .map(|p| {
+            p.to_str().ok_or_else(|| SandboxError::SandboxDir {
+                path: p.display().to_string(),
+                reason: "path is not valid UTF-8".to_string(),
+            })
+        })
+        .transpose()?;
+    let wasi_ctx = build_wasi_ctx(security, &wasi_config, dir)?;
+
+    let max_memory = security.limits.max_memory_mb as usize * 1024 * 1024;
+    let mut host_state = HostState::with_limits(wasi_ctx, max_memory);
+    host_state.set_net_policy(net_policy);
+    let limits = &security.limits;
+    host_state.set_egress_limits(limits.max_http_requests.into(), limits.max_egress_bytes);
+    if let Some(sink) = &hooks.progress {
+        host_state.set_progress_sink(Arc::clone(sink));
+    }
+    if let Some(kv) = &hooks.key_value {
+        host_state.set_key_value(Arc::clone(kv));
+    }
+    if let Some(secrets) = &hooks.secrets {
+        host_state.set_secret_source(Arc::clone(secrets));
+    }
+    let mut store = create_store(engine, host_state, security.limits.max_fuel)?;
+    let timeout = Duration::from_millis(security.limits.max_execution_ms);
+    preemption.apply(&mut store, timeout, &hooks.cancel)?;
+    Ok((store, output))
+}
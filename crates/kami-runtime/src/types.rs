@@ -3,21 +3,50 @@
 use async_trait::async_trait;
 use wasmtime::component::Component;
 
-use kami_types::SecurityConfig;
+use kami_engine::{EgressStats, MemoryStats};
+use kami_types::{ContentPart, SecurityConfig};
 
 use crate::error::RuntimeError;
 
 /// Result of a tool execution.
 #[derive(Debug, Clone)]
 pub struct ExecutionResult {
-    /// Output content from the tool.
+    /// Output content from the tool: its text parts, one per line, or the
+    /// error message of a failed call.
     pub content: String,
+    /// Every part of a successful result (a single text part for tools
+    /// exporting only `run`); empty for failed calls.
+    pub parts: Vec<ContentPart>,
     /// Execution duration in milliseconds.
     pub duration_ms: u64,
     /// Whether execution succeeded.
     pub success: bool,
     /// Fuel consumed during execution.
     pub fuel_consumed: u64,
+    /// Linear-memory and table usage of the instance.
+    pub memory: MemoryStats,
+    /// Outgoing HTTP requests and bytes, per host.
+    pub egress: EgressStats,
+    /// Output the guest wrote to stdout/stderr.
+    pub logs: GuestLogs,
+}
+
+/// Guest stdout/stderr captured during one execution.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GuestLogs {
+    /// Captured stdout (lossy UTF-8).
+    pub stdout: String,
+    /// Captured stderr (lossy UTF-8).
+    pub stderr: String,
+    /// Whether output beyond `max_log_bytes` was dropped.
+    pub truncated: bool,
+}
+
+impl GuestLogs {
+    /// Returns true if the guest wrote nothing.
+    pub fn is_empty(&self) -> bool {
+        self.stdout.is_empty() && self.stderr.is_empty()
+    }
 }
 
 /// Trait for executing compiled WASM components.
@@ -43,9 +72,13 @@ mod tests {
     fn execution_result_clone_preserves_fields() {
         let r = ExecutionResult {
             content: "ok".into(),
+            parts: vec![ContentPart::text("ok")],
             duration_ms: 42,
             success: true,
             fuel_consumed: 1000,
+            memory: MemoryStats::default(),
+            egress: EgressStats::default(),
+            logs: GuestLogs::default(),
         };
         let c = r.clone();
         assert_eq!(c.content, "ok");
@@ -58,9 +91,13 @@ mod tests {
     fn execution_result_debug_format() {
         let r = ExecutionResult {
             content: "x".into(),
+            parts: Vec::new(),
             duration_ms: 0,
             success: false,
             fuel_consumed: 0,
+            memory: MemoryStats::default(),
+            egress: EgressStats::default(),
+            logs: GuestLogs::default(),
         };
         let dbg = format!("{r:?}");
         assert!(dbg.contains("ExecutionResult"));
@@ -0,0 +1,102 @@
+//! Dedicated tokio runtime for guest execution.
+//!
+//! Even with yielding, a busy guest occupies a worker of the runtime that
+//! polls it. Running executions on their own multi-threaded runtime keeps
+//! the caller's runtime (HTTP transport, health checks, stdio loop) free.
+
+use std::future::Future;
+
+use tokio::runtime::{Builder, Handle, Runtime};
+use tokio::task::AbortHandle;
+
+use crate::error::RuntimeError;
+
+/// Thread-name prefix of the dedicated workers.
+const THREAD_NAME: &str = "kami-exec";
+
+/// Owned multi-threaded runtime on which executions are spawned.
+pub struct ExecutionWorkers {
+    runtime: Option<Runtime>,
+    threads: usize,
+}
+
+impl ExecutionWorkers {
+    /// Starts a runtime with `threads` worker threads.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RuntimeError::WorkerUnavailable` if `threads` is zero or the
+    /// runtime cannot be built.
+    pub fn start(threads: usize) -> Result<Self, RuntimeError> {
+        if threads == 0 {
+            return Err(RuntimeError::WorkerUnavailable {
+                reason: "at least one worker thread is required".to_string(),
+            });
+        }
+        let runtime = Builder::new_multi_thread()
+            .worker_threads(threads)
+            .thread_name(THREAD_NAME)
+            .enable_all()
+            .build()
+            .map_err(|e| RuntimeError::WorkerUnavailable {
+                reason: e.to_string(),
+            })?;
+        Ok(Self {
+            runtime: Some(runtime),
+            threads,
+        })
+    }
+
+    /// Returns the number of worker threads.
+    pub fn threads(&self) -> usize {
+        self.threads
+    }
+
+    /// Runs `task` on the dedicated runtime and waits for its output.
+    ///
+    /// Dropping the returned future aborts the task.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RuntimeError::WorkerUnavailable` if the task panicked or the
+    /// runtime is shutting down.
+    pub async fn run<F>(&self, task: F) -> Result<F::Output, RuntimeError>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        let handle = self
+            .handle()
+            .ok_or_else(|| RuntimeError::WorkerUnavailable {
+                reason: "runtime is shutting down".to_string(),
+            })?;
+        let join = handle.spawn(task);
+        let _abort = AbortOnDrop(join.abort_handle());
+        join.await.map_err(|e| RuntimeError::WorkerUnavailable {
+            reason: e.to_string(),
+        })
+    }
+
+    fn handle(&self) -> Option<&Handle> {
+        self.runtime.as_ref().map(Runtime::handle)
+    }
+}
+
+impl Drop for ExecutionWorkers {
+    /// Shuts down without blocking, so the owner may be dropped from
+    /// within another runtime.
+    fn drop(&mut self) {
+        if let Some(runtime) = self.runtime.take() {
+            runtime.shutdown_background();
+        }
+    }
+}
+
+/// Aborts a spawned task when the future awaiting it is dropped.
+struct AbortOnDrop(AbortHandle);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
@@ -0,0 +1,138 @@
+//! On-disk compiled-artifact cache: roundtrip, corruption, eviction,
+//! and reuse across resolvers (i.e. across processes).
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use kami_engine::{create_engine, load_component, InstanceConfig};
+use kami_registry::{RepositoryError, ToolQuery, ToolRepository};
+use kami_runtime::{compute_hash, ArtifactCache, ComponentCache, ToolResolver};
+use kami_types::{SecurityConfig, Tool, ToolId, ToolManifest, ToolVersion};
+use wasmtime::Engine;
+
+#[cfg(unix)]
+#[path = "artifact_cache/permissions.rs"]
+mod permissions;
+
+const WAT: &str = "(component)";
+
+struct SingleTool(Tool);
+
+#[async_trait]
+impl ToolRepository for SingleTool {
+    async fn find_by_id(&self, _id: &ToolId) -> Result<Option<Tool>, RepositoryError> {
+        Ok(Some(self.0.clone()))
+    }
+    async fn find_all(&self, _query: ToolQuery) -> Result<Vec<Tool>, RepositoryError> {
+        Ok(vec![self.0.clone()])
+    }
+    async fn insert(&self, _tool: &Tool) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+    async fn update(&self, _tool: &Tool) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+    async fn delete(&self, _id: &ToolId) -> Result<bool, RepositoryError> {
+        Ok(false)
+    }
+}
+
+fn engine() -> Engine {
+    create_engine(&InstanceConfig::default()).expect("engine")
+}
+
+fn cached_files(dir: &std::path::Path) -> Vec<std::path::PathBuf> {
+    std::fs::read_dir(dir)
+        .map(|d| d.filter_map(|e| e.ok()).map(|e| e.path()).collect())
+        .unwrap_or_default()
+}
+
+#[test]
+fn store_then_load_roundtrips() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let engine = engine();
+    let cache = ArtifactCache::new(dir.path(), u64::MAX);
+    let component = load_component(&engine, WAT.as_bytes()).expect("compile");
+
+    cache.store(&engine, "abc123", &component).expect("store");
+
+    assert!(cache.load(&engine, "abc123").is_some());
+    assert!(cache.load(&engine, "other").is_none());
+}
+
+#[test]
+fn corrupt_entry_is_discarded() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let engine = engine();
+    let cache = ArtifactCache::new(dir.path(), u64::MAX);
+    let component = load_component(&engine, WAT.as_bytes()).expect("compile");
+    cache.store(&engine, "abc123", &component).expect("store");
+    let path = cached_files(dir.path()).pop().expect("entry");
+    let mut bytes = std::fs::read(&path).expect("read");
+    let last = bytes.len() - 1;
+    bytes[last] ^= 0xff;
+    std::fs::write(&path, bytes).expect("write");
+
+    assert!(cache.load(&engine, "abc123").is_none());
+    assert!(!path.exists());
+}
+
+#[test]
+fn total_size_is_bounded() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let engine = engine();
+    let component = load_component(&engine, WAT.as_bytes()).expect("compile");
+    ArtifactCache::new(dir.path(), u64::MAX)
+        .store(&engine, "first", &component)
+        .expect("store");
+    let one_entry = std::fs::metadata(&cached_files(dir.path())[0])
+        .expect("meta")
+        .len();
+
+    let cache = ArtifactCache::new(dir.path(), one_entry);
+    cache.store(&engine, "second", &component).expect("store");
+
+    assert_eq!(cached_files(dir.path()).len(), 1);
+}
+
+#[tokio::test]
+async fn fresh_resolver_reuses_persisted_artifact() {
+    let tool_dir = tempfile::tempdir().expect("tool dir");
+    let cache_dir = tempfile::tempdir().expect("cache dir");
+    std::fs::write(tool_dir.path().join("tool.wasm"), WAT).expect("write wasm");
+    let tool = Tool {
+        manifest: ToolManifest {
+            id: ToolId::new("dev.test.artifact").expect("id"),
+            name: "artifact".to_string(),
+            version: ToolVersion::new(1, 0, 0),
+            wasm: "tool.wasm".to_string(),
+            description: "Artifact cache test".to_string(),
+            arguments: vec![],
+            output: vec![],
+            security: SecurityConfig::default(),
+            wasm_sha256: Some(compute_hash(WAT.as_bytes())),
+            signature: None,
+            signer_public_key: None,
+        },
+        install_path: tool_dir.path().display().to_string(),
+        enabled: true,
+        pinned_version: None,
+        updated_at: None,
+    };
+    let repo: Arc<dyn ToolRepository> = Arc::new(SingleTool(tool.clone()));
+    let resolver = |engine: Engine| {
+        ToolResolver::new(engine, ComponentCache::new(4), repo.clone())
+            .with_artifact_cache(ArtifactCache::new(cache_dir.path(), u64::MAX))
+    };
+
+    resolver(engine())
+        .resolve(&tool.manifest.id)
+        .await
+        .expect("cold");
+    assert_eq!(cached_files(cache_dir.path()).len(), 1);
+
+    let warm = resolver(engine()).resolve(&tool.manifest.id).await;
+    assert!(warm.is_ok());
+    assert_eq!(cached_files(cache_dir.path()).len(), 1);
+}
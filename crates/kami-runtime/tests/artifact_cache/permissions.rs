@@ -0,0 +1,41 @@
+//! Owner-only permissions on the artifact cache directory.
+
+use std::os::unix::fs::PermissionsExt;
+
+use kami_engine::load_component;
+use kami_runtime::ArtifactCache;
+
+use super::{engine, WAT};
+
+#[test]
+fn new_directory_is_owner_only() {
+    let root = tempfile::tempdir().expect("tempdir");
+    let dir = root.path().join("artifacts");
+    let engine = engine();
+    let component = load_component(&engine, WAT.as_bytes()).expect("compile");
+
+    ArtifactCache::new(&dir, u64::MAX)
+        .store(&engine, "abc123", &component)
+        .expect("store");
+
+    let mode = std::fs::metadata(&dir).expect("meta").permissions().mode();
+    assert_eq!(mode & 0o777, 0o700);
+}
+
+#[test]
+fn directory_writable_by_others_is_refused() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let engine = engine();
+    let cache = ArtifactCache::new(dir.path(), u64::MAX);
+    let component = load_component(&engine, WAT.as_bytes()).expect("compile");
+    cache.store(&engine, "abc123", &component).expect("store");
+
+    let open = std::fs::Permissions::from_mode(0o777);
+    std::fs::set_permissions(dir.path(), open).expect("chmod");
+
+    assert!(cache.load(&engine, "abc123").is_none());
+    let err = cache
+        .store(&engine, "abc123", &component)
+        .expect_err("refused");
+    assert_eq!(err.kind(), std::io::ErrorKind::PermissionDenied);
+}
@@ -28,6 +28,7 @@ async fn insert_and_get() {
             CachedComponent {
                 component,
                 security: SecurityConfig::default(),
+                signer: None,
                 wasm_path: "test.wasm".to_string(),
             },
         )
@@ -55,6 +56,7 @@ async fn invalidate_removes_entry() {
             CachedComponent {
                 component: make_component(),
                 security: SecurityConfig::default(),
+                signer: None,
                 wasm_path: "test.wasm".to_string(),
             },
         )
@@ -76,6 +78,7 @@ async fn eviction_at_capacity() {
                 CachedComponent {
                     component: make_component(),
                     security: SecurityConfig::default(),
+                    signer: None,
                     wasm_path: format!("t{i}.wasm"),
                 },
             )
@@ -95,6 +98,7 @@ async fn lru_evicts_least_recently_used() {
     let entry = |path: &str| CachedComponent {
         component: make_component(),
         security: SecurityConfig::default(),
+        signer: None,
         wasm_path: path.to_string(),
     };
 
@@ -119,6 +123,7 @@ async fn clear_empties_cache_and_is_empty() {
     let entry = CachedComponent {
         component: make_component(),
         security: SecurityConfig::default(),
+        signer: None,
         wasm_path: "clr.wasm".to_string(),
     };
     cache.insert(&id, entry).await;
@@ -135,6 +140,7 @@ async fn reinserting_same_key_keeps_count_at_one() {
     let mk = || CachedComponent {
         component: make_component(),
         security: SecurityConfig::default(),
+        signer: None,
         wasm_path: "ri.wasm".to_string(),
     };
     cache.insert(&id, mk()).await;
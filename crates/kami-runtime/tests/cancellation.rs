@@ -0,0 +1,78 @@
+//! `KamiRuntime::cancel` stops a running execution.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use kami_engine::YieldStrategy;
+use kami_runtime::{KamiRuntime, RuntimeConfig, RuntimeError};
+use kami_types::ToolId;
+
+mod common;
+mod serve;
+
+/// Flat component whose `run` spins forever.
+const SPIN_WAT: &str = include_str!("../../../tests/fixtures/wat/spin.wat");
+
+/// Installs the spinning tool under `dir` and returns a runtime serving it.
+async fn spin_runtime(dir: &tempfile::TempDir, yield_strategy: YieldStrategy) -> KamiRuntime {
+    let config = RuntimeConfig {
+        epoch_interruption: true,
+        yield_strategy,
+        ..RuntimeConfig::default()
+    };
+    serve::wat_runtime(dir, "dev.test.spin", SPIN_WAT, config).await
+}
+
+/// Starts the spinning tool, cancels it after `delay` and returns the
+/// outcome with the time it took to stop.
+async fn cancel_after(
+    yield_strategy: YieldStrategy,
+    delay: Duration,
+) -> (
+    Result<kami_runtime::ExecutionResult, RuntimeError>,
+    Duration,
+) {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let runtime = Arc::new(spin_runtime(&dir, yield_strategy).await);
+    let id = runtime.next_execution_id();
+    let running = Arc::clone(&runtime);
+    let call = tokio::spawn(async move {
+        let tool = ToolId::new("dev.test.spin").expect("id");
+        running.execute_as(id, &tool, "{}").await
+    });
+
+    tokio::time::sleep(delay).await;
+    let cancelled_at = Instant::now();
+    assert!(runtime.cancel(id), "execution should still be running");
+    let result = tokio::time::timeout(Duration::from_secs(10), call)
+        .await
+        .expect("cancelled promptly")
+        .expect("join");
+    (result, cancelled_at.elapsed())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn cancel_stops_a_spinning_tool() {
+    let (result, _) = cancel_after(YieldStrategy::default(), Duration::from_millis(200)).await;
+
+    assert!(matches!(result, Err(RuntimeError::Cancelled)));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn cancel_interrupts_a_guest_that_never_yields() {
+    let (result, took) = cancel_after(YieldStrategy::Never, Duration::from_millis(200)).await;
+
+    assert!(matches!(result, Err(RuntimeError::Cancelled)));
+    assert!(took < Duration::from_secs(5), "took {took:?}");
+}
+
+#[tokio::test]
+async fn cancel_of_unknown_execution_is_false() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let runtime = spin_runtime(&dir, YieldStrategy::default()).await;
+
+    let id = runtime.next_execution_id();
+
+    assert!(!runtime.cancel(id));
+    assert_ne!(id, runtime.next_execution_id());
+}
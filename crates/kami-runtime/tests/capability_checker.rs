@@ -0,0 +1,120 @@
+//! The runtime's capability checker narrows configs and vets mounts.
+
+use std::sync::{Arc, Mutex};
+
+use kami_registry::ToolRepository;
+use kami_runtime::{KamiRuntime, RuntimeConfig, RuntimeError};
+use kami_sandbox::{CapabilityChecker, DefaultCapabilityChecker, PolicyChecker, SandboxError};
+use kami_store_sqlite::SqliteToolRepository;
+use kami_types::{Capability, FsAccess, HostPolicy, SecurityConfig, ToolId};
+
+mod common;
+
+const TOOL: &str = "dev.test.checked";
+
+/// Flat component whose `run` echoes its input as `Ok(input)`.
+const ECHO_WAT: &str = include_str!("../../../tests/fixtures/wat/echo.wat");
+
+/// Records what it is asked, refusing writes if told to.
+#[derive(Debug, Default)]
+struct Recording {
+    seen: Mutex<Vec<Capability>>,
+    signer: Mutex<Option<Option<String>>>,
+    refuse_writes: bool,
+}
+
+impl CapabilityChecker for Recording {
+    fn check(&self, capability: &Capability, config: &SecurityConfig) -> Result<(), SandboxError> {
+        self.seen.lock().expect("lock").push(capability.clone());
+        if self.refuse_writes && matches!(capability, Capability::FsWrite(_)) {
+            return Err(SandboxError::FsDenied {
+                path: "refused".to_string(),
+            });
+        }
+        DefaultCapabilityChecker.check(capability, config)
+    }
+
+    fn restrict(&self, config: &SecurityConfig, signer: Option<&str>) -> SecurityConfig {
+        *self.signer.lock().expect("lock") = Some(signer.map(str::to_string));
+        config.clone()
+    }
+}
+
+/// Installs the echo tool with sandbox filesystem access and a runtime
+/// mounting tool directories under `root`.
+async fn runtime(dir: &tempfile::TempDir, root: &tempfile::TempDir) -> KamiRuntime {
+    let repo = Arc::new(SqliteToolRepository::open_in_memory().expect("db"));
+    let security = SecurityConfig {
+        fs_access: FsAccess::Sandbox,
+        ..common::long_running()
+    };
+    repo.insert(&common::wat_tool(dir, TOOL, ECHO_WAT, security))
+        .await
+        .expect("insert");
+    let config = RuntimeConfig {
+        sandbox_root: Some(root.path().to_path_buf()),
+        ..RuntimeConfig::default()
+    };
+    KamiRuntime::new(config, repo).expect("runtime")
+}
+
+fn id() -> ToolId {
+    ToolId::new(TOOL).expect("id")
+}
+
+#[tokio::test]
+async fn checker_vets_the_mount_of_an_unsigned_tool() {
+    let dir = tempfile::tempdir().expect("dir");
+    let root = tempfile::tempdir().expect("root");
+    let checker = Arc::new(Recording::default());
+    let runtime = runtime(&dir, &root)
+        .await
+        .with_capability_checker(checker.clone());
+
+    let result = runtime.execute(&id(), "hi").await.expect("execute");
+
+    assert!(result.success);
+    assert_eq!(*checker.signer.lock().expect("lock"), Some(None));
+    let seen = checker.seen.lock().expect("lock");
+    assert!(seen.iter().any(|c| matches!(c, Capability::FsRead(_))));
+    assert!(seen.iter().any(|c| matches!(c, Capability::FsWrite(_))));
+}
+
+#[tokio::test]
+async fn refused_mount_fails_the_call() {
+    let dir = tempfile::tempdir().expect("dir");
+    let root = tempfile::tempdir().expect("root");
+    let checker = Recording {
+        refuse_writes: true,
+        ..Recording::default()
+    };
+    let runtime = runtime(&dir, &root)
+        .await
+        .with_capability_checker(Arc::new(checker));
+
+    let err = runtime.execute(&id(), "hi").await.expect_err("refused");
+
+    assert!(matches!(
+        err,
+        RuntimeError::Sandbox(SandboxError::FsDenied { .. })
+    ));
+}
+
+#[tokio::test]
+async fn host_policy_narrows_filesystem_access() {
+    let dir = tempfile::tempdir().expect("dir");
+    let root = tempfile::tempdir().expect("root");
+    let policy = HostPolicy {
+        max_fs_access: Some(FsAccess::None),
+        ..HostPolicy::default()
+    };
+    let checker = PolicyChecker::new(policy).expect("policy");
+    let runtime = runtime(&dir, &root)
+        .await
+        .with_capability_checker(Arc::new(checker));
+
+    let result = runtime.execute(&id(), "hi").await.expect("execute");
+
+    assert!(result.success);
+    assert!(!root.path().join(TOOL).exists());
+}
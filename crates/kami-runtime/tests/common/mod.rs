@@ -0,0 +1,39 @@
+//! Helpers shared by the integration tests that run WAT tools.
+
+use kami_types::{ResourceLimits, SecurityConfig, Tool, ToolId, ToolManifest, ToolVersion};
+
+/// Security config letting a tool run for up to a minute.
+pub fn long_running() -> SecurityConfig {
+    SecurityConfig {
+        limits: ResourceLimits {
+            max_fuel: u64::MAX / 2,
+            max_execution_ms: 60_000,
+            ..ResourceLimits::default()
+        },
+        ..SecurityConfig::default()
+    }
+}
+
+/// Writes the component `wat` under `dir` and returns tool `id` running it.
+pub fn wat_tool(dir: &tempfile::TempDir, id: &str, wat: &str, security: SecurityConfig) -> Tool {
+    std::fs::write(dir.path().join("tool.wasm"), wat).expect("write wasm");
+    Tool {
+        manifest: ToolManifest {
+            id: ToolId::new(id).expect("id"),
+            name: "test".to_string(),
+            version: ToolVersion::new(1, 0, 0),
+            wasm: "tool.wasm".to_string(),
+            description: "Test tool".to_string(),
+            arguments: vec![],
+            output: vec![],
+            security,
+            wasm_sha256: None,
+            signature: None,
+            signer_public_key: None,
+        },
+        install_path: dir.path().display().to_string(),
+        enabled: true,
+        pinned_version: None,
+        updated_at: None,
+    }
+}
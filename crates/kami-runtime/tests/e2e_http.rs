@@ -37,6 +37,7 @@ async fn register_http_fetch(repo: &SqliteToolRepository, net_allow_list: Vec<St
             wasm: "http_fetch_tool.wasm".to_string(),
             description: "Fetches a URL via WASI HTTP".to_string(),
             arguments: vec![],
+            output: vec![],
             security: SecurityConfig {
                 net_allow_list,
                 limits: ResourceLimits {
@@ -0,0 +1,12 @@
+//! Flat echo components that run some WAT before echoing their input, for
+//! the executor tests that exercise limits and scheduling.
+
+const TEMPLATE: &str = include_str!("../../../../tests/fixtures/wat/echo_after.wat");
+
+/// Returns an echo component with `pages` initial memory pages whose `run`
+/// first executes `prelude`.
+pub fn echo_after(pages: u32, prelude: &str) -> String {
+    TEMPLATE
+        .replace("{PAGES}", &pages.to_string())
+        .replace("{PRELUDE}", prelude)
+}
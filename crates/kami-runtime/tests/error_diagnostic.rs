@@ -1,6 +1,6 @@
 //! Diagnostic hint/fix coverage for RuntimeError.
 
-use kami_runtime::RuntimeError;
+use kami_runtime::{GuestLogs, RuntimeError};
 use kami_types::{DiagnosticError, ErrorKind, KamiError};
 
 #[test]
@@ -23,14 +23,20 @@ fn tool_not_found_fix_suggests_install() {
 
 #[test]
 fn timeout_hint_contains_ms() {
-    let e = RuntimeError::Timeout { timeout_ms: 3000 };
+    let e = RuntimeError::Timeout {
+        timeout_ms: 3000,
+        logs: GuestLogs::default(),
+    };
     let hint = e.hint().expect("has hint");
     assert!(hint.contains("3000"));
 }
 
 #[test]
 fn timeout_fix_suggests_increase() {
-    let e = RuntimeError::Timeout { timeout_ms: 5000 };
+    let e = RuntimeError::Timeout {
+        timeout_ms: 5000,
+        logs: GuestLogs::default(),
+    };
     let fix = e.fix().expect("has fix");
     assert!(fix.contains("max_execution_ms"));
 }
@@ -80,31 +86,6 @@ fn rate_limited_hint_mentions_too_many() {
     assert!(hint.to_lowercase().contains("too many"));
 }
 
-#[test]
-fn sandbox_invalid_config_maps_to_invalid_input() {
-    let sandbox = kami_sandbox::SandboxError::InvalidConfig {
-        reason: "bad config".into(),
-    };
-    let err: KamiError = RuntimeError::Sandbox(sandbox).into();
-    assert_eq!(err.kind, ErrorKind::InvalidInput);
-}
-
-#[test]
-fn sandbox_other_maps_to_permission_denied() {
-    let sandbox = kami_sandbox::SandboxError::CapabilityDenied {
-        capability: "net".into(),
-    };
-    let err: KamiError = RuntimeError::Sandbox(sandbox).into();
-    assert_eq!(err.kind, ErrorKind::PermissionDenied);
-}
-
-#[test]
-fn engine_error_maps_to_internal() {
-    let engine = kami_engine::EngineError::Config("bad".into());
-    let err: KamiError = RuntimeError::Engine(engine).into();
-    assert_eq!(err.kind, ErrorKind::Internal);
-}
-
 #[test]
 fn sandbox_error_hint_delegates() {
     let sandbox = kami_sandbox::SandboxError::CapabilityDenied {
@@ -122,3 +103,27 @@ fn engine_error_hint_delegates() {
     let _ = e.hint();
     let _ = e.fix();
 }
+
+#[test]
+fn fuel_exhausted_fix_suggests_raising_max_fuel() {
+    let e = RuntimeError::FuelExhausted {
+        max_fuel: 1_000,
+        logs: GuestLogs::default(),
+    };
+    assert!(e.hint().expect("has hint").contains("1000"));
+    assert!(e.fix().expect("has fix").contains("max_fuel = 10000"));
+    let err: KamiError = e.into();
+    assert_eq!(err.kind, ErrorKind::ResourceExhausted);
+}
+
+#[test]
+fn memory_limit_fix_suggests_raising_max_memory_mb() {
+    let e = RuntimeError::MemoryLimitExceeded {
+        max_memory_mb: 16,
+        logs: GuestLogs::default(),
+    };
+    assert!(e.hint().expect("has hint").contains("16 MiB"));
+    assert!(e.fix().expect("has fix").contains("max_memory_mb = 32"));
+    let err: KamiError = e.into();
+    assert_eq!(err.kind, ErrorKind::ResourceExhausted);
+}
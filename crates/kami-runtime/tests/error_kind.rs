@@ -0,0 +1,35 @@
+//! `KamiError` kind mapping for RuntimeError.
+
+use kami_runtime::RuntimeError;
+use kami_types::{ErrorKind, KamiError};
+
+#[test]
+fn sandbox_invalid_config_maps_to_invalid_input() {
+    let sandbox = kami_sandbox::SandboxError::InvalidConfig {
+        reason: "bad config".into(),
+    };
+    let err: KamiError = RuntimeError::Sandbox(sandbox).into();
+    assert_eq!(err.kind, ErrorKind::InvalidInput);
+}
+
+#[test]
+fn sandbox_other_maps_to_permission_denied() {
+    let sandbox = kami_sandbox::SandboxError::CapabilityDenied {
+        capability: "net".into(),
+    };
+    let err: KamiError = RuntimeError::Sandbox(sandbox).into();
+    assert_eq!(err.kind, ErrorKind::PermissionDenied);
+}
+
+#[test]
+fn engine_error_maps_to_internal() {
+    let engine = kami_engine::EngineError::Config("bad".into());
+    let err: KamiError = RuntimeError::Engine(engine).into();
+    assert_eq!(err.kind, ErrorKind::Internal);
+}
+
+#[test]
+fn engine_epoch_deadline_maps_to_timeout() {
+    let err: KamiError = RuntimeError::Engine(kami_engine::EngineError::EpochDeadline).into();
+    assert_eq!(err.kind, ErrorKind::Timeout);
+}
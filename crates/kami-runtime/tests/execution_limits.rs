@@ -0,0 +1,93 @@
+//! Each resource limit surfaces as its own `RuntimeError` variant.
+
+use kami_engine::{create_engine, create_linker, load_component, InstanceConfig};
+use kami_runtime::{RuntimeError, ToolExecutor, WasmToolExecutor};
+use kami_types::{DiagnosticError, ResourceLimits, SecurityConfig};
+
+mod echo;
+
+async fn execute(
+    pages: u32,
+    prelude: &str,
+    limits: ResourceLimits,
+) -> Result<kami_runtime::ExecutionResult, RuntimeError> {
+    let engine = create_engine(&InstanceConfig::default()).expect("engine");
+    let linker = create_linker(&engine).expect("linker");
+    let wat = echo::echo_after(pages, prelude);
+    let component = load_component(&engine, wat.as_bytes()).expect("component");
+    let executor = WasmToolExecutor::new(engine, linker);
+    let security = SecurityConfig {
+        limits,
+        ..SecurityConfig::default()
+    };
+    executor.execute(&component, "hi", &security).await
+}
+
+#[tokio::test]
+async fn spinning_past_fuel_budget_is_fuel_exhausted() {
+    let limits = ResourceLimits {
+        max_fuel: 10_000,
+        ..ResourceLimits::default()
+    };
+
+    let err = execute(1, "(loop $l (br $l))", limits)
+        .await
+        .expect_err("out of fuel");
+
+    assert!(matches!(
+        err,
+        RuntimeError::FuelExhausted {
+            max_fuel: 10_000,
+            ..
+        }
+    ));
+    assert!(err.fix().expect("has fix").contains("max_fuel"));
+}
+
+#[tokio::test]
+async fn growing_past_cap_is_memory_limit_exceeded() {
+    let limits = ResourceLimits {
+        max_memory_mb: 1,
+        ..ResourceLimits::default()
+    };
+
+    let err = execute(1, "(drop (memory.grow (i32.const 32)))", limits)
+        .await
+        .expect_err("memory cap");
+
+    assert!(matches!(
+        err,
+        RuntimeError::MemoryLimitExceeded {
+            max_memory_mb: 1,
+            ..
+        }
+    ));
+    assert!(err.fix().expect("has fix").contains("max_memory_mb"));
+}
+
+#[tokio::test]
+async fn initial_memory_above_cap_is_memory_limit_exceeded() {
+    let limits = ResourceLimits {
+        max_memory_mb: 1,
+        ..ResourceLimits::default()
+    };
+
+    let err = execute(32, "", limits).await.expect_err("memory cap");
+
+    assert!(matches!(
+        err,
+        RuntimeError::MemoryLimitExceeded {
+            max_memory_mb: 1,
+            ..
+        }
+    ));
+}
+
+#[tokio::test]
+async fn unreachable_is_a_guest_trap() {
+    let err = execute(1, "unreachable", ResourceLimits::default())
+        .await
+        .expect_err("trap");
+
+    assert!(matches!(err, RuntimeError::Trapped { .. }));
+}
@@ -0,0 +1,88 @@
+//! Guest stdout/stderr are captured per execution, never inherited.
+
+use kami_engine::{create_engine, create_linker, load_component, InstanceConfig};
+use kami_runtime::{RuntimeError, ToolExecutor, WasmToolExecutor};
+use kami_types::{ResourceLimits, SecurityConfig};
+use wasmtime::component::Component;
+
+/// Flat component that writes its input to `stream` (`stdout`/`stderr`)
+/// `{REPEAT}` times, runs `{END}`, then returns `Ok(input)`.
+const PRINTER_WAT: &str = include_str!("../../../tests/fixtures/wat/printer.wat");
+
+fn setup(stream: &str, repeat: u32, end: &str) -> (WasmToolExecutor, Component) {
+    let engine = create_engine(&InstanceConfig::default()).expect("engine");
+    let linker = create_linker(&engine).expect("linker");
+    let wat = PRINTER_WAT
+        .replace("{STREAM}", stream)
+        .replace("{REPEAT}", &repeat.to_string())
+        .replace("{END}", end);
+    let component = load_component(&engine, wat.as_bytes()).expect("component");
+    (WasmToolExecutor::new(engine, linker), component)
+}
+
+fn security(max_log_bytes: u64) -> SecurityConfig {
+    SecurityConfig {
+        limits: ResourceLimits {
+            max_log_bytes,
+            ..ResourceLimits::default()
+        },
+        ..SecurityConfig::default()
+    }
+}
+
+#[tokio::test]
+async fn stdout_is_captured_into_result() {
+    let (executor, component) = setup("stdout", 2, "");
+
+    let result = executor
+        .execute(&component, "hi\n", &security(1024))
+        .await
+        .expect("execute");
+
+    assert!(result.success);
+    assert_eq!(result.content, "hi\n");
+    assert_eq!(result.logs.stdout, "hi\nhi\n");
+    assert!(result.logs.stderr.is_empty());
+    assert!(!result.logs.truncated);
+}
+
+#[tokio::test]
+async fn stderr_is_captured_separately() {
+    let (executor, component) = setup("stderr", 1, "");
+
+    let result = executor
+        .execute(&component, "oops", &security(1024))
+        .await
+        .expect("execute");
+
+    assert_eq!(result.logs.stderr, "oops");
+    assert!(result.logs.stdout.is_empty());
+}
+
+#[tokio::test]
+async fn output_beyond_cap_is_truncated_without_failing() {
+    let (executor, component) = setup("stdout", 100, "");
+
+    let result = executor
+        .execute(&component, "0123456789", &security(25))
+        .await
+        .expect("execute");
+
+    assert!(result.success);
+    assert_eq!(result.logs.stdout, "0123456789012345678901234");
+    assert!(result.logs.truncated);
+}
+
+#[tokio::test]
+async fn output_before_a_trap_is_kept_with_the_error() {
+    let (executor, component) = setup("stderr", 1, "unreachable");
+
+    let err = executor
+        .execute(&component, "last words", &security(1024))
+        .await
+        .expect_err("trap");
+
+    assert!(matches!(err, RuntimeError::Trapped { .. }), "{err:?}");
+    let logs = err.logs().expect("logs");
+    assert_eq!(logs.stderr, "last words");
+}
@@ -0,0 +1,129 @@
+//! Guest `kami:tool/kv` calls reach the tool's key/value store.
+
+use std::sync::Arc;
+
+use kami_registry::{KeyValueStore, ToolRepository};
+use kami_runtime::{ExecutionResult, KamiRuntime, RuntimeConfig};
+use kami_store_sqlite::SqliteToolRepository;
+use kami_types::{SecurityConfig, ToolId};
+
+mod common;
+
+const TOOL: &str = "dev.test.memo";
+
+/// Flat component that stores its input under `"count"`, then returns
+/// `Ok(get("count"))`, or `Err` with the error of `set`.
+const MEMO_WAT: &str = r#"
+(component
+  (import "kami:tool/kv@0.1.0" (instance $kv
+    (export "get" (func (param "key" string)
+      (result (result (option (list u8)) (error string)))))
+    (export "set" (func (param "key" string) (param "value" (list u8))
+      (result (result (error string)))))))
+
+  (core module $libc
+    (memory (export "memory") 1)
+    (global $next (mut i32) (i32.const 8192))
+    (func (export "cabi_realloc") (param i32 i32 i32 i32) (result i32)
+      (local $r i32)
+      (local.set $r (global.get $next))
+      (global.set $next (i32.add (global.get $next) (local.get 3)))
+      (local.get $r))
+    (data (i32.const 64) "count"))
+  (core instance $libc (instantiate $libc))
+  (core func $get (canon lower (func $kv "get")
+    (memory $libc "memory") (realloc (func $libc "cabi_realloc"))))
+  (core func $set (canon lower (func $kv "set")
+    (memory $libc "memory") (realloc (func $libc "cabi_realloc"))))
+
+  (core module $m
+    (import "libc" "memory" (memory 1))
+    (import "kv" "get" (func $get (param i32 i32 i32)))
+    (import "kv" "set" (func $set (param i32 i32 i32 i32 i32)))
+    (func (export "run") (param $ptr i32) (param $len i32) (result i32)
+      (call $set (i32.const 64) (i32.const 5) (local.get $ptr) (local.get $len) (i32.const 1024))
+      (if (i32.load8_u (i32.const 1024))
+        (then
+          (i32.store (i32.const 4096) (i32.const 1))
+          (i32.store (i32.const 4100) (i32.load (i32.const 1028)))
+          (i32.store (i32.const 4104) (i32.load (i32.const 1032)))
+          (return (i32.const 4096))))
+      (call $get (i32.const 64) (i32.const 5) (i32.const 1040))
+      (i32.store (i32.const 4096) (i32.const 0))
+      (i32.store (i32.const 4100) (i32.load (i32.const 1048)))
+      (i32.store (i32.const 4104) (i32.load (i32.const 1052)))
+      i32.const 4096)
+    (func (export "cabi_post_run") (param i32)))
+  (core instance $i (instantiate $m
+    (with "libc" (instance $libc))
+    (with "kv" (instance (export "get" (func $get)) (export "set" (func $set))))))
+  (func (export "run")
+    (param "input" string)
+    (result (result string (error string)))
+    (canon lift
+      (core func $i "run")
+      (memory $libc "memory")
+      (realloc (func $libc "cabi_realloc"))
+      (post-return (func $i "cabi_post_run"))))
+)
+"#;
+
+/// Installs the memo tool with `security` and runs it on `input`.
+async fn run_memo(
+    security: SecurityConfig,
+    input: &str,
+) -> (ExecutionResult, Arc<SqliteToolRepository>) {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let repo = Arc::new(SqliteToolRepository::open_in_memory().expect("db"));
+    let tool = common::wat_tool(&dir, TOOL, MEMO_WAT, security);
+    repo.insert(&tool).await.expect("insert");
+    let runtime = KamiRuntime::new(RuntimeConfig::default(), repo.clone())
+        .expect("runtime")
+        .with_key_value_store(repo.clone());
+    let tool_id = ToolId::new(TOOL).expect("id");
+    let result = runtime.execute(&tool_id, input).await.expect("execute");
+    (result, repo)
+}
+
+fn granted() -> SecurityConfig {
+    SecurityConfig {
+        key_value: true,
+        ..common::long_running()
+    }
+}
+
+#[tokio::test]
+async fn granted_tool_persists_values() {
+    let (result, repo) = run_memo(granted(), r#"{"n":1}"#).await;
+
+    assert!(result.success, "unexpected error: {}", result.content);
+    assert_eq!(result.content, r#"{"n":1}"#);
+    let tool_id = ToolId::new(TOOL).expect("id");
+    let stored = repo.get(&tool_id, "count").await.expect("get");
+    assert_eq!(stored.as_deref(), Some(br#"{"n":1}"#.as_slice()));
+}
+
+#[tokio::test]
+async fn tool_without_capability_is_denied() {
+    let (result, repo) = run_memo(common::long_running(), "{}").await;
+
+    assert!(!result.success);
+    assert!(result.content.contains("not granted"), "{}", result.content);
+    let tool_id = ToolId::new(TOOL).expect("id");
+    assert!(repo.list_keys(&tool_id, "").await.expect("list").is_empty());
+}
+
+#[tokio::test]
+async fn write_beyond_quota_is_rejected() {
+    let mut security = granted();
+    security.limits.max_kv_bytes = 8;
+
+    let (result, _) = run_memo(security, r#"{"n":12345}"#).await;
+
+    assert!(!result.success);
+    assert!(
+        result.content.contains("quota of 8 bytes"),
+        "{}",
+        result.content
+    );
+}
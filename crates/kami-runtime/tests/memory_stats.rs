@@ -0,0 +1,52 @@
+//! Per-execution memory usage reported through `ExecutionResult::memory`.
+
+use kami_engine::{create_engine, create_linker, load_component, InstanceConfig};
+use kami_runtime::{ToolExecutor, WasmToolExecutor};
+use kami_types::{ResourceLimits, SecurityConfig};
+
+mod echo;
+
+const PAGE: u64 = 64 * 1024;
+
+async fn run_growing(grow_pages: u32, max_memory_mb: u32) -> kami_runtime::ExecutionResult {
+    let engine = create_engine(&InstanceConfig::default()).expect("engine");
+    let linker = create_linker(&engine).expect("linker");
+    // Starts with 2 pages and 3 table elements, then grows by `grow_pages`.
+    let grow = format!(
+        "(drop (table.grow (ref.null func) (i32.const 3)))
+         (drop (memory.grow (i32.const {grow_pages})))"
+    );
+    let wat = echo::echo_after(2, &grow);
+    let component = load_component(&engine, wat.as_bytes()).expect("component");
+    let executor = WasmToolExecutor::new(engine, linker);
+    let security = SecurityConfig {
+        limits: ResourceLimits {
+            max_memory_mb,
+            ..ResourceLimits::default()
+        },
+        ..SecurityConfig::default()
+    };
+    executor
+        .execute(&component, "hi", &security)
+        .await
+        .expect("execute")
+}
+
+#[tokio::test]
+async fn initial_and_grown_memory_are_reported() {
+    let result = run_growing(3, 1).await;
+
+    assert!(result.success);
+    assert_eq!(result.memory.peak_bytes, 5 * PAGE);
+    assert_eq!(result.memory.current_bytes, 5 * PAGE);
+    assert_eq!(result.memory.limit_bytes, 1024 * 1024);
+    assert_eq!(result.memory.peak_table_elements, 3);
+}
+
+#[tokio::test]
+async fn usage_percent_reflects_the_cap() {
+    let result = run_growing(14, 1).await;
+
+    assert!(result.success);
+    assert!((result.memory.peak_percent() - 100.0).abs() < f64::EPSILON);
+}
@@ -1,6 +1,8 @@
 //! Tests for `ExecutionMetrics` and `MetricsSnapshot`.
 
+use kami_engine::MemoryStats;
 use kami_runtime::{ExecutionMetrics, MetricsSnapshot};
+use kami_types::ToolId;
 
 fn zeroed_snapshot() -> MetricsSnapshot {
     MetricsSnapshot {
@@ -10,6 +12,9 @@ fn zeroed_snapshot() -> MetricsSnapshot {
         total_fuel_consumed: 0,
         cache_hits: 0,
         cache_misses: 0,
+        total_peak_memory_bytes: 0,
+        max_peak_memory_bytes: 0,
+        near_memory_limit: 0,
     }
 }
 
@@ -55,6 +60,38 @@ fn record_cache_hit_and_miss() {
     assert_eq!(s.cache_misses, 1);
 }
 
+fn stats(peak_bytes: u64, limit_bytes: u64) -> MemoryStats {
+    MemoryStats {
+        current_bytes: peak_bytes,
+        peak_bytes,
+        limit_bytes,
+        ..MemoryStats::default()
+    }
+}
+
+fn tool() -> ToolId {
+    ToolId::new("dev.test.memory").expect("id")
+}
+
+#[test]
+fn record_memory_aggregates_peaks() {
+    let m = ExecutionMetrics::default();
+    m.record_memory(&tool(), &stats(100, 1000));
+    m.record_memory(&tool(), &stats(300, 1000));
+    let s = m.snapshot();
+    assert_eq!(s.total_peak_memory_bytes, 400);
+    assert_eq!(s.max_peak_memory_bytes, 300);
+    assert_eq!(s.near_memory_limit, 0);
+}
+
+#[test]
+fn record_memory_flags_executions_near_cap() {
+    let m = ExecutionMetrics::default();
+    assert!(m.record_memory(&tool(), &stats(950, 1000)));
+    assert!(!m.record_memory(&tool(), &stats(10, 1000)));
+    assert_eq!(m.snapshot().near_memory_limit, 1);
+}
+
 #[test]
 fn new_shared_returns_arc_with_defaults() {
     let m = ExecutionMetrics::new_shared();
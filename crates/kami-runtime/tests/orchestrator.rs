@@ -155,6 +155,7 @@ async fn orchestrator_rate_limited_rejects_excess_requests() {
             global: 0,
             window: Duration::from_secs(60),
         },
+        ..RuntimeConfig::default()
     };
     let runtime = KamiRuntime::new(config, Arc::new(EmptyRepository)).expect("runtime");
     let id = ToolId::new("dev.test.ratelimit").expect("id");
@@ -0,0 +1,118 @@
+//! Per-tool grants and overrides apply from a tool's next call on.
+
+use std::sync::Arc;
+
+use kami_registry::ToolPolicyStore;
+use kami_runtime::{KamiRuntime, RuntimeConfig, RuntimeError, SecurityPolicy};
+use kami_sandbox::{PolicyChecker, SandboxError};
+use kami_store_sqlite::SqliteToolRepository;
+use kami_types::{HostPolicy, LimitCaps, SecurityConfig, ToolId};
+
+mod common;
+
+const TOOL: &str = "dev.test.overridden";
+
+/// Flat component whose `run` echoes its input as `Ok(input)`.
+const ECHO_WAT: &str = include_str!("../../../tests/fixtures/wat/echo.wat");
+
+fn id() -> ToolId {
+    ToolId::new(TOOL).expect("id")
+}
+
+fn fuel(max_fuel: u64) -> LimitCaps {
+    LimitCaps {
+        max_fuel: Some(max_fuel),
+        ..LimitCaps::default()
+    }
+}
+
+/// Installs the echo tool and returns a runtime enforcing `repo`'s policies.
+async fn runtime(dir: &tempfile::TempDir, repo: &Arc<SqliteToolRepository>) -> KamiRuntime {
+    let tool = common::wat_tool(dir, TOOL, ECHO_WAT, common::long_running());
+    repo.insert_granted(&tool).await.expect("install");
+    KamiRuntime::new(RuntimeConfig::default(), repo.clone())
+        .expect("runtime")
+        .with_tool_policies(repo.clone())
+}
+
+#[tokio::test]
+async fn override_applies_from_the_next_call() {
+    let dir = tempfile::tempdir().expect("dir");
+    let repo = Arc::new(SqliteToolRepository::open_in_memory().expect("db"));
+    let runtime = runtime(&dir, &repo).await;
+    assert!(runtime.execute(&id(), "hi").await.expect("before").success);
+
+    repo.set_overrides(&id(), &fuel(1)).await.expect("set");
+    let err = runtime.execute(&id(), "hi").await.expect_err("capped");
+    assert!(matches!(
+        err,
+        RuntimeError::FuelExhausted { max_fuel: 1, .. }
+    ));
+
+    repo.clear_overrides(&id()).await.expect("clear");
+    assert!(runtime.execute(&id(), "hi").await.expect("after").success);
+}
+
+#[tokio::test]
+async fn manifest_beyond_its_grant_is_refused() {
+    let dir = tempfile::tempdir().expect("dir");
+    let repo = Arc::new(SqliteToolRepository::open_in_memory().expect("db"));
+    let runtime = runtime(&dir, &repo).await;
+
+    repo.grant(&id(), &SecurityConfig::default())
+        .await
+        .expect("grant");
+    let err = runtime.execute(&id(), "hi").await.expect_err("refused");
+    let RuntimeError::Sandbox(SandboxError::NotGranted { changes, .. }) = err else {
+        panic!("expected NotGranted, got {err:?}");
+    };
+    assert!(changes.contains("max_fuel"));
+
+    repo.grant(&id(), &common::long_running())
+        .await
+        .expect("approve");
+    assert!(runtime.execute(&id(), "hi").await.expect("granted").success);
+}
+
+#[tokio::test]
+async fn effective_config_is_the_tightest_of_manifest_host_and_tool() {
+    let repo = Arc::new(SqliteToolRepository::open_in_memory().expect("db"));
+    repo.set_overrides(&id(), &fuel(500)).await.expect("set");
+    let manifest = common::long_running();
+    repo.grant(&id(), &manifest).await.expect("grant");
+    let host = HostPolicy {
+        limits: LimitCaps {
+            max_memory_mb: Some(16),
+            max_fuel: Some(1_000),
+            ..LimitCaps::default()
+        },
+        ..HostPolicy::default()
+    };
+    let checker = PolicyChecker::new(host).expect("policy");
+    let policy = SecurityPolicy::new(Arc::new(checker)).with_store(repo);
+
+    let effective = policy
+        .effective(&id(), &manifest, None)
+        .await
+        .expect("effective");
+
+    assert_eq!(effective.limits.max_memory_mb, 16);
+    assert_eq!(effective.limits.max_fuel, 500);
+    assert_eq!(effective.limits.max_execution_ms, 60_000);
+}
+
+#[tokio::test]
+async fn tool_without_a_grant_is_refused() {
+    let repo = Arc::new(SqliteToolRepository::open_in_memory().expect("db"));
+    let policy = SecurityPolicy::default().with_store(repo);
+
+    let err = policy
+        .effective(&id(), &SecurityConfig::default(), None)
+        .await
+        .expect_err("never approved");
+
+    assert!(matches!(
+        err,
+        RuntimeError::Sandbox(SandboxError::NotGranted { .. })
+    ));
+}
@@ -0,0 +1,81 @@
+//! Instance pool: warm calls reuse the pre-instantiated component.
+
+use kami_engine::{create_engine, create_linker, load_component, InstanceConfig};
+use kami_runtime::{InstancePool, WasmToolExecutor};
+use kami_types::{SecurityConfig, ToolId};
+
+/// Flat component whose `run` echoes its input as `Ok(input)`.
+const ECHO_WAT: &str = include_str!("../../../tests/fixtures/wat/echo.wat");
+
+fn id(s: &str) -> ToolId {
+    ToolId::new(s).expect("id")
+}
+
+#[tokio::test]
+async fn warm_calls_reuse_pooled_entry() {
+    let engine = create_engine(&InstanceConfig::default()).expect("engine");
+    let linker = create_linker(&engine).expect("linker");
+    let component = load_component(&engine, ECHO_WAT.as_bytes()).expect("component");
+    let executor = WasmToolExecutor::new(engine, linker).with_pool(InstancePool::new(4));
+    let tool = id("dev.test.warm");
+    let security = SecurityConfig::default();
+
+    for input in ["cold", "warm"] {
+        let result = executor
+            .execute_tool(&tool, &component, input, &security)
+            .await
+            .expect("execute");
+        assert_eq!(result.content, input);
+    }
+
+    assert_eq!(executor.pool().len().await, 1);
+    assert!(executor.pool().contains(&tool).await);
+}
+
+#[tokio::test]
+async fn pool_evicts_least_recently_used() {
+    let engine = create_engine(&InstanceConfig::default()).expect("engine");
+    let linker = create_linker(&engine).expect("linker");
+    let component = load_component(&engine, ECHO_WAT.as_bytes()).expect("component");
+    let pool = InstancePool::new(2);
+
+    for name in ["dev.test.a", "dev.test.b", "dev.test.a", "dev.test.c"] {
+        pool.get_or_prepare(&id(name), &component, &linker)
+            .await
+            .expect("prepare");
+    }
+
+    assert_eq!(pool.len().await, 2);
+    assert!(pool.contains(&id("dev.test.a")).await);
+    assert!(!pool.contains(&id("dev.test.b")).await);
+}
+
+#[tokio::test]
+async fn zero_size_pool_never_stores() {
+    let engine = create_engine(&InstanceConfig::default()).expect("engine");
+    let linker = create_linker(&engine).expect("linker");
+    let component = load_component(&engine, ECHO_WAT.as_bytes()).expect("component");
+    let pool = InstancePool::new(0);
+
+    pool.get_or_prepare(&id("dev.test.off"), &component, &linker)
+        .await
+        .expect("prepare");
+
+    assert!(pool.is_empty().await);
+}
+
+#[tokio::test]
+async fn invalidate_drops_pooled_entry() {
+    let engine = create_engine(&InstanceConfig::default()).expect("engine");
+    let linker = create_linker(&engine).expect("linker");
+    let component = load_component(&engine, ECHO_WAT.as_bytes()).expect("component");
+    let pool = InstancePool::new(2);
+    let tool = id("dev.test.stale");
+    pool.get_or_prepare(&tool, &component, &linker)
+        .await
+        .expect("prepare");
+
+    pool.invalidate(&tool).await;
+
+    assert!(!pool.contains(&tool).await);
+}
@@ -0,0 +1,78 @@
+//! Guest `report-progress` calls reach the execution's `ProgressSink`.
+
+use std::sync::{Arc, Mutex};
+
+use kami_runtime::{Progress, ProgressSink, RuntimeConfig};
+use kami_types::ToolId;
+
+mod common;
+mod serve;
+
+/// Flat component that reports `1/4`, `2/4 "halfway"` and an invalid NaN
+/// update, then returns `Ok(input)`.
+const REPORTER_WAT: &str = include_str!("../../../tests/fixtures/wat/reporter.wat");
+
+#[derive(Default)]
+struct Recorder(Mutex<Vec<Progress>>);
+
+impl ProgressSink for Recorder {
+    fn report(&self, progress: Progress) {
+        self.0.lock().expect("lock").push(progress);
+    }
+}
+
+#[tokio::test]
+async fn guest_progress_reaches_the_sink() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let runtime = serve::wat_runtime(
+        &dir,
+        "dev.test.reporter",
+        REPORTER_WAT,
+        RuntimeConfig::default(),
+    )
+    .await;
+    let recorder = Arc::new(Recorder::default());
+    let tool = ToolId::new("dev.test.reporter").expect("id");
+
+    let id = runtime.next_execution_id();
+    let result = runtime
+        .execute_with_progress(id, &tool, "{}", recorder.clone())
+        .await
+        .expect("execute");
+
+    assert!(result.success);
+    let reports = recorder.0.lock().expect("lock").clone();
+    assert_eq!(
+        reports,
+        vec![
+            Progress {
+                progress: 1.0,
+                total: Some(4.0),
+                message: None
+            },
+            Progress {
+                progress: 2.0,
+                total: Some(4.0),
+                message: Some("halfway".to_string())
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn progress_without_sink_is_ignored() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let runtime = serve::wat_runtime(
+        &dir,
+        "dev.test.reporter",
+        REPORTER_WAT,
+        RuntimeConfig::default(),
+    )
+    .await;
+    let tool = ToolId::new("dev.test.reporter").expect("id");
+
+    let result = runtime.execute(&tool, "{}").await.expect("execute");
+
+    assert!(result.success);
+    assert_eq!(result.content, "{}");
+}
@@ -88,6 +88,7 @@ async fn resolve_returns_not_found_when_wasm_file_missing() {
             wasm: "nonexistent.wasm".to_string(),
             description: "Tool with missing WASM".to_string(),
             arguments: vec![],
+            output: vec![],
             security: SecurityConfig::default(),
             wasm_sha256: None,
             signature: None,
@@ -137,6 +138,7 @@ async fn resolve_integrity_violation_on_wrong_hash() {
             wasm: wasm_filename,
             description: "Integrity test".to_string(),
             arguments: vec![],
+            output: vec![],
             security: SecurityConfig::default(),
             wasm_sha256: Some("0".repeat(64)),
             signature: None,
@@ -0,0 +1,131 @@
+//! Per-tool sandbox directories mounted by the executor.
+
+use kami_engine::{create_engine, create_linker, load_component, InstanceConfig};
+use kami_runtime::WasmToolExecutor;
+use kami_sandbox::{SandboxDirMode, SandboxDirs};
+use kami_types::{FsAccess, SecurityConfig, ToolId};
+use wasmtime::component::Component;
+
+/// Flat component whose `run` echoes its input as `Ok(input)`.
+const ECHO_WAT: &str = include_str!("../../../tests/fixtures/wat/echo.wat");
+
+/// Component whose `run` writes "kami" to the file named by its input,
+/// relative to its first preopened directory.
+const WRITER_WAT: &str = include_str!("../../../tests/fixtures/wat/writer.wat");
+
+fn setup(root: &std::path::Path, mode: SandboxDirMode) -> (WasmToolExecutor, Component) {
+    setup_with(root, mode, ECHO_WAT)
+}
+
+fn setup_with(
+    root: &std::path::Path,
+    mode: SandboxDirMode,
+    wat: &str,
+) -> (WasmToolExecutor, Component) {
+    let engine = create_engine(&InstanceConfig::default()).expect("engine");
+    let linker = create_linker(&engine).expect("linker");
+    let component = load_component(&engine, wat.as_bytes()).expect("component");
+    let executor =
+        WasmToolExecutor::new(engine, linker).with_sandbox_dirs(SandboxDirs::new(root, mode));
+    (executor, component)
+}
+
+fn security(fs_access: FsAccess) -> SecurityConfig {
+    SecurityConfig {
+        fs_access,
+        ..SecurityConfig::default()
+    }
+}
+
+#[tokio::test]
+async fn sandbox_access_creates_persistent_tool_dir() {
+    let root = tempfile::tempdir().expect("tempdir");
+    let (executor, component) = setup(root.path(), SandboxDirMode::Persistent);
+    let id = ToolId::new("dev.test.files").expect("id");
+
+    let result = executor
+        .execute_tool(&id, &component, "hi", &security(FsAccess::Sandbox))
+        .await
+        .expect("execute");
+
+    assert!(result.success);
+    assert!(root.path().join("dev.test.files").is_dir());
+}
+
+#[tokio::test]
+async fn read_only_access_also_mounts_dir() {
+    let root = tempfile::tempdir().expect("tempdir");
+    let (executor, component) = setup(root.path(), SandboxDirMode::Persistent);
+    let id = ToolId::new("dev.test.reader").expect("id");
+
+    executor
+        .execute_tool(&id, &component, "hi", &security(FsAccess::ReadOnly))
+        .await
+        .expect("execute");
+
+    assert!(root.path().join("dev.test.reader").is_dir());
+}
+
+#[tokio::test]
+async fn no_fs_access_creates_nothing() {
+    let root = tempfile::tempdir().expect("tempdir");
+    let (executor, component) = setup(root.path(), SandboxDirMode::Persistent);
+    let id = ToolId::new("dev.test.nofs").expect("id");
+
+    executor
+        .execute_tool(&id, &component, "hi", &security(FsAccess::None))
+        .await
+        .expect("execute");
+
+    assert!(!root.path().join("dev.test.nofs").exists());
+}
+
+#[tokio::test]
+async fn guest_writes_inside_its_dir() {
+    let root = tempfile::tempdir().expect("tempdir");
+    let (executor, component) = setup_with(root.path(), SandboxDirMode::Persistent, WRITER_WAT);
+    let id = ToolId::new("dev.test.writer").expect("id");
+
+    let result = executor
+        .execute_tool(&id, &component, "note.txt", &security(FsAccess::Sandbox))
+        .await
+        .expect("execute");
+
+    assert!(result.success, "{}", result.content);
+    let written = root.path().join("dev.test.writer").join("note.txt");
+    assert_eq!(std::fs::read_to_string(written).expect("note"), "kami");
+}
+
+#[tokio::test]
+async fn guest_cannot_reach_outside_its_dir() {
+    let root = tempfile::tempdir().expect("tempdir");
+    let (executor, component) = setup_with(root.path(), SandboxDirMode::Persistent, WRITER_WAT);
+    let id = ToolId::new("dev.test.escape").expect("id");
+    let outside = root.path().join("escaped.txt");
+
+    for path in ["../escaped.txt".to_string(), outside.display().to_string()] {
+        let result = executor
+            .execute_tool(&id, &component, &path, &security(FsAccess::Sandbox))
+            .await
+            .expect("execute");
+        assert!(!result.success, "{path} was reachable");
+    }
+    assert!(!outside.exists());
+}
+
+#[tokio::test]
+async fn scratch_dir_is_removed_after_call() {
+    let root = tempfile::tempdir().expect("tempdir");
+    let (executor, component) = setup_with(root.path(), SandboxDirMode::Scratch, WRITER_WAT);
+    let id = ToolId::new("dev.test.scratch").expect("id");
+
+    let result = executor
+        .execute_tool(&id, &component, "note.txt", &security(FsAccess::Sandbox))
+        .await
+        .expect("execute");
+
+    assert!(result.success, "{}", result.content);
+    let scratch = root.path().join(".scratch");
+    assert!(scratch.is_dir());
+    assert_eq!(std::fs::read_dir(&scratch).expect("scratch").count(), 0);
+}
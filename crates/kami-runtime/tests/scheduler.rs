@@ -1,6 +1,6 @@
 //! External scheduler tests for drain and concurrency coverage.
 
-use kami_runtime::{Scheduler, SchedulerConfig};
+use kami_runtime::{ExecutionWorkers, RuntimeConfig, Scheduler, SchedulerConfig};
 
 #[test]
 fn scheduler_max_concurrent_accessor() {
@@ -38,3 +38,41 @@ async fn scheduler_drain_waits_for_in_flight() {
     handle.await.expect("drain completes");
     assert_eq!(s.available_permits(), 2);
 }
+
+#[test]
+fn execution_workers_need_a_thread() {
+    assert!(ExecutionWorkers::start(0).is_err());
+}
+
+#[tokio::test]
+async fn scheduler_runs_inline_without_workers() {
+    let s = Scheduler::new(&SchedulerConfig::default());
+    assert_eq!(s.worker_threads(), 0);
+    let value = s.run(async { 7 }).await.expect("run");
+    assert_eq!(value, 7);
+}
+
+#[tokio::test]
+async fn scheduler_runs_on_dedicated_workers() {
+    let workers = ExecutionWorkers::start(2).expect("workers");
+    let s = Scheduler::new(&SchedulerConfig::default()).with_workers(workers);
+    assert_eq!(s.worker_threads(), 2);
+
+    let name = s
+        .run(async { std::thread::current().name().map(str::to_owned) })
+        .await
+        .expect("run");
+
+    assert_eq!(name.as_deref(), Some("kami-exec"));
+    // Dropping the scheduler here must not block inside this runtime.
+}
+
+#[tokio::test]
+async fn runtime_config_attaches_workers() {
+    let config = RuntimeConfig {
+        execution_threads: 1,
+        ..RuntimeConfig::default()
+    };
+    assert_eq!(config.scheduler().worker_threads(), 1);
+    assert_eq!(RuntimeConfig::default().scheduler().worker_threads(), 0);
+}
@@ -0,0 +1,122 @@
+//! Guest `get-secret` calls read declared secrets and are audited.
+
+use std::sync::Arc;
+
+use kami_registry::{AuditLog, SecretStore, ToolRepository};
+use kami_runtime::{ExecutionResult, KamiRuntime, RuntimeConfig};
+use kami_store_sqlite::{SecretKey, SqliteSecretStore, SqliteToolRepository};
+use kami_types::{DomainEvent, SecretAccess, SecurityConfig, ToolId};
+
+mod common;
+
+const TOOL: &str = "dev.test.vault";
+
+/// Flat component returning `get-secret(input)` unchanged: both results
+/// share the `result<string, string>` layout.
+const VAULT_WAT: &str = r#"
+(component
+  (import "kami:tool/secrets@0.1.0" (instance $host
+    (export "get-secret" (func (param "name" string)
+      (result (result string (error string)))))))
+
+  (core module $libc
+    (memory (export "memory") 1)
+    (global $next (mut i32) (i32.const 8192))
+    (func (export "cabi_realloc") (param i32 i32 i32 i32) (result i32)
+      (local $r i32)
+      (local.set $r (global.get $next))
+      (global.set $next (i32.add (global.get $next) (local.get 3)))
+      (local.get $r)))
+  (core instance $libc (instantiate $libc))
+  (core func $get_secret (canon lower (func $host "get-secret")
+    (memory $libc "memory") (realloc (func $libc "cabi_realloc"))))
+
+  (core module $m
+    (import "libc" "memory" (memory 1))
+    (import "host" "get-secret" (func $get_secret (param i32 i32 i32)))
+    (func (export "run") (param $ptr i32) (param $len i32) (result i32)
+      (call $get_secret (local.get $ptr) (local.get $len) (i32.const 4096))
+      i32.const 4096)
+    (func (export "cabi_post_run") (param i32)))
+  (core instance $i (instantiate $m
+    (with "libc" (instance $libc))
+    (with "host" (instance (export "get-secret" (func $get_secret))))))
+  (func (export "run")
+    (param "input" string)
+    (result (result string (error string)))
+    (canon lift
+      (core func $i "run")
+      (memory $libc "memory")
+      (realloc (func $libc "cabi_realloc"))
+      (post-return (func $i "cabi_post_run"))))
+)
+"#;
+
+/// Installs the vault tool declaring `API_TOKEN` (set) and `UNSET`, then
+/// asks it for the secret `name`.
+async fn run_vault(name: &str) -> (ExecutionResult, Arc<SqliteToolRepository>) {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let repo = Arc::new(SqliteToolRepository::open_in_memory().expect("db"));
+    let security = SecurityConfig {
+        secrets: vec!["API_TOKEN".to_string(), "UNSET".to_string()],
+        ..common::long_running()
+    };
+    let tool = common::wat_tool(&dir, TOOL, VAULT_WAT, security);
+    repo.insert(&tool).await.expect("insert");
+    let (key, _) = SecretKey::generate().expect("key");
+    let store = Arc::new(SqliteSecretStore::new(repo.clone(), key));
+    store.set("API_TOKEN", "t0ken").await.expect("set");
+    store.set("OTHER", "not-yours").await.expect("set");
+    let runtime = KamiRuntime::new(RuntimeConfig::default(), repo.clone())
+        .expect("runtime")
+        .with_secrets(store, repo.clone());
+    let tool_id = ToolId::new(TOOL).expect("id");
+    let result = runtime.execute(&tool_id, name).await.expect("execute");
+    (result, repo)
+}
+
+/// Returns the access recorded by the latest audit event.
+async fn last_access(repo: &SqliteToolRepository) -> (String, SecretAccess) {
+    let events = repo.recent(1).await.expect("audit");
+    match events.first() {
+        Some(DomainEvent::SecretAccessed {
+            tool_id: Some(tool),
+            name,
+            access,
+            ..
+        }) => {
+            assert_eq!(tool.as_str(), TOOL);
+            (name.clone(), *access)
+        }
+        other => panic!("unexpected audit entry: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn declared_secret_is_returned_and_audited() {
+    let (result, repo) = run_vault("API_TOKEN").await;
+
+    assert!(result.success, "unexpected error: {}", result.content);
+    assert_eq!(result.content, "t0ken");
+    let access = last_access(&repo).await;
+    assert_eq!(access, ("API_TOKEN".to_string(), SecretAccess::Read));
+}
+
+#[tokio::test]
+async fn undeclared_secret_is_denied_and_audited() {
+    let (result, repo) = run_vault("OTHER").await;
+
+    assert!(!result.success);
+    assert!(result.content.contains("not granted"), "{}", result.content);
+    assert!(!result.content.contains("not-yours"));
+    let access = last_access(&repo).await;
+    assert_eq!(access, ("OTHER".to_string(), SecretAccess::Denied));
+}
+
+#[tokio::test]
+async fn declared_but_unset_secret_fails() {
+    let (result, _) = run_vault("UNSET").await;
+
+    assert!(!result.success);
+    assert!(result.content.contains("not set"), "{}", result.content);
+}
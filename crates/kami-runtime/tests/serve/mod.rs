@@ -0,0 +1,27 @@
+//! Runtime serving a WAT tool, for the integration tests that only call it.
+//!
+//! Kept apart from `common` so tests that build their own runtime do not
+//! compile it unused.
+
+use std::sync::Arc;
+
+use kami_registry::ToolRepository;
+use kami_runtime::{KamiRuntime, RuntimeConfig};
+use kami_store_sqlite::SqliteToolRepository;
+
+use crate::common::{long_running, wat_tool};
+
+/// Installs the component `wat` as tool `id` under `dir` and returns a
+/// runtime serving it.
+pub async fn wat_runtime(
+    dir: &tempfile::TempDir,
+    id: &str,
+    wat: &str,
+    config: RuntimeConfig,
+) -> KamiRuntime {
+    let repo = Arc::new(SqliteToolRepository::open_in_memory().expect("db"));
+    repo.insert(&wat_tool(dir, id, wat, long_running()))
+        .await
+        .expect("insert");
+    KamiRuntime::new(config, repo).expect("runtime")
+}
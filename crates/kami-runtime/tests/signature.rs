@@ -2,7 +2,9 @@
 
 use std::io::Write;
 
-use kami_runtime::{generate_keypair, public_key_from_secret, sign_file, verify_file_signature};
+use kami_runtime::{
+    generate_keypair, public_key_from_secret, sign_file, verify_file_signature, verify_signature,
+};
 use tempfile::NamedTempFile;
 
 fn tmp_file(content: &[u8]) -> NamedTempFile {
@@ -45,6 +47,15 @@ fn verify_rejects_tampered_file() {
     assert!(verify_file_signature(f.path(), &sig, &kp.public_key).is_err());
 }
 
+#[test]
+fn verify_signature_checks_the_given_bytes() {
+    let kp = generate_keypair();
+    let f = tmp_file(b"compiled");
+    let sig = sign_file(f.path(), &kp.secret_key).expect("sign");
+    assert!(verify_signature(b"compiled", &sig, &kp.public_key).is_ok());
+    assert!(verify_signature(b"swapped", &sig, &kp.public_key).is_err());
+}
+
 #[test]
 fn public_key_derivation() {
     let kp = generate_keypair();
@@ -0,0 +1,82 @@
+//! Concurrency tests: one execution's timeout must not interrupt another.
+
+use std::sync::Arc;
+
+use kami_engine::{create_engine, create_linker, load_component, InstanceConfig};
+use kami_runtime::{ExecutionResult, RuntimeError, ToolExecutor, WasmToolExecutor};
+use kami_types::{ResourceLimits, SecurityConfig};
+use wasmtime::component::Component;
+
+mod echo;
+
+const SPIN_WAT: &str = include_str!("../../../tests/fixtures/wat/spin.wat");
+const COUNT_DOWN: &str = include_str!("../../../tests/fixtures/wat/count_down.wat");
+
+fn setup() -> (Arc<WasmToolExecutor>, Component, Component) {
+    let config = InstanceConfig {
+        epoch_interruption: true,
+        ..InstanceConfig::default()
+    };
+    let engine = create_engine(&config).expect("engine");
+    let linker = create_linker(&engine).expect("linker");
+    let spin = load_component(&engine, SPIN_WAT.as_bytes()).expect("spin");
+    // Counts down from 2^29 before echoing (a few hundred milliseconds).
+    let long_wat = echo::echo_after(1, &COUNT_DOWN.replace("{COUNT}", "536870912"));
+    let long = load_component(&engine, long_wat.as_bytes()).expect("long");
+    let executor = Arc::new(WasmToolExecutor::new(engine, linker));
+    (executor, spin, long)
+}
+
+fn security(max_execution_ms: u64) -> SecurityConfig {
+    SecurityConfig {
+        limits: ResourceLimits {
+            max_fuel: 10_000_000_000,
+            max_execution_ms,
+            ..ResourceLimits::default()
+        },
+        ..SecurityConfig::default()
+    }
+}
+
+#[tokio::test]
+async fn spinning_tool_is_interrupted_at_its_own_deadline() {
+    let (executor, spin, _) = setup();
+
+    let result = executor.execute(&spin, "x", &security(50)).await;
+
+    assert!(matches!(
+        result,
+        Err(RuntimeError::Timeout { timeout_ms: 50, .. })
+    ));
+}
+
+/// Runs one execution on its own blocking thread so neither call can
+/// starve the other of a runtime worker.
+fn spawn_execute(
+    executor: &Arc<WasmToolExecutor>,
+    component: Component,
+    input: &'static str,
+    timeout_ms: u64,
+) -> tokio::task::JoinHandle<Result<ExecutionResult, RuntimeError>> {
+    let executor = Arc::clone(executor);
+    let handle = tokio::runtime::Handle::current();
+    tokio::task::spawn_blocking(move || {
+        handle.block_on(executor.execute(&component, input, &security(timeout_ms)))
+    })
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn short_timeout_does_not_interrupt_long_neighbour() {
+    let (executor, spin, long) = setup();
+
+    let long_task = spawn_execute(&executor, long, "survived", 30_000);
+    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    let short_task = spawn_execute(&executor, spin, "x", 50);
+
+    let short = short_task.await.expect("short join");
+    let long = long_task.await.expect("long join").expect("long run");
+
+    assert!(short.is_err(), "short tool must time out");
+    assert!(long.success);
+    assert_eq!(long.content, "survived");
+}
@@ -0,0 +1,80 @@
+//! Disabled tools are refused, from the next call on when toggled at runtime.
+
+use std::sync::Arc;
+
+use kami_registry::ToolRepository;
+use kami_runtime::{KamiRuntime, RuntimeConfig, RuntimeError};
+use kami_store_sqlite::SqliteToolRepository;
+use kami_types::ToolId;
+
+mod common;
+mod serve;
+
+const TOOL: &str = "dev.test.toggled";
+
+/// Flat component whose `run` echoes its input as `Ok(input)`.
+const ECHO_WAT: &str = include_str!("../../../tests/fixtures/wat/echo.wat");
+
+fn id() -> ToolId {
+    ToolId::new(TOOL).expect("id")
+}
+
+#[tokio::test]
+async fn disabled_tool_is_refused() {
+    let dir = tempfile::tempdir().expect("dir");
+    let repo = Arc::new(SqliteToolRepository::open_in_memory().expect("db"));
+    let mut tool = common::wat_tool(&dir, TOOL, ECHO_WAT, common::long_running());
+    tool.enabled = false;
+    repo.insert(&tool).await.expect("insert");
+    let runtime = KamiRuntime::new(RuntimeConfig::default(), repo).expect("runtime");
+
+    let err = runtime.execute(&id(), "hi").await.expect_err("disabled");
+
+    assert!(matches!(err, RuntimeError::ToolDisabled { .. }), "{err:?}");
+}
+
+#[tokio::test]
+async fn toggling_applies_to_a_cached_tool() {
+    let dir = tempfile::tempdir().expect("dir");
+    let runtime = serve::wat_runtime(&dir, TOOL, ECHO_WAT, RuntimeConfig::default()).await;
+    assert!(runtime.execute(&id(), "hi").await.expect("cached").success);
+
+    assert!(runtime.set_enabled(&id(), false).await.expect("disable"));
+    let err = runtime.execute(&id(), "hi").await.expect_err("disabled");
+    assert!(matches!(err, RuntimeError::ToolDisabled { .. }), "{err:?}");
+
+    assert!(!runtime.set_enabled(&id(), false).await.expect("again"));
+    assert!(runtime.set_enabled(&id(), true).await.expect("enable"));
+    assert!(runtime.execute(&id(), "hi").await.expect("enabled").success);
+}
+
+#[tokio::test]
+async fn disabling_in_the_registry_refuses_a_warm_tool() {
+    let dir = tempfile::tempdir().expect("dir");
+    let repo = Arc::new(SqliteToolRepository::open_in_memory().expect("db"));
+    let mut tool = common::wat_tool(&dir, TOOL, ECHO_WAT, common::long_running());
+    repo.insert(&tool).await.expect("insert");
+    let runtime = KamiRuntime::new(RuntimeConfig::default(), repo.clone()).expect("runtime");
+    assert!(runtime.execute(&id(), "hi").await.expect("warm").success);
+
+    // As `kami disable` does from another process: the cache is untouched.
+    tool.enabled = false;
+    repo.update(&tool).await.expect("update");
+    let err = runtime.execute(&id(), "hi").await.expect_err("disabled");
+
+    assert!(matches!(err, RuntimeError::ToolDisabled { .. }), "{err:?}");
+}
+
+#[tokio::test]
+async fn toggling_an_unknown_tool_fails() {
+    let dir = tempfile::tempdir().expect("dir");
+    let runtime = serve::wat_runtime(&dir, TOOL, ECHO_WAT, RuntimeConfig::default()).await;
+    let other = ToolId::new("dev.test.absent").expect("id");
+
+    let err = runtime
+        .set_enabled(&other, false)
+        .await
+        .expect_err("absent");
+
+    assert!(matches!(err, RuntimeError::ToolNotFound { .. }), "{err:?}");
+}
@@ -0,0 +1,70 @@
+//! Only signatures by an operator-trusted key lift the `[unsigned]` caps.
+
+use std::sync::Arc;
+
+use kami_engine::{create_engine, InstanceConfig};
+use kami_registry::ToolRepository;
+use kami_runtime::{generate_keypair, sign_file, ComponentCache, SecurityPolicy, ToolResolver};
+use kami_sandbox::PolicyChecker;
+use kami_store_sqlite::SqliteToolRepository;
+use kami_types::{HostPolicy, LimitCaps, ToolId};
+
+mod common;
+
+const TOOL: &str = "dev.test.signed";
+
+/// Installs an empty component signed by a fresh key and returns the key
+/// its signature was verified against by the resolver.
+async fn resolved_signer(dir: &tempfile::TempDir) -> Option<String> {
+    let keys = generate_keypair();
+    let mut tool = common::wat_tool(dir, TOOL, "(component)", common::long_running());
+    let wasm = dir.path().join(&tool.manifest.wasm);
+    tool.manifest.signature = Some(sign_file(&wasm, &keys.secret_key).expect("sign"));
+    tool.manifest.signer_public_key = Some(keys.public_key);
+    let repo = Arc::new(SqliteToolRepository::open_in_memory().expect("db"));
+    repo.insert(&tool).await.expect("insert");
+
+    let engine = create_engine(&InstanceConfig::default()).expect("engine");
+    let resolver = ToolResolver::new(engine, ComponentCache::new(4), repo);
+    let id = ToolId::new(TOOL).expect("id");
+    resolver.resolve(&id).await.expect("resolve").signer
+}
+
+/// Returns the fuel a tool signed by `signer` runs with when only
+/// `trusted` is trusted and unsigned tools are capped at 1000.
+async fn fuel(signer: Option<&str>, trusted: &str) -> u64 {
+    let host = HostPolicy {
+        unsigned: LimitCaps {
+            max_fuel: Some(1_000),
+            ..LimitCaps::default()
+        },
+        trusted_keys: vec![trusted.to_string()],
+        ..HostPolicy::default()
+    };
+    let policy = SecurityPolicy::new(Arc::new(PolicyChecker::new(host).expect("policy")));
+    let id = ToolId::new(TOOL).expect("id");
+    let effective = policy
+        .effective(&id, &common::long_running(), signer)
+        .await
+        .expect("effective");
+    effective.limits.max_fuel
+}
+
+#[tokio::test]
+async fn self_signed_tool_gets_the_unsigned_caps() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let signer = resolved_signer(&dir).await;
+    assert!(signer.is_some(), "the signature verifies");
+
+    let publisher = generate_keypair().public_key;
+    assert_eq!(fuel(signer.as_deref(), &publisher).await, 1_000);
+}
+
+#[tokio::test]
+async fn trusted_signer_escapes_the_unsigned_caps() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let signer = resolved_signer(&dir).await.expect("signer");
+
+    let fuel = fuel(Some(&signer), &signer).await;
+    assert_eq!(fuel, common::long_running().limits.max_fuel);
+}
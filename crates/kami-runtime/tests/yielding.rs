@@ -0,0 +1,87 @@
+//! A CPU-bound guest yields so other tasks on the same runtime progress.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use kami_engine::{create_engine, create_linker, load_component, InstanceConfig, YieldStrategy};
+use kami_runtime::{RuntimeError, ToolExecutor, WasmToolExecutor};
+use kami_types::{ResourceLimits, SecurityConfig};
+
+mod echo;
+
+const COUNT_DOWN: &str = include_str!("../../../tests/fixtures/wat/count_down.wat");
+
+fn security(max_execution_ms: u64) -> SecurityConfig {
+    SecurityConfig {
+        limits: ResourceLimits {
+            max_fuel: 10_000_000_000,
+            max_execution_ms,
+            ..ResourceLimits::default()
+        },
+        ..SecurityConfig::default()
+    }
+}
+
+/// Runs the long loop while a 1 ms ticker task shares the (single-threaded)
+/// runtime; returns the execution result and how often the ticker ran.
+async fn run_with_ticker(
+    strategy: YieldStrategy,
+    max_execution_ms: u64,
+) -> (Result<kami_runtime::ExecutionResult, RuntimeError>, u64) {
+    let config = InstanceConfig {
+        epoch_interruption: true,
+        yield_strategy: strategy,
+        ..InstanceConfig::default()
+    };
+    let engine = create_engine(&config).expect("engine");
+    let linker = create_linker(&engine).expect("linker");
+    // Counts down from 2^28 before echoing.
+    let wat = echo::echo_after(1, &COUNT_DOWN.replace("{COUNT}", "268435456"));
+    let component = load_component(&engine, wat.as_bytes()).expect("component");
+    let executor = WasmToolExecutor::new(engine, linker).with_yield_strategy(strategy);
+
+    let ticks = Arc::new(AtomicU64::new(0));
+    let counter = Arc::clone(&ticks);
+    let ticker = tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            counter.fetch_add(1, Ordering::Relaxed);
+        }
+    });
+    // Let the ticker arm its first timer before the guest starts.
+    tokio::task::yield_now().await;
+
+    let result = executor
+        .execute(&component, "x", &security(max_execution_ms))
+        .await;
+    let seen = ticks.load(Ordering::Relaxed);
+    ticker.abort();
+    (result, seen)
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn fuel_yielding_lets_other_tasks_run() {
+    let (result, ticks) = run_with_ticker(YieldStrategy::default(), 60_000).await;
+
+    assert!(result.expect("execute").success);
+    assert!(ticks > 0, "ticker never ran while the guest was busy");
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn epoch_yielding_lets_other_tasks_run() {
+    let (result, ticks) = run_with_ticker(YieldStrategy::Epoch, 60_000).await;
+
+    assert!(result.expect("execute").success);
+    assert!(ticks > 0, "ticker never ran while the guest was busy");
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn epoch_yielding_still_enforces_the_timeout() {
+    let (result, _) = run_with_ticker(YieldStrategy::Epoch, 20).await;
+
+    assert!(matches!(
+        result,
+        Err(RuntimeError::Timeout { timeout_ms: 20, .. })
+    ));
+}
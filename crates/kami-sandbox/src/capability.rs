@@ -1,25 +1,80 @@
 //! Capability checking trait and types.
 
+use std::fmt;
+use std::net::IpAddr;
+use std::sync::Arc;
+
 use kami_types::{Capability, FsAccess, SecurityConfig};
 
 use crate::error::SandboxError;
-use crate::network::is_host_allowed;
+use crate::network::{is_host_allowed, is_public, NetworkDenyReason};
 
 /// Trait for checking capabilities against a security config.
-pub trait CapabilityChecker: Send + Sync {
+///
+/// The runtime consults its checker on every socket connect, HTTP
+/// request, filesystem mount and environment variable exposed to a tool.
+pub trait CapabilityChecker: Send + Sync + fmt::Debug {
     /// Checks whether a capability is allowed by the security config.
     fn check(&self, capability: &Capability, config: &SecurityConfig) -> Result<(), SandboxError>;
+
+    /// Returns the config a tool actually runs with; `signer` is the key
+    /// its signature was verified against, if any. The default keeps the
+    /// manifest's config.
+    fn restrict(&self, config: &SecurityConfig, signer: Option<&str>) -> SecurityConfig {
+        let _ = signer;
+        config.clone()
+    }
+}
+
+/// A checker bound to the security config of one execution.
+#[derive(Debug, Clone)]
+pub struct CapabilityGuard {
+    checker: Arc<dyn CapabilityChecker>,
+    config: Arc<SecurityConfig>,
+}
+
+impl CapabilityGuard {
+    /// Binds `checker` to `config`.
+    pub fn new(checker: Arc<dyn CapabilityChecker>, config: SecurityConfig) -> Self {
+        Self {
+            checker,
+            config: Arc::new(config),
+        }
+    }
+
+    /// Checks `capability` against the bound config.
+    ///
+    /// # Errors
+    ///
+    /// Returns the checker's refusal.
+    pub fn check(&self, capability: &Capability) -> Result<(), SandboxError> {
+        self.checker.check(capability, &self.config)
+    }
 }
 
 /// Default capability checker enforcing deny-all by default.
+#[derive(Debug, Clone, Copy, Default)]
 pub struct DefaultCapabilityChecker;
 
 impl CapabilityChecker for DefaultCapabilityChecker {
     fn check(&self, capability: &Capability, config: &SecurityConfig) -> Result<(), SandboxError> {
         match capability {
             Capability::Network(host) => {
-                if !is_host_allowed(host, &config.net_allow_list) {
-                    return Err(SandboxError::NetworkDenied { host: host.clone() });
+                let literal = host.trim_matches(['[', ']']).parse::<IpAddr>();
+                let reason = match literal {
+                    Ok(ip) if !config.allow_private_networks && !is_public(ip) => {
+                        Some(NetworkDenyReason::NonPublicAddress(ip))
+                    }
+                    _ if !is_host_allowed(host, &config.net_allow_list) => {
+                        Some(NetworkDenyReason::NotAllowListed)
+                    }
+                    _ => None,
+                };
+                if let Some(reason) = reason {
+                    return Err(SandboxError::NetworkDenied {
+                        host: host.clone(),
+                        reason,
+                    });
                 }
             }
             Capability::FsRead(path) => {
@@ -39,35 +94,21 @@ impl CapabilityChecker for DefaultCapabilityChecker {
                     });
                 }
             }
+            Capability::KeyValue => {
+                if !config.key_value {
+                    return Err(SandboxError::CapabilityDenied {
+                        capability: "key-value".to_string(),
+                    });
+                }
+            }
+            Capability::Secret(name) => {
+                if !config.secrets.iter().any(|s| s == name) {
+                    return Err(SandboxError::CapabilityDenied {
+                        capability: format!("secret:{name}"),
+                    });
+                }
+            }
         }
         Ok(())
     }
 }
-
-/// Validates a `SecurityConfig` for well-formedness before use.
-///
-/// Catches misconfigurations early rather than at execution time.
-pub fn validate_security_config(config: &SecurityConfig) -> Result<(), SandboxError> {
-    // Validate network patterns
-    crate::network::validate_allow_list(&config.net_allow_list)
-        .map_err(|reason| SandboxError::InvalidConfig { reason })?;
-
-    // Validate resource limits
-    if config.limits.max_fuel == 0 {
-        return Err(SandboxError::InvalidConfig {
-            reason: "max_fuel must be > 0".to_string(),
-        });
-    }
-    if config.limits.max_memory_mb == 0 {
-        return Err(SandboxError::InvalidConfig {
-            reason: "max_memory_mb must be > 0".to_string(),
-        });
-    }
-    if config.limits.max_execution_ms == 0 {
-        return Err(SandboxError::InvalidConfig {
-            reason: "max_execution_ms must be > 0".to_string(),
-        });
-    }
-
-    Ok(())
-}
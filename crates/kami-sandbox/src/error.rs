@@ -3,15 +3,23 @@
 use kami_types::{DiagnosticError, ErrorKind, KamiError};
 use thiserror::Error;
 
+use crate::network::NetworkDenyReason;
+
 /// Errors from the sandbox layer.
 #[derive(Debug, Error)]
 pub enum SandboxError {
     /// A required capability was not granted.
     #[error("capability denied: {capability}")]
     CapabilityDenied { capability: String },
+    /// The manifest asks for more than the operator granted the tool.
+    #[error("tool {tool} requests capabilities that were not granted: {changes}")]
+    NotGranted { tool: String, changes: String },
     /// Network access denied.
-    #[error("network access denied for host: {host}")]
-    NetworkDenied { host: String },
+    #[error("network access denied for host: {host} ({reason})")]
+    NetworkDenied {
+        host: String,
+        reason: NetworkDenyReason,
+    },
     /// Filesystem access denied.
     #[error("filesystem access denied: {path}")]
     FsDenied { path: String },
@@ -21,15 +29,19 @@ pub enum SandboxError {
     /// Invalid security configuration.
     #[error("invalid security config: {reason}")]
     InvalidConfig { reason: String },
+    /// The per-tool sandbox directory could not be prepared.
+    #[error("cannot prepare sandbox directory '{path}': {reason}")]
+    SandboxDir { path: String, reason: String },
 }
 
 impl From<SandboxError> for KamiError {
     fn from(e: SandboxError) -> Self {
         let kind = match &e {
             SandboxError::CapabilityDenied { .. }
+            | SandboxError::NotGranted { .. }
             | SandboxError::NetworkDenied { .. }
             | SandboxError::FsDenied { .. } => ErrorKind::PermissionDenied,
-            SandboxError::WasiBuild { .. } => ErrorKind::Internal,
+            SandboxError::WasiBuild { .. } | SandboxError::SandboxDir { .. } => ErrorKind::Internal,
             SandboxError::InvalidConfig { .. } => ErrorKind::InvalidInput,
         };
         KamiError::new(kind, e.to_string())
@@ -42,8 +54,11 @@ impl DiagnosticError for SandboxError {
             Self::CapabilityDenied { capability } => Some(format!(
                 "The tool requires the '{capability}' capability but it was not granted."
             )),
-            Self::NetworkDenied { host } => Some(format!(
-                "The tool tried to connect to '{host}' but network access is denied."
+            Self::NotGranted { .. } => {
+                Some("It was never approved, or its manifest changed since it was.".into())
+            }
+            Self::NetworkDenied { host, reason } => Some(format!(
+                "The tool tried to connect to '{host}' but network access is denied: {reason}."
             )),
             Self::FsDenied { path } => Some(format!(
                 "The tool tried to access '{path}' outside its sandbox."
@@ -51,81 +66,45 @@ impl DiagnosticError for SandboxError {
             Self::InvalidConfig { .. } => {
                 Some("The security configuration in tool.toml has invalid values.".into())
             }
+            Self::SandboxDir { path, .. } => Some(format!(
+                "The tool needs filesystem access but '{path}' could not be created."
+            )),
             Self::WasiBuild { .. } => None,
         }
     }
 
     fn fix(&self) -> Option<String> {
         match self {
-            Self::NetworkDenied { host } => Some(format!(
-                "Add to tool.toml:\n  [security]\n  net_allow_list = [\"{host}\"]"
-            )),
+            Self::NetworkDenied { host, reason } => Some(match reason {
+                NetworkDenyReason::NonPublicAddress(_) => {
+                    "If the tool must reach internal services, add to tool.toml:\n  [security]\n  allow_private_networks = true".into()
+                }
+                NetworkDenyReason::DenyRule => {
+                    "Remove the matching '!' entry from net_allow_list in tool.toml.".into()
+                }
+                NetworkDenyReason::HostPolicy => {
+                    "The operator's host policy forbids this destination; tool.toml cannot override it.".into()
+                }
+                _ => format!("Add to tool.toml:\n  [security]\n  net_allow_list = [\"{host}\"]"),
+            }),
             Self::FsDenied { .. } => Some(
                 "Set fs_access in tool.toml:\n  [security]\n  fs_access = \"read-only\"".into(),
             ),
             Self::CapabilityDenied { .. } => {
                 Some("Grant the required capability in tool.toml [security] section.".into())
             }
+            Self::NotGranted { tool, .. } => Some(format!(
+                "Review and approve the new capabilities with `kami update {tool}`."
+            )),
+            Self::SandboxDir { .. } => Some(
+                "Check that the KAMI data directory (KAMI_DATA_DIR or ~/.kami) is writable."
+                    .into(),
+            ),
             _ => None,
         }
     }
 }
 
 #[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn capability_denied_maps_to_permission_denied() {
-        let err: KamiError = SandboxError::CapabilityDenied {
-            capability: "net".into(),
-        }
-        .into();
-        assert_eq!(err.kind, ErrorKind::PermissionDenied);
-    }
-
-    #[test]
-    fn network_denied_has_hint_with_host() {
-        let e = SandboxError::NetworkDenied {
-            host: "evil.com".into(),
-        };
-        let hint = e.hint().expect("has hint");
-        assert!(hint.contains("evil.com"));
-    }
-
-    #[test]
-    fn network_denied_has_fix_with_toml() {
-        let e = SandboxError::NetworkDenied {
-            host: "api.x.com".into(),
-        };
-        let fix = e.fix().expect("has fix");
-        assert!(fix.contains("net_allow_list"));
-        assert!(fix.contains("api.x.com"));
-    }
-
-    #[test]
-    fn fs_denied_maps_to_permission_denied() {
-        let err: KamiError = SandboxError::FsDenied {
-            path: "/etc".into(),
-        }
-        .into();
-        assert_eq!(err.kind, ErrorKind::PermissionDenied);
-    }
-
-    #[test]
-    fn invalid_config_maps_to_invalid_input() {
-        let err: KamiError = SandboxError::InvalidConfig {
-            reason: "bad".into(),
-        }
-        .into();
-        assert_eq!(err.kind, ErrorKind::InvalidInput);
-    }
-
-    #[test]
-    fn wasi_build_has_no_fix() {
-        let e = SandboxError::WasiBuild {
-            reason: "oom".into(),
-        };
-        assert!(e.fix().is_none());
-    }
-}
+#[path = "error_tests.rs"]
+mod tests;
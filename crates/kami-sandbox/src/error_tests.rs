@@ -0,0 +1,97 @@
+//! Tests for sandbox errors.
+
+use super::*;
+
+#[test]
+fn capability_denied_maps_to_permission_denied() {
+    let err: KamiError = SandboxError::CapabilityDenied {
+        capability: "net".into(),
+    }
+    .into();
+    assert_eq!(err.kind, ErrorKind::PermissionDenied);
+}
+
+#[test]
+fn network_denied_has_hint_with_host() {
+    let e = SandboxError::NetworkDenied {
+        host: "evil.com".into(),
+        reason: NetworkDenyReason::NotAllowListed,
+    };
+    let hint = e.hint().expect("has hint");
+    assert!(hint.contains("evil.com"));
+}
+
+#[test]
+fn network_denied_has_fix_with_toml() {
+    let e = SandboxError::NetworkDenied {
+        host: "api.x.com".into(),
+        reason: NetworkDenyReason::NotAllowListed,
+    };
+    let fix = e.fix().expect("has fix");
+    assert!(fix.contains("net_allow_list"));
+    assert!(fix.contains("api.x.com"));
+}
+
+#[test]
+fn fs_denied_maps_to_permission_denied() {
+    let err: KamiError = SandboxError::FsDenied {
+        path: "/etc".into(),
+    }
+    .into();
+    assert_eq!(err.kind, ErrorKind::PermissionDenied);
+}
+
+#[test]
+fn invalid_config_maps_to_invalid_input() {
+    let err: KamiError = SandboxError::InvalidConfig {
+        reason: "bad".into(),
+    }
+    .into();
+    assert_eq!(err.kind, ErrorKind::InvalidInput);
+}
+
+#[test]
+fn wasi_build_has_no_fix() {
+    let e = SandboxError::WasiBuild {
+        reason: "oom".into(),
+    };
+    assert!(e.fix().is_none());
+}
+
+#[test]
+fn non_public_denial_suggests_opt_in() {
+    let e = SandboxError::NetworkDenied {
+        host: "internal.example.com".into(),
+        reason: NetworkDenyReason::NonPublicAddress([10, 0, 0, 5].into()),
+    };
+    assert!(e.to_string().contains("10.0.0.5"));
+    let fix = e.fix().expect("has fix");
+    assert!(fix.contains("allow_private_networks = true"));
+}
+
+#[test]
+fn host_policy_fix_points_at_operator() {
+    let e = SandboxError::NetworkDenied {
+        host: "db.internal".into(),
+        reason: NetworkDenyReason::HostPolicy,
+    };
+    let fix = e.fix().expect("has fix");
+    assert!(fix.contains("host policy"));
+    assert!(!fix.contains("net_allow_list"));
+}
+
+#[test]
+fn not_granted_fix_names_the_update_command() {
+    let e = SandboxError::NotGranted {
+        tool: "dev.x.y".into(),
+        changes: "network: api.x.com".into(),
+    };
+    let err: KamiError = SandboxError::NotGranted {
+        tool: "dev.x.y".into(),
+        changes: String::new(),
+    }
+    .into();
+    assert_eq!(err.kind, ErrorKind::PermissionDenied);
+    assert!(e.to_string().contains("network: api.x.com"));
+    assert!(e.fix().expect("has fix").contains("kami update dev.x.y"));
+}
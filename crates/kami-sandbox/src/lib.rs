@@ -10,9 +10,17 @@ pub mod capability;
 pub mod error;
 pub mod filesystem;
 pub mod network;
+pub mod output;
+pub mod policy_checker;
+pub mod sandbox_dir;
+pub mod validation;
 pub mod wasi;
 
-pub use capability::{validate_security_config, CapabilityChecker, DefaultCapabilityChecker};
+pub use capability::{CapabilityChecker, CapabilityGuard, DefaultCapabilityChecker};
 pub use error::SandboxError;
 pub use filesystem::FsJail;
+pub use output::{CapturePipe, GuestOutput};
+pub use policy_checker::PolicyChecker;
+pub use sandbox_dir::{SandboxDir, SandboxDirMode, SandboxDirs};
+pub use validation::{validate_security_config, MAX_LOG_BYTES};
 pub use wasi::{build_wasi_ctx, WasiConfig};
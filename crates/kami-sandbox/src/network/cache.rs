@@ -0,0 +1,75 @@
+//! Short-lived IP→hostname cache of one execution.
+
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use super::host::HostPattern;
+use super::resolver::HostResolver;
+use super::rule::NetRule;
+
+/// Names each address resolved from, with the resolution time.
+type Resolved = (Instant, HashMap<IpAddr, Vec<String>>);
+
+/// Addresses of the allow-listed names, valid for a fixed time.
+#[derive(Debug)]
+pub(super) struct NameCache {
+    ttl: Duration,
+    resolved: Mutex<Option<Resolved>>,
+}
+
+impl NameCache {
+    pub(super) fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            resolved: Mutex::new(None),
+        }
+    }
+
+    /// Names `ip` resolved from, or `None` if the cache is stale or empty.
+    pub(super) fn lookup(&self, ip: IpAddr) -> Option<Vec<String>> {
+        let resolved = self.resolved.lock().unwrap_or_else(|e| e.into_inner());
+        let (_, entries) = resolved
+            .as_ref()
+            .filter(|(at, _)| at.elapsed() < self.ttl)?;
+        Some(entries.get(&ip).cloned().unwrap_or_default())
+    }
+
+    /// Replaces the cache with fresh resolutions.
+    fn store(&self, entries: HashMap<IpAddr, Vec<String>>) {
+        let mut resolved = self.resolved.lock().unwrap_or_else(|e| e.into_inner());
+        *resolved = Some((Instant::now(), entries));
+    }
+
+    /// Resolves every exact host name in `rules`, replaces the cache and
+    /// returns the names `ip` resolved from.
+    pub(super) async fn refresh(
+        &self,
+        rules: &[NetRule],
+        resolver: &dyn HostResolver,
+        ip: IpAddr,
+    ) -> Vec<String> {
+        let mut names: Vec<&str> = rules
+            .iter()
+            .filter_map(|r| match &r.host {
+                HostPattern::Name(name) => Some(name.as_str()),
+                _ => None,
+            })
+            .collect();
+        names.sort_unstable();
+        names.dedup();
+        let mut entries: HashMap<IpAddr, Vec<String>> = HashMap::new();
+        for name in names {
+            for resolved in resolver.resolve(name).await {
+                entries
+                    .entry(resolved.to_canonical())
+                    .or_default()
+                    .push(name.to_string());
+            }
+        }
+        let hosts = entries.get(&ip).cloned().unwrap_or_default();
+        self.store(entries);
+        hosts
+    }
+}
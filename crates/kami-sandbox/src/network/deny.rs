@@ -0,0 +1,32 @@
+//! Why a network destination was refused.
+
+use std::fmt;
+use std::net::IpAddr;
+
+/// Structured reason carried by `SandboxError::NetworkDenied`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkDenyReason {
+    /// No allow rule matches the destination.
+    NotAllowListed,
+    /// A `!` rule matches the destination.
+    DenyRule,
+    /// The destination resolves to a non-public address and the tool does
+    /// not set `allow_private_networks`.
+    NonPublicAddress(IpAddr),
+    /// The host name did not resolve to any address.
+    Unresolved,
+    /// The host's capability policy refuses the destination.
+    HostPolicy,
+}
+
+impl fmt::Display for NetworkDenyReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotAllowListed => f.write_str("not in net_allow_list"),
+            Self::DenyRule => f.write_str("matched a deny rule"),
+            Self::NonPublicAddress(ip) => write!(f, "resolves to non-public address {ip}"),
+            Self::Unresolved => f.write_str("host did not resolve"),
+            Self::HostPolicy => f.write_str("refused by the host policy"),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+//! Host part of a network rule: names, IPs, CIDR ranges and address classes.
+
+use std::net::IpAddr;
+
+use super::range::{AddrClass, Cidr};
+
+/// What a rule's host part matches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HostPattern {
+    /// `*`: any host name (never a raw socket address).
+    Any,
+    /// An exact, lower-case host name.
+    Name(String),
+    /// `*.suffix`: the suffix itself and all its subdomains.
+    Suffix(String),
+    /// An explicit IP address.
+    Ip(IpAddr),
+    /// An IP network.
+    Cidr(Cidr),
+    /// A named address class.
+    Class(AddrClass),
+}
+
+impl HostPattern {
+    /// Parses a host without brackets or port.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for empty hosts and misplaced wildcards.
+    pub fn parse(host: &str) -> Result<Self, String> {
+        if host == "*" {
+            return Ok(Self::Any);
+        }
+        if let Ok(ip) = host.parse::<IpAddr>() {
+            return Ok(Self::Ip(ip));
+        }
+        if let Some(class) = AddrClass::parse(host) {
+            return Ok(Self::Class(class));
+        }
+        let (bare, wildcard) = match host.strip_prefix("*.") {
+            Some(suffix) => (suffix, true),
+            None => (host, false),
+        };
+        if bare.is_empty() || bare.contains('*') {
+            return Err(format!("invalid wildcard pattern: {host}"));
+        }
+        if bare.contains([':', '@', '[', ']']) {
+            return Err(format!("invalid host: {host}"));
+        }
+        let bare = bare.to_ascii_lowercase();
+        Ok(if wildcard {
+            Self::Suffix(bare)
+        } else {
+            Self::Name(bare)
+        })
+    }
+
+    /// Returns true if `host` (a name or IP literal) matches.
+    pub fn matches_name(&self, host: &str) -> bool {
+        let host = host.trim_start_matches('[').trim_end_matches(']');
+        if let Ok(ip) = host.parse::<IpAddr>() {
+            return matches!(self, Self::Any) || self.matches_ip(ip);
+        }
+        let host = host.to_ascii_lowercase();
+        match self {
+            Self::Any => true,
+            Self::Name(name) => host == *name,
+            Self::Suffix(suffix) => host == *suffix || host.ends_with(&format!(".{suffix}")),
+            Self::Ip(_) | Self::Cidr(_) | Self::Class(_) => false,
+        }
+    }
+
+    /// Returns true if the address `ip` is named explicitly by this pattern.
+    pub fn matches_ip(&self, ip: IpAddr) -> bool {
+        match self {
+            Self::Ip(own) => own.to_canonical() == ip.to_canonical(),
+            Self::Cidr(cidr) => cidr.contains(ip),
+            Self::Class(class) => class.contains(ip),
+            Self::Any | Self::Name(_) | Self::Suffix(_) => false,
+        }
+    }
+}
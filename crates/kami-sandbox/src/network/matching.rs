@@ -0,0 +1,102 @@
+//! Matching of requests and socket addresses against a [`NetRule`].
+
+use std::net::SocketAddr;
+
+use super::rule::NetRule;
+
+/// An outgoing HTTP request, as checked against the allow list.
+#[derive(Debug, Clone, Copy)]
+pub struct HttpTarget<'a> {
+    /// Request method, e.g. `GET`.
+    pub method: &'a str,
+    /// `http` or `https`.
+    pub scheme: &'a str,
+    /// Host name or IP address (IPv6 with or without brackets).
+    pub host: &'a str,
+    /// Destination port.
+    pub port: u16,
+    /// Request path, without the query string.
+    pub path: &'a str,
+}
+
+impl NetRule {
+    /// Returns true if `host` matches this rule's host pattern.
+    pub fn matches_host(&self, host: &str) -> bool {
+        self.host.matches_name(host)
+    }
+
+    /// Returns true if this rule may grant or deny raw sockets: method and
+    /// path restrictions cannot be enforced below HTTP, so such rules never
+    /// allow a socket.
+    pub fn covers_sockets(&self) -> bool {
+        self.deny || (self.methods.is_empty() && self.path.is_none())
+    }
+
+    /// Returns true if a raw socket connection to `addr` matches: only
+    /// rules naming that IP explicitly (or its CIDR range or address class)
+    /// and its port, if any, match.
+    pub fn matches_addr(&self, addr: &SocketAddr) -> bool {
+        self.covers_sockets()
+            && self.host.matches_ip(addr.ip())
+            && self.port.is_none_or(|p| p == addr.port())
+    }
+
+    /// Returns true if a socket to `port` on a host resolved from `host`
+    /// matches this rule.
+    pub fn matches_resolved(&self, host: &str, port: u16) -> bool {
+        self.covers_sockets() && self.host.matches_name(host) && self.port.is_none_or(|p| p == port)
+    }
+
+    /// Returns true if every part of `target` is allowed by this rule.
+    pub fn matches_request(&self, target: &HttpTarget<'_>) -> bool {
+        let method_ok = self.methods.is_empty()
+            || self
+                .methods
+                .iter()
+                .any(|m| m.eq_ignore_ascii_case(target.method));
+        let scheme_ok = self
+            .scheme
+            .as_deref()
+            .is_none_or(|s| s.eq_ignore_ascii_case(target.scheme));
+        method_ok
+            && scheme_ok
+            && self.port.is_none_or(|p| p == target.port)
+            && self.matches_host(target.host)
+            && self
+                .path
+                .as_deref()
+                .is_none_or(|p| path_matches(p, target.path))
+    }
+}
+
+/// Matches `path` against a glob, refusing dot segments that a server
+/// could resolve to a path outside the pattern.
+fn path_matches(pattern: &str, path: &str) -> bool {
+    let dot_segment = path.split('/').any(|seg| {
+        let seg = seg.to_ascii_lowercase().replace("%2e", ".");
+        seg == "." || seg == ".."
+    });
+    !dot_segment && glob_match(pattern.as_bytes(), path.as_bytes())
+}
+
+/// Glob match where `*` matches any (possibly empty) run of bytes.
+fn glob_match(pattern: &[u8], text: &[u8]) -> bool {
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while t < text.len() {
+        if p < pattern.len() && pattern[p] == b'*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if p < pattern.len() && pattern[p] == text[t] {
+            p += 1;
+            t += 1;
+        } else if let Some((star, matched)) = backtrack {
+            p = star + 1;
+            t = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|&b| b == b'*')
+}
@@ -0,0 +1,103 @@
+//! Network allow-list enforcement.
+//!
+//! Each `net_allow_list` entry is a [`NetRule`]: a hostname pattern, an
+//! explicit IP address, a CIDR range or an address class, optionally
+//! narrowed by methods, scheme, port and path
+//! (`GET https://api.github.com/repos/*`). Entries starting with `!` deny
+//! and take precedence. Sockets and HTTP connections are checked by a
+//! [`NetworkPolicy`], which resolves host names on the host side and
+//! refuses non-public addresses unless the tool opts in; an IP no allowed
+//! name resolves to is refused, which prevents bypassing hostname-based
+//! allow-lists.
+
+mod cache;
+mod deny;
+mod host;
+mod matching;
+mod policy;
+mod range;
+mod resolver;
+mod rule;
+mod vetting;
+
+use std::net::SocketAddr;
+
+pub use deny::NetworkDenyReason;
+pub use host::HostPattern;
+pub use matching::HttpTarget;
+pub use policy::{NetworkPolicy, CACHE_TTL};
+pub use range::{is_public, AddrClass, Cidr};
+pub use resolver::{HostResolver, ResolveFuture, StaticResolver, SystemResolver};
+pub use rule::NetRule;
+
+/// Checks if a socket address is permitted by the allow list, without
+/// resolving host names.
+///
+/// **Security:** Raw IP connections only succeed if the IP is listed
+/// explicitly or through a CIDR range or address class, on the rule's port
+/// when it names one (or a scheme). Hostname patterns never match an IP;
+/// use [`NetworkPolicy`] to allow the addresses they resolve to.
+pub fn is_addr_allowed(addr: &SocketAddr, allow_list: &[String]) -> bool {
+    let rules = parse_allow_list(allow_list);
+    !rules.iter().any(|r| r.deny && r.matches_addr(addr))
+        && rules.iter().any(|r| r.matches_addr(addr))
+}
+
+/// Checks if a hostname string matches the host part of any allow rule and
+/// no host-wide deny rule.
+///
+/// Supports:
+/// - Exact matches: `"api.github.com"`
+/// - Wildcard subdomain: `"*.example.com"` (matches `sub.example.com`)
+pub fn is_host_allowed(host: &str, allow_list: &[String]) -> bool {
+    let rules = parse_allow_list(allow_list);
+    let host_wide = |r: &&NetRule| {
+        r.methods.is_empty() && r.scheme.is_none() && r.port.is_none() && r.path.is_none()
+    };
+    !rules
+        .iter()
+        .filter(|r| r.deny)
+        .filter(host_wide)
+        .any(|r| r.matches_host(host))
+        && rules.iter().any(|r| !r.deny && r.matches_host(host))
+}
+
+/// Checks an outgoing HTTP request against method, scheme, host, port and
+/// path of every rule. Deny-all when the list is empty; deny rules win.
+pub fn is_request_allowed(target: &HttpTarget<'_>, allow_list: &[NetRule]) -> bool {
+    let (deny, allow): (Vec<&NetRule>, Vec<&NetRule>) =
+        allow_list.iter().partition(|rule| rule.deny);
+    !deny.iter().any(|rule| rule.matches_request(target))
+        && allow.iter().any(|rule| rule.matches_request(target))
+}
+
+/// Parses an allow list, skipping (and logging) malformed entries.
+///
+/// Lists are validated when a tool is installed or run, so a malformed
+/// allow entry here can only narrow access. A malformed deny entry would
+/// widen it, so the whole list is then dropped (deny-all).
+pub fn parse_allow_list(allow_list: &[String]) -> Vec<NetRule> {
+    let mut rules = Vec::with_capacity(allow_list.len());
+    for entry in allow_list {
+        match NetRule::parse(entry) {
+            Ok(rule) => rules.push(rule),
+            Err(reason) if entry.trim_start().starts_with('!') => {
+                tracing::warn!(%entry, %reason, "malformed deny rule, denying all network access");
+                return Vec::new();
+            }
+            Err(reason) => tracing::warn!(%entry, %reason, "ignoring malformed network rule"),
+        }
+    }
+    rules
+}
+
+/// Validates that all entries in a network allow list are well-formed.
+///
+/// # Errors
+///
+/// Returns an error string describing the first malformed entry.
+pub fn validate_allow_list(patterns: &[String]) -> Result<(), String> {
+    patterns
+        .iter()
+        .try_for_each(|p| NetRule::parse(p).map(drop))
+}
@@ -0,0 +1,119 @@
+//! Per-execution network policy: rules, host-side resolution and SSRF
+//! protection.
+//!
+//! Raw sockets only see an address, so hostname rules are resolved on the
+//! host side and remembered for [`CACHE_TTL`]; wildcard patterns cannot be
+//! resolved ahead of time and never grant raw sockets. Unless
+//! `allow_private_networks` is set, non-public addresses are refused.
+//! HTTP resolution and the per-address screening live in `vetting`.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use kami_types::SecurityConfig;
+
+use super::cache::NameCache;
+use super::deny::NetworkDenyReason;
+use super::parse_allow_list;
+use super::resolver::HostResolver;
+use super::rule::NetRule;
+use crate::capability::CapabilityGuard;
+
+/// How long resolved addresses stay valid.
+pub const CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// Decides which destinations a guest may reach.
+#[derive(Debug)]
+pub struct NetworkPolicy {
+    pub(super) rules: Vec<NetRule>,
+    pub(super) resolver: Arc<dyn HostResolver>,
+    pub(super) allow_private: bool,
+    pub(super) guard: Option<CapabilityGuard>,
+    cache: NameCache,
+}
+
+impl NetworkPolicy {
+    /// Creates a policy refusing non-public addresses, with an empty cache.
+    pub fn new(rules: Vec<NetRule>, resolver: Arc<dyn HostResolver>) -> Self {
+        Self {
+            rules,
+            resolver,
+            allow_private: false,
+            guard: None,
+            cache: NameCache::new(CACHE_TTL),
+        }
+    }
+
+    /// Creates the policy of a tool's `[security]` section.
+    pub fn from_config(security: &SecurityConfig, resolver: Arc<dyn HostResolver>) -> Self {
+        Self::new(parse_allow_list(&security.net_allow_list), resolver)
+            .allow_private_networks(security.allow_private_networks)
+    }
+
+    /// Allows (or refuses) private, loopback and other non-public addresses.
+    pub fn allow_private_networks(mut self, allow: bool) -> Self {
+        self.allow_private = allow;
+        self
+    }
+
+    /// Also consults `guard` with the destination's name (or IP literal)
+    /// once the rules allow it.
+    pub fn with_guard(mut self, guard: CapabilityGuard) -> Self {
+        self.guard = Some(guard);
+        self
+    }
+
+    /// Overrides the cache lifetime.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.cache = NameCache::new(ttl);
+        self
+    }
+
+    /// Returns the parsed rules.
+    pub fn rules(&self) -> &[NetRule] {
+        &self.rules
+    }
+
+    /// Returns true if a socket to `addr` is allowed.
+    pub async fn allows(&self, addr: SocketAddr) -> bool {
+        self.check_socket(addr).await.is_ok()
+    }
+
+    /// Checks a socket to `addr`.
+    ///
+    /// Deny rules and the non-public check come first; then explicit IPs,
+    /// CIDR ranges and address classes, then the names `addr` was
+    /// resolved from; finally the guard, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns why the socket is refused.
+    pub async fn check_socket(&self, addr: SocketAddr) -> Result<(), NetworkDenyReason> {
+        let ip = addr.ip().to_canonical();
+        let addr = SocketAddr::new(ip, addr.port());
+        self.screen(&addr)?;
+        if self.rules.iter().any(|r| r.matches_addr(&addr)) {
+            return self.consult(&ip.to_string());
+        }
+        let hosts = match self.cache.lookup(ip) {
+            Some(hosts) => hosts,
+            None => self.cache.refresh(&self.rules, &*self.resolver, ip).await,
+        };
+        let port = addr.port();
+        let matching = |deny: bool| {
+            hosts.iter().find(|h| {
+                self.rules
+                    .iter()
+                    .any(|r| r.deny == deny && r.matches_resolved(h, port))
+            })
+        };
+        if matching(true).is_some() {
+            return Err(NetworkDenyReason::DenyRule);
+        }
+        match matching(false) {
+            Some(host) => self.consult(host),
+            None => Err(NetworkDenyReason::NotAllowListed),
+        }
+    }
+}
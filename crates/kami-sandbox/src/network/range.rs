@@ -0,0 +1,120 @@
+//! Address ranges usable as a rule's host: CIDR networks and named classes.
+
+use std::net::IpAddr;
+
+/// Named address ranges usable in place of a host, e.g. `"!private"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddrClass {
+    /// RFC 1918 IPv4 ranges and IPv6 unique local addresses (`fc00::/7`).
+    Private,
+    /// `127.0.0.0/8` and `::1`.
+    Loopback,
+    /// `169.254.0.0/16` and `fe80::/10`.
+    LinkLocal,
+}
+
+impl AddrClass {
+    /// Parses `private`, `loopback` or `link-local`.
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "private" => Some(Self::Private),
+            "loopback" => Some(Self::Loopback),
+            "link-local" => Some(Self::LinkLocal),
+            _ => None,
+        }
+    }
+
+    /// Returns true if `ip` (IPv4-mapped addresses included) is in the class.
+    pub fn contains(self, ip: IpAddr) -> bool {
+        match (self, ip.to_canonical()) {
+            (Self::Private, IpAddr::V4(v4)) => v4.is_private(),
+            (Self::Private, IpAddr::V6(v6)) => v6.segments()[0] & 0xfe00 == 0xfc00,
+            (Self::Loopback, ip) => ip.is_loopback(),
+            (Self::LinkLocal, IpAddr::V4(v4)) => v4.is_link_local(),
+            (Self::LinkLocal, IpAddr::V6(v6)) => v6.segments()[0] & 0xffc0 == 0xfe80,
+        }
+    }
+}
+
+/// An IP network such as `10.0.0.0/8` or `fd00::/8`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cidr {
+    network: IpAddr,
+    prefix: u8,
+}
+
+impl Cidr {
+    /// Builds a network from an address and prefix length.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `prefix` exceeds the address width.
+    pub fn new(network: IpAddr, prefix: u8) -> Result<Self, String> {
+        let width = if network.is_ipv4() { 32 } else { 128 };
+        if prefix > width {
+            return Err(format!("prefix /{prefix} too long for {network}"));
+        }
+        Ok(Self { network, prefix })
+    }
+
+    /// Returns true if `ip` lies in this network.
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        match (self.network, ip.to_canonical()) {
+            (IpAddr::V4(net), IpAddr::V4(ip)) => {
+                let mask = u32::MAX
+                    .checked_shl(32 - u32::from(self.prefix))
+                    .unwrap_or(0);
+                u32::from(net) & mask == u32::from(ip) & mask
+            }
+            (IpAddr::V6(net), IpAddr::V6(ip)) => {
+                let mask = u128::MAX
+                    .checked_shl(128 - u32::from(self.prefix))
+                    .unwrap_or(0);
+                u128::from(net) & mask == u128::from(ip) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Returns true if `ip` is publicly routable.
+///
+/// Refuses the [`AddrClass`]es plus unspecified, shared (`100.64.0.0/10`),
+/// benchmarking, reserved, broadcast and multicast ranges, local-use NAT64
+/// (`64:ff9b:1::/48`) and documentation (`2001:db8::/32`) addresses.
+/// IPv4-mapped, NAT64 (`64:ff9b::/96`) and 6to4 (`2002::/16`) addresses are
+/// judged by the IPv4 address they embed.
+pub fn is_public(ip: IpAddr) -> bool {
+    let classes = [
+        AddrClass::Private,
+        AddrClass::Loopback,
+        AddrClass::LinkLocal,
+    ];
+    if classes.iter().any(|class| class.contains(ip)) {
+        return false;
+    }
+    match ip.to_canonical() {
+        IpAddr::V4(v4) => {
+            let [a, b, c, _] = v4.octets();
+            !(a == 0
+                || (a == 100 && b & 0xc0 == 64)
+                || (a == 192 && b == 0 && c == 0)
+                || (a == 198 && b & 0xfe == 18)
+                || a >= 224)
+        }
+        IpAddr::V6(v6) => {
+            let s = v6.segments();
+            let o = v6.octets();
+            if s[..6] == [0x64, 0xff9b, 0, 0, 0, 0] {
+                return is_public(IpAddr::from([o[12], o[13], o[14], o[15]]));
+            }
+            if s[0] == 0x2002 {
+                return is_public(IpAddr::from([o[2], o[3], o[4], o[5]]));
+            }
+            !(v6.is_unspecified()
+                || v6.is_multicast()
+                || s[..3] == [0x64, 0xff9b, 1]
+                || s[..2] == [0x2001, 0xdb8])
+        }
+    }
+}
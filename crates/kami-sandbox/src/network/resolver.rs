@@ -0,0 +1,78 @@
+//! Host-side name resolution for the socket allow-list check.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::net::{IpAddr, ToSocketAddrs};
+use std::pin::Pin;
+
+/// Future returned by [`HostResolver::resolve`].
+///
+/// `Sync` because wasmtime-wasi requires it of `socket_addr_check` futures.
+pub type ResolveFuture = Pin<Box<dyn Future<Output = Vec<IpAddr>> + Send + Sync>>;
+
+/// Resolves allow-listed host names to the addresses a guest may connect to.
+pub trait HostResolver: Send + Sync + fmt::Debug {
+    /// Returns the addresses of `host`; empty if it does not resolve.
+    fn resolve(&self, host: &str) -> ResolveFuture;
+}
+
+/// Resolver backed by the operating system (`getaddrinfo`).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemResolver;
+
+impl HostResolver for SystemResolver {
+    fn resolve(&self, host: &str) -> ResolveFuture {
+        let host = host.to_string();
+        Box::pin(async move {
+            let lookup = tokio::task::spawn_blocking(move || {
+                (host.as_str(), 0)
+                    .to_socket_addrs()
+                    .map(|addrs| addrs.map(|a| a.ip()).collect::<Vec<_>>())
+                    .map_err(|e| (host, e))
+            });
+            match lookup.await {
+                Ok(Ok(ips)) => ips,
+                Ok(Err((host, e))) => {
+                    tracing::warn!(%host, error = %e, "allow-listed host did not resolve");
+                    Vec::new()
+                }
+                Err(e) => {
+                    tracing::warn!(error = %e, "host resolution task failed");
+                    Vec::new()
+                }
+            }
+        })
+    }
+}
+
+/// Fixed name-to-address table, for tests and offline setups.
+#[derive(Debug, Clone, Default)]
+pub struct StaticResolver {
+    entries: HashMap<String, Vec<IpAddr>>,
+}
+
+impl StaticResolver {
+    /// Creates an empty resolver; every lookup fails.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds (or replaces) the addresses of `host`.
+    pub fn with(mut self, host: &str, ips: impl IntoIterator<Item = IpAddr>) -> Self {
+        self.entries
+            .insert(host.to_ascii_lowercase(), ips.into_iter().collect());
+        self
+    }
+}
+
+impl HostResolver for StaticResolver {
+    fn resolve(&self, host: &str) -> ResolveFuture {
+        let ips = self
+            .entries
+            .get(&host.to_ascii_lowercase())
+            .cloned()
+            .unwrap_or_default();
+        Box::pin(async move { ips })
+    }
+}
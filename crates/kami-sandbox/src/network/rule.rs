@@ -0,0 +1,149 @@
+//! Parsing and matching of a single `net_allow_list` entry.
+//!
+//! Grammar: `[!][METHODS ][scheme://]host[:port][/path]`, e.g.
+//! `GET,HEAD https://api.github.com:443/repos/*`. Omitted parts match
+//! anything, except that a scheme without a port implies its default port.
+//! The host may also be a CIDR range (`10.0.0.0/8`) or an address class
+//! (`private`, `loopback`, `link-local`); a leading `!` makes the rule a
+//! deny. Matching lives in the sibling `matching` module.
+
+use std::net::IpAddr;
+
+use super::host::HostPattern;
+use super::range::Cidr;
+
+/// HTTP methods accepted in a rule's method list.
+const METHODS: [&str; 9] = [
+    "GET", "HEAD", "POST", "PUT", "PATCH", "DELETE", "OPTIONS", "CONNECT", "TRACE",
+];
+
+/// One parsed allow-list entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetRule {
+    /// True for `!` rules, which take precedence over every allow rule.
+    pub deny: bool,
+    /// Allowed methods, upper-case; empty allows any method.
+    pub methods: Vec<String>,
+    /// `http` or `https`; `None` allows both.
+    pub scheme: Option<String>,
+    /// Host name, wildcard, IP address, CIDR range or address class.
+    pub host: HostPattern,
+    /// Allowed port; `None` allows any port.
+    pub port: Option<u16>,
+    /// Path glob where `*` matches any run of characters; `None` allows any.
+    pub path: Option<String>,
+}
+
+impl NetRule {
+    /// Parses an allow-list entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the first malformed part.
+    pub fn parse(entry: &str) -> Result<Self, String> {
+        let entry = entry.trim();
+        let (deny, entry) = match entry.strip_prefix('!') {
+            Some(rest) => (true, rest.trim_start()),
+            None => (false, entry),
+        };
+        if entry.is_empty() {
+            return Err("empty pattern in network allow list".to_string());
+        }
+        let (methods, target) = match entry.split_once(char::is_whitespace) {
+            Some((methods, rest)) => (parse_methods(methods)?, rest.trim_start()),
+            None => (Vec::new(), entry),
+        };
+        let (scheme, rest) = match target.split_once("://") {
+            Some((scheme, rest)) => (Some(parse_scheme(scheme)?), rest),
+            None => (None, target),
+        };
+        let (authority, path) = match rest.find('/') {
+            Some(i) => (&rest[..i], Some(rest[i..].to_string())),
+            None => (rest, None),
+        };
+        let (host, port) = split_authority(authority)?;
+        let (host, path) = match cidr_of(&host, path.as_deref(), scheme.is_none()) {
+            Some(cidr) => (HostPattern::Cidr(cidr?), None),
+            None => (parse_host(&host, entry)?, path),
+        };
+        let port = port.or(match scheme.as_deref() {
+            Some("http") => Some(80),
+            Some("https") => Some(443),
+            _ => None,
+        });
+        Ok(Self {
+            deny,
+            methods,
+            scheme,
+            host,
+            port,
+            path,
+        })
+    }
+}
+
+fn parse_methods(list: &str) -> Result<Vec<String>, String> {
+    list.split(',')
+        .map(|m| {
+            let upper = m.to_ascii_uppercase();
+            if METHODS.contains(&upper.as_str()) {
+                Ok(upper)
+            } else {
+                Err(format!("unknown HTTP method '{m}' in network rule"))
+            }
+        })
+        .collect()
+}
+
+fn parse_scheme(scheme: &str) -> Result<String, String> {
+    let lower = scheme.to_ascii_lowercase();
+    match lower.as_str() {
+        "http" | "https" => Ok(lower),
+        _ => Err(format!("unsupported scheme '{scheme}' (use http or https)")),
+    }
+}
+
+/// Splits `host[:port]`, `[ipv6][:port]` or a bare IPv6 address.
+fn split_authority(authority: &str) -> Result<(String, Option<u16>), String> {
+    if let Some(rest) = authority.strip_prefix('[') {
+        let (host, tail) = rest
+            .split_once(']')
+            .ok_or_else(|| format!("unclosed '[' in network rule: {authority}"))?;
+        let port = match tail.strip_prefix(':') {
+            Some(port) => Some(parse_port(port)?),
+            None if tail.is_empty() => None,
+            None => return Err(format!("unexpected '{tail}' after IPv6 address")),
+        };
+        return Ok((host.to_string(), port));
+    }
+    if authority.parse::<IpAddr>().is_ok() {
+        return Ok((authority.to_string(), None));
+    }
+    match authority.rsplit_once(':') {
+        Some((host, port)) => Ok((host.to_ascii_lowercase(), Some(parse_port(port)?))),
+        None => Ok((authority.to_ascii_lowercase(), None)),
+    }
+}
+
+fn parse_port(port: &str) -> Result<u16, String> {
+    match port.parse::<u16>() {
+        Ok(p) if p > 0 => Ok(p),
+        _ => Err(format!("invalid port '{port}' in network rule")),
+    }
+}
+
+/// Recognises `ip/prefix` (without scheme) as a CIDR range; a prefix with
+/// a suffix, e.g. `10.0.0.0/8:443`, is an error rather than a one-host path.
+fn cidr_of(host: &str, path: Option<&str>, bare: bool) -> Option<Result<Cidr, String>> {
+    let digits = |p: &&str| p.starts_with(|c: char| c.is_ascii_digit());
+    let prefix = path?.strip_prefix('/').filter(digits)?;
+    let ip = host.parse::<IpAddr>().ok().filter(|_| bare)?;
+    Some(match prefix.parse::<u8>() {
+        Ok(prefix) => Cidr::new(ip, prefix),
+        Err(_) => Err(format!("invalid CIDR prefix '/{prefix}' in network rule")),
+    })
+}
+
+fn parse_host(host: &str, entry: &str) -> Result<HostPattern, String> {
+    HostPattern::parse(host).map_err(|reason| format!("{reason} in network rule: {entry}"))
+}
@@ -0,0 +1,67 @@
+//! Screening of single addresses and host-side resolution of HTTP
+//! destinations for a [`NetworkPolicy`].
+
+use std::net::{IpAddr, SocketAddr};
+
+use kami_types::Capability;
+
+use super::deny::NetworkDenyReason;
+use super::policy::NetworkPolicy;
+use super::range::is_public;
+use crate::error::SandboxError;
+
+impl NetworkPolicy {
+    /// Resolves the host of an allowed HTTP request and keeps the
+    /// addresses that pass deny rules and the non-public check. Connecting
+    /// to these (not re-resolving) closes the DNS rebinding window. The
+    /// guard, if any, is consulted with the host before resolution.
+    ///
+    /// # Errors
+    ///
+    /// Returns why no address is usable.
+    pub async fn resolve_http(
+        &self,
+        host: &str,
+        port: u16,
+    ) -> Result<Vec<SocketAddr>, NetworkDenyReason> {
+        let host = host.trim_start_matches('[').trim_end_matches(']');
+        self.consult(host)?;
+        let ips = match host.parse::<IpAddr>() {
+            Ok(ip) => vec![ip],
+            Err(_) => self.resolver.resolve(host).await,
+        };
+        let mut refused = NetworkDenyReason::Unresolved;
+        let mut usable = Vec::with_capacity(ips.len());
+        for ip in ips {
+            let addr = SocketAddr::new(ip.to_canonical(), port);
+            match self.screen(&addr) {
+                Ok(()) => usable.push(addr),
+                Err(reason) => refused = reason,
+            }
+        }
+        Some(usable).filter(|u| !u.is_empty()).ok_or(refused)
+    }
+
+    /// Applies deny rules and the non-public check to an address.
+    pub(super) fn screen(&self, addr: &SocketAddr) -> Result<(), NetworkDenyReason> {
+        if self.rules.iter().any(|r| r.deny && r.matches_addr(addr)) {
+            return Err(NetworkDenyReason::DenyRule);
+        }
+        if !self.allow_private && !is_public(addr.ip()) {
+            return Err(NetworkDenyReason::NonPublicAddress(addr.ip()));
+        }
+        Ok(())
+    }
+
+    /// Asks the guard, if any, whether `host` may be reached.
+    pub(super) fn consult(&self, host: &str) -> Result<(), NetworkDenyReason> {
+        let Some(guard) = &self.guard else {
+            return Ok(());
+        };
+        match guard.check(&Capability::Network(host.to_string())) {
+            Ok(()) => Ok(()),
+            Err(SandboxError::NetworkDenied { reason, .. }) => Err(reason),
+            Err(_) => Err(NetworkDenyReason::HostPolicy),
+        }
+    }
+}
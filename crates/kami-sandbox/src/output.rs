@@ -0,0 +1,136 @@
+//! Bounded capture of guest stdout/stderr.
+//!
+//! Inheriting the host's stdio lets a guest write into the JSON-RPC stream
+//! of `kami serve`. Instead each execution gets in-memory pipes that keep
+//! at most a fixed number of bytes. Output beyond the cap is counted and
+//! dropped, never reported to the guest as an error, so a chatty tool
+//! cannot trap itself by logging.
+
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use bytes::Bytes;
+use wasmtime_wasi::{HostOutputStream, StdoutStream, StreamResult, Subscribe};
+
+/// Write budget advertised to the guest on each `check-write`.
+const WRITE_BUDGET: usize = 64 * 1024;
+
+/// In-memory output stream keeping the first `max_bytes` bytes written.
+#[derive(Debug, Clone)]
+pub struct CapturePipe {
+    inner: Arc<Mutex<Captured>>,
+    max_bytes: usize,
+}
+
+#[derive(Debug, Default)]
+struct Captured {
+    buf: Vec<u8>,
+    dropped: usize,
+}
+
+impl CapturePipe {
+    /// Creates a pipe retaining at most `max_bytes` bytes.
+    pub fn new(max_bytes: usize) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(Captured::default())),
+            max_bytes,
+        }
+    }
+
+    /// Returns the captured bytes.
+    pub fn contents(&self) -> Vec<u8> {
+        self.lock().buf.clone()
+    }
+
+    /// Returns the number of bytes dropped because the cap was reached.
+    pub fn dropped(&self) -> usize {
+        self.lock().dropped
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Captured> {
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl HostOutputStream for CapturePipe {
+    fn write(&mut self, bytes: Bytes) -> StreamResult<()> {
+        let mut captured = self.lock();
+        let room = self.max_bytes.saturating_sub(captured.buf.len());
+        let kept = bytes.len().min(room);
+        captured.buf.extend_from_slice(&bytes[..kept]);
+        captured.dropped += bytes.len() - kept;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> StreamResult<()> {
+        Ok(())
+    }
+
+    fn check_write(&mut self) -> StreamResult<usize> {
+        Ok(WRITE_BUDGET)
+    }
+}
+
+#[wasmtime_wasi::async_trait]
+impl Subscribe for CapturePipe {
+    async fn ready(&mut self) {}
+}
+
+impl StdoutStream for CapturePipe {
+    fn stream(&self) -> Box<dyn HostOutputStream> {
+        Box::new(self.clone())
+    }
+
+    fn isatty(&self) -> bool {
+        false
+    }
+}
+
+/// Captured stdout and stderr of one execution.
+#[derive(Debug, Clone)]
+pub struct GuestOutput {
+    /// Guest stdout.
+    pub stdout: CapturePipe,
+    /// Guest stderr.
+    pub stderr: CapturePipe,
+}
+
+impl GuestOutput {
+    /// Creates a capture keeping at most `max_bytes` per stream.
+    pub fn new(max_bytes: usize) -> Self {
+        Self {
+            stdout: CapturePipe::new(max_bytes),
+            stderr: CapturePipe::new(max_bytes),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_within_cap_is_kept() {
+        let mut pipe = CapturePipe::new(16);
+        pipe.write(Bytes::from_static(b"hello")).expect("write");
+        assert_eq!(pipe.contents(), b"hello");
+        assert_eq!(pipe.dropped(), 0);
+    }
+
+    #[test]
+    fn write_beyond_cap_is_truncated_not_failed() {
+        let mut pipe = CapturePipe::new(4);
+        pipe.write(Bytes::from_static(b"hello")).expect("write");
+        pipe.write(Bytes::from_static(b"world")).expect("write");
+        assert_eq!(pipe.contents(), b"hell");
+        assert_eq!(pipe.dropped(), 6);
+        assert!(pipe.check_write().is_ok());
+    }
+
+    #[test]
+    fn clones_share_the_buffer() {
+        let pipe = CapturePipe::new(16);
+        let mut stream = pipe.stream();
+        stream.write(Bytes::from_static(b"x")).expect("write");
+        assert_eq!(pipe.contents(), b"x");
+    }
+}
@@ -0,0 +1,99 @@
+//! Capability checker enforcing an operator's host-wide policy.
+//!
+//! Wraps another checker (the default one unless told otherwise): the
+//! policy can refuse what the inner checker would allow, never the reverse.
+
+use std::sync::Arc;
+
+use kami_types::{Capability, FsAccess, HostPolicy, SecurityConfig};
+
+use crate::capability::{CapabilityChecker, DefaultCapabilityChecker};
+use crate::error::SandboxError;
+use crate::network::{NetRule, NetworkDenyReason};
+
+/// Checker applying a [`HostPolicy`] on top of an inner checker.
+#[derive(Debug)]
+pub struct PolicyChecker {
+    policy: HostPolicy,
+    deny_hosts: Vec<NetRule>,
+    inner: Arc<dyn CapabilityChecker>,
+}
+
+impl PolicyChecker {
+    /// Creates a checker wrapping [`DefaultCapabilityChecker`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `SandboxError::InvalidConfig` if a `deny_hosts` entry is
+    /// malformed.
+    pub fn new(policy: HostPolicy) -> Result<Self, SandboxError> {
+        let deny_hosts = policy
+            .deny_hosts
+            .iter()
+            .map(|entry| NetRule::parse(entry))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|reason| SandboxError::InvalidConfig {
+                reason: format!("host policy deny_hosts: {reason}"),
+            })?;
+        if deny_hosts.iter().any(|rule| rule.deny) {
+            return Err(SandboxError::InvalidConfig {
+                reason: "host policy deny_hosts entries must not start with '!'".to_string(),
+            });
+        }
+        Ok(Self {
+            policy,
+            deny_hosts,
+            inner: Arc::new(DefaultCapabilityChecker),
+        })
+    }
+
+    /// Consults `inner` for everything the policy does not refuse.
+    pub fn wrapping(mut self, inner: Arc<dyn CapabilityChecker>) -> Self {
+        self.inner = inner;
+        self
+    }
+
+    /// Returns the enforced policy.
+    pub fn policy(&self) -> &HostPolicy {
+        &self.policy
+    }
+
+    /// Returns true if the policy's filesystem ceiling is below `needed`.
+    fn exceeds_fs(&self, needed: FsAccess) -> bool {
+        self.policy
+            .max_fs_access
+            .as_ref()
+            .is_some_and(|max| *max < needed)
+    }
+}
+
+impl CapabilityChecker for PolicyChecker {
+    fn check(&self, capability: &Capability, config: &SecurityConfig) -> Result<(), SandboxError> {
+        match capability {
+            Capability::Network(host) if self.deny_hosts.iter().any(|r| r.matches_host(host)) => {
+                return Err(SandboxError::NetworkDenied {
+                    host: host.clone(),
+                    reason: NetworkDenyReason::HostPolicy,
+                });
+            }
+            Capability::FsRead(path) if self.exceeds_fs(FsAccess::ReadOnly) => {
+                return Err(SandboxError::FsDenied { path: path.clone() });
+            }
+            Capability::FsWrite(path) if self.exceeds_fs(FsAccess::Sandbox) => {
+                return Err(SandboxError::FsDenied { path: path.clone() });
+            }
+            Capability::EnvVar(var) if self.policy.deny_env.contains(var) => {
+                return Err(SandboxError::CapabilityDenied {
+                    capability: format!("env:{var}"),
+                });
+            }
+            _ => {}
+        }
+        self.inner.check(capability, config)
+    }
+
+    fn restrict(&self, config: &SecurityConfig, signer: Option<&str>) -> SecurityConfig {
+        let narrowed = self.inner.restrict(config, signer);
+        self.policy.restrict(&narrowed, signer)
+    }
+}
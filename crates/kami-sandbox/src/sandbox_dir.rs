@@ -0,0 +1,116 @@
+//! Per-tool sandbox directories preopened for filesystem access.
+//!
+//! Each tool gets its own directory under a data root
+//! (`<root>/<tool-id>/`). The tool id is validated through `FsJail` so it
+//! cannot point outside the root. Scratch mode instead hands out a fresh
+//! directory per call under `<root>/.scratch/`, removed when dropped.
+
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::error::SandboxError;
+use crate::filesystem::FsJail;
+
+/// Lifetime of the directory handed to a tool.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SandboxDirMode {
+    /// One directory per tool, kept across calls.
+    #[default]
+    Persistent,
+    /// A fresh directory per call, deleted after execution.
+    Scratch,
+}
+
+/// Allocator of per-tool sandbox directories under a common root.
+#[derive(Debug, Clone)]
+pub struct SandboxDirs {
+    jail: FsJail,
+    mode: SandboxDirMode,
+}
+
+/// A prepared sandbox directory. Scratch directories are removed on drop.
+#[derive(Debug)]
+pub struct SandboxDir {
+    path: PathBuf,
+    scratch: bool,
+}
+
+static SCRATCH_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+impl SandboxDirs {
+    /// Creates an allocator rooted at `root`.
+    pub fn new(root: impl Into<PathBuf>, mode: SandboxDirMode) -> Self {
+        Self {
+            jail: FsJail::new(root),
+            mode,
+        }
+    }
+
+    /// Returns the data root.
+    pub fn root(&self) -> &Path {
+        self.jail.root()
+    }
+
+    /// Returns the directory mode.
+    pub fn mode(&self) -> SandboxDirMode {
+        self.mode
+    }
+
+    /// Creates (if needed) and returns the directory for `tool_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SandboxError::FsDenied` if `tool_id` is not a single plain
+    /// path segment or resolves outside the root, and
+    /// `SandboxError::SandboxDir` if the directory cannot be created.
+    pub fn prepare(&self, tool_id: &str) -> Result<SandboxDir, SandboxError> {
+        let segment = Path::new(tool_id);
+        let mut components = segment.components();
+        if !matches!(
+            (components.next(), components.next()),
+            (Some(Component::Normal(_)), None)
+        ) {
+            return Err(SandboxError::FsDenied {
+                path: tool_id.to_string(),
+            });
+        }
+        let (relative, scratch) = match self.mode {
+            SandboxDirMode::Persistent => (segment.to_path_buf(), false),
+            SandboxDirMode::Scratch => {
+                let n = SCRATCH_COUNTER.fetch_add(1, Ordering::Relaxed);
+                let name = format!("{tool_id}-{}-{n}", std::process::id());
+                (Path::new(".scratch").join(name), true)
+            }
+        };
+        let path = self.jail.validate_path(&relative)?;
+        std::fs::create_dir_all(&path).map_err(|e| SandboxError::SandboxDir {
+            path: path.display().to_string(),
+            reason: e.to_string(),
+        })?;
+        // Re-check now that the directory exists (symlink escape).
+        let path = self.jail.validate_path(&relative)?;
+        Ok(SandboxDir { path, scratch })
+    }
+}
+
+impl SandboxDir {
+    /// Returns the host path of the directory.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns `true` if the directory is removed when dropped.
+    pub fn is_scratch(&self) -> bool {
+        self.scratch
+    }
+}
+
+impl Drop for SandboxDir {
+    fn drop(&mut self) {
+        if self.scratch {
+            if let Err(e) = std::fs::remove_dir_all(&self.path) {
+                tracing::warn!(path = %self.path.display(), %e, "failed to remove scratch dir");
+            }
+        }
+    }
+}
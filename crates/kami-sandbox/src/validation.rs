@@ -0,0 +1,43 @@
+//! Well-formedness checks on a `SecurityConfig` and host-wide ceilings
+//! no manifest may exceed.
+
+use kami_types::SecurityConfig;
+
+use crate::error::SandboxError;
+
+/// Largest `max_log_bytes` a manifest may request: guest output is
+/// buffered in host memory, once per stream and per execution.
+pub const MAX_LOG_BYTES: u64 = 4 * 1024 * 1024;
+
+/// Validates a `SecurityConfig` for well-formedness before use.
+///
+/// Catches misconfigurations early rather than at execution time.
+pub fn validate_security_config(config: &SecurityConfig) -> Result<(), SandboxError> {
+    // Validate network patterns
+    crate::network::validate_allow_list(&config.net_allow_list)
+        .map_err(|reason| SandboxError::InvalidConfig { reason })?;
+
+    // Validate resource limits
+    if config.limits.max_fuel == 0 {
+        return Err(SandboxError::InvalidConfig {
+            reason: "max_fuel must be > 0".to_string(),
+        });
+    }
+    if config.limits.max_memory_mb == 0 {
+        return Err(SandboxError::InvalidConfig {
+            reason: "max_memory_mb must be > 0".to_string(),
+        });
+    }
+    if config.limits.max_execution_ms == 0 {
+        return Err(SandboxError::InvalidConfig {
+            reason: "max_execution_ms must be > 0".to_string(),
+        });
+    }
+    if config.limits.max_log_bytes > MAX_LOG_BYTES {
+        return Err(SandboxError::InvalidConfig {
+            reason: format!("max_log_bytes must be <= {MAX_LOG_BYTES}"),
+        });
+    }
+
+    Ok(())
+}
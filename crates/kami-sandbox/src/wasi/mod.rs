@@ -0,0 +1,110 @@
+//! WASI context builder for sandboxed execution.
+//!
+//! Builds a `WasiCtx` from a `SecurityConfig`, enforcing deny-all defaults
+//! and granular permissions for network and filesystem access. Mounts
+//! and socket filtering live in the `mounts` and `sockets` submodules.
+
+mod mounts;
+mod sockets;
+
+use std::sync::Arc;
+
+use kami_types::{Capability, SecurityConfig};
+use wasmtime_wasi::{WasiCtx, WasiCtxBuilder};
+
+use crate::capability::CapabilityGuard;
+use crate::error::SandboxError;
+use crate::network::NetworkPolicy;
+use crate::output::GuestOutput;
+
+/// Options controlling WASI context construction.
+#[derive(Debug, Clone, Default)]
+pub struct WasiConfig {
+    /// Whether to inherit stdout (useful for CLI tools).
+    pub inherit_stdout: bool,
+    /// Whether to inherit stderr (useful for debugging).
+    pub inherit_stderr: bool,
+    /// Extra environment variables to expose.
+    pub env_vars: Vec<(String, String)>,
+    /// Declared secrets exposed as env vars; bypasses `env_allow_list`.
+    pub secret_env: Vec<(String, String)>,
+    /// Captures stdout/stderr in memory; takes precedence over `inherit_*`.
+    pub capture: Option<GuestOutput>,
+    /// Network policy shared with the HTTP layer; built from the security
+    /// config with the system resolver when `None`.
+    pub net_policy: Option<Arc<NetworkPolicy>>,
+    /// Consulted on every mount, environment variable and connection.
+    pub guard: Option<CapabilityGuard>,
+}
+
+/// Builds a `WasiCtx` from a `SecurityConfig` and optional overrides.
+///
+/// Enforces:
+/// - Network: deny-all unless hosts match `net_allow_list` patterns
+/// - Filesystem: deny-all / read-only / sandbox based on `fs_access`
+/// - Env vars: only explicit vars from `wasi_config`, plus granted secrets
+/// - DNS: disabled unless network is allowed
+///
+/// With a guard, each env var and secret must also pass it, and a mount
+/// it refuses fails the build.
+pub fn build_wasi_ctx(
+    security: &SecurityConfig,
+    wasi_config: &WasiConfig,
+    sandbox_dir: Option<&str>,
+) -> Result<WasiCtx, SandboxError> {
+    let mut builder = WasiCtxBuilder::new();
+
+    // -- stdio --
+    if let Some(capture) = &wasi_config.capture {
+        builder.stdout(capture.stdout.clone());
+        builder.stderr(capture.stderr.clone());
+    } else {
+        if wasi_config.inherit_stdout {
+            builder.inherit_stdout();
+        }
+        if wasi_config.inherit_stderr {
+            builder.inherit_stderr();
+        }
+    }
+
+    // -- environment variables (filtered by env_allow_list) --
+    // If env_allow_list is non-empty, only listed vars are exposed.
+    // If env_allow_list is empty, all explicitly provided vars are allowed.
+    let guard = wasi_config.guard.as_ref();
+    for (key, value) in &wasi_config.env_vars {
+        if !security.env_allow_list.is_empty() && !security.env_allow_list.contains(key) {
+            tracing::warn!(
+                key = %key,
+                "env var blocked by allow-list"
+            );
+            continue;
+        }
+        if refused(guard, Capability::EnvVar(key.clone())) {
+            continue;
+        }
+        builder.env(key, value);
+    }
+    for (key, value) in &wasi_config.secret_env {
+        if !refused(guard, Capability::Secret(key.clone())) {
+            builder.env(key, value);
+        }
+    }
+
+    // -- filesystem --
+    mounts::configure_filesystem(&mut builder, security, sandbox_dir, guard)?;
+
+    // -- network --
+    let policy = wasi_config.net_policy.clone();
+    sockets::configure_network(&mut builder, security, policy, guard);
+
+    Ok(builder.build())
+}
+
+/// Returns true (and logs) if `guard` refuses `capability`.
+fn refused(guard: Option<&CapabilityGuard>, capability: Capability) -> bool {
+    let Some(Err(e)) = guard.map(|g| g.check(&capability)) else {
+        return false;
+    };
+    tracing::warn!(error = %e, "env var blocked by capability checker");
+    true
+}
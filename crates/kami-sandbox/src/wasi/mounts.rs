@@ -0,0 +1,49 @@
+//! Filesystem preopens of a sandboxed WASI context.
+
+use kami_types::{Capability, FsAccess, SecurityConfig};
+use wasmtime_wasi::{DirPerms, FilePerms, WasiCtxBuilder};
+
+use crate::capability::CapabilityGuard;
+use crate::error::SandboxError;
+
+/// Configures filesystem access based on security policy.
+///
+/// The guard, if any, must allow reading (and for `sandbox`, writing)
+/// the directory before it is mounted.
+pub(super) fn configure_filesystem(
+    builder: &mut WasiCtxBuilder,
+    security: &SecurityConfig,
+    sandbox_dir: Option<&str>,
+    guard: Option<&CapabilityGuard>,
+) -> Result<(), SandboxError> {
+    let Some(dir) = sandbox_dir else {
+        return Ok(());
+    };
+    match security.fs_access {
+        FsAccess::None => {
+            // No filesystem access - nothing to configure
+        }
+        FsAccess::ReadOnly => {
+            if let Some(guard) = guard {
+                guard.check(&Capability::FsRead(dir.to_string()))?;
+            }
+            builder
+                .preopened_dir(dir, ".", DirPerms::READ, FilePerms::READ)
+                .map_err(|e| SandboxError::WasiBuild {
+                    reason: format!("failed to preopen read-only dir: {e}"),
+                })?;
+        }
+        FsAccess::Sandbox => {
+            if let Some(guard) = guard {
+                guard.check(&Capability::FsRead(dir.to_string()))?;
+                guard.check(&Capability::FsWrite(dir.to_string()))?;
+            }
+            builder
+                .preopened_dir(dir, ".", DirPerms::all(), FilePerms::all())
+                .map_err(|e| SandboxError::WasiBuild {
+                    reason: format!("failed to preopen sandbox dir: {e}"),
+                })?;
+        }
+    }
+    Ok(())
+}
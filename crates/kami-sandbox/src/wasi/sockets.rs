@@ -0,0 +1,46 @@
+//! Socket filtering of a sandboxed WASI context.
+
+use std::sync::Arc;
+
+use kami_types::SecurityConfig;
+use wasmtime_wasi::WasiCtxBuilder;
+
+use crate::capability::CapabilityGuard;
+use crate::network::{NetworkPolicy, SystemResolver};
+
+/// Configures network access using `socket_addr_check` for granular control.
+///
+/// Every socket is checked by a [`NetworkPolicy`] living as long as this
+/// context, so its IP→hostname cache is per execution. A policy built
+/// here consults `guard`; a shared one carries its own.
+pub(super) fn configure_network(
+    builder: &mut WasiCtxBuilder,
+    security: &SecurityConfig,
+    policy: Option<Arc<NetworkPolicy>>,
+    guard: Option<&CapabilityGuard>,
+) {
+    let has_network = !security.net_allow_list.is_empty();
+
+    if has_network {
+        let policy = policy.unwrap_or_else(|| {
+            let resolver = Arc::new(SystemResolver);
+            let policy = NetworkPolicy::from_config(security, resolver);
+            Arc::new(match guard {
+                Some(guard) => policy.with_guard(guard.clone()),
+                None => policy,
+            })
+        });
+        builder.socket_addr_check(move |addr, _addr_use| {
+            let policy = Arc::clone(&policy);
+            Box::pin(async move {
+                let checked = policy.check_socket(addr).await;
+                if let Err(reason) = &checked {
+                    tracing::warn!(%addr, %reason, "socket denied by network policy");
+                }
+                checked.is_ok()
+            })
+        });
+        builder.allow_ip_name_lookup(true);
+    }
+    // If no allow_list: network is deny-all by default (no inherit_network)
+}
@@ -1,7 +1,7 @@
-//! Tests for capability checking and security config validation.
+//! Tests for capability checking.
 
-use kami_sandbox::{validate_security_config, CapabilityChecker, DefaultCapabilityChecker};
-use kami_types::{Capability, FsAccess, ResourceLimits, SecurityConfig};
+use kami_sandbox::{CapabilityChecker, DefaultCapabilityChecker};
+use kami_types::{Capability, FsAccess, SecurityConfig};
 
 #[test]
 fn default_checker_denies_network() {
@@ -83,54 +83,43 @@ fn checker_allows_env_when_listed() {
 }
 
 #[test]
-fn validate_default_config_is_ok() {
+fn checker_denies_key_value_by_default() {
+    let checker = DefaultCapabilityChecker;
     let config = SecurityConfig::default();
-    assert!(validate_security_config(&config).is_ok());
+    assert!(checker.check(&Capability::KeyValue, &config).is_err());
 }
 
 #[test]
-fn validate_rejects_zero_fuel() {
-    let config = SecurityConfig {
-        limits: ResourceLimits {
-            max_fuel: 0,
-            ..ResourceLimits::default()
-        },
-        ..SecurityConfig::default()
-    };
-    assert!(validate_security_config(&config).is_err());
-}
-
-#[test]
-fn validate_rejects_zero_memory() {
+fn checker_allows_key_value_when_granted() {
+    let checker = DefaultCapabilityChecker;
     let config = SecurityConfig {
-        limits: ResourceLimits {
-            max_memory_mb: 0,
-            ..ResourceLimits::default()
-        },
+        key_value: true,
         ..SecurityConfig::default()
     };
-    assert!(validate_security_config(&config).is_err());
+    assert!(checker.check(&Capability::KeyValue, &config).is_ok());
 }
 
 #[test]
-fn validate_rejects_zero_execution_ms() {
+fn checker_denies_undeclared_secret() {
+    let checker = DefaultCapabilityChecker;
     let config = SecurityConfig {
-        limits: ResourceLimits {
-            max_execution_ms: 0,
-            ..ResourceLimits::default()
-        },
+        secrets: vec!["GITHUB_TOKEN".to_string()],
         ..SecurityConfig::default()
     };
-    assert!(validate_security_config(&config).is_err());
+    let cap = Capability::Secret("AWS_KEY".to_string());
+    let err = checker.check(&cap, &config).unwrap_err();
+    assert!(err.to_string().contains("secret:AWS_KEY"));
 }
 
 #[test]
-fn validate_rejects_empty_net_pattern() {
+fn checker_allows_declared_secret() {
+    let checker = DefaultCapabilityChecker;
     let config = SecurityConfig {
-        net_allow_list: vec!["".to_string()],
+        secrets: vec!["GITHUB_TOKEN".to_string()],
         ..SecurityConfig::default()
     };
-    assert!(validate_security_config(&config).is_err());
+    let cap = Capability::Secret("GITHUB_TOKEN".to_string());
+    assert!(checker.check(&cap, &config).is_ok());
 }
 
 #[test]
@@ -70,3 +70,15 @@ fn wasi_build_has_no_hint() {
     };
     assert!(e.hint().is_none());
 }
+
+#[test]
+fn sandbox_dir_maps_to_internal_with_fix() {
+    let e = SandboxError::SandboxDir {
+        path: "/data/tool".into(),
+        reason: "denied".into(),
+    };
+    assert!(e.hint().expect("hint").contains("/data/tool"));
+    assert!(e.fix().expect("fix").contains("KAMI_DATA_DIR"));
+    let err: KamiError = e.into();
+    assert_eq!(err.kind, ErrorKind::Internal);
+}
@@ -1,45 +1,82 @@
 //! Property-based tests for network allow-list enforcement.
 //!
-//! Uses `proptest` to fuzz hostnames, IP addresses, and patterns —
-//! verifying that IP addresses never match hostname wildcard patterns.
+//! Uses `proptest` to fuzz hostnames, paths and patterns — verifying that
+//! method, scheme, port and path restrictions cannot be bypassed. IP and
+//! CIDR rules are fuzzed in `fuzz_network_cidr.rs`.
 
-use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
-
-use kami_sandbox::network::{is_addr_allowed, is_host_allowed};
+use kami_sandbox::network::{is_host_allowed, is_request_allowed, HttpTarget, NetRule};
 use proptest::prelude::*;
 
-/// Strategy producing random IPv4 addresses.
-fn ipv4_strategy() -> impl Strategy<Value = Ipv4Addr> {
-    (any::<u8>(), any::<u8>(), any::<u8>(), any::<u8>())
-        .prop_map(|(a, b, c, d)| Ipv4Addr::new(a, b, c, d))
+/// Strategy producing request paths, including dot segments and encodings.
+fn path_strategy() -> impl Strategy<Value = String> {
+    prop::collection::vec(
+        prop_oneof![
+            "[a-z0-9]{1,6}",
+            Just("..".to_string()),
+            Just("%2e%2e".to_string())
+        ],
+        0..5,
+    )
+    .prop_map(|segments| format!("/{}", segments.join("/")))
 }
 
-/// Strategy producing wildcard hostname patterns like `*.example.com`.
-fn wildcard_pattern_strategy() -> impl Strategy<Value = String> {
-    "[a-z]{2,8}\\.[a-z]{2,4}".prop_map(|domain| format!("*.{domain}"))
+/// Strategy producing HTTP methods.
+fn method_strategy() -> impl Strategy<Value = &'static str> {
+    prop::sample::select(vec!["GET", "HEAD", "POST", "PUT", "PATCH", "DELETE"])
 }
 
 proptest! {
-    /// An IP address must never match a wildcard hostname pattern.
+    /// Parsing arbitrary input never panics.
     #[test]
-    fn ip_never_matches_wildcard(
-        ip in ipv4_strategy(),
-        pattern in wildcard_pattern_strategy(),
-    ) {
-        let addr = SocketAddr::V4(SocketAddrV4::new(ip, 8080));
-        let allow_list = vec![pattern.clone()];
-        prop_assert!(
-            !is_addr_allowed(&addr, &allow_list),
-            "IP {ip} matched wildcard {pattern}",
-        );
+    fn parse_never_panics(entry in "\\PC{0,64}") {
+        let _ = NetRule::parse(&entry);
+    }
+
+    /// A `GET`-only rule never admits another method.
+    #[test]
+    fn method_rule_blocks_other_methods(method in method_strategy(), path in path_strategy()) {
+        let rule = NetRule::parse("GET https://api.example.com").expect("rule");
+        let target = HttpTarget {
+            method,
+            scheme: "https",
+            host: "api.example.com",
+            port: 443,
+            path: &path,
+        };
+        prop_assert_eq!(is_request_allowed(&target, &[rule]), method == "GET");
+    }
+
+    /// An `https://` rule admits only https on its port.
+    #[test]
+    fn scheme_rule_pins_scheme_and_port(https in any::<bool>(), port in 1u16..) {
+        let rule = NetRule::parse("https://api.example.com").expect("rule");
+        let target = HttpTarget {
+            method: "GET",
+            scheme: if https { "https" } else { "http" },
+            host: "api.example.com",
+            port,
+            path: "/",
+        };
+        prop_assert_eq!(is_request_allowed(&target, &[rule]), https && port == 443);
     }
 
-    /// An IP address matches only when explicitly listed.
+    /// A path rule never admits a path outside its prefix or with dot segments.
     #[test]
-    fn ip_matches_only_when_listed(ip in ipv4_strategy()) {
-        let addr = SocketAddr::V4(SocketAddrV4::new(ip, 443));
-        let allow_list = vec![ip.to_string()];
-        prop_assert!(is_addr_allowed(&addr, &allow_list));
+    fn path_rule_stays_under_prefix(path in path_strategy(), under in any::<bool>()) {
+        let path = if under { format!("/repos{path}") } else { path };
+        let rule = NetRule::parse("https://api.example.com/repos/*").expect("rule");
+        let target = HttpTarget {
+            method: "GET",
+            scheme: "https",
+            host: "api.example.com",
+            port: 443,
+            path: &path,
+        };
+        if is_request_allowed(&target, &[rule]) {
+            prop_assert!(path.starts_with("/repos/"));
+            prop_assert!(!path.contains(".."), "dot segment admitted: {}", path);
+            prop_assert!(!path.to_ascii_lowercase().contains("%2e"));
+        }
     }
 
     /// A hostname never matches when the allow list is empty.
@@ -0,0 +1,80 @@
+//! Property-based tests for IP, CIDR and address-class rules.
+//!
+//! Uses `proptest` to fuzz IP addresses — verifying that they never match
+//! hostname wildcard patterns, that deny classes beat CIDR allows and that
+//! tunnelled or reserved IPv6 ranges are judged by what they reach.
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4};
+
+use kami_sandbox::network::{is_addr_allowed, is_public};
+use proptest::prelude::*;
+
+/// Strategy producing random IPv4 addresses.
+fn ipv4_strategy() -> impl Strategy<Value = Ipv4Addr> {
+    (any::<u8>(), any::<u8>(), any::<u8>(), any::<u8>())
+        .prop_map(|(a, b, c, d)| Ipv4Addr::new(a, b, c, d))
+}
+
+/// Strategy producing wildcard hostname patterns like `*.example.com`.
+fn wildcard_pattern_strategy() -> impl Strategy<Value = String> {
+    "[a-z]{2,8}\\.[a-z]{2,4}".prop_map(|domain| format!("*.{domain}"))
+}
+
+proptest! {
+    /// A socket rule with a port only admits that port.
+    #[test]
+    fn ip_rule_with_port_pins_port(ip in ipv4_strategy(), port in 1u16..) {
+        let addr = SocketAddr::V4(SocketAddrV4::new(ip, port));
+        let allow_list = vec![format!("{ip}:8443")];
+        prop_assert_eq!(is_addr_allowed(&addr, &allow_list), port == 8443);
+    }
+
+    /// An IP address must never match a wildcard hostname pattern.
+    #[test]
+    fn ip_never_matches_wildcard(
+        ip in ipv4_strategy(),
+        pattern in wildcard_pattern_strategy(),
+    ) {
+        let addr = SocketAddr::V4(SocketAddrV4::new(ip, 8080));
+        let allow_list = vec![pattern.clone()];
+        prop_assert!(
+            !is_addr_allowed(&addr, &allow_list),
+            "IP {ip} matched wildcard {pattern}",
+        );
+    }
+
+    /// An IP address matches only when explicitly listed.
+    #[test]
+    fn ip_matches_only_when_listed(ip in ipv4_strategy()) {
+        let addr = SocketAddr::V4(SocketAddrV4::new(ip, 443));
+        let allow_list = vec![ip.to_string()];
+        prop_assert!(is_addr_allowed(&addr, &allow_list));
+    }
+
+    /// A `!private` deny wins over a catch-all CIDR allow for 10.0.0.0/8.
+    #[test]
+    fn deny_class_beats_cidr_allow(b in any::<u8>(), c in any::<u8>(), d in any::<u8>()) {
+        let addr = SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::new(10, b, c, d), 443));
+        let allow_list = vec!["0.0.0.0/0".to_string(), "!private".to_string()];
+        prop_assert!(!is_addr_allowed(&addr, &allow_list));
+    }
+
+    /// A 6to4 address is only as public as the IPv4 address it embeds.
+    #[test]
+    fn six_to_four_follows_embedded_ipv4(ip in ipv4_strategy(), host in any::<u64>()) {
+        let [a, b, c, d] = ip.octets();
+        let prefix = u128::from(u16::from_be_bytes([a, b])) << 96
+            | u128::from(u16::from_be_bytes([c, d])) << 80;
+        let v6 = Ipv6Addr::from(0x2002_u128 << 112 | prefix | u128::from(host));
+        prop_assert_eq!(is_public(IpAddr::V6(v6)), is_public(IpAddr::V4(ip)));
+    }
+
+    /// Local-use NAT64 and documentation addresses are never public.
+    #[test]
+    fn local_nat64_and_documentation_are_not_public(low in any::<u128>()) {
+        let nat64 = 0x0064_ff9b_0001_u128 << 80 | low >> 48;
+        let doc = 0x2001_0db8_u128 << 96 | low >> 32;
+        prop_assert!(!is_public(IpAddr::V6(Ipv6Addr::from(nat64))));
+        prop_assert!(!is_public(IpAddr::V6(Ipv6Addr::from(doc))));
+    }
+}
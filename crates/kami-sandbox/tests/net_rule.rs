@@ -0,0 +1,147 @@
+//! Tests for rich `net_allow_list` rules: methods, scheme, port and path.
+//!
+//! CIDR, address-class and deny rules are tested in `net_rule_cidr.rs`.
+
+use kami_sandbox::network::{
+    is_request_allowed, parse_allow_list, HostPattern, HttpTarget, NetRule,
+};
+
+/// An HTTPS `GET` on the default port.
+fn get<'a>(host: &'a str, path: &'a str) -> HttpTarget<'a> {
+    HttpTarget {
+        method: "GET",
+        scheme: "https",
+        host,
+        port: 443,
+        path,
+    }
+}
+
+fn rules(entries: &[&str]) -> Vec<NetRule> {
+    let entries: Vec<String> = entries.iter().map(|e| e.to_string()).collect();
+    parse_allow_list(&entries)
+}
+
+#[test]
+fn parses_full_rule() {
+    let rule = NetRule::parse("get,HEAD https://API.github.com:8443/repos/*").expect("parse");
+    assert_eq!(rule.methods, vec!["GET", "HEAD"]);
+    assert_eq!(rule.scheme.as_deref(), Some("https"));
+    assert_eq!(rule.host, HostPattern::Name("api.github.com".to_string()));
+    assert_eq!(rule.port, Some(8443));
+    assert_eq!(rule.path.as_deref(), Some("/repos/*"));
+}
+
+#[test]
+fn scheme_implies_default_port() {
+    assert_eq!(NetRule::parse("https://a.com").expect("p").port, Some(443));
+    assert_eq!(NetRule::parse("http://a.com").expect("p").port, Some(80));
+    assert_eq!(NetRule::parse("a.com").expect("p").port, None);
+}
+
+#[test]
+fn parses_ipv6_with_and_without_port() {
+    let bracketed = NetRule::parse("[::1]:8080").expect("parse");
+    let loopback = HostPattern::Ip("::1".parse().expect("ip"));
+    assert_eq!((&bracketed.host, bracketed.port), (&loopback, Some(8080)));
+    let bare = NetRule::parse("::1").expect("parse");
+    assert_eq!((&bare.host, bare.port), (&loopback, None));
+}
+
+#[test]
+fn rejects_malformed_rules() {
+    for entry in [
+        "",
+        "FETCH api.github.com",
+        "ftp://api.github.com",
+        "api.github.com:0",
+        "api.github.com:http",
+        "[::1",
+        "*.",
+        "a.*.com",
+        "user@host.com",
+        "10.0.0.0/33",
+        "!",
+    ] {
+        assert!(NetRule::parse(entry).is_err(), "accepted {entry:?}");
+    }
+}
+
+#[test]
+fn bare_host_allows_any_method_scheme_port_and_path() {
+    let list = rules(&["api.github.com"]);
+    let target = HttpTarget {
+        method: "DELETE",
+        scheme: "http",
+        port: 8080,
+        ..get("api.github.com", "/anything")
+    };
+    assert!(is_request_allowed(&target, &list));
+}
+
+#[test]
+fn https_rule_blocks_plain_http_and_other_ports() {
+    let list = rules(&["https://api.github.com"]);
+    assert!(is_request_allowed(&get("api.github.com", "/"), &list));
+    let plain = HttpTarget {
+        scheme: "http",
+        port: 80,
+        ..get("api.github.com", "/")
+    };
+    assert!(!is_request_allowed(&plain, &list));
+    let other_port = HttpTarget {
+        port: 8443,
+        ..get("api.github.com", "/")
+    };
+    assert!(!is_request_allowed(&other_port, &list));
+}
+
+#[test]
+fn method_restriction_is_enforced() {
+    let list = rules(&["GET https://api.github.com"]);
+    assert!(is_request_allowed(&get("api.github.com", "/"), &list));
+    let post = HttpTarget {
+        method: "POST",
+        ..get("api.github.com", "/")
+    };
+    assert!(!is_request_allowed(&post, &list));
+}
+
+#[test]
+fn path_glob_is_enforced() {
+    let list = rules(&["https://api.github.com/repos/*"]);
+    assert!(is_request_allowed(
+        &get("api.github.com", "/repos/a/b"),
+        &list
+    ));
+    assert!(!is_request_allowed(&get("api.github.com", "/user"), &list));
+    assert!(!is_request_allowed(&get("api.github.com", "/repos"), &list));
+}
+
+#[test]
+fn path_rules_refuse_dot_segments() {
+    let list = rules(&["https://api.github.com/repos/*"]);
+    for path in ["/repos/../admin", "/repos/%2e%2E/admin", "/repos/./x"] {
+        assert!(
+            !is_request_allowed(&get("api.github.com", path), &list),
+            "{path}"
+        );
+    }
+}
+
+#[test]
+fn host_matching_is_case_insensitive() {
+    let list = rules(&["*.Example.com"]);
+    assert!(is_request_allowed(&get("API.example.COM", "/"), &list));
+}
+
+#[test]
+fn star_allows_any_host() {
+    let list = rules(&["*"]);
+    assert!(is_request_allowed(&get("anything.net", "/"), &list));
+}
+
+#[test]
+fn empty_list_denies_every_request() {
+    assert!(!is_request_allowed(&get("api.github.com", "/"), &[]));
+}
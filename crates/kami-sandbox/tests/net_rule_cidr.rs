@@ -0,0 +1,62 @@
+//! Tests for CIDR, address-class and `!` deny rules in `net_allow_list`.
+
+use kami_sandbox::network::{
+    is_request_allowed, parse_allow_list, AddrClass, HostPattern, HttpTarget, NetRule,
+};
+
+/// An HTTPS `GET /` on the default port.
+fn get(host: &str) -> HttpTarget<'_> {
+    HttpTarget {
+        method: "GET",
+        scheme: "https",
+        host,
+        port: 443,
+        path: "/",
+    }
+}
+
+fn rules(entries: &[&str]) -> Vec<NetRule> {
+    let entries: Vec<String> = entries.iter().map(|e| e.to_string()).collect();
+    parse_allow_list(&entries)
+}
+
+#[test]
+fn parses_cidr_classes_and_denies() {
+    let cidr = NetRule::parse("10.0.0.0/8").expect("parse");
+    assert!(matches!(cidr.host, HostPattern::Cidr(_)));
+    assert_eq!(cidr.path, None);
+    let class = NetRule::parse("!link-local").expect("parse");
+    assert!(class.deny);
+    assert_eq!(class.host, HostPattern::Class(AddrClass::LinkLocal));
+    // With a scheme, an IP followed by digits is a path, not a prefix.
+    let path = NetRule::parse("http://10.0.0.1/8").expect("parse");
+    assert_eq!(path.path.as_deref(), Some("/8"));
+}
+
+#[test]
+fn rejects_cidr_with_port_path_or_bad_prefix() {
+    for entry in [
+        "10.0.0.0/8:443",
+        "10.0.0.0/8/api",
+        "10.0.0.0/33",
+        "::/129",
+        "10.0.0.0/8x",
+    ] {
+        assert!(NetRule::parse(entry).is_err(), "accepted {entry:?}");
+    }
+    // A path that is not a prefix still makes a single-host rule.
+    let host = NetRule::parse("10.0.0.1/api").expect("parse");
+    assert_eq!(host.path.as_deref(), Some("/api"));
+}
+
+#[test]
+fn deny_rule_overrides_allow() {
+    let list = rules(&["*.example.com", "!GET admin.example.com"]);
+    assert!(is_request_allowed(&get("api.example.com"), &list));
+    assert!(!is_request_allowed(&get("admin.example.com"), &list));
+}
+
+#[test]
+fn malformed_deny_rule_denies_everything() {
+    assert!(rules(&["api.github.com", "!a.*.com"]).is_empty());
+}
@@ -0,0 +1,120 @@
+//! Tests for DNS-aware socket filtering with a stub resolver.
+
+use std::net::{IpAddr, SocketAddr};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use kami_sandbox::network::{
+    parse_allow_list, HostResolver, NetworkPolicy, ResolveFuture, StaticResolver,
+};
+
+fn ip(s: &str) -> IpAddr {
+    s.parse().expect("ip")
+}
+
+fn addr(s: &str) -> SocketAddr {
+    s.parse().expect("socket addr")
+}
+
+fn policy(entries: &[&str], resolver: StaticResolver) -> NetworkPolicy {
+    let entries: Vec<String> = entries.iter().map(|e| e.to_string()).collect();
+    NetworkPolicy::new(parse_allow_list(&entries), Arc::new(resolver))
+}
+
+fn example() -> StaticResolver {
+    StaticResolver::new().with("api.example.com", [ip("93.184.216.34"), ip("2606:2800::1")])
+}
+
+#[tokio::test]
+async fn allowed_hostname_permits_its_addresses() {
+    let policy = policy(&["api.example.com"], example());
+    assert!(policy.allows(addr("93.184.216.34:443")).await);
+    assert!(policy.allows(addr("[2606:2800::1]:443")).await);
+    assert!(!policy.allows(addr("93.184.216.35:443")).await);
+}
+
+#[tokio::test]
+async fn hostname_port_is_enforced() {
+    let policy = policy(&["https://api.example.com"], example());
+    assert!(policy.allows(addr("93.184.216.34:443")).await);
+    assert!(!policy.allows(addr("93.184.216.34:22")).await);
+}
+
+#[tokio::test]
+async fn wildcard_and_path_rules_never_grant_sockets() {
+    let resolver = example();
+    let wildcard = policy(&["*.example.com"], resolver.clone());
+    assert!(!wildcard.allows(addr("93.184.216.34:443")).await);
+    let path = policy(&["api.example.com/v1/*"], resolver);
+    assert!(!path.allows(addr("93.184.216.34:443")).await);
+}
+
+#[tokio::test]
+async fn cidr_rule_allows_range() {
+    let policy = policy(&["10.1.0.0/16"], StaticResolver::new()).allow_private_networks(true);
+    assert!(policy.allows(addr("10.1.200.3:5432")).await);
+    assert!(!policy.allows(addr("10.2.0.1:5432")).await);
+}
+
+#[tokio::test]
+async fn deny_classes_override_resolved_names() {
+    let resolver = StaticResolver::new()
+        .with("internal.example.com", [ip("10.0.0.5")])
+        .with("metadata.example.com", [ip("169.254.169.254")])
+        .with("local.example.com", [ip("127.0.0.1")]);
+    let policy = policy(
+        &[
+            "internal.example.com",
+            "metadata.example.com",
+            "local.example.com",
+            "!private",
+            "!link-local",
+            "!loopback",
+        ],
+        resolver,
+    )
+    .allow_private_networks(true);
+    for target in ["10.0.0.5:80", "169.254.169.254:80", "127.0.0.1:80"] {
+        assert!(!policy.allows(addr(target)).await, "{target}");
+    }
+}
+
+#[tokio::test]
+async fn deny_applies_to_ipv4_mapped_addresses() {
+    let policy = policy(&["0.0.0.0/0", "!loopback"], StaticResolver::new());
+    assert!(policy.allows(addr("8.8.8.8:53")).await);
+    assert!(!policy.allows(addr("[::ffff:127.0.0.1]:80")).await);
+}
+
+/// Stub resolver counting lookups.
+#[derive(Debug, Default)]
+struct Counting(AtomicUsize);
+
+impl HostResolver for Counting {
+    fn resolve(&self, _host: &str) -> ResolveFuture {
+        self.0.fetch_add(1, Ordering::SeqCst);
+        Box::pin(async { vec![ip("93.184.216.34")] })
+    }
+}
+
+async fn lookups_after_two_connects(ttl: Duration) -> usize {
+    let resolver = Arc::new(Counting::default());
+    let rules = parse_allow_list(&["api.example.com".to_string()]);
+    let policy = NetworkPolicy::new(rules, resolver.clone()).with_ttl(ttl);
+    assert!(policy.allows(addr("93.184.216.34:443")).await);
+    assert!(policy.allows(addr("93.184.216.34:443")).await);
+    resolver.0.load(Ordering::SeqCst)
+}
+
+#[tokio::test]
+async fn cache_is_reused_then_expires() {
+    assert_eq!(lookups_after_two_connects(Duration::from_secs(60)).await, 1);
+    assert_eq!(lookups_after_two_connects(Duration::ZERO).await, 2);
+}
+
+#[tokio::test]
+async fn unresolvable_host_denies() {
+    let policy = policy(&["api.example.com"], StaticResolver::new());
+    assert!(!policy.allows(addr("93.184.216.34:443")).await);
+}
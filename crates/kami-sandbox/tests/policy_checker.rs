@@ -0,0 +1,147 @@
+//! Tests for the host policy checker and capability guards.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use kami_sandbox::network::{parse_allow_list, NetworkDenyReason, NetworkPolicy, StaticResolver};
+use kami_sandbox::{
+    build_wasi_ctx, CapabilityChecker, CapabilityGuard, PolicyChecker, SandboxError, WasiConfig,
+};
+use kami_types::{Capability, FsAccess, HostPolicy, SecurityConfig};
+
+fn checker(policy: HostPolicy) -> PolicyChecker {
+    PolicyChecker::new(policy).expect("valid policy")
+}
+
+fn deny_internal() -> PolicyChecker {
+    checker(HostPolicy {
+        deny_hosts: vec!["*.internal".to_string()],
+        ..HostPolicy::default()
+    })
+}
+
+fn open_config() -> SecurityConfig {
+    SecurityConfig {
+        net_allow_list: vec!["*".to_string()],
+        fs_access: FsAccess::Sandbox,
+        env_allow_list: vec!["HOME".to_string(), "TOKEN".to_string()],
+        ..SecurityConfig::default()
+    }
+}
+
+fn network(host: &str) -> Capability {
+    Capability::Network(host.to_string())
+}
+
+#[test]
+fn denied_hosts_beat_the_manifest() {
+    let checker = deny_internal();
+    let config = open_config();
+    assert!(checker.check(&network("api.example.com"), &config).is_ok());
+    let err = checker
+        .check(&network("db.prod.internal"), &config)
+        .expect_err("denied");
+    assert!(matches!(
+        err,
+        SandboxError::NetworkDenied {
+            reason: NetworkDenyReason::HostPolicy,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn inner_checker_still_applies() {
+    let checker = deny_internal();
+    let config = SecurityConfig::default();
+    assert!(checker.check(&network("api.example.com"), &config).is_err());
+}
+
+#[test]
+fn fs_ceiling_and_env_denials() {
+    let checker = checker(HostPolicy {
+        max_fs_access: Some(FsAccess::ReadOnly),
+        deny_env: vec!["TOKEN".to_string()],
+        ..HostPolicy::default()
+    });
+    let config = open_config();
+    let read = Capability::FsRead("/data".to_string());
+    let write = Capability::FsWrite("/data".to_string());
+    assert!(checker.check(&read, &config).is_ok());
+    assert!(checker.check(&write, &config).is_err());
+    let home = Capability::EnvVar("HOME".to_string());
+    let token = Capability::EnvVar("TOKEN".to_string());
+    assert!(checker.check(&home, &config).is_ok());
+    assert!(checker.check(&token, &config).is_err());
+}
+
+#[test]
+fn restrict_applies_the_policy() {
+    let out = deny_internal().restrict(&open_config(), None);
+    assert_eq!(out.net_allow_list, ["*", "!*.internal"]);
+}
+
+#[test]
+fn malformed_or_negated_deny_hosts_are_rejected() {
+    for entry in ["*.bad*", "!*.internal"] {
+        let policy = HostPolicy {
+            deny_hosts: vec![entry.to_string()],
+            ..HostPolicy::default()
+        };
+        assert!(PolicyChecker::new(policy).is_err(), "{entry}");
+    }
+}
+
+fn guarded_policy(entries: &[&str], resolver: StaticResolver) -> NetworkPolicy {
+    let entries: Vec<String> = entries.iter().map(|e| e.to_string()).collect();
+    let config = SecurityConfig {
+        net_allow_list: entries.clone(),
+        ..SecurityConfig::default()
+    };
+    let guard = CapabilityGuard::new(Arc::new(deny_internal()), config);
+    NetworkPolicy::new(parse_allow_list(&entries), Arc::new(resolver)).with_guard(guard)
+}
+
+#[tokio::test]
+async fn guard_sees_the_name_a_socket_resolved_from() {
+    let ip = "93.184.216.34".parse().expect("ip");
+    let resolver = StaticResolver::new()
+        .with("api.example.com", [ip])
+        .with("api.corp.internal", [ip]);
+    let addr: SocketAddr = "93.184.216.34:443".parse().expect("addr");
+
+    let open = guarded_policy(&["api.example.com"], resolver.clone());
+    assert!(open.check_socket(addr).await.is_ok());
+
+    let internal = guarded_policy(&["api.corp.internal"], resolver);
+    let refused = internal.check_socket(addr).await;
+    assert_eq!(refused, Err(NetworkDenyReason::HostPolicy));
+}
+
+#[tokio::test]
+async fn guard_refuses_http_before_resolution() {
+    let resolver =
+        StaticResolver::new().with("api.corp.internal", ["93.184.216.34".parse().expect("ip")]);
+    let policy = guarded_policy(&["*"], resolver);
+    let refused = policy.resolve_http("api.corp.internal", 443).await;
+    assert_eq!(refused, Err(NetworkDenyReason::HostPolicy));
+}
+
+#[test]
+fn guard_refusing_a_mount_fails_the_build() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let security = SecurityConfig {
+        fs_access: FsAccess::Sandbox,
+        ..SecurityConfig::default()
+    };
+    let read_only = checker(HostPolicy {
+        max_fs_access: Some(FsAccess::ReadOnly),
+        ..HostPolicy::default()
+    });
+    let wasi_config = WasiConfig {
+        guard: Some(CapabilityGuard::new(Arc::new(read_only), security.clone())),
+        ..WasiConfig::default()
+    };
+    let built = build_wasi_ctx(&security, &wasi_config, dir.path().to_str());
+    assert!(matches!(built, Err(SandboxError::FsDenied { .. })));
+}
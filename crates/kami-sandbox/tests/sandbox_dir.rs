@@ -0,0 +1,87 @@
+//! Tests for per-tool sandbox directory allocation.
+
+use kami_sandbox::{build_wasi_ctx, SandboxDirMode, SandboxDirs, SandboxError, WasiConfig};
+use kami_types::{FsAccess, SecurityConfig};
+
+#[test]
+fn persistent_dir_is_created_under_root() {
+    let root = tempfile::tempdir().expect("tempdir");
+    let dirs = SandboxDirs::new(root.path(), SandboxDirMode::Persistent);
+
+    let dir = dirs.prepare("dev.test.files").expect("prepare");
+
+    assert!(dir.path().is_dir());
+    assert!(dir.path().ends_with("dev.test.files"));
+    assert!(!dir.is_scratch());
+}
+
+#[test]
+fn persistent_dir_survives_drop() {
+    let root = tempfile::tempdir().expect("tempdir");
+    let dirs = SandboxDirs::new(root.path(), SandboxDirMode::Persistent);
+    let dir = dirs.prepare("dev.test.keep").expect("prepare");
+    std::fs::write(dir.path().join("state.txt"), "1").expect("write");
+    let path = dir.path().to_path_buf();
+
+    drop(dir);
+
+    assert!(path.join("state.txt").exists());
+}
+
+#[test]
+fn scratch_dirs_are_unique_and_removed_on_drop() {
+    let root = tempfile::tempdir().expect("tempdir");
+    let dirs = SandboxDirs::new(root.path(), SandboxDirMode::Scratch);
+
+    let a = dirs.prepare("dev.test.tmp").expect("a");
+    let b = dirs.prepare("dev.test.tmp").expect("b");
+    let path = a.path().to_path_buf();
+
+    assert_ne!(a.path(), b.path());
+    assert!(a.is_scratch());
+    drop(a);
+    assert!(!path.exists());
+    assert!(b.path().is_dir());
+}
+
+#[test]
+fn traversal_tool_id_is_rejected() {
+    let root = tempfile::tempdir().expect("tempdir");
+    let dirs = SandboxDirs::new(root.path(), SandboxDirMode::Persistent);
+
+    for id in ["..", "../escape", "a/b", "/etc", ""] {
+        let result = dirs.prepare(id);
+        assert!(
+            matches!(result, Err(SandboxError::FsDenied { .. })),
+            "{id:?} should be denied"
+        );
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn symlinked_tool_dir_escaping_root_is_rejected() {
+    let root = tempfile::tempdir().expect("root");
+    let outside = tempfile::tempdir().expect("outside");
+    std::os::unix::fs::symlink(outside.path(), root.path().join("dev.test.link")).expect("link");
+    let dirs = SandboxDirs::new(root.path(), SandboxDirMode::Persistent);
+
+    let result = dirs.prepare("dev.test.link");
+
+    assert!(matches!(result, Err(SandboxError::FsDenied { .. })));
+}
+
+#[test]
+fn prepared_dir_can_be_preopened() {
+    let root = tempfile::tempdir().expect("tempdir");
+    let dirs = SandboxDirs::new(root.path(), SandboxDirMode::Persistent);
+    let dir = dirs.prepare("dev.test.preopen").expect("prepare");
+    let security = SecurityConfig {
+        fs_access: FsAccess::Sandbox,
+        ..SecurityConfig::default()
+    };
+
+    let ctx = build_wasi_ctx(&security, &WasiConfig::default(), dir.path().to_str());
+
+    assert!(ctx.is_ok());
+}
@@ -0,0 +1,122 @@
+//! Tests for SSRF protection: non-public addresses are refused after
+//! resolution unless the tool sets `allow_private_networks`.
+
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+
+use kami_sandbox::network::{is_public, NetworkDenyReason, NetworkPolicy, StaticResolver};
+use kami_sandbox::{CapabilityChecker, DefaultCapabilityChecker, SandboxError};
+use kami_types::{Capability, SecurityConfig};
+
+fn ip(s: &str) -> IpAddr {
+    s.parse().expect("ip")
+}
+
+fn addr(s: &str) -> SocketAddr {
+    s.parse().expect("socket addr")
+}
+
+/// `api.example.com` is allowed but its DNS points at internal addresses.
+fn rebound(allow_private: bool) -> NetworkPolicy {
+    let resolver = StaticResolver::new().with(
+        "api.example.com",
+        [ip("127.0.0.1"), ip("10.0.0.7"), ip("169.254.169.254")],
+    );
+    let security = SecurityConfig {
+        net_allow_list: vec!["api.example.com".to_string()],
+        allow_private_networks: allow_private,
+        ..SecurityConfig::default()
+    };
+    NetworkPolicy::from_config(&security, Arc::new(resolver))
+}
+
+#[test]
+fn classifies_non_public_ranges() {
+    for private in [
+        "0.0.0.0",
+        "10.1.2.3",
+        "100.64.0.1",
+        "127.0.0.1",
+        "169.254.169.254",
+        "172.16.0.1",
+        "192.168.1.1",
+        "198.18.0.1",
+        "224.0.0.1",
+        "255.255.255.255",
+        "::",
+        "::1",
+        "fd00::1",
+        "fe80::1",
+        "::ffff:127.0.0.1",
+        "64:ff9b::a00:1",
+        "64:ff9b:1::808:808",
+        "2002:a00:1::1",
+        "2002:7f00:1::",
+        "2001:db8::1",
+    ] {
+        assert!(!is_public(ip(private)), "{private}");
+    }
+    for public in [
+        "93.184.216.34",
+        "8.8.8.8",
+        "2606:2800::1",
+        "64:ff9b::808:808",
+        "2002:808:808::1",
+    ] {
+        assert!(is_public(ip(public)), "{public}");
+    }
+}
+
+#[tokio::test]
+async fn allowed_host_resolving_to_internal_addresses_is_refused() {
+    let policy = rebound(false);
+    for target in ["127.0.0.1:443", "10.0.0.7:443", "169.254.169.254:80"] {
+        let denied = policy.check_socket(addr(target)).await;
+        assert_eq!(
+            denied,
+            Err(NetworkDenyReason::NonPublicAddress(addr(target).ip()))
+        );
+    }
+    assert!(matches!(
+        policy.resolve_http("api.example.com", 443).await,
+        Err(NetworkDenyReason::NonPublicAddress(_))
+    ));
+}
+
+#[tokio::test]
+async fn opt_in_allows_private_networks() {
+    let policy = rebound(true);
+    assert!(policy.allows(addr("10.0.0.7:443")).await);
+    let addrs = policy.resolve_http("api.example.com", 443).await;
+    assert_eq!(addrs.map(|a| a.len()), Ok(3));
+}
+
+#[tokio::test]
+async fn http_keeps_only_public_addresses() {
+    let resolver =
+        StaticResolver::new().with("mixed.example.com", [ip("10.0.0.1"), ip("93.184.216.34")]);
+    let policy = NetworkPolicy::new(Vec::new(), Arc::new(resolver));
+    let addrs = policy.resolve_http("mixed.example.com", 443).await;
+    assert_eq!(addrs, Ok(vec![addr("93.184.216.34:443")]));
+    assert_eq!(
+        policy.resolve_http("nowhere.example.com", 443).await,
+        Err(NetworkDenyReason::Unresolved)
+    );
+}
+
+#[test]
+fn capability_check_refuses_non_public_literal() {
+    let security = SecurityConfig {
+        net_allow_list: vec!["127.0.0.1".to_string()],
+        ..SecurityConfig::default()
+    };
+    let denied =
+        DefaultCapabilityChecker.check(&Capability::Network("127.0.0.1".into()), &security);
+    assert!(matches!(
+        denied,
+        Err(SandboxError::NetworkDenied {
+            reason: NetworkDenyReason::NonPublicAddress(_),
+            ..
+        })
+    ));
+}
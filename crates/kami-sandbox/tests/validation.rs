@@ -0,0 +1,64 @@
+//! Tests for security config validation.
+
+use kami_sandbox::{validate_security_config, MAX_LOG_BYTES};
+use kami_types::{ResourceLimits, SecurityConfig};
+
+#[test]
+fn validate_default_config_is_ok() {
+    let config = SecurityConfig::default();
+    assert!(validate_security_config(&config).is_ok());
+}
+
+#[test]
+fn validate_rejects_zero_fuel() {
+    let config = SecurityConfig {
+        limits: ResourceLimits {
+            max_fuel: 0,
+            ..ResourceLimits::default()
+        },
+        ..SecurityConfig::default()
+    };
+    assert!(validate_security_config(&config).is_err());
+}
+
+#[test]
+fn validate_rejects_zero_memory() {
+    let config = SecurityConfig {
+        limits: ResourceLimits {
+            max_memory_mb: 0,
+            ..ResourceLimits::default()
+        },
+        ..SecurityConfig::default()
+    };
+    assert!(validate_security_config(&config).is_err());
+}
+
+#[test]
+fn validate_rejects_zero_execution_ms() {
+    let config = SecurityConfig {
+        limits: ResourceLimits {
+            max_execution_ms: 0,
+            ..ResourceLimits::default()
+        },
+        ..SecurityConfig::default()
+    };
+    assert!(validate_security_config(&config).is_err());
+}
+
+#[test]
+fn validate_rejects_empty_net_pattern() {
+    let config = SecurityConfig {
+        net_allow_list: vec!["".to_string()],
+        ..SecurityConfig::default()
+    };
+    assert!(validate_security_config(&config).is_err());
+}
+
+#[test]
+fn validate_rejects_log_bytes_over_the_host_ceiling() {
+    let mut config = SecurityConfig::default();
+    config.limits.max_log_bytes = MAX_LOG_BYTES;
+    assert!(validate_security_config(&config).is_ok());
+    config.limits.max_log_bytes = MAX_LOG_BYTES + 1;
+    assert!(validate_security_config(&config).is_err());
+}
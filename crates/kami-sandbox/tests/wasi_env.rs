@@ -0,0 +1,66 @@
+//! Tests for the guest environment: the env allow-list and injected secrets.
+
+use kami_sandbox::{build_wasi_ctx, WasiConfig};
+use kami_types::SecurityConfig;
+
+#[test]
+fn env_allow_list_blocks_unlisted_var() {
+    let security = SecurityConfig {
+        env_allow_list: vec!["ALLOWED".to_string()],
+        ..SecurityConfig::default()
+    };
+    let wasi_config = WasiConfig {
+        env_vars: vec![
+            ("ALLOWED".to_string(), "yes".to_string()),
+            ("BLOCKED".to_string(), "no".to_string()),
+        ],
+        ..WasiConfig::default()
+    };
+    // Should succeed — blocked var is skipped silently
+    let ctx = build_wasi_ctx(&security, &wasi_config, None);
+    assert!(ctx.is_ok());
+}
+
+#[test]
+fn empty_allow_list_permits_all_env_vars() {
+    let security = SecurityConfig {
+        env_allow_list: vec![],
+        ..SecurityConfig::default()
+    };
+    let wasi_config = WasiConfig {
+        env_vars: vec![("FOO".to_string(), "bar".to_string())],
+        ..WasiConfig::default()
+    };
+    let ctx = build_wasi_ctx(&security, &wasi_config, None);
+    assert!(ctx.is_ok());
+}
+
+#[test]
+fn secret_env_is_injected_despite_allow_list() {
+    let security = SecurityConfig {
+        env_allow_list: vec!["ONLY_THIS".to_string()],
+        secrets: vec!["API_TOKEN".to_string()],
+        ..SecurityConfig::default()
+    };
+    let wasi_config = WasiConfig {
+        secret_env: vec![("API_TOKEN".to_string(), "s3cr3t".to_string())],
+        ..WasiConfig::default()
+    };
+    let ctx = build_wasi_ctx(&security, &wasi_config, None);
+    assert!(ctx.is_ok());
+}
+
+#[test]
+fn secret_env_sits_beside_plain_env_vars() {
+    let security = SecurityConfig {
+        secrets: vec!["API_TOKEN".to_string()],
+        ..SecurityConfig::default()
+    };
+    let wasi_config = WasiConfig {
+        env_vars: vec![("LANG".to_string(), "en_US".to_string())],
+        secret_env: vec![("API_TOKEN".to_string(), "s3cr3t".to_string())],
+        ..WasiConfig::default()
+    };
+    let ctx = build_wasi_ctx(&security, &wasi_config, None);
+    assert!(ctx.is_ok());
+}
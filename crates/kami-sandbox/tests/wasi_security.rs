@@ -4,34 +4,20 @@ use kami_sandbox::{build_wasi_ctx, WasiConfig};
 use kami_types::{FsAccess, SecurityConfig};
 
 #[test]
-fn env_allow_list_blocks_unlisted_var() {
-    let security = SecurityConfig {
-        env_allow_list: vec!["ALLOWED".to_string()],
-        ..SecurityConfig::default()
-    };
-    let wasi_config = WasiConfig {
-        env_vars: vec![
-            ("ALLOWED".to_string(), "yes".to_string()),
-            ("BLOCKED".to_string(), "no".to_string()),
-        ],
-        ..WasiConfig::default()
-    };
-    // Should succeed — blocked var is skipped silently
-    let ctx = build_wasi_ctx(&security, &wasi_config, None);
+fn default_security_produces_ctx() {
+    let ctx = build_wasi_ctx(&SecurityConfig::default(), &WasiConfig::default(), None);
     assert!(ctx.is_ok());
 }
 
 #[test]
-fn empty_allow_list_permits_all_env_vars() {
-    let security = SecurityConfig {
-        env_allow_list: vec![],
-        ..SecurityConfig::default()
-    };
+fn ctx_with_stdout_and_env() {
     let wasi_config = WasiConfig {
-        env_vars: vec![("FOO".to_string(), "bar".to_string())],
+        inherit_stdout: true,
+        inherit_stderr: true,
+        env_vars: vec![("LANG".to_string(), "en_US".to_string())],
         ..WasiConfig::default()
     };
-    let ctx = build_wasi_ctx(&security, &wasi_config, None);
+    let ctx = build_wasi_ctx(&SecurityConfig::default(), &wasi_config, None);
     assert!(ctx.is_ok());
 }
 
@@ -0,0 +1,41 @@
+//! `AuditLog` trait implementation for `SqliteToolRepository`.
+
+use async_trait::async_trait;
+use kami_registry::{AuditLog, RepositoryError};
+use kami_types::DomainEvent;
+use rusqlite::params;
+
+use crate::repository::SqliteToolRepository;
+use crate::repository_impl::map_sqlite_err;
+
+#[async_trait]
+impl AuditLog for SqliteToolRepository {
+    async fn record(&self, event: &DomainEvent) -> Result<(), RepositoryError> {
+        let json = serde_json::to_string(event).map_err(|e| RepositoryError::Storage {
+            message: format!("failed to serialize audit event: {e}"),
+        })?;
+        let conn = self.lock_conn()?;
+        conn.execute("INSERT INTO audit_log (event) VALUES (?1)", params![json])
+            .map_err(map_sqlite_err)?;
+        Ok(())
+    }
+
+    async fn recent(&self, limit: usize) -> Result<Vec<DomainEvent>, RepositoryError> {
+        let conn = self.lock_conn()?;
+        let mut stmt = conn
+            .prepare("SELECT event FROM audit_log ORDER BY id DESC LIMIT ?1")
+            .map_err(map_sqlite_err)?;
+        let rows = stmt
+            .query_map(params![limit as i64], |row| row.get::<_, String>(0))
+            .map_err(map_sqlite_err)?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(map_sqlite_err)?;
+        rows.iter()
+            .map(|json| {
+                serde_json::from_str(json).map_err(|e| RepositoryError::Storage {
+                    message: format!("corrupt audit entry: {e}"),
+                })
+            })
+            .collect()
+    }
+}
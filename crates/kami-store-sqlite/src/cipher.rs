@@ -0,0 +1,108 @@
+//! AES-256-GCM encryption of secret values at rest.
+
+mod key_file;
+
+use std::io;
+use std::path::Path;
+
+use kami_registry::RepositoryError;
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN};
+use ring::rand::{SecureRandom, SystemRandom};
+
+/// Length in bytes of a secret store key.
+pub const KEY_LEN: usize = 32;
+
+/// Symmetric key protecting the secret store.
+pub struct SecretKey {
+    key: LessSafeKey,
+}
+
+impl SecretKey {
+    /// Builds a key from raw bytes; exactly [`KEY_LEN`] bytes are required.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, RepositoryError> {
+        let unbound = UnboundKey::new(&AES_256_GCM, bytes).map_err(|_| {
+            storage(format!(
+                "secret key must be {KEY_LEN} bytes, got {}",
+                bytes.len()
+            ))
+        })?;
+        Ok(Self {
+            key: LessSafeKey::new(unbound),
+        })
+    }
+
+    /// Generates a fresh random key, returning it with its raw bytes.
+    pub fn generate() -> Result<(Self, [u8; KEY_LEN]), RepositoryError> {
+        let mut bytes = [0u8; KEY_LEN];
+        fill_random(&mut bytes)?;
+        Ok((Self::from_bytes(&bytes)?, bytes))
+    }
+
+    /// Loads the key file at `path`, creating it (mode 0600, in a 0700
+    /// directory) if missing. An existing key file that group or others
+    /// can read is refused rather than used.
+    pub fn load_or_create(path: &Path) -> Result<Self, RepositoryError> {
+        let unusable = |e: io::Error| storage(format!("key file {}: {e}", path.display()));
+        match key_file::read(path) {
+            Ok(bytes) => return Self::from_bytes(&bytes),
+            Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(unusable(e)),
+            Err(_) => {}
+        }
+        let (key, bytes) = Self::generate()?;
+        match key_file::create(path, &bytes) {
+            Ok(()) => Ok(key),
+            // Another process created it first: use theirs.
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+                Self::from_bytes(&key_file::read(path).map_err(unusable)?)
+            }
+            Err(e) => Err(unusable(e)),
+        }
+    }
+
+    /// Encrypts `plaintext`, binding it to `name`. Returns `(nonce, ciphertext)`.
+    pub fn seal(
+        &self,
+        name: &str,
+        plaintext: &[u8],
+    ) -> Result<(Vec<u8>, Vec<u8>), RepositoryError> {
+        let mut nonce = [0u8; NONCE_LEN];
+        fill_random(&mut nonce)?;
+        let mut data = plaintext.to_vec();
+        self.key
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::from(name.as_bytes()),
+                &mut data,
+            )
+            .map_err(|_| storage(format!("failed to encrypt secret '{name}'")))?;
+        Ok((nonce.to_vec(), data))
+    }
+
+    /// Decrypts a value sealed for `name`; fails if it was tampered with.
+    pub fn open(
+        &self,
+        name: &str,
+        nonce: &[u8],
+        ciphertext: &[u8],
+    ) -> Result<Vec<u8>, RepositoryError> {
+        let corrupt = || storage(format!("secret '{name}' cannot be decrypted"));
+        let nonce = Nonce::try_assume_unique_for_key(nonce).map_err(|_| corrupt())?;
+        let mut data = ciphertext.to_vec();
+        let plain = self
+            .key
+            .open_in_place(nonce, Aad::from(name.as_bytes()), &mut data)
+            .map_err(|_| corrupt())?;
+        Ok(plain.to_vec())
+    }
+}
+
+/// Fills `buf` from the operating system's secure random source.
+fn fill_random(buf: &mut [u8]) -> Result<(), RepositoryError> {
+    SystemRandom::new()
+        .fill(buf)
+        .map_err(|_| storage("system random source unavailable".to_string()))
+}
+
+fn storage(message: String) -> RepositoryError {
+    RepositoryError::Storage { message }
+}
@@ -0,0 +1,84 @@
+//! `KeyValueStore` trait implementation for `SqliteToolRepository`.
+
+use async_trait::async_trait;
+use kami_registry::{KeyValueStore, RepositoryError};
+use kami_types::ToolId;
+use rusqlite::params;
+
+use crate::repository::SqliteToolRepository;
+use crate::repository_impl::map_sqlite_err;
+use crate::row_mapping::OptionalExt;
+
+#[async_trait]
+impl KeyValueStore for SqliteToolRepository {
+    async fn get(&self, tool: &ToolId, key: &str) -> Result<Option<Vec<u8>>, RepositoryError> {
+        let conn = self.lock_conn()?;
+        conn.query_row(
+            "SELECT value FROM kv WHERE tool_id = ?1 AND key = ?2",
+            params![tool.as_str(), key],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(map_sqlite_err)
+    }
+
+    async fn set(
+        &self,
+        tool: &ToolId,
+        key: &str,
+        value: &[u8],
+        max_bytes: u64,
+    ) -> Result<(), RepositoryError> {
+        let conn = self.lock_conn()?;
+        let tx = conn.unchecked_transaction().map_err(map_sqlite_err)?;
+        // Usage of every other key; the entry being replaced no longer counts.
+        let others: i64 = tx
+            .query_row(
+                "SELECT COALESCE(SUM(LENGTH(CAST(key AS BLOB)) + LENGTH(value)), 0) \
+                 FROM kv WHERE tool_id = ?1 AND key <> ?2",
+                params![tool.as_str(), key],
+                |row| row.get(0),
+            )
+            .map_err(map_sqlite_err)?;
+        let used = u64::try_from(others).unwrap_or(0);
+        let total = used.saturating_add((key.len() + value.len()) as u64);
+        if total > max_bytes {
+            return Err(RepositoryError::QuotaExceeded { limit: max_bytes });
+        }
+        tx.execute(
+            "INSERT INTO kv (tool_id, key, value) VALUES (?1, ?2, ?3) \
+             ON CONFLICT (tool_id, key) \
+             DO UPDATE SET value = excluded.value, updated_at = datetime('now')",
+            params![tool.as_str(), key, value],
+        )
+        .map_err(map_sqlite_err)?;
+        tx.commit().map_err(map_sqlite_err)
+    }
+
+    async fn remove(&self, tool: &ToolId, key: &str) -> Result<bool, RepositoryError> {
+        let conn = self.lock_conn()?;
+        let affected = conn
+            .execute(
+                "DELETE FROM kv WHERE tool_id = ?1 AND key = ?2",
+                params![tool.as_str(), key],
+            )
+            .map_err(map_sqlite_err)?;
+        Ok(affected > 0)
+    }
+
+    async fn list_keys(&self, tool: &ToolId, prefix: &str) -> Result<Vec<String>, RepositoryError> {
+        let conn = self.lock_conn()?;
+        let mut stmt = conn
+            .prepare(
+                "SELECT key FROM kv WHERE tool_id = ?1 \
+                 AND substr(key, 1, length(?2)) = ?2 ORDER BY key",
+            )
+            .map_err(map_sqlite_err)?;
+        let keys = stmt
+            .query_map(params![tool.as_str(), prefix], |row| row.get(0))
+            .map_err(map_sqlite_err)?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(map_sqlite_err)?;
+        Ok(keys)
+    }
+}
@@ -1,12 +1,23 @@
 //! # kami-store-sqlite
 //!
 //! SQLite adapter for the KAMI tool registry.
-//! Implements `ToolRepository` with full CRUD operations.
+//! Implements `ToolRepository` with full CRUD operations, and
+//! `KeyValueStore` for the per-tool key/value storage, `ToolPolicyStore`
+//! for the operator's per-tool overrides, `AuditLog`, and an encrypted
+//! `SecretStore`.
 
+mod audit_impl;
+pub mod cipher;
+mod kv_impl;
 pub mod migrations;
+mod policy_impl;
 mod query_builder;
 pub mod repository;
 mod repository_impl;
 mod row_mapping;
+mod secret_store;
+mod tool_rows;
 
+pub use cipher::SecretKey;
 pub use repository::SqliteToolRepository;
+pub use secret_store::SqliteSecretStore;
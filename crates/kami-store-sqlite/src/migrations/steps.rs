@@ -0,0 +1,141 @@
+//! Schema changes of each version, applied in order by
+//! [`run_migrations`](super::run_migrations).
+
+use kami_registry::RepositoryError;
+use rusqlite::Connection;
+
+/// Creates the initial schema (v1).
+pub(super) fn migrate_v1(conn: &Connection) -> Result<(), RepositoryError> {
+    apply(
+        conn,
+        1,
+        "CREATE TABLE IF NOT EXISTS tools (
+            id          TEXT PRIMARY KEY NOT NULL,
+            name        TEXT NOT NULL,
+            version     TEXT NOT NULL,
+            description TEXT NOT NULL DEFAULT '',
+            wasm_path   TEXT NOT NULL,
+            install_path TEXT NOT NULL,
+            enabled     INTEGER NOT NULL DEFAULT 1,
+            security    TEXT NOT NULL DEFAULT '{}',
+            arguments   TEXT NOT NULL DEFAULT '[]',
+            installed_at TEXT NOT NULL DEFAULT (datetime('now'))
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_tools_name ON tools(name);
+        CREATE INDEX IF NOT EXISTS idx_tools_enabled ON tools(enabled);",
+    )
+}
+
+/// Adds the `wasm_sha256` column for WASM integrity verification (v2).
+pub(super) fn migrate_v2(conn: &Connection) -> Result<(), RepositoryError> {
+    apply(conn, 2, "ALTER TABLE tools ADD COLUMN wasm_sha256 TEXT;")
+}
+
+/// Adds versioning columns for update & pin support (v3).
+pub(super) fn migrate_v3(conn: &Connection) -> Result<(), RepositoryError> {
+    apply(
+        conn,
+        3,
+        "ALTER TABLE tools ADD COLUMN pinned_version TEXT;
+         ALTER TABLE tools ADD COLUMN updated_at TEXT;",
+    )
+}
+
+/// Adds cryptographic signature columns for plugin signing (v4).
+pub(super) fn migrate_v4(conn: &Connection) -> Result<(), RepositoryError> {
+    apply(
+        conn,
+        4,
+        "ALTER TABLE tools ADD COLUMN signature TEXT;
+         ALTER TABLE tools ADD COLUMN signer_public_key TEXT;",
+    )
+}
+
+/// Adds the per-tool key/value store (v5).
+pub(super) fn migrate_v5(conn: &Connection) -> Result<(), RepositoryError> {
+    apply(
+        conn,
+        5,
+        "CREATE TABLE IF NOT EXISTS kv (
+            tool_id    TEXT NOT NULL,
+            key        TEXT NOT NULL,
+            value      BLOB NOT NULL,
+            updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+            PRIMARY KEY (tool_id, key)
+        );",
+    )
+}
+
+/// Adds the encrypted secret store and the audit log (v6).
+pub(super) fn migrate_v6(conn: &Connection) -> Result<(), RepositoryError> {
+    apply(
+        conn,
+        6,
+        "CREATE TABLE IF NOT EXISTS secrets (
+            name       TEXT PRIMARY KEY NOT NULL,
+            nonce      BLOB NOT NULL,
+            ciphertext BLOB NOT NULL,
+            updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+        );
+        CREATE TABLE IF NOT EXISTS audit_log (
+            id          INTEGER PRIMARY KEY AUTOINCREMENT,
+            event       TEXT NOT NULL,
+            recorded_at TEXT NOT NULL DEFAULT (datetime('now'))
+        );",
+    )
+}
+
+/// Adds the operator's per-tool limit overrides (v7).
+///
+/// Not keyed to `tools`: overrides outlive an uninstall.
+pub(super) fn migrate_v7(conn: &Connection) -> Result<(), RepositoryError> {
+    apply(
+        conn,
+        7,
+        "CREATE TABLE IF NOT EXISTS tool_policies (
+            tool_id    TEXT PRIMARY KEY NOT NULL,
+            overrides  TEXT NOT NULL,
+            updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+        );",
+    )
+}
+
+/// Adds the capability grants approved at install and update (v8).
+///
+/// A grant belongs to an installed tool: uninstalling drops it. Tools
+/// installed before grants existed were approved by installing them, so
+/// their manifest's security config becomes their grant.
+pub(super) fn migrate_v8(conn: &Connection) -> Result<(), RepositoryError> {
+    apply(
+        conn,
+        8,
+        "CREATE TABLE IF NOT EXISTS tool_grants (
+            tool_id    TEXT PRIMARY KEY NOT NULL,
+            security   TEXT NOT NULL,
+            granted_at TEXT NOT NULL DEFAULT (datetime('now'))
+        );
+        CREATE TRIGGER IF NOT EXISTS tool_grants_uninstall AFTER DELETE ON tools
+        BEGIN
+            DELETE FROM tool_grants WHERE tool_id = OLD.id;
+        END;
+        INSERT OR IGNORE INTO tool_grants (tool_id, security) SELECT id, security FROM tools;",
+    )
+}
+
+/// Adds the fields of each tool's structured result (v9).
+pub(super) fn migrate_v9(conn: &Connection) -> Result<(), RepositoryError> {
+    apply(
+        conn,
+        9,
+        "ALTER TABLE tools ADD COLUMN output TEXT NOT NULL DEFAULT '[]';",
+    )
+}
+
+/// Runs the statements of migration `version`.
+fn apply(conn: &Connection, version: u32, sql: &str) -> Result<(), RepositoryError> {
+    conn.execute_batch(sql)
+        .map_err(|e| RepositoryError::Storage {
+            message: format!("migration v{version} failed: {e}"),
+        })
+}
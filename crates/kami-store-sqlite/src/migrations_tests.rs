@@ -0,0 +1,104 @@
+//! Tests for schema migrations.
+
+use super::*;
+
+#[test]
+fn migrations_run_on_fresh_db() {
+    let conn = Connection::open_in_memory().expect("in-memory db");
+    run_migrations(&conn).expect("migrations should succeed");
+
+    let version = get_schema_version(&conn).expect("version");
+    assert_eq!(version, SCHEMA_VERSION);
+}
+
+#[test]
+fn migrations_are_idempotent() {
+    let conn = Connection::open_in_memory().expect("in-memory db");
+    run_migrations(&conn).expect("first run");
+    run_migrations(&conn).expect("second run should also succeed");
+}
+
+#[test]
+fn v6_creates_secrets_and_audit_tables() {
+    let conn = Connection::open_in_memory().expect("in-memory db");
+    run_migrations(&conn).expect("migrations");
+    for table in ["secrets", "audit_log"] {
+        let count: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = ?1",
+                [table],
+                |row| row.get(0),
+            )
+            .expect("query");
+        assert_eq!(count, 1, "missing table {table}");
+    }
+}
+
+#[test]
+fn v7_keeps_existing_tools_and_adds_policies() {
+    let conn = Connection::open_in_memory().expect("in-memory db");
+    for migrate in &MIGRATIONS[..6] {
+        migrate(&conn).expect("older migration");
+    }
+    set_schema_version(&conn, 6).expect("version");
+    conn.execute(
+        "INSERT INTO tools (id, name, version, wasm_path, install_path) \
+         VALUES ('dev.a.b', 'b', '1.0.0', 'b.wasm', '/tmp')",
+        [],
+    )
+    .expect("insert");
+
+    run_migrations(&conn).expect("upgrade");
+
+    let tools: i64 = conn
+        .query_row("SELECT COUNT(*) FROM tools", [], |row| row.get(0))
+        .expect("tools");
+    let policies: i64 = conn
+        .query_row("SELECT COUNT(*) FROM tool_policies", [], |row| row.get(0))
+        .expect("policies");
+    assert_eq!((tools, policies), (1, 0));
+}
+
+#[test]
+fn v8_grants_existing_tools_their_manifest() {
+    let conn = Connection::open_in_memory().expect("in-memory db");
+    for migrate in &MIGRATIONS[..7] {
+        migrate(&conn).expect("older migration");
+    }
+    set_schema_version(&conn, 7).expect("version");
+    conn.execute(
+        "INSERT INTO tools (id, name, version, wasm_path, install_path, security) \
+         VALUES ('dev.a.b', 'b', '1.0.0', 'b.wasm', '/tmp', '{\"net_allow_list\":[\"x\"]}')",
+        [],
+    )
+    .expect("insert");
+
+    run_migrations(&conn).expect("upgrade");
+
+    let grant: String = conn
+        .query_row("SELECT security FROM tool_grants", [], |row| row.get(0))
+        .expect("grant");
+    assert_eq!(grant, r#"{"net_allow_list":["x"]}"#);
+}
+
+#[test]
+fn v9_gives_existing_tools_an_empty_output() {
+    let conn = Connection::open_in_memory().expect("in-memory db");
+    for migrate in &MIGRATIONS[..8] {
+        migrate(&conn).expect("older migration");
+    }
+    set_schema_version(&conn, 8).expect("version");
+    conn.execute(
+        "INSERT INTO tools (id, name, version, wasm_path, install_path) \
+         VALUES ('dev.a.b', 'b', '1.0.0', 'b.wasm', '/tmp')",
+        [],
+    )
+    .expect("insert");
+
+    run_migrations(&conn).expect("upgrade");
+
+    let output: String = conn
+        .query_row("SELECT output FROM tools", [], |row| row.get(0))
+        .expect("output");
+    assert_eq!(output, "[]");
+}
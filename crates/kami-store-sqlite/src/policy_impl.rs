@@ -0,0 +1,130 @@
+//! `ToolPolicyStore` trait implementation for `SqliteToolRepository`.
+
+use async_trait::async_trait;
+use kami_registry::{RepositoryError, ToolPolicyStore};
+use kami_types::{LimitCaps, SecurityConfig, Tool, ToolId};
+use rusqlite::{params, Connection};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::repository::SqliteToolRepository;
+use crate::repository_impl::map_sqlite_err;
+use crate::row_mapping::{to_json, OptionalExt};
+use crate::tool_rows::{insert_row, update_row};
+
+/// Records `?2` as the grant of tool `?1`.
+const GRANT_SQL: &str = "INSERT INTO tool_grants (tool_id, security) VALUES (?1, ?2) \
+     ON CONFLICT (tool_id) \
+     DO UPDATE SET security = excluded.security, granted_at = datetime('now')";
+
+#[async_trait]
+impl ToolPolicyStore for SqliteToolRepository {
+    async fn overrides(&self, tool: &ToolId) -> Result<Option<LimitCaps>, RepositoryError> {
+        self.read_json(
+            "SELECT overrides FROM tool_policies WHERE tool_id = ?1",
+            tool,
+        )
+    }
+
+    async fn set_overrides(&self, tool: &ToolId, caps: &LimitCaps) -> Result<(), RepositoryError> {
+        self.write_json(
+            "INSERT INTO tool_policies (tool_id, overrides) VALUES (?1, ?2) \
+             ON CONFLICT (tool_id) \
+             DO UPDATE SET overrides = excluded.overrides, updated_at = datetime('now')",
+            tool,
+            caps,
+        )
+    }
+
+    async fn clear_overrides(&self, tool: &ToolId) -> Result<bool, RepositoryError> {
+        let conn = self.lock_conn()?;
+        let affected = conn
+            .execute(
+                "DELETE FROM tool_policies WHERE tool_id = ?1",
+                params![tool.as_str()],
+            )
+            .map_err(map_sqlite_err)?;
+        Ok(affected > 0)
+    }
+
+    async fn granted(&self, tool: &ToolId) -> Result<Option<SecurityConfig>, RepositoryError> {
+        self.read_json("SELECT security FROM tool_grants WHERE tool_id = ?1", tool)
+    }
+
+    async fn grant(&self, tool: &ToolId, security: &SecurityConfig) -> Result<(), RepositoryError> {
+        self.write_json(GRANT_SQL, tool, security)
+    }
+}
+
+impl SqliteToolRepository {
+    /// Installs `tool` with its manifest's security config as its grant.
+    ///
+    /// Both are written in one transaction, so an approved grant never
+    /// outlives a failed install.
+    ///
+    /// # Errors
+    /// Returns `RepositoryError::Conflict` if the tool is already installed.
+    pub async fn insert_granted(&self, tool: &Tool) -> Result<(), RepositoryError> {
+        self.write_granted(tool, insert_row)
+    }
+
+    /// Updates `tool` and replaces its grant with its manifest's security
+    /// config, in one transaction.
+    ///
+    /// # Errors
+    /// Returns `RepositoryError::NotFound` if the tool is not installed.
+    pub async fn update_granted(&self, tool: &Tool) -> Result<(), RepositoryError> {
+        self.write_granted(tool, update_row)
+    }
+
+    fn write_granted(
+        &self,
+        tool: &Tool,
+        write_row: fn(&Connection, &Tool) -> Result<(), RepositoryError>,
+    ) -> Result<(), RepositoryError> {
+        let m = &tool.manifest;
+        let grant = to_json(&m.security, "grant")?;
+        let mut conn = self.lock_conn()?;
+        let tx = conn.transaction().map_err(map_sqlite_err)?;
+        write_row(&tx, tool)?;
+        tx.execute(GRANT_SQL, params![m.id.as_str(), grant])
+            .map_err(map_sqlite_err)?;
+        tx.commit().map_err(map_sqlite_err)
+    }
+
+    /// Reads the JSON value selected by `sql` for `tool`.
+    fn read_json<T: DeserializeOwned>(
+        &self,
+        sql: &str,
+        tool: &ToolId,
+    ) -> Result<Option<T>, RepositoryError> {
+        let json: Option<String> = {
+            let conn = self.lock_conn()?;
+            conn.query_row(sql, params![tool.as_str()], |row| row.get(0))
+                .optional()
+                .map_err(map_sqlite_err)?
+        };
+        json.map(|json| {
+            serde_json::from_str(&json).map_err(|e| RepositoryError::Storage {
+                message: format!("corrupt policy record for {tool}: {e}"),
+            })
+        })
+        .transpose()
+    }
+
+    /// Runs `sql` with `tool` and `value` serialized as JSON.
+    fn write_json<T: Serialize>(
+        &self,
+        sql: &str,
+        tool: &ToolId,
+        value: &T,
+    ) -> Result<(), RepositoryError> {
+        let json = serde_json::to_string(value).map_err(|e| RepositoryError::Storage {
+            message: format!("cannot serialize policy record: {e}"),
+        })?;
+        let conn = self.lock_conn()?;
+        conn.execute(sql, params![tool.as_str(), json])
+            .map_err(map_sqlite_err)?;
+        Ok(())
+    }
+}
@@ -1,8 +1,10 @@
 //! Row-to-domain mapping for the SQLite tool repository.
 //!
-//! Converts raw SQLite rows into `Tool` domain objects and provides
-//! the `OptionalExt` helper for query results.
+//! Converts raw SQLite rows into `Tool` domain objects, serializes the
+//! manifest fields stored as JSON and provides the `OptionalExt` helper
+//! for query results.
 
+use kami_registry::RepositoryError;
 use kami_types::{SecurityConfig, Tool, ToolArgument, ToolId, ToolManifest, ToolVersion};
 
 /// Maps a SQLite row to a `Tool` domain object.
@@ -21,6 +23,7 @@ pub(crate) fn row_to_tool(row: &rusqlite::Row<'_>) -> rusqlite::Result<Tool> {
     let updated_at: Option<String> = row.get(11)?;
     let signature: Option<String> = row.get(12)?;
     let signer_public_key: Option<String> = row.get(13)?;
+    let output_json: String = row.get(14)?;
 
     let id = ToolId::new(id_str).map_err(|e| {
         rusqlite::Error::FromSqlConversionFailure(0, rusqlite::types::Type::Text, Box::new(e))
@@ -41,6 +44,10 @@ pub(crate) fn row_to_tool(row: &rusqlite::Row<'_>) -> rusqlite::Result<Tool> {
         rusqlite::Error::FromSqlConversionFailure(8, rusqlite::types::Type::Text, Box::new(e))
     })?;
 
+    let output: Vec<ToolArgument> = serde_json::from_str(&output_json).map_err(|e| {
+        rusqlite::Error::FromSqlConversionFailure(14, rusqlite::types::Type::Text, Box::new(e))
+    })?;
+
     Ok(Tool {
         manifest: ToolManifest {
             id,
@@ -49,6 +56,7 @@ pub(crate) fn row_to_tool(row: &rusqlite::Row<'_>) -> rusqlite::Result<Tool> {
             wasm: wasm_path,
             description,
             arguments,
+            output,
             security,
             wasm_sha256,
             signature,
@@ -61,6 +69,16 @@ pub(crate) fn row_to_tool(row: &rusqlite::Row<'_>) -> rusqlite::Result<Tool> {
     })
 }
 
+/// Serializes a manifest field stored as a JSON column.
+pub(crate) fn to_json<T: serde::Serialize>(
+    value: &T,
+    field: &str,
+) -> Result<String, RepositoryError> {
+    serde_json::to_string(value).map_err(|e| RepositoryError::Storage {
+        message: format!("serialize {field}: {e}"),
+    })
+}
+
 /// Extension trait for optional query results.
 pub(crate) trait OptionalExt<T> {
     /// Converts a "no rows" error into `Ok(None)`.
@@ -0,0 +1,84 @@
+//! Encrypted `SecretStore` backed by the registry database.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use kami_registry::{RepositoryError, SecretStore};
+use rusqlite::params;
+
+use crate::cipher::SecretKey;
+use crate::repository::SqliteToolRepository;
+use crate::repository_impl::map_sqlite_err;
+use crate::row_mapping::OptionalExt;
+
+/// Secret store keeping AES-256-GCM encrypted values in the `secrets` table.
+pub struct SqliteSecretStore {
+    repo: Arc<SqliteToolRepository>,
+    key: SecretKey,
+}
+
+impl SqliteSecretStore {
+    /// Creates a store over `repo`'s database, encrypting with `key`.
+    pub fn new(repo: Arc<SqliteToolRepository>, key: SecretKey) -> Self {
+        Self { repo, key }
+    }
+}
+
+#[async_trait]
+impl SecretStore for SqliteSecretStore {
+    async fn get(&self, name: &str) -> Result<Option<String>, RepositoryError> {
+        let row: Option<(Vec<u8>, Vec<u8>)> = {
+            let conn = self.repo.lock_conn()?;
+            conn.query_row(
+                "SELECT nonce, ciphertext FROM secrets WHERE name = ?1",
+                params![name],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()
+            .map_err(map_sqlite_err)?
+        };
+        let Some((nonce, ciphertext)) = row else {
+            return Ok(None);
+        };
+        let plain = self.key.open(name, &nonce, &ciphertext)?;
+        String::from_utf8(plain)
+            .map(Some)
+            .map_err(|_| RepositoryError::Storage {
+                message: format!("secret '{name}' is not valid UTF-8"),
+            })
+    }
+
+    async fn set(&self, name: &str, value: &str) -> Result<(), RepositoryError> {
+        let (nonce, ciphertext) = self.key.seal(name, value.as_bytes())?;
+        let conn = self.repo.lock_conn()?;
+        conn.execute(
+            "INSERT INTO secrets (name, nonce, ciphertext) VALUES (?1, ?2, ?3) \
+             ON CONFLICT (name) DO UPDATE SET nonce = excluded.nonce, \
+             ciphertext = excluded.ciphertext, updated_at = datetime('now')",
+            params![name, nonce, ciphertext],
+        )
+        .map_err(map_sqlite_err)?;
+        Ok(())
+    }
+
+    async fn remove(&self, name: &str) -> Result<bool, RepositoryError> {
+        let conn = self.repo.lock_conn()?;
+        let affected = conn
+            .execute("DELETE FROM secrets WHERE name = ?1", params![name])
+            .map_err(map_sqlite_err)?;
+        Ok(affected > 0)
+    }
+
+    async fn list(&self) -> Result<Vec<String>, RepositoryError> {
+        let conn = self.repo.lock_conn()?;
+        let mut stmt = conn
+            .prepare("SELECT name FROM secrets ORDER BY name")
+            .map_err(map_sqlite_err)?;
+        let names = stmt
+            .query_map([], |row| row.get(0))
+            .map_err(map_sqlite_err)?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(map_sqlite_err)?;
+        Ok(names)
+    }
+}
@@ -0,0 +1,96 @@
+//! Writes of `tools` rows, shared by the repository and the writes that
+//! also record a grant in the same transaction.
+
+use kami_registry::RepositoryError;
+use kami_types::Tool;
+use rusqlite::{params, Connection};
+
+use crate::repository_impl::map_sqlite_err;
+use crate::row_mapping::to_json;
+
+/// Inserts the row of `tool`.
+///
+/// # Errors
+/// Returns `RepositoryError::Conflict` if the tool is already installed.
+pub(crate) fn insert_row(conn: &Connection, tool: &Tool) -> Result<(), RepositoryError> {
+    let m = &tool.manifest;
+    let security_json = to_json(&m.security, "security")?;
+    let args_json = to_json(&m.arguments, "arguments")?;
+    let output_json = to_json(&m.output, "output")?;
+    conn.execute(
+        "INSERT INTO tools (id, name, version, description, wasm_path, \
+         install_path, enabled, security, arguments, wasm_sha256, \
+         signature, signer_public_key, output) \
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
+        params![
+            m.id.as_str(),
+            m.name,
+            m.version.to_string(),
+            m.description,
+            m.wasm,
+            tool.install_path,
+            tool.enabled as i32,
+            security_json,
+            args_json,
+            m.wasm_sha256,
+            m.signature,
+            m.signer_public_key,
+            output_json,
+        ],
+    )
+    .map_err(|e| {
+        if let rusqlite::Error::SqliteFailure(ref err, _) = e {
+            if err.code == rusqlite::ErrorCode::ConstraintViolation {
+                return RepositoryError::Conflict {
+                    message: format!("tool already exists: {}", m.id),
+                };
+            }
+        }
+        RepositoryError::Storage {
+            message: e.to_string(),
+        }
+    })?;
+    Ok(())
+}
+
+/// Replaces the row of `tool`.
+///
+/// # Errors
+/// Returns `RepositoryError::NotFound` if the tool is not installed.
+pub(crate) fn update_row(conn: &Connection, tool: &Tool) -> Result<(), RepositoryError> {
+    let m = &tool.manifest;
+    let security_json = to_json(&m.security, "security")?;
+    let args_json = to_json(&m.arguments, "arguments")?;
+    let output_json = to_json(&m.output, "output")?;
+    let affected = conn
+        .execute(
+            "UPDATE tools SET name=?2, version=?3, description=?4, wasm_path=?5, \
+             install_path=?6, enabled=?7, security=?8, arguments=?9, wasm_sha256=?10, \
+             pinned_version=?11, updated_at=?12, signature=?13, \
+             signer_public_key=?14, output=?15 WHERE id=?1",
+            params![
+                m.id.as_str(),
+                m.name,
+                m.version.to_string(),
+                m.description,
+                m.wasm,
+                tool.install_path,
+                tool.enabled as i32,
+                security_json,
+                args_json,
+                m.wasm_sha256,
+                tool.pinned_version,
+                tool.updated_at,
+                m.signature,
+                m.signer_public_key,
+                output_json,
+            ],
+        )
+        .map_err(map_sqlite_err)?;
+    if affected == 0 {
+        return Err(RepositoryError::NotFound {
+            id: m.id.to_string(),
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,105 @@
+//! Tests for the per-tool key/value store.
+
+use kami_registry::{KeyValueStore, RepositoryError, ToolRepository};
+use kami_store_sqlite::SqliteToolRepository;
+use kami_types::{SecurityConfig, Tool, ToolId, ToolManifest, ToolVersion};
+
+const QUOTA: u64 = 1024;
+
+fn id(s: &str) -> ToolId {
+    ToolId::new(s).expect("id")
+}
+
+#[tokio::test]
+async fn set_get_remove_roundtrip() {
+    let repo = SqliteToolRepository::open_in_memory().expect("db");
+    let tool = id("dev.kv.memo");
+
+    repo.set(&tool, "k", b"v1", QUOTA).await.expect("set");
+    repo.set(&tool, "k", b"v2", QUOTA).await.expect("overwrite");
+    assert_eq!(
+        repo.get(&tool, "k").await.expect("get"),
+        Some(b"v2".to_vec())
+    );
+
+    assert!(repo.remove(&tool, "k").await.expect("remove"));
+    assert!(!repo.remove(&tool, "k").await.expect("remove again"));
+    assert_eq!(repo.get(&tool, "k").await.expect("get"), None);
+}
+
+#[tokio::test]
+async fn keys_are_namespaced_per_tool() {
+    let repo = SqliteToolRepository::open_in_memory().expect("db");
+    let (a, b) = (id("dev.kv.a"), id("dev.kv.b"));
+
+    repo.set(&a, "shared", b"a", QUOTA).await.expect("set a");
+
+    assert_eq!(repo.get(&b, "shared").await.expect("get"), None);
+    assert!(repo.list_keys(&b, "").await.expect("list").is_empty());
+    assert!(!repo.remove(&b, "shared").await.expect("remove"));
+    assert!(repo.get(&a, "shared").await.expect("get").is_some());
+}
+
+#[tokio::test]
+async fn list_keys_filters_by_prefix_in_order() {
+    let repo = SqliteToolRepository::open_in_memory().expect("db");
+    let tool = id("dev.kv.memo");
+    for key in ["page:2", "other", "page:1"] {
+        repo.set(&tool, key, b"x", QUOTA).await.expect("set");
+    }
+
+    let keys = repo.list_keys(&tool, "page:").await.expect("list");
+
+    assert_eq!(keys, vec!["page:1", "page:2"]);
+    assert_eq!(repo.list_keys(&tool, "").await.expect("all").len(), 3);
+}
+
+#[tokio::test]
+async fn set_beyond_quota_is_rejected() {
+    let repo = SqliteToolRepository::open_in_memory().expect("db");
+    let tool = id("dev.kv.memo");
+    repo.set(&tool, "a", &[0; 6], 10)
+        .await
+        .expect("7 bytes fit");
+
+    let err = repo
+        .set(&tool, "b", &[0; 3], 10)
+        .await
+        .expect_err("11 bytes");
+
+    assert!(matches!(err, RepositoryError::QuotaExceeded { limit: 10 }));
+    assert_eq!(repo.get(&tool, "b").await.expect("get"), None);
+    // Replacing a value only counts the new one.
+    repo.set(&tool, "a", &[0; 9], 10).await.expect("replace");
+}
+
+#[tokio::test]
+async fn deleting_a_tool_clears_its_entries() {
+    let repo = SqliteToolRepository::open_in_memory().expect("db");
+    let tool = Tool {
+        manifest: ToolManifest {
+            id: id("dev.kv.memo"),
+            name: "memo".to_string(),
+            version: ToolVersion::new(1, 0, 0),
+            wasm: "memo.wasm".to_string(),
+            description: "memo tool".to_string(),
+            arguments: vec![],
+            output: vec![],
+            security: SecurityConfig::default(),
+            wasm_sha256: None,
+            signature: None,
+            signer_public_key: None,
+        },
+        install_path: "/tools/memo".to_string(),
+        enabled: true,
+        pinned_version: None,
+        updated_at: None,
+    };
+    repo.insert(&tool).await.expect("insert");
+    let tool_id = &tool.manifest.id;
+    repo.set(tool_id, "k", b"v", QUOTA).await.expect("set");
+
+    repo.delete(tool_id).await.expect("uninstall");
+
+    assert_eq!(repo.get(tool_id, "k").await.expect("get"), None);
+}
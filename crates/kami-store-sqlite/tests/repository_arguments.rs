@@ -0,0 +1,52 @@
+//! Argument and output schema persistence for `SqliteToolRepository`.
+
+use kami_registry::ToolRepository;
+use kami_store_sqlite::SqliteToolRepository;
+use kami_types::{
+    ArgumentConstraints, SecurityConfig, Tool, ToolArgument, ToolId, ToolManifest, ToolVersion,
+};
+
+#[tokio::test]
+async fn arguments_and_output_roundtrip() {
+    let repo = SqliteToolRepository::open_in_memory().expect("open");
+
+    let mut tool = Tool {
+        manifest: ToolManifest {
+            id: ToolId::new("dev.test.with-args").expect("id"),
+            name: "with-args".to_string(),
+            version: ToolVersion::new(1, 0, 0),
+            wasm: "args.wasm".to_string(),
+            description: "Tool with arguments".to_string(),
+            arguments: vec![ToolArgument {
+                name: "url".to_string(),
+                arg_type: "string".to_string(),
+                description: "URL to fetch".to_string(),
+                required: true,
+                constraints: ArgumentConstraints {
+                    pattern: Some("^https://".to_string()),
+                    ..ArgumentConstraints::default()
+                },
+                ..ToolArgument::default()
+            }],
+            output: vec![],
+            security: SecurityConfig::default(),
+            wasm_sha256: None,
+            signature: None,
+            signer_public_key: None,
+        },
+        install_path: "/tools/with-args".to_string(),
+        enabled: true,
+        pinned_version: None,
+        updated_at: None,
+    };
+
+    tool.manifest.output = tool.manifest.arguments.clone();
+    repo.insert(&tool).await.expect("insert");
+    let found = repo
+        .find_by_id(&tool.manifest.id)
+        .await
+        .expect("find")
+        .expect("exists");
+    assert_eq!(found.manifest.arguments, tool.manifest.arguments);
+    assert_eq!(found.manifest.output, tool.manifest.output);
+}
@@ -13,6 +13,7 @@ fn sample_tool() -> Tool {
             wasm: "sample.wasm".to_string(),
             description: "A sample tool".to_string(),
             arguments: vec![],
+            output: vec![],
             security: SecurityConfig::default(),
             wasm_sha256: None,
             signature: None,
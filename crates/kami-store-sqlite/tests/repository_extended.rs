@@ -13,6 +13,7 @@ fn tool(id: &str, name: &str, enabled: bool) -> Tool {
             wasm: format!("{name}.wasm"),
             description: format!("{name} tool"),
             arguments: vec![],
+            output: vec![],
             security: SecurityConfig::default(),
             wasm_sha256: Some("abc123".to_string()),
             signature: None,
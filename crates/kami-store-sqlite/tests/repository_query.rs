@@ -2,7 +2,7 @@
 
 use kami_registry::{ToolQuery, ToolRepository};
 use kami_store_sqlite::SqliteToolRepository;
-use kami_types::{SecurityConfig, Tool, ToolArgument, ToolId, ToolManifest, ToolVersion};
+use kami_types::{SecurityConfig, Tool, ToolId, ToolManifest, ToolVersion};
 
 #[tokio::test]
 async fn find_all_returns_all() {
@@ -16,6 +16,7 @@ async fn find_all_returns_all() {
             wasm: "alpha.wasm".to_string(),
             description: "Alpha".to_string(),
             arguments: vec![],
+            output: vec![],
             security: SecurityConfig::default(),
             wasm_sha256: None,
             signature: None,
@@ -34,6 +35,7 @@ async fn find_all_returns_all() {
             wasm: "beta.wasm".to_string(),
             description: "Beta".to_string(),
             arguments: vec![],
+            output: vec![],
             security: SecurityConfig::default(),
             wasm_sha256: None,
             signature: None,
@@ -64,6 +66,7 @@ async fn find_all_with_name_filter() {
             wasm: "f.wasm".to_string(),
             description: "Fetch".to_string(),
             arguments: vec![],
+            output: vec![],
             security: SecurityConfig::default(),
             wasm_sha256: None,
             signature: None,
@@ -82,6 +85,7 @@ async fn find_all_with_name_filter() {
             wasm: "c.wasm".to_string(),
             description: "Calculator".to_string(),
             arguments: vec![],
+            output: vec![],
             security: SecurityConfig::default(),
             wasm_sha256: None,
             signature: None,
@@ -103,42 +107,3 @@ async fn find_all_with_name_filter() {
     assert_eq!(results.len(), 1);
     assert_eq!(results[0].manifest.name, "fetch-url");
 }
-
-#[tokio::test]
-async fn find_all_with_arguments() {
-    let repo = SqliteToolRepository::open_in_memory().expect("open");
-
-    let tool = Tool {
-        manifest: ToolManifest {
-            id: ToolId::new("dev.test.with-args").expect("id"),
-            name: "with-args".to_string(),
-            version: ToolVersion::new(1, 0, 0),
-            wasm: "args.wasm".to_string(),
-            description: "Tool with arguments".to_string(),
-            arguments: vec![ToolArgument {
-                name: "url".to_string(),
-                arg_type: "string".to_string(),
-                description: "URL to fetch".to_string(),
-                required: true,
-                default: None,
-            }],
-            security: SecurityConfig::default(),
-            wasm_sha256: None,
-            signature: None,
-            signer_public_key: None,
-        },
-        install_path: "/tools/with-args".to_string(),
-        enabled: true,
-        pinned_version: None,
-        updated_at: None,
-    };
-
-    repo.insert(&tool).await.expect("insert");
-    let found = repo
-        .find_by_id(&tool.manifest.id)
-        .await
-        .expect("find")
-        .expect("exists");
-    assert_eq!(found.manifest.arguments.len(), 1);
-    assert_eq!(found.manifest.arguments[0].name, "url");
-}
@@ -0,0 +1,55 @@
+//! Tests for creating and loading the secret store key file.
+
+use std::path::PathBuf;
+
+use kami_store_sqlite::SecretKey;
+
+/// Returns a fresh directory path under the system temp dir, not created.
+fn scratch(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("kami_{name}_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn load_or_create_reuses_key_file() {
+    let dir = scratch("secret_key");
+    let path = dir.join("secrets.key");
+    let first = SecretKey::load_or_create(&path).expect("create");
+    let (nonce, sealed) = first.seal("A", b"value").expect("seal");
+    let second = SecretKey::load_or_create(&path).expect("load");
+    assert_eq!(second.open("A", &nonce, &sealed).expect("open"), b"value");
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[cfg(unix)]
+#[test]
+fn new_key_file_and_directory_are_owner_only() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = scratch("secret_key_modes");
+    let path = dir.join("nested").join("secrets.key");
+    SecretKey::load_or_create(&path).expect("create");
+
+    let mode = |p: &std::path::Path| std::fs::metadata(p).expect("meta").permissions().mode();
+    assert_eq!(mode(&path) & 0o777, 0o600);
+    assert_eq!(mode(&dir.join("nested")) & 0o777, 0o700);
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[cfg(unix)]
+#[test]
+fn key_file_readable_by_others_is_refused() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = scratch("secret_key_open");
+    let path = dir.join("secrets.key");
+    SecretKey::load_or_create(&path).expect("create");
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).expect("chmod");
+
+    let Err(err) = SecretKey::load_or_create(&path) else {
+        panic!("a world-readable key must be refused");
+    };
+    assert!(err.to_string().contains("chmod 600"), "{err}");
+    let _ = std::fs::remove_dir_all(&dir);
+}
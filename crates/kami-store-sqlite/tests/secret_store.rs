@@ -0,0 +1,102 @@
+//! Tests for the encrypted secret store and the audit log.
+
+use std::sync::Arc;
+
+use kami_registry::{AuditLog, SecretStore};
+use kami_store_sqlite::{SecretKey, SqliteSecretStore, SqliteToolRepository};
+use kami_types::{DomainEvent, SecretAccess, ToolId};
+
+fn store() -> (Arc<SqliteToolRepository>, SqliteSecretStore) {
+    let repo = Arc::new(SqliteToolRepository::open_in_memory().expect("db"));
+    let (key, _) = SecretKey::generate().expect("key");
+    let store = SqliteSecretStore::new(Arc::clone(&repo), key);
+    (repo, store)
+}
+
+#[test]
+fn cipher_roundtrip_and_tamper_detection() {
+    let (key, _) = SecretKey::generate().expect("key");
+    let (nonce, mut sealed) = key.seal("TOKEN", b"hunter2").expect("seal");
+    assert_ne!(sealed, b"hunter2");
+    assert_eq!(
+        key.open("TOKEN", &nonce, &sealed).expect("open"),
+        b"hunter2"
+    );
+    // Bound to the secret name: cannot be swapped to another row.
+    assert!(key.open("OTHER", &nonce, &sealed).is_err());
+    sealed[0] ^= 0xff;
+    assert!(key.open("TOKEN", &nonce, &sealed).is_err());
+}
+
+#[test]
+fn key_requires_32_bytes() {
+    assert!(SecretKey::from_bytes(&[0u8; 16]).is_err());
+    assert!(SecretKey::from_bytes(&[0u8; 32]).is_ok());
+}
+
+#[tokio::test]
+async fn set_get_list_remove() {
+    let (_, store) = store();
+    store.set("B_TOKEN", "b").await.expect("set");
+    store.set("A_TOKEN", "a1").await.expect("set");
+    store.set("A_TOKEN", "a2").await.expect("overwrite");
+
+    assert_eq!(
+        store.get("A_TOKEN").await.expect("get").as_deref(),
+        Some("a2")
+    );
+    assert_eq!(
+        store.list().await.expect("list"),
+        vec!["A_TOKEN", "B_TOKEN"]
+    );
+    assert!(store.remove("A_TOKEN").await.expect("remove"));
+    assert!(!store.remove("A_TOKEN").await.expect("remove again"));
+    assert_eq!(store.get("A_TOKEN").await.expect("get"), None);
+}
+
+#[tokio::test]
+async fn values_are_not_stored_in_plaintext() {
+    let repo = SqliteToolRepository::open_in_memory().expect("db");
+    let repo = Arc::new(repo);
+    let (key, _) = SecretKey::generate().expect("key");
+    SqliteSecretStore::new(Arc::clone(&repo), key)
+        .set("PW", "plaintext-marker")
+        .await
+        .expect("set");
+    // A store with another key cannot read the value back.
+    let (other, _) = SecretKey::generate().expect("key");
+    let foreign = SqliteSecretStore::new(repo, other);
+    assert!(foreign.get("PW").await.is_err());
+}
+
+#[tokio::test]
+async fn audit_log_returns_newest_first() {
+    let (repo, _) = store();
+    let tool = ToolId::new("dev.audit.tool").expect("id");
+    repo.record(&DomainEvent::secret_accessed(
+        None,
+        "A",
+        SecretAccess::Write,
+    ))
+    .await
+    .expect("record");
+    repo.record(&DomainEvent::secret_accessed(
+        Some(tool),
+        "A",
+        SecretAccess::Denied,
+    ))
+    .await
+    .expect("record");
+
+    let events = repo.recent(10).await.expect("recent");
+    assert_eq!(events.len(), 2);
+    assert!(matches!(
+        &events[0],
+        DomainEvent::SecretAccessed {
+            access: SecretAccess::Denied,
+            tool_id: Some(_),
+            ..
+        }
+    ));
+    assert_eq!(repo.recent(1).await.expect("recent").len(), 1);
+}
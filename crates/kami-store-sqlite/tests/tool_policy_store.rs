@@ -0,0 +1,120 @@
+//! Tests for the per-tool limit overrides and capability grants.
+
+use kami_registry::{ToolPolicyStore, ToolRepository};
+use kami_store_sqlite::SqliteToolRepository;
+use kami_types::{LimitCaps, SecurityConfig, Tool, ToolId, ToolManifest, ToolVersion};
+
+fn id(s: &str) -> ToolId {
+    ToolId::new(s).expect("id")
+}
+
+#[tokio::test]
+async fn set_replace_and_clear_overrides() {
+    let repo = SqliteToolRepository::open_in_memory().expect("db");
+    let tool = id("dev.policy.tool");
+    assert_eq!(repo.overrides(&tool).await.expect("get"), None);
+
+    let caps = LimitCaps {
+        max_memory_mb: Some(32),
+        ..LimitCaps::default()
+    };
+    repo.set_overrides(&tool, &caps).await.expect("set");
+    assert_eq!(repo.overrides(&tool).await.expect("get"), Some(caps));
+
+    let fuel = LimitCaps {
+        max_fuel: Some(10),
+        ..LimitCaps::default()
+    };
+    repo.set_overrides(&tool, &fuel).await.expect("replace");
+    assert_eq!(repo.overrides(&tool).await.expect("get"), Some(fuel));
+
+    assert!(repo.clear_overrides(&tool).await.expect("clear"));
+    assert!(!repo.clear_overrides(&tool).await.expect("clear again"));
+    assert_eq!(repo.overrides(&tool).await.expect("get"), None);
+}
+
+#[tokio::test]
+async fn overrides_are_per_tool() {
+    let repo = SqliteToolRepository::open_in_memory().expect("db");
+    let caps = LimitCaps {
+        max_execution_ms: Some(100),
+        ..LimitCaps::default()
+    };
+    repo.set_overrides(&id("dev.policy.a"), &caps)
+        .await
+        .expect("set");
+
+    let other = repo.overrides(&id("dev.policy.b")).await.expect("get");
+
+    assert_eq!(other, None);
+}
+
+/// An installed tool whose manifest requests `security`.
+fn granted_tool(security: SecurityConfig) -> Tool {
+    Tool {
+        manifest: ToolManifest {
+            id: id("dev.policy.granted"),
+            name: "granted".into(),
+            version: ToolVersion::new(1, 0, 0),
+            wasm: "granted.wasm".into(),
+            description: "grant test".into(),
+            arguments: vec![],
+            output: vec![],
+            security,
+            wasm_sha256: None,
+            signature: None,
+            signer_public_key: None,
+        },
+        install_path: "/granted".into(),
+        enabled: true,
+        pinned_version: None,
+        updated_at: None,
+    }
+}
+
+fn networked() -> SecurityConfig {
+    SecurityConfig {
+        net_allow_list: vec!["api.example.com".into()],
+        ..SecurityConfig::default()
+    }
+}
+
+#[tokio::test]
+async fn grants_are_replaced_and_dropped_on_uninstall() {
+    let repo = SqliteToolRepository::open_in_memory().expect("db");
+    let tool = granted_tool(SecurityConfig::default());
+    let tool_id = tool.manifest.id.clone();
+    repo.insert(&tool).await.expect("insert");
+    assert!(repo.granted(&tool_id).await.expect("get").is_none());
+
+    repo.grant(&tool_id, &SecurityConfig::default())
+        .await
+        .expect("grant");
+    repo.grant(&tool_id, &networked()).await.expect("regrant");
+    let stored = repo.granted(&tool_id).await.expect("get").expect("grant");
+    assert_eq!(stored.net_allow_list, networked().net_allow_list);
+
+    repo.delete(&tool_id).await.expect("delete");
+    assert!(repo.granted(&tool_id).await.expect("get").is_none());
+}
+
+#[tokio::test]
+async fn failed_install_records_no_grant() {
+    let repo = SqliteToolRepository::open_in_memory().expect("db");
+    let tool_id = id("dev.policy.granted");
+    repo.insert_granted(&granted_tool(SecurityConfig::default()))
+        .await
+        .expect("install");
+
+    let again = repo.insert_granted(&granted_tool(networked())).await;
+
+    assert!(again.is_err(), "already installed");
+    let grant = repo.granted(&tool_id).await.expect("get").expect("grant");
+    assert!(grant.net_allow_list.is_empty(), "grant kept: {grant:?}");
+
+    repo.update_granted(&granted_tool(networked()))
+        .await
+        .expect("update");
+    let grant = repo.granted(&tool_id).await.expect("get").expect("grant");
+    assert_eq!(grant.net_allow_list, networked().net_allow_list);
+}
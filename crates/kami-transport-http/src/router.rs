@@ -1,5 +1,10 @@
 //! Axum router for the MCP HTTP/JSON-RPC transport.
 //! Routes: `POST /mcp` (requests), `GET /health` (liveness), `GET /health/ready` (readiness).
+//!
+//! The transport keeps no sessions: each request is answered for the
+//! revision in its `MCP-Protocol-Version` header, or 2025-03-26 without one.
+//! Without a session to tie them to a client's calls, cancellations are
+//! ignored.
 
 use std::sync::Arc;
 
@@ -12,11 +17,20 @@ use axum::{
 };
 use serde_json::{json, Value};
 
-use kami_mcp::McpHandler;
+use kami_mcp::{McpHandler, Session, SessionState};
+use kami_protocol::mcp::ProtocolVersion;
 use kami_protocol::{error_codes, JsonRpcNotification, JsonRpcRequest};
 
 use crate::auth;
 
+/// Header naming the negotiated protocol revision on each request
+/// (MCP 2025-06-18).
+const PROTOCOL_VERSION_HEADER: &str = "mcp-protocol-version";
+
+/// Revision assumed for a request without [`PROTOCOL_VERSION_HEADER`], as
+/// the MCP specification prescribes for servers that cannot tell otherwise.
+const DEFAULT_PROTOCOL: ProtocolVersion = ProtocolVersion::V2025_03_26;
+
 /// Shared state threaded through all axum handlers.
 #[derive(Clone)]
 pub struct AppState {
@@ -59,6 +73,21 @@ async fn handle_mcp(
         }
     }
 
+    let protocol = match headers.get(PROTOCOL_VERSION_HEADER) {
+        None => DEFAULT_PROTOCOL,
+        Some(value) => match value.to_str().ok().and_then(ProtocolVersion::parse) {
+            Some(protocol) => protocol,
+            None => {
+                let message = format!("unsupported {PROTOCOL_VERSION_HEADER} header");
+                return json_rpc_error(
+                    StatusCode::BAD_REQUEST,
+                    error_codes::INVALID_REQUEST,
+                    &message,
+                );
+            }
+        },
+    };
+
     let json_val: Value = match serde_json::from_str(&body) {
         Ok(v) => v,
         Err(_) => return json_rpc_error(StatusCode::OK, error_codes::PARSE_ERROR, "Parse error"),
@@ -67,7 +96,9 @@ async fn handle_mcp(
     let has_id = json_val.get("id").is_some_and(|v| !v.is_null());
     if !has_id {
         if let Ok(notif) = serde_json::from_value::<JsonRpcNotification>(json_val) {
-            state.handler.handle_notification(&notif);
+            state
+                .handler
+                .handle_notification(&notif, &SessionState::default());
         }
         return StatusCode::NO_CONTENT.into_response();
     }
@@ -83,7 +114,10 @@ async fn handle_mcp(
         }
     };
 
-    let output = state.handler.dispatch(&request).await;
+    let session = SessionState::new(Session { protocol });
+    let Some(output) = state.handler.dispatch(&request, &session).await else {
+        return StatusCode::NO_CONTENT.into_response();
+    };
     match output.to_json() {
         Ok(json_str) => (
             StatusCode::OK,
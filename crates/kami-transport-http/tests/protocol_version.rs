@@ -0,0 +1,90 @@
+//! Responses follow the `MCP-Protocol-Version` request header.
+
+use std::sync::Arc;
+
+use axum::body::Body;
+use http::Request;
+use serde_json::Value;
+use tower::ServiceExt;
+
+use kami_mcp::McpHandler;
+use kami_registry::ToolRepository;
+use kami_runtime::{KamiRuntime, RuntimeConfig};
+use kami_store_sqlite::SqliteToolRepository;
+use kami_transport_http::{build_router, AppState};
+use kami_types::{SecurityConfig, Tool, ToolId, ToolManifest, ToolVersion};
+
+async fn state_with_tool() -> AppState {
+    let repo = Arc::new(SqliteToolRepository::open_in_memory().expect("db"));
+    let tool = Tool {
+        manifest: ToolManifest {
+            id: ToolId::new("dev.test.echo").expect("id"),
+            name: "Echo".into(),
+            version: ToolVersion::new(1, 0, 0),
+            wasm: "echo.wasm".into(),
+            description: "Echo".into(),
+            arguments: vec![],
+            output: vec![],
+            security: SecurityConfig::default(),
+            wasm_sha256: None,
+            signature: None,
+            signer_public_key: None,
+        },
+        install_path: "/tmp".into(),
+        enabled: true,
+        pinned_version: None,
+        updated_at: None,
+    };
+    repo.insert(&tool).await.expect("insert");
+    let runtime = KamiRuntime::new(RuntimeConfig::default(), repo.clone()).expect("rt");
+    AppState {
+        handler: Arc::new(McpHandler::new(Arc::new(runtime), repo)),
+        token: None,
+    }
+}
+
+async fn tools_list(state: AppState, version: Option<&str>) -> (u16, Value) {
+    let mut req = Request::builder().method("POST").uri("/mcp");
+    if let Some(version) = version {
+        req = req.header("MCP-Protocol-Version", version);
+    }
+    let body = r#"{"jsonrpc":"2.0","id":1,"method":"tools/list"}"#;
+    let resp = build_router(state)
+        .oneshot(req.body(Body::from(body)).expect("req"))
+        .await
+        .expect("resp");
+    let status = resp.status().as_u16();
+    let bytes = axum::body::to_bytes(resp.into_body(), 8192)
+        .await
+        .expect("body");
+    (status, serde_json::from_slice(&bytes).expect("json"))
+}
+
+#[tokio::test]
+async fn header_selects_the_revision() {
+    let state = state_with_tool().await;
+
+    let (status, old) = tools_list(state.clone(), Some("2024-11-05")).await;
+    assert_eq!(status, 200);
+    let tool = &old["result"]["tools"][0];
+    assert!(tool.get("title").is_none() && tool.get("annotations").is_none());
+
+    let (_, new) = tools_list(state, Some("2025-06-18")).await;
+    assert_eq!(new["result"]["tools"][0]["title"], "Echo");
+}
+
+#[tokio::test]
+async fn missing_header_means_2025_03_26() {
+    let (status, body) = tools_list(state_with_tool().await, None).await;
+    assert_eq!(status, 200);
+    let tool = &body["result"]["tools"][0];
+    assert!(tool.get("annotations").is_some(), "{tool}");
+    assert!(tool.get("title").is_none(), "{tool}");
+}
+
+#[tokio::test]
+async fn unsupported_header_is_a_bad_request() {
+    let (status, body) = tools_list(state_with_tool().await, Some("2023-01-01")).await;
+    assert_eq!(status, 400);
+    assert_eq!(body["error"]["code"], -32600);
+}
@@ -6,11 +6,12 @@
 //! and a server loop that ties it together.
 
 pub mod error;
+mod message;
 pub mod server;
 pub mod transport;
 
 pub use error::TransportError;
 // McpHandler lives in kami-mcp (APPLICATION layer); re-exported for convenience.
-pub use kami_mcp::{JsonRpcOutput, McpHandler};
+pub use kami_mcp::{JsonRpcOutput, McpHandler, SessionState};
 pub use server::McpServer;
 pub use transport::StdioTransport;
@@ -0,0 +1,31 @@
+//! Classification of incoming stdio lines and encoding of notifications.
+
+use kami_protocol::{JsonRpcNotification, JsonRpcRequest};
+
+use crate::error::TransportError;
+
+/// One parsed line of input.
+pub(crate) enum Message {
+    /// A request, which must get a response.
+    Request(JsonRpcRequest),
+    /// A notification (no `id` field), which must not.
+    Notification(JsonRpcNotification),
+    /// Neither; answered with a parse error.
+    Invalid(serde_json::Error),
+}
+
+/// Parses `line` as a request, falling back to a notification.
+pub(crate) fn parse_message(line: &str) -> Message {
+    match serde_json::from_str::<JsonRpcRequest>(line) {
+        Ok(request) => Message::Request(request),
+        Err(_) => match serde_json::from_str::<JsonRpcNotification>(line) {
+            Ok(notification) => Message::Notification(notification),
+            Err(e) => Message::Invalid(e),
+        },
+    }
+}
+
+/// Serializes an outgoing notification to one line of JSON.
+pub(crate) fn encode(notification: &JsonRpcNotification) -> Result<String, TransportError> {
+    serde_json::to_string(notification).map_err(|e| TransportError::Write(e.to_string()))
+}
@@ -1,23 +1,38 @@
 //! MCP server loop over stdio transport.
 //!
-//! Reads JSON-RPC requests line by line, dispatches them via
-//! `McpHandler`, and writes responses back. JSON-RPC notifications
-//! (which have no `id` field) are handled silently without a response.
+//! Reads JSON-RPC requests line by line, dispatches them via `McpHandler`
+//! and writes responses back; notifications get no response. Handler
+//! notifications (guest progress, logs) precede the request's response.
+//!
+//! Requests are served one at a time, but input keeps being read while one
+//! runs: notifications (e.g. `notifications/cancelled`) take effect at once
+//! and further requests are queued until it completes.
+
+use std::collections::VecDeque;
 
+use tokio::sync::mpsc::UnboundedReceiver;
 use tracing::{debug, error, info, warn};
 
-use kami_mcp::{JsonRpcOutput, McpHandler};
-use kami_protocol::{
-    error_codes, JsonRpcErrorResponse, JsonRpcNotification, JsonRpcRequest, RequestId,
-};
+use kami_mcp::{JsonRpcOutput, McpHandler, Notifier, SessionState};
+use kami_protocol::{error_codes, JsonRpcErrorResponse, JsonRpcNotification, RequestId};
 
 use crate::error::TransportError;
+use crate::message::{encode, parse_message, Message};
 use crate::transport::StdioTransport;
 
+mod serve;
+
 /// MCP server that reads from a transport and dispatches to a handler.
 pub struct McpServer<R, W> {
     transport: StdioTransport<R, W>,
     handler: McpHandler,
+    /// What the client negotiated: one stdio connection, one session.
+    session: SessionState,
+    notifications: UnboundedReceiver<JsonRpcNotification>,
+    /// Lines read while a request was running, served next.
+    pending: VecDeque<String>,
+    /// Whether the input stream has reached EOF.
+    closed: bool,
 }
 
 impl<R, W> McpServer<R, W>
@@ -26,63 +41,75 @@ where
     W: tokio::io::AsyncWrite + Unpin,
 {
     /// Creates a new server with the given transport and handler.
+    ///
+    /// Attaches a notifier to `handler` so it can reach the client.
     pub fn new(transport: StdioTransport<R, W>, handler: McpHandler) -> Self {
-        Self { transport, handler }
+        let (notifier, notifications) = Notifier::channel();
+        Self {
+            transport,
+            handler: handler.with_notifier(notifier),
+            session: SessionState::default(),
+            notifications,
+            pending: VecDeque::new(),
+            closed: false,
+        }
     }
 
-    /// Runs the server loop until the transport is closed.
-    ///
-    /// Each incoming line is parsed as either a JSON-RPC request
-    /// (response required) or a notification (silently handled).
+    /// Runs the server loop until the transport is closed, answering each
+    /// JSON-RPC request and silently handling each notification.
     pub async fn run(&mut self) -> Result<(), TransportError> {
         info!("MCP server starting on stdio");
-
-        loop {
-            // 1. Read next line
-            let line = match self.transport.read_line().await? {
-                Some(line) if line.is_empty() => continue,
-                Some(line) => line,
-                None => {
-                    info!("stdin closed, shutting down");
-                    return Ok(());
+        while let Some(line) = self.next_line().await? {
+            match parse_message(&line) {
+                Message::Request(request) => {
+                    debug!(method = %request.method, id = ?request.id, "received request");
+                    let output = self.serve(&request).await?;
+                    self.flush_notifications().await?;
+                    if let Some(output) = output {
+                        self.write_output(&output).await?;
+                    }
                 }
-            };
-
-            // 2. Try parsing as a JSON-RPC request (has an `id`)
-            match serde_json::from_str::<JsonRpcRequest>(&line) {
-                Ok(request) => {
-                    debug!(
-                        method = %request.method,
-                        id = ?request.id,
-                        "received request"
-                    );
-                    let output = self.handler.dispatch(&request).await;
-                    self.write_output(&output).await?;
+                Message::Notification(notif) => {
+                    debug!(method = %notif.method, "received notification");
+                    // Notifications must not receive a response.
+                    self.handler.handle_notification(&notif, &self.session);
                 }
-                Err(_) => {
-                    // 3. Fall back: try as notification (no `id` field)
-                    match serde_json::from_str::<JsonRpcNotification>(&line) {
-                        Ok(notif) => {
-                            debug!(
-                                method = %notif.method,
-                                "received notification"
-                            );
-                            self.handler.handle_notification(&notif);
-                            // Notifications must not receive a response.
-                        }
-                        Err(e) => {
-                            warn!(error = %e, "failed to parse JSON-RPC message");
-                            let err = JsonRpcErrorResponse::error(
-                                RequestId::Number(0),
-                                error_codes::PARSE_ERROR,
-                                format!("parse error: {e}"),
-                            );
-                            self.write_output(&JsonRpcOutput::Error(err)).await?;
-                        }
-                    }
+                Message::Invalid(e) => {
+                    warn!(error = %e, "failed to parse JSON-RPC message");
+                    let err = JsonRpcErrorResponse::error(
+                        RequestId::Number(0),
+                        error_codes::PARSE_ERROR,
+                        format!("parse error: {e}"),
+                    );
+                    self.write_output(&JsonRpcOutput::Error(err)).await?;
                 }
             }
         }
+        info!("stdin closed, shutting down");
+        Ok(())
+    }
+
+    /// Returns the next non-empty line, queued lines first; `None` on EOF.
+    async fn next_line(&mut self) -> Result<Option<String>, TransportError> {
+        if let Some(line) = self.pending.pop_front() {
+            return Ok(Some(line));
+        }
+        while !self.closed {
+            match self.transport.read_line().await? {
+                Some(line) if line.is_empty() => continue,
+                Some(line) => return Ok(Some(line)),
+                None => self.closed = true,
+            }
+        }
+        Ok(None)
+    }
+
+    /// Writes every notification queued by the handler.
+    async fn flush_notifications(&mut self) -> Result<(), TransportError> {
+        while let Ok(notification) = self.notifications.try_recv() {
+            self.transport.write_line(&encode(&notification)?).await?;
+        }
+        Ok(())
     }
 
     /// Serializes and writes a JSON-RPC output to the transport.
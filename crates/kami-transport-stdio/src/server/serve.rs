@@ -0,0 +1,46 @@
+//! Serving one request while the connection stays live.
+
+use tracing::debug;
+
+use kami_mcp::JsonRpcOutput;
+use kami_protocol::JsonRpcRequest;
+
+use super::McpServer;
+use crate::error::TransportError;
+use crate::message::{encode, parse_message, Message};
+
+impl<R, W> McpServer<R, W>
+where
+    R: tokio::io::AsyncRead + Unpin,
+    W: tokio::io::AsyncWrite + Unpin,
+{
+    /// Dispatches `request` while writing the handler's notifications and
+    /// reading input: notifications are handled at once, other lines queued.
+    /// Returns `None` if the client cancelled the request.
+    pub(super) async fn serve(
+        &mut self,
+        request: &JsonRpcRequest,
+    ) -> Result<Option<JsonRpcOutput>, TransportError> {
+        let dispatch = self.handler.dispatch(request, &self.session);
+        tokio::pin!(dispatch);
+        loop {
+            tokio::select! {
+                output = &mut dispatch => return Ok(output),
+                Some(notification) = self.notifications.recv() => {
+                    self.transport.write_line(&encode(&notification)?).await?;
+                }
+                line = self.transport.read_line(), if !self.closed => match line? {
+                    None => self.closed = true,
+                    Some(line) if line.is_empty() => {}
+                    Some(line) => match parse_message(&line) {
+                        Message::Notification(notif) => {
+                            debug!(method = %notif.method, "received notification mid-request");
+                            self.handler.handle_notification(&notif, &self.session);
+                        }
+                        _ => self.pending.push_back(line),
+                    },
+                },
+            }
+        }
+    }
+}
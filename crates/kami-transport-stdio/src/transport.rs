@@ -3,7 +3,7 @@
 //! Each JSON-RPC message is a single line terminated by `\n`.
 //! This follows the MCP stdio transport specification.
 
-use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, Lines};
 use tracing::trace;
 
 use crate::error::TransportError;
@@ -13,7 +13,7 @@ use crate::error::TransportError;
 /// Uses line-delimited JSON: one complete JSON object per line.
 /// This struct is generic over reader/writer for testability.
 pub struct StdioTransport<R, W> {
-    reader: BufReader<R>,
+    reader: Lines<BufReader<R>>,
     writer: W,
 }
 
@@ -25,25 +25,24 @@ where
     /// Creates a new transport with the given reader and writer.
     pub fn new(reader: R, writer: W) -> Self {
         Self {
-            reader: BufReader::new(reader),
+            reader: BufReader::new(reader).lines(),
             writer,
         }
     }
 
     /// Reads the next line from the input stream.
     ///
-    /// Returns `None` on EOF (connection closed).
+    /// Returns `None` on EOF (connection closed). Cancel-safe: dropping the
+    /// future before it completes loses no input.
     pub async fn read_line(&mut self) -> Result<Option<String>, TransportError> {
-        let mut line = String::new();
-        let bytes_read = self
+        let Some(line) = self
             .reader
-            .read_line(&mut line)
+            .next_line()
             .await
-            .map_err(|e| TransportError::Read(e.to_string()))?;
-
-        if bytes_read == 0 {
+            .map_err(|e| TransportError::Read(e.to_string()))?
+        else {
             return Ok(None);
-        }
+        };
 
         let trimmed = line.trim().to_string();
         if trimmed.is_empty() {
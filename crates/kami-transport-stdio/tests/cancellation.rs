@@ -0,0 +1,86 @@
+//! `notifications/cancelled` aborts a running `tools/call` over stdio, and
+//! only from the session that made the call.
+
+use std::time::Duration;
+
+use tokio::io::AsyncWriteExt;
+use tokio::time::timeout;
+
+use kami_protocol::{JsonRpcNotification, JsonRpcRequest};
+use kami_transport_stdio::{McpServer, SessionState, StdioTransport};
+
+mod common;
+
+/// Flat component whose `run` spins forever.
+const SPIN_WAT: &str = include_str!("../../../tests/fixtures/wat/spin.wat");
+
+#[tokio::test]
+async fn cancelled_notification_aborts_running_call() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let (mut client, server_end) = tokio::io::duplex(4096);
+    let mut output = Vec::new();
+    let transport = StdioTransport::new(server_end, &mut output);
+    let mut server = McpServer::new(
+        transport,
+        common::wat_handler(&dir, "dev.test.spin", SPIN_WAT).await,
+    );
+
+    let client_side = async move {
+        let call = r#"{"jsonrpc":"2.0","id":7,"method":"tools/call","params":{"name":"dev.test.spin","arguments":{}}}"#;
+        client
+            .write_all(format!("{call}\n").as_bytes())
+            .await
+            .expect("write call");
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        let cancel = r#"{"jsonrpc":"2.0","method":"notifications/cancelled","params":{"requestId":7,"reason":"no longer needed"}}"#;
+        client
+            .write_all(format!("{cancel}\n").as_bytes())
+            .await
+            .expect("write cancel");
+        client.shutdown().await.expect("close");
+        // Keep the stream open for reading until the server is done.
+        client
+    };
+    let (served, _client) = tokio::time::timeout(Duration::from_secs(10), async {
+        tokio::join!(server.run(), client_side)
+    })
+    .await
+    .expect("call was cancelled");
+    served.expect("run");
+
+    assert!(
+        output.is_empty(),
+        "cancelled call was answered: {}",
+        String::from_utf8_lossy(&output)
+    );
+}
+
+#[tokio::test]
+async fn only_the_calling_session_can_cancel() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let handler = common::wat_handler(&dir, "dev.test.spin", SPIN_WAT).await;
+    let (caller, other) = (SessionState::default(), SessionState::default());
+    let call: JsonRpcRequest = serde_json::from_str(
+        r#"{"jsonrpc":"2.0","id":7,"method":"tools/call","params":{"name":"dev.test.spin","arguments":{}}}"#,
+    )
+    .expect("call");
+    let cancel: JsonRpcNotification = serde_json::from_str(
+        r#"{"jsonrpc":"2.0","method":"notifications/cancelled","params":{"requestId":7}}"#,
+    )
+    .expect("cancel");
+
+    let running = handler.dispatch(&call, &caller);
+    tokio::pin!(running);
+    let still_running = Duration::from_millis(300);
+    assert!(timeout(still_running, &mut running).await.is_err());
+    handler.handle_notification(&cancel, &other);
+    assert!(
+        timeout(still_running, &mut running).await.is_err(),
+        "another session cancelled the call"
+    );
+    handler.handle_notification(&cancel, &caller);
+    let output = timeout(Duration::from_secs(10), running)
+        .await
+        .expect("call was cancelled");
+    assert!(output.is_none(), "cancelled call was answered");
+}
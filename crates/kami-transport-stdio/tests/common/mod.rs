@@ -0,0 +1,49 @@
+//! Helpers shared by the integration tests that serve WAT tools.
+
+use std::sync::Arc;
+
+use kami_registry::ToolRepository;
+use kami_runtime::{KamiRuntime, RuntimeConfig};
+use kami_store_sqlite::SqliteToolRepository;
+use kami_transport_stdio::McpHandler;
+use kami_types::{ResourceLimits, SecurityConfig, Tool, ToolId, ToolManifest, ToolVersion};
+
+/// Installs the component `wat` as tool `id` under `dir` and returns a
+/// handler serving it with epoch interruption enabled.
+pub async fn wat_handler(dir: &tempfile::TempDir, id: &str, wat: &str) -> McpHandler {
+    std::fs::write(dir.path().join("tool.wasm"), wat).expect("write wasm");
+    let repo = Arc::new(SqliteToolRepository::open_in_memory().expect("db"));
+    let tool = Tool {
+        manifest: ToolManifest {
+            id: ToolId::new(id).expect("id"),
+            name: "test".to_string(),
+            version: ToolVersion::new(1, 0, 0),
+            wasm: "tool.wasm".to_string(),
+            description: "Test tool".to_string(),
+            arguments: vec![],
+            output: vec![],
+            security: SecurityConfig {
+                limits: ResourceLimits {
+                    max_fuel: u64::MAX / 2,
+                    max_execution_ms: 60_000,
+                    ..ResourceLimits::default()
+                },
+                ..SecurityConfig::default()
+            },
+            wasm_sha256: None,
+            signature: None,
+            signer_public_key: None,
+        },
+        install_path: dir.path().display().to_string(),
+        enabled: true,
+        pinned_version: None,
+        updated_at: None,
+    };
+    repo.insert(&tool).await.expect("insert");
+    let config = RuntimeConfig {
+        epoch_interruption: true,
+        ..RuntimeConfig::default()
+    };
+    let runtime = KamiRuntime::new(config, repo.clone()).expect("runtime");
+    McpHandler::new(Arc::new(runtime), repo)
+}
@@ -8,7 +8,7 @@ use kami_protocol::mcp::methods;
 use kami_protocol::{JsonRpcNotification, JsonRpcRequest, RequestId};
 use kami_runtime::{KamiRuntime, RuntimeConfig};
 use kami_store_sqlite::SqliteToolRepository;
-use kami_transport_stdio::McpHandler;
+use kami_transport_stdio::{McpHandler, SessionState};
 
 fn make_handler() -> McpHandler {
     let repo = Arc::new(SqliteToolRepository::open_in_memory().expect("db"));
@@ -35,8 +35,8 @@ fn rpc(method: &str, id: i64, params: Option<Value>) -> JsonRpcRequest {
 async fn tools_call_missing_params_returns_error() {
     let handler = make_handler();
     let req = rpc(methods::TOOLS_CALL, 1, None);
-    let output = handler.dispatch(&req).await;
-    let json_str = output.to_json().expect("ser");
+    let output = handler.dispatch(&req, &SessionState::default()).await;
+    let json_str = output.expect("response").to_json().expect("ser");
     let parsed: Value = serde_json::from_str(&json_str).expect("de");
     assert!(parsed["error"]["code"].is_i64());
 }
@@ -45,8 +45,8 @@ async fn tools_call_missing_params_returns_error() {
 async fn tools_call_invalid_params_returns_error() {
     let handler = make_handler();
     let req = rpc(methods::TOOLS_CALL, 2, Some(json!("not an object")));
-    let output = handler.dispatch(&req).await;
-    let json_str = output.to_json().expect("ser");
+    let output = handler.dispatch(&req, &SessionState::default()).await;
+    let json_str = output.expect("response").to_json().expect("ser");
     let parsed: Value = serde_json::from_str(&json_str).expect("de");
     assert!(parsed["error"]["message"].as_str().is_some());
 }
@@ -59,8 +59,8 @@ async fn tools_call_invalid_tool_name_returns_error() {
         3,
         Some(json!({"name": "no-dot", "arguments": {}})),
     );
-    let output = handler.dispatch(&req).await;
-    let json_str = output.to_json().expect("ser");
+    let output = handler.dispatch(&req, &SessionState::default()).await;
+    let json_str = output.expect("response").to_json().expect("ser");
     let parsed: Value = serde_json::from_str(&json_str).expect("de");
     assert!(parsed["error"]["message"]
         .as_str()
@@ -76,8 +76,8 @@ async fn tools_call_nonexistent_tool_returns_error_content() {
         4,
         Some(json!({"name": "dev.test.missing", "arguments": {}})),
     );
-    let output = handler.dispatch(&req).await;
-    let json_str = output.to_json().expect("ser");
+    let output = handler.dispatch(&req, &SessionState::default()).await;
+    let json_str = output.expect("response").to_json().expect("ser");
     let parsed: Value = serde_json::from_str(&json_str).expect("de");
     // Either an error response or a call result with isError=true
     let has_error = parsed.get("error").is_some()
@@ -92,8 +92,8 @@ async fn tools_call_nonexistent_tool_returns_error_content() {
 async fn initialize_without_params_succeeds() {
     let handler = make_handler();
     let req = rpc(methods::INITIALIZE, 10, None);
-    let output = handler.dispatch(&req).await;
-    let json_str = output.to_json().expect("ser");
+    let output = handler.dispatch(&req, &SessionState::default()).await;
+    let json_str = output.expect("response").to_json().expect("ser");
     let parsed: Value = serde_json::from_str(&json_str).expect("de");
     assert_eq!(parsed["result"]["serverInfo"]["name"], "kami");
 }
@@ -106,8 +106,8 @@ async fn initialize_with_invalid_params_returns_error() {
         11,
         Some(json!({"protocolVersion": 123})),
     );
-    let output = handler.dispatch(&req).await;
-    let json_str = output.to_json().expect("ser");
+    let output = handler.dispatch(&req, &SessionState::default()).await;
+    let json_str = output.expect("response").to_json().expect("ser");
     let parsed: Value = serde_json::from_str(&json_str).expect("de");
     assert!(parsed["error"].is_object());
 }
@@ -120,15 +120,28 @@ fn handle_notification_initialized_does_not_panic() {
         method: methods::NOTIFICATIONS_INITIALIZED.into(),
         params: None,
     };
-    handler.handle_notification(&notif);
+    handler.handle_notification(&notif, &SessionState::default());
+}
+
+#[test]
+fn handle_notification_cancelled_for_unknown_request_is_ignored() {
+    let handler = make_handler();
+    for params in [Some(json!({"requestId": 99})), Some(json!("bad")), None] {
+        let notif = JsonRpcNotification {
+            jsonrpc: "2.0".into(),
+            method: methods::NOTIFICATIONS_CANCELLED.into(),
+            params,
+        };
+        handler.handle_notification(&notif, &SessionState::default());
+    }
 }
 
 #[tokio::test]
 async fn prompts_list_returns_empty_array() {
     let handler = make_handler();
     let req = rpc(methods::PROMPTS_LIST, 20, None);
-    let output = handler.dispatch(&req).await;
-    let json_str = output.to_json().expect("ser");
+    let output = handler.dispatch(&req, &SessionState::default()).await;
+    let json_str = output.expect("response").to_json().expect("ser");
     let parsed: Value = serde_json::from_str(&json_str).expect("de");
     let prompts = parsed["result"]["prompts"].as_array().expect("arr");
     assert!(prompts.is_empty());
@@ -138,8 +151,8 @@ async fn prompts_list_returns_empty_array() {
 async fn resources_list_returns_empty_array() {
     let handler = make_handler();
     let req = rpc(methods::RESOURCES_LIST, 21, None);
-    let output = handler.dispatch(&req).await;
-    let json_str = output.to_json().expect("ser");
+    let output = handler.dispatch(&req, &SessionState::default()).await;
+    let json_str = output.expect("response").to_json().expect("ser");
     let parsed: Value = serde_json::from_str(&json_str).expect("de");
     let resources = parsed["result"]["resources"].as_array().expect("arr");
     assert!(resources.is_empty());
@@ -149,8 +162,8 @@ async fn resources_list_returns_empty_array() {
 async fn resources_read_without_params_returns_error() {
     let handler = make_handler();
     let req = rpc(methods::RESOURCES_READ, 22, None);
-    let output = handler.dispatch(&req).await;
-    let json_str = output.to_json().expect("ser");
+    let output = handler.dispatch(&req, &SessionState::default()).await;
+    let json_str = output.expect("response").to_json().expect("ser");
     let parsed: Value = serde_json::from_str(&json_str).expect("de");
     assert!(parsed["error"]["code"].is_i64());
 }
@@ -163,8 +176,8 @@ async fn resources_read_with_uri_returns_not_found() {
         23,
         Some(json!({"uri": "file:///test"})),
     );
-    let output = handler.dispatch(&req).await;
-    let json_str = output.to_json().expect("ser");
+    let output = handler.dispatch(&req, &SessionState::default()).await;
+    let json_str = output.expect("response").to_json().expect("ser");
     let parsed: Value = serde_json::from_str(&json_str).expect("de");
     assert!(parsed["error"]["message"]
         .as_str()
@@ -176,8 +189,8 @@ async fn resources_read_with_uri_returns_not_found() {
 async fn initialize_advertises_all_capabilities() {
     let handler = make_handler();
     let req = rpc(methods::INITIALIZE, 30, None);
-    let output = handler.dispatch(&req).await;
-    let json_str = output.to_json().expect("ser");
+    let output = handler.dispatch(&req, &SessionState::default()).await;
+    let json_str = output.expect("response").to_json().expect("ser");
     let parsed: Value = serde_json::from_str(&json_str).expect("de");
     let caps = &parsed["result"]["capabilities"];
     assert!(caps["tools"].is_object(), "tools capability missing");
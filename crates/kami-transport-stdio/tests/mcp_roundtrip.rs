@@ -12,7 +12,7 @@ use kami_protocol::{JsonRpcRequest, RequestId};
 use kami_registry::ToolRepository;
 use kami_runtime::{KamiRuntime, RuntimeConfig};
 use kami_store_sqlite::SqliteToolRepository;
-use kami_transport_stdio::McpHandler;
+use kami_transport_stdio::{McpHandler, SessionState};
 use kami_types::{SecurityConfig, Tool, ToolId, ToolManifest, ToolVersion};
 
 fn make_handler() -> (McpHandler, Arc<dyn ToolRepository>) {
@@ -47,6 +47,7 @@ fn sample_tool(id: &str, name: &str) -> Tool {
             wasm: "sample.wasm".to_string(),
             description: format!("{name} tool"),
             arguments: vec![],
+            output: vec![],
             security: SecurityConfig::default(),
             wasm_sha256: None,
             signature: None,
@@ -71,8 +72,8 @@ async fn mcp_initialize_returns_capabilities() {
             "clientInfo": { "name": "test", "version": "0.1" }
         })),
     );
-    let output = handler.dispatch(&req).await;
-    let json_str = output.to_json().expect("serialize");
+    let output = handler.dispatch(&req, &SessionState::default()).await;
+    let json_str = output.expect("response").to_json().expect("serialize");
     let parsed: Value = serde_json::from_str(&json_str).expect("parse");
 
     assert_eq!(parsed["jsonrpc"], "2.0");
@@ -86,8 +87,8 @@ async fn mcp_tools_list_empty_then_populated() {
 
     // 1. tools/list should return empty
     let req = rpc(methods::TOOLS_LIST, 1, None);
-    let output = handler.dispatch(&req).await;
-    let parsed: Value = serde_json::from_str(&output.to_json().expect("s")).expect("p");
+    let output = handler.dispatch(&req, &SessionState::default()).await;
+    let parsed: Value = serde_json::from_str(&output.expect("r").to_json().expect("s")).expect("p");
     let tools = parsed["result"]["tools"].as_array().expect("array");
     assert!(tools.is_empty(), "expected no tools initially");
 
@@ -97,8 +98,9 @@ async fn mcp_tools_list_empty_then_populated() {
 
     // 3. tools/list should now return one tool
     let req2 = rpc(methods::TOOLS_LIST, 2, None);
-    let output2 = handler.dispatch(&req2).await;
-    let parsed2: Value = serde_json::from_str(&output2.to_json().expect("s")).expect("p");
+    let output2 = handler.dispatch(&req2, &SessionState::default()).await;
+    let parsed2: Value =
+        serde_json::from_str(&output2.expect("r").to_json().expect("s")).expect("p");
     let tools2 = parsed2["result"]["tools"].as_array().expect("array");
     assert_eq!(tools2.len(), 1);
     assert_eq!(tools2[0]["name"], "dev.test.alpha");
@@ -108,8 +110,8 @@ async fn mcp_tools_list_empty_then_populated() {
 async fn mcp_unknown_method_returns_error() {
     let (handler, _) = make_handler();
     let req = rpc("nonexistent/method", 99, None);
-    let output = handler.dispatch(&req).await;
-    let json_str = output.to_json().expect("serialize");
+    let output = handler.dispatch(&req, &SessionState::default()).await;
+    let json_str = output.expect("response").to_json().expect("serialize");
     let parsed: Value = serde_json::from_str(&json_str).expect("parse");
 
     assert!(parsed["error"]["code"].is_i64());
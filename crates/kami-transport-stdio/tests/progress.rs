@@ -0,0 +1,54 @@
+//! Guest progress reaches the client as `notifications/progress`.
+
+use serde_json::Value;
+
+use kami_transport_stdio::{McpServer, StdioTransport};
+
+mod common;
+
+/// Flat component that reports `1/4`, `2/4 "halfway"` and an invalid NaN
+/// update, then returns `Ok(input)`.
+const REPORTER_WAT: &str = include_str!("../../../tests/fixtures/wat/reporter.wat");
+
+/// Calls the reporter with `params` and returns every line written back.
+async fn call_reporter(params: &str) -> Vec<Value> {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let handler = common::wat_handler(&dir, "dev.test.reporter", REPORTER_WAT).await;
+    let input = format!(r#"{{"jsonrpc":"2.0","id":1,"method":"tools/call","params":{params}}}"#);
+    let input = format!("{input}\n");
+    let mut output = Vec::new();
+    let transport = StdioTransport::new(input.as_bytes(), &mut output);
+    McpServer::new(transport, handler).run().await.expect("run");
+    String::from_utf8(output)
+        .expect("utf8")
+        .lines()
+        .map(|l| serde_json::from_str(l).expect("json line"))
+        .collect()
+}
+
+#[tokio::test]
+async fn progress_notifications_precede_the_response() {
+    let lines = call_reporter(
+        r#"{"name":"dev.test.reporter","arguments":{},"_meta":{"progressToken":"p1"}}"#,
+    )
+    .await;
+
+    assert_eq!(lines.len(), 3, "unexpected output: {lines:?}");
+    for (line, progress) in lines.iter().zip([1.0, 2.0]) {
+        assert_eq!(line["method"], "notifications/progress");
+        assert_eq!(line["params"]["progressToken"], "p1");
+        assert_eq!(line["params"]["progress"], progress);
+        assert_eq!(line["params"]["total"], 4.0);
+    }
+    assert_eq!(lines[1]["params"]["message"], "halfway");
+    assert_eq!(lines[2]["id"], 1);
+    assert_eq!(lines[2]["result"]["isError"], false);
+}
+
+#[tokio::test]
+async fn no_progress_without_token() {
+    let lines = call_reporter(r#"{"name":"dev.test.reporter","arguments":{}}"#).await;
+
+    assert_eq!(lines.len(), 1, "unexpected output: {lines:?}");
+    assert_eq!(lines[0]["id"], 1);
+}
@@ -89,3 +89,16 @@ async fn server_unknown_method_returns_error() {
     let response = String::from_utf8(output).expect("utf8");
     assert!(response.contains("unknown method"));
 }
+
+#[tokio::test]
+async fn server_accepts_logging_set_level() {
+    let input = "{\"jsonrpc\":\"2.0\",\"id\":3,\"method\":\"logging/setLevel\",\"params\":{\"level\":\"warning\"}}\n";
+    let reader = tokio::io::BufReader::new(input.as_bytes());
+    let mut output = Vec::new();
+    let transport = StdioTransport::new(reader, &mut output);
+    let mut server = McpServer::new(transport, make_handler());
+    server.run().await.expect("run");
+    let response = String::from_utf8(output).expect("utf8");
+    assert!(response.contains("\"id\":3"));
+    assert!(response.contains("\"result\":{}"));
+}
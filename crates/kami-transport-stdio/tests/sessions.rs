@@ -0,0 +1,76 @@
+//! Each connection keeps the protocol revision it negotiated.
+
+use std::sync::Arc;
+
+use serde_json::{json, Value};
+
+use kami_protocol::mcp::methods;
+use kami_protocol::{JsonRpcRequest, RequestId};
+use kami_registry::ToolRepository;
+use kami_runtime::{KamiRuntime, RuntimeConfig};
+use kami_store_sqlite::SqliteToolRepository;
+use kami_transport_stdio::{McpHandler, SessionState};
+use kami_types::{SecurityConfig, Tool, ToolId, ToolManifest, ToolVersion};
+
+async fn handler() -> McpHandler {
+    let repo = Arc::new(SqliteToolRepository::open_in_memory().expect("db"));
+    let tool = Tool {
+        manifest: ToolManifest {
+            id: ToolId::new("dev.test.echo").expect("id"),
+            name: "Echo".into(),
+            version: ToolVersion::new(1, 0, 0),
+            wasm: "echo.wasm".into(),
+            description: "Echo".into(),
+            arguments: vec![],
+            output: vec![],
+            security: SecurityConfig::default(),
+            wasm_sha256: None,
+            signature: None,
+            signer_public_key: None,
+        },
+        install_path: "/echo".into(),
+        enabled: true,
+        pinned_version: None,
+        updated_at: None,
+    };
+    repo.insert(&tool).await.expect("insert");
+    let runtime = KamiRuntime::new(RuntimeConfig::default(), repo.clone()).expect("runtime");
+    McpHandler::new(Arc::new(runtime), repo)
+}
+
+async fn call(handler: &McpHandler, session: &SessionState, method: &str, params: Value) -> Value {
+    let request = JsonRpcRequest {
+        jsonrpc: "2.0".into(),
+        id: RequestId::Number(1),
+        method: method.into(),
+        params: Some(params),
+    };
+    let output = handler.dispatch(&request, session).await;
+    serde_json::from_str(&output.expect("response").to_json().expect("ser")).expect("de")
+}
+
+async fn initialize(handler: &McpHandler, session: &SessionState, version: &str) {
+    let params = json!({
+        "protocolVersion": version,
+        "capabilities": {},
+        "clientInfo": {"name": "client", "version": "1.0"},
+    });
+    let answer = call(handler, session, methods::INITIALIZE, params).await;
+    assert_eq!(answer["result"]["protocolVersion"], version);
+}
+
+#[tokio::test]
+async fn two_clients_keep_their_own_revision() {
+    let handler = handler().await;
+    let (old, new) = (SessionState::default(), SessionState::default());
+    initialize(&handler, &old, "2024-11-05").await;
+    initialize(&handler, &new, "2025-06-18").await;
+
+    let listed = call(&handler, &old, methods::TOOLS_LIST, json!({})).await;
+    let tool = &listed["result"]["tools"][0];
+    assert!(tool.get("title").is_none() && tool.get("annotations").is_none());
+
+    let listed = call(&handler, &new, methods::TOOLS_LIST, json!({})).await;
+    assert_eq!(listed["result"]["tools"][0]["title"], "Echo");
+    assert_eq!(old.get().protocol.as_str(), "2024-11-05");
+}
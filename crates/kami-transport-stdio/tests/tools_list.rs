@@ -9,7 +9,7 @@ use kami_protocol::{JsonRpcRequest, RequestId};
 use kami_registry::ToolRepository;
 use kami_runtime::{KamiRuntime, RuntimeConfig};
 use kami_store_sqlite::SqliteToolRepository;
-use kami_transport_stdio::McpHandler;
+use kami_transport_stdio::{McpHandler, SessionState};
 use kami_types::{SecurityConfig, Tool, ToolArgument, ToolId, ToolManifest, ToolVersion};
 
 fn rpc(method: &str, id: i64, params: Option<Value>) -> JsonRpcRequest {
@@ -37,8 +37,8 @@ async fn tools_list_empty_registry() {
     let repo = Arc::new(SqliteToolRepository::open_in_memory().expect("db"));
     let handler = make_handler_with_repo(repo);
     let req = rpc(methods::TOOLS_LIST, 1, None);
-    let output = handler.dispatch(&req).await;
-    let json_str = output.to_json().expect("ser");
+    let output = handler.dispatch(&req, &SessionState::default()).await;
+    let json_str = output.expect("response").to_json().expect("ser");
     let parsed: Value = serde_json::from_str(&json_str).expect("de");
     let tools = parsed["result"]["tools"].as_array().expect("arr");
     assert!(tools.is_empty());
@@ -59,8 +59,9 @@ async fn tools_list_with_registered_tool() {
                 arg_type: "string".into(),
                 description: "message".into(),
                 required: true,
-                default: None,
+                ..ToolArgument::default()
             }],
+            output: vec![],
             security: SecurityConfig::default(),
             wasm_sha256: None,
             signature: None,
@@ -74,8 +75,8 @@ async fn tools_list_with_registered_tool() {
     repo.insert(&tool).await.expect("insert");
     let handler = make_handler_with_repo(repo);
     let req = rpc(methods::TOOLS_LIST, 2, None);
-    let output = handler.dispatch(&req).await;
-    let json_str = output.to_json().expect("ser");
+    let output = handler.dispatch(&req, &SessionState::default()).await;
+    let json_str = output.expect("response").to_json().expect("ser");
     let parsed: Value = serde_json::from_str(&json_str).expect("de");
     let tools = parsed["result"]["tools"].as_array().expect("arr");
     assert_eq!(tools.len(), 1);
@@ -94,6 +95,7 @@ async fn tools_list_skips_disabled_tools() {
             wasm: "off.wasm".into(),
             description: "disabled".into(),
             arguments: vec![],
+            output: vec![],
             security: SecurityConfig::default(),
             wasm_sha256: None,
             signature: None,
@@ -107,8 +109,8 @@ async fn tools_list_skips_disabled_tools() {
     repo.insert(&tool).await.expect("insert");
     let handler = make_handler_with_repo(repo);
     let req = rpc(methods::TOOLS_LIST, 3, None);
-    let output = handler.dispatch(&req).await;
-    let json_str = output.to_json().expect("ser");
+    let output = handler.dispatch(&req, &SessionState::default()).await;
+    let json_str = output.expect("response").to_json().expect("ser");
     let parsed: Value = serde_json::from_str(&json_str).expect("de");
     let tools = parsed["result"]["tools"].as_array().expect("arr");
     assert!(tools.is_empty());
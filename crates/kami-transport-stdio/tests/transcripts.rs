@@ -0,0 +1,108 @@
+//! MCP sessions replayed from captured transcripts, one per protocol
+//! revision.
+//!
+//! A transcript lists what the client sent (`-> `) and what the server
+//! answered (`<- `), one JSON message per line; `#` starts a comment.
+//! `$VERSION` stands for the server version.
+
+use std::sync::Arc;
+
+use serde_json::Value;
+
+use kami_registry::ToolRepository;
+use kami_runtime::{KamiRuntime, RuntimeConfig};
+use kami_store_sqlite::SqliteToolRepository;
+use kami_transport_stdio::{McpHandler, McpServer, StdioTransport};
+use kami_types::{SecurityConfig, Tool, ToolArgument, ToolId, ToolManifest, ToolVersion};
+
+/// A `kami-tool-v2` component whose `run-v2` returns the text `{"n":1}`,
+/// an `audio/wav` blob and a link to `file:///c.svg`.
+const COUNTER_WAT: &str = include_str!("transcripts/counter.wat");
+
+/// Serves the counter as `dev.test.counter`, declaring an integer `n`
+/// output field.
+async fn counter_handler(dir: &tempfile::TempDir) -> McpHandler {
+    std::fs::write(dir.path().join("counter.wasm"), COUNTER_WAT).expect("write wasm");
+    let repo = Arc::new(SqliteToolRepository::open_in_memory().expect("db"));
+    let tool = Tool {
+        manifest: ToolManifest {
+            id: ToolId::new("dev.test.counter").expect("id"),
+            name: "Counter".to_string(),
+            version: ToolVersion::new(1, 0, 0),
+            wasm: "counter.wasm".to_string(),
+            description: "Counts things".to_string(),
+            arguments: vec![],
+            output: vec![ToolArgument {
+                name: "n".to_string(),
+                arg_type: "integer".to_string(),
+                required: true,
+                ..ToolArgument::default()
+            }],
+            security: SecurityConfig::default(),
+            wasm_sha256: None,
+            signature: None,
+            signer_public_key: None,
+        },
+        install_path: dir.path().display().to_string(),
+        enabled: true,
+        pinned_version: None,
+        updated_at: None,
+    };
+    repo.insert(&tool).await.expect("insert");
+    let runtime = KamiRuntime::new(RuntimeConfig::default(), repo.clone()).expect("runtime");
+    McpHandler::new(Arc::new(runtime), repo)
+}
+
+/// Sends the client side of `transcript` and checks the server answers
+/// with exactly its server side.
+async fn replay(transcript: &str) {
+    let mut input = String::new();
+    let mut expected = Vec::new();
+    for line in transcript.lines() {
+        if let Some(sent) = line.strip_prefix("-> ") {
+            input.push_str(sent);
+            input.push('\n');
+        } else if let Some(answer) = line.strip_prefix("<- ") {
+            let answer = answer.replace("$VERSION", env!("CARGO_PKG_VERSION"));
+            expected.push(serde_json::from_str::<Value>(&answer).expect("transcript json"));
+        }
+    }
+
+    let dir = tempfile::tempdir().expect("tempdir");
+    let mut output = Vec::new();
+    let transport = StdioTransport::new(input.as_bytes(), &mut output);
+    McpServer::new(transport, counter_handler(&dir).await)
+        .run()
+        .await
+        .expect("run");
+    let actual: Vec<Value> = String::from_utf8(output)
+        .expect("utf8")
+        .lines()
+        .map(|l| serde_json::from_str(l).expect("json line"))
+        .collect();
+
+    assert_eq!(actual.len(), expected.len(), "answers: {actual:#?}");
+    for (actual, expected) in actual.iter().zip(&expected) {
+        assert_eq!(actual, expected);
+    }
+}
+
+#[tokio::test]
+async fn session_2024_11_05() {
+    replay(include_str!("transcripts/2024-11-05.txt")).await;
+}
+
+#[tokio::test]
+async fn session_2025_03_26() {
+    replay(include_str!("transcripts/2025-03-26.txt")).await;
+}
+
+#[tokio::test]
+async fn session_2025_06_18() {
+    replay(include_str!("transcripts/2025-06-18.txt")).await;
+}
+
+#[tokio::test]
+async fn unknown_revision_falls_back_to_latest() {
+    replay(include_str!("transcripts/unknown-revision.txt")).await;
+}
@@ -0,0 +1,45 @@
+(component
+  (core module $m
+    (memory (export "memory") 1)
+    (data (i32.const 512) "{\"n\":1}")
+    (data (i32.const 528) "audio/wav")
+    (data (i32.const 544) "UklG")
+    (data (i32.const 560) "file:///c.svg")
+    (data (i32.const 576) "chart")
+    (data (i32.const 1024) "\00\00\00\00\00\02\00\00\07\00\00\00")
+    (data (i32.const 1068) "\01\00\00\00\10\02\00\00\09\00\00\00\20\02\00\00\04\00\00\00")
+    (data (i32.const 1112) "\03\00\00\00\30\02\00\00\0d\00\00\00\40\02\00\00\05\00\00\00")
+    (data (i32.const 4096) "\00\00\00\00\00\04\00\00\03\00\00\00")
+    (data (i32.const 4112) "\40\02\00\00\05\00\00\00")
+    (func (export "cabi_realloc") (param i32 i32 i32 i32) (result i32) i32.const 8192)
+    (func (export "run") (param i32 i32) (result i32) i32.const 4096)
+    (func (export "describe") (result i32) i32.const 4112)
+    (func (export "run-v2") (param i32 i32) (result i32) i32.const 4096)
+  )
+  (core instance $i (instantiate $m))
+
+  (type $blob (record (field "mime-type" string) (field "data" string)))
+  (type $contents (variant (case "text" string) (case "blob" string)))
+  (type $embedded (record
+    (field "uri" string) (field "mime-type" (option string)) (field "contents" $contents)))
+  (type $link (record
+    (field "uri" string) (field "name" string)
+    (field "description" (option string)) (field "mime-type" (option string))))
+  (type $part (variant
+    (case "text" string) (case "blob" $blob) (case "embedded" $embedded) (case "link" $link)))
+
+  (func $run (param "input" string) (result (result string (error string)))
+    (canon lift (core func $i "run") (memory $i "memory") (realloc (func $i "cabi_realloc"))))
+  (func $describe (result string)
+    (canon lift (core func $i "describe") (memory $i "memory")))
+  (func $run-v2 (param "input" string) (result (result (list $part) (error string)))
+    (canon lift (core func $i "run-v2") (memory $i "memory") (realloc (func $i "cabi_realloc"))))
+
+  (instance $tool (export "run" (func $run)) (export "describe" (func $describe)))
+  (instance $content
+    (export "blob" (type $blob)) (export "resource-contents" (type $contents))
+    (export "embedded" (type $embedded)) (export "link" (type $link))
+    (export "content-part" (type $part)) (export "run-v2" (func $run-v2)))
+  (export "kami:tool/tool@0.1.0" (instance $tool))
+  (export "kami:tool/content@0.1.0" (instance $content))
+)
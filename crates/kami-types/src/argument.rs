@@ -0,0 +1,69 @@
+//! MCP argument definitions declared in `[[mcp.arguments]]`.
+//!
+//! Besides a name, type and description, an argument may constrain its
+//! value the way JSON Schema does: allowed values, bounds, a pattern, the
+//! schema of array items or the properties of a nested object. Every
+//! constraint is optional, so definitions stored before they existed still
+//! deserialize.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Number, Value};
+
+/// MCP argument definition for a tool.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ToolArgument {
+    /// Argument name.
+    pub name: String,
+    /// JSON Schema type (string, number, boolean, etc.).
+    #[serde(rename = "type")]
+    pub arg_type: String,
+    /// Human-readable description.
+    #[serde(default)]
+    pub description: String,
+    /// Whether this argument is required.
+    #[serde(default)]
+    pub required: bool,
+    /// Default value if not required, of the argument's type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<Value>,
+    /// Constraints on the value.
+    #[serde(flatten)]
+    pub constraints: ArgumentConstraints,
+}
+
+/// Schema of the items of an array argument.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ArgumentItems {
+    /// JSON Schema type of each item.
+    #[serde(rename = "type")]
+    pub item_type: String,
+    /// Constraints on each item.
+    #[serde(flatten)]
+    pub constraints: ArgumentConstraints,
+}
+
+/// JSON Schema constraints on an argument or array item value.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ArgumentConstraints {
+    /// The only values allowed (`enum`).
+    #[serde(rename = "enum", default, skip_serializing_if = "Option::is_none")]
+    pub allowed: Option<Vec<Value>>,
+    /// Smallest allowed number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub minimum: Option<Number>,
+    /// Largest allowed number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maximum: Option<Number>,
+    /// Regular expression a string must match.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+    /// Format hint for strings, e.g. `uri` or `date-time`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    /// Schema of the items of an array.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub items: Option<Box<ArgumentItems>>,
+    /// Properties of a nested object.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub properties: Vec<ToolArgument>,
+}
@@ -2,8 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
-/// Filesystem access level.
-#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+/// Filesystem access level, ordered from narrowest to widest.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum FsAccess {
     /// No filesystem access.
@@ -27,6 +27,19 @@ pub struct ResourceLimits {
     /// Maximum fuel (instruction budget).
     #[serde(default = "default_max_fuel")]
     pub max_fuel: u64,
+    /// Maximum bytes of stdout and of stderr captured per execution.
+    #[serde(default = "default_max_log_bytes")]
+    pub max_log_bytes: u64,
+    /// Maximum bytes (keys plus values) kept in the tool's key/value store.
+    #[serde(default = "default_max_kv_bytes")]
+    pub max_kv_bytes: u64,
+    /// Maximum outgoing HTTP requests per execution.
+    #[serde(default = "default_max_http_requests")]
+    pub max_http_requests: u32,
+    /// Maximum bytes sent over HTTP (request lines, headers and bodies)
+    /// per execution.
+    #[serde(default = "default_max_egress_bytes")]
+    pub max_egress_bytes: u64,
 }
 
 fn default_max_memory_mb() -> u32 {
@@ -38,6 +51,18 @@ fn default_max_execution_ms() -> u64 {
 fn default_max_fuel() -> u64 {
     1_000_000
 }
+fn default_max_log_bytes() -> u64 {
+    64 * 1024
+}
+fn default_max_kv_bytes() -> u64 {
+    1024 * 1024
+}
+fn default_max_http_requests() -> u32 {
+    100
+}
+fn default_max_egress_bytes() -> u64 {
+    10 * 1024 * 1024
+}
 
 impl Default for ResourceLimits {
     fn default() -> Self {
@@ -45,6 +70,10 @@ impl Default for ResourceLimits {
             max_memory_mb: default_max_memory_mb(),
             max_execution_ms: default_max_execution_ms(),
             max_fuel: default_max_fuel(),
+            max_log_bytes: default_max_log_bytes(),
+            max_kv_bytes: default_max_kv_bytes(),
+            max_http_requests: default_max_http_requests(),
+            max_egress_bytes: default_max_egress_bytes(),
         }
     }
 }
@@ -55,12 +84,25 @@ pub struct SecurityConfig {
     /// Allowed network destinations (glob patterns).
     #[serde(default)]
     pub net_allow_list: Vec<String>,
+    /// Allow connections to private, loopback and other non-public
+    /// addresses (refused by default, even for allowed hosts).
+    #[serde(default)]
+    pub allow_private_networks: bool,
     /// Filesystem access level.
     #[serde(default)]
     pub fs_access: FsAccess,
     /// Allowed environment variable names (exact match, deny-all by default).
     #[serde(default)]
     pub env_allow_list: Vec<String>,
+    /// Whether the tool may use the host key/value store (deny by default).
+    #[serde(default)]
+    pub key_value: bool,
+    /// Names of the secrets the tool may read (deny-all by default).
+    #[serde(default)]
+    pub secrets: Vec<String>,
+    /// Also inject the declared secrets as environment variables.
+    #[serde(default)]
+    pub secrets_as_env: bool,
     /// Resource limits.
     #[serde(flatten)]
     pub limits: ResourceLimits,
@@ -70,8 +112,12 @@ impl Default for SecurityConfig {
     fn default() -> Self {
         Self {
             net_allow_list: Vec::new(),
+            allow_private_networks: false,
             fs_access: FsAccess::None,
             env_allow_list: Vec::new(),
+            key_value: false,
+            secrets: Vec::new(),
+            secrets_as_env: false,
             limits: ResourceLimits::default(),
         }
     }
@@ -89,32 +135,12 @@ pub enum Capability {
     FsWrite(String),
     /// Environment variable access.
     EnvVar(String),
+    /// Access to the tool's own key/value store.
+    KeyValue,
+    /// Read access to a named secret.
+    Secret(String),
 }
 
 #[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn default_security_is_deny_all() {
-        let config = SecurityConfig::default();
-        assert!(config.net_allow_list.is_empty());
-        assert!(config.env_allow_list.is_empty());
-        assert_eq!(config.fs_access, FsAccess::None);
-    }
-
-    #[test]
-    fn resource_limits_defaults() {
-        let limits = ResourceLimits::default();
-        assert_eq!(limits.max_memory_mb, 64);
-        assert_eq!(limits.max_execution_ms, 5000);
-        assert_eq!(limits.max_fuel, 1_000_000);
-    }
-
-    #[test]
-    fn fs_access_serialization() {
-        let access = FsAccess::ReadOnly;
-        let json = serde_json::to_string(&access).unwrap();
-        assert_eq!(json, "\"read-only\"");
-    }
-}
+#[path = "capability_tests.rs"]
+mod tests;
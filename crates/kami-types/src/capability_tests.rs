@@ -0,0 +1,41 @@
+//! Tests for capability-based security types.
+
+use super::*;
+
+#[test]
+fn default_security_is_deny_all() {
+    let config = SecurityConfig::default();
+    assert!(config.net_allow_list.is_empty());
+    assert!(config.env_allow_list.is_empty());
+    assert!(!config.key_value);
+    assert!(config.secrets.is_empty());
+    assert!(!config.secrets_as_env);
+    assert_eq!(config.fs_access, FsAccess::None);
+}
+
+#[test]
+fn resource_limits_defaults() {
+    let limits = ResourceLimits::default();
+    assert_eq!(limits.max_memory_mb, 64);
+    assert_eq!(limits.max_execution_ms, 5000);
+    assert_eq!(limits.max_fuel, 1_000_000);
+    assert_eq!(limits.max_log_bytes, 64 * 1024);
+    assert_eq!(limits.max_kv_bytes, 1024 * 1024);
+    assert_eq!(limits.max_http_requests, 100);
+    assert_eq!(limits.max_egress_bytes, 10 * 1024 * 1024);
+}
+
+#[test]
+fn fs_access_serialization() {
+    let access = FsAccess::ReadOnly;
+    let json = serde_json::to_string(&access).unwrap();
+    assert_eq!(json, "\"read-only\"");
+}
+
+#[test]
+fn secrets_deserialize_from_security_section() {
+    let json = r#"{"secrets": ["API_KEY"], "secrets_as_env": true}"#;
+    let config: SecurityConfig = serde_json::from_str(json).expect("parse");
+    assert_eq!(config.secrets, vec!["API_KEY".to_string()]);
+    assert!(config.secrets_as_env);
+}
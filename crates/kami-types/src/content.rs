@@ -0,0 +1,81 @@
+//! Parts of a tool result.
+//!
+//! Tools exporting only `run` return a single text part. Tools exporting
+//! `run-v2` may mix text with base64 media and resources, mirroring the
+//! content types of MCP.
+
+use serde::{Deserialize, Serialize};
+
+/// One part of a tool result.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ContentPart {
+    /// Plain text.
+    Text { text: String },
+    /// Base64-encoded image.
+    Image { data: String, mime_type: String },
+    /// Base64-encoded audio.
+    Audio { data: String, mime_type: String },
+    /// A resource returned with its contents.
+    Resource {
+        uri: String,
+        mime_type: Option<String>,
+        contents: ResourceContents,
+    },
+    /// A link to a resource the client can read itself.
+    ResourceLink {
+        uri: String,
+        name: String,
+        description: Option<String>,
+        mime_type: Option<String>,
+    },
+}
+
+/// Contents of an embedded resource.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResourceContents {
+    /// UTF-8 text.
+    Text(String),
+    /// Base64-encoded binary data.
+    Blob(String),
+}
+
+impl ContentPart {
+    /// Creates a text part.
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::Text { text: text.into() }
+    }
+
+    /// Returns the text of a text part.
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Self::Text { text } => Some(text),
+            _ => None,
+        }
+    }
+}
+
+/// Joins the text parts of a result, one per line.
+pub fn joined_text(parts: &[ContentPart]) -> String {
+    let texts: Vec<&str> = parts.iter().filter_map(ContentPart::as_text).collect();
+    texts.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn joined_text_skips_other_parts() {
+        let parts = [
+            ContentPart::text("a"),
+            ContentPart::Image {
+                data: "AAAA".into(),
+                mime_type: "image/png".into(),
+            },
+            ContentPart::text("b"),
+        ];
+        assert_eq!(joined_text(&parts), "a\nb");
+    }
+}
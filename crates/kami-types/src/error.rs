@@ -15,6 +15,8 @@ pub enum ErrorKind {
     InvalidInput,
     /// Operation timed out.
     Timeout,
+    /// Operation cancelled by the caller.
+    Cancelled,
     /// Resource limit exceeded (memory, fuel, etc.).
     ResourceExhausted,
     /// Internal error.
@@ -31,6 +31,28 @@ pub enum DomainEvent {
         tool_id: ToolId,
         timestamp: SystemTime,
     },
+    /// A secret was read, written or removed. Never carries the value.
+    SecretAccessed {
+        /// The tool reading it; `None` for operator access (e.g. the CLI).
+        tool_id: Option<ToolId>,
+        name: String,
+        access: SecretAccess,
+        timestamp: SystemTime,
+    },
+}
+
+/// Kind of access recorded by [`DomainEvent::SecretAccessed`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SecretAccess {
+    /// The value was read, or injected into a tool.
+    Read,
+    /// The value was created or replaced.
+    Write,
+    /// The secret was deleted.
+    Remove,
+    /// A tool asked for a secret it did not declare.
+    Denied,
 }
 
 impl DomainEvent {
@@ -59,65 +81,18 @@ impl DomainEvent {
             timestamp: SystemTime::now(),
         }
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn tool_installed_event() {
-        let id = ToolId::new("dev.test.tool").unwrap();
-        let event = DomainEvent::tool_installed(id);
-        match &event {
-            DomainEvent::ToolInstalled { tool_id, .. } => {
-                assert_eq!(tool_id.as_str(), "dev.test.tool");
-            }
-            _ => panic!("unexpected event variant"),
-        }
-    }
 
-    #[test]
-    fn execution_started_event() {
-        let id = ToolId::new("dev.test.run").unwrap();
-        let event = DomainEvent::execution_started(id);
-        match &event {
-            DomainEvent::ExecutionStarted { tool_id, .. } => {
-                assert_eq!(tool_id.as_str(), "dev.test.run");
-            }
-            _ => panic!("unexpected event variant"),
-        }
-    }
-
-    #[test]
-    fn execution_completed_event() {
-        let id = ToolId::new("dev.test.done").unwrap();
-        let event = DomainEvent::execution_completed(id, 42, true);
-        match &event {
-            DomainEvent::ExecutionCompleted {
-                duration_ms,
-                success,
-                ..
-            } => {
-                assert_eq!(*duration_ms, 42);
-                assert!(*success);
-            }
-            _ => panic!("unexpected event variant"),
-        }
-    }
-
-    #[test]
-    fn event_serialization_roundtrip() {
-        let id = ToolId::new("dev.test.serde").unwrap();
-        let event = DomainEvent::tool_installed(id);
-        let json = serde_json::to_string(&event).expect("serialize");
-        assert!(json.contains("tool_installed"));
-        let back: DomainEvent = serde_json::from_str(&json).expect("deserialize");
-        match back {
-            DomainEvent::ToolInstalled { tool_id, .. } => {
-                assert_eq!(tool_id.as_str(), "dev.test.serde");
-            }
-            _ => panic!("unexpected variant after roundtrip"),
+    /// Creates a secret-accessed event.
+    pub fn secret_accessed(tool_id: Option<ToolId>, name: &str, access: SecretAccess) -> Self {
+        Self::SecretAccessed {
+            tool_id,
+            name: name.to_string(),
+            access,
+            timestamp: SystemTime::now(),
         }
     }
 }
+
+#[cfg(test)]
+#[path = "event_tests.rs"]
+mod tests;
@@ -0,0 +1,79 @@
+//! Tests for domain events.
+
+use super::*;
+
+#[test]
+fn tool_installed_event() {
+    let id = ToolId::new("dev.test.tool").unwrap();
+    let event = DomainEvent::tool_installed(id);
+    match &event {
+        DomainEvent::ToolInstalled { tool_id, .. } => {
+            assert_eq!(tool_id.as_str(), "dev.test.tool");
+        }
+        _ => panic!("unexpected event variant"),
+    }
+}
+
+#[test]
+fn execution_started_event() {
+    let id = ToolId::new("dev.test.run").unwrap();
+    let event = DomainEvent::execution_started(id);
+    match &event {
+        DomainEvent::ExecutionStarted { tool_id, .. } => {
+            assert_eq!(tool_id.as_str(), "dev.test.run");
+        }
+        _ => panic!("unexpected event variant"),
+    }
+}
+
+#[test]
+fn execution_completed_event() {
+    let id = ToolId::new("dev.test.done").unwrap();
+    let event = DomainEvent::execution_completed(id, 42, true);
+    match &event {
+        DomainEvent::ExecutionCompleted {
+            duration_ms,
+            success,
+            ..
+        } => {
+            assert_eq!(*duration_ms, 42);
+            assert!(*success);
+        }
+        _ => panic!("unexpected event variant"),
+    }
+}
+
+#[test]
+fn event_serialization_roundtrip() {
+    let id = ToolId::new("dev.test.serde").unwrap();
+    let event = DomainEvent::tool_installed(id);
+    let json = serde_json::to_string(&event).expect("serialize");
+    assert!(json.contains("tool_installed"));
+    let back: DomainEvent = serde_json::from_str(&json).expect("deserialize");
+    match back {
+        DomainEvent::ToolInstalled { tool_id, .. } => {
+            assert_eq!(tool_id.as_str(), "dev.test.serde");
+        }
+        _ => panic!("unexpected variant after roundtrip"),
+    }
+}
+
+#[test]
+fn secret_accessed_event_serializes_without_value() {
+    let id = ToolId::new("dev.test.secret").unwrap();
+    let event = DomainEvent::secret_accessed(Some(id), "API_KEY", SecretAccess::Read);
+    let json = serde_json::to_string(&event).expect("serialize");
+    assert!(json.contains(r#""type":"secret_accessed""#));
+    assert!(json.contains(r#""access":"read""#));
+    let back: DomainEvent = serde_json::from_str(&json).expect("deserialize");
+    match back {
+        DomainEvent::SecretAccessed { tool_id, name, .. } => {
+            assert_eq!(
+                tool_id.as_ref().map(ToolId::as_str),
+                Some("dev.test.secret")
+            );
+            assert_eq!(name, "API_KEY");
+        }
+        _ => panic!("unexpected variant after roundtrip"),
+    }
+}
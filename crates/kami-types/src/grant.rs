@@ -0,0 +1,143 @@
+//! Capability grants — what an operator approved for a tool.
+//!
+//! `kami install` and `kami update` record the security config they were
+//! allowed to give a tool. [`capability_diff`] lists what a manifest asks
+//! for beyond that grant: each entry needs the operator's consent.
+
+use std::fmt;
+
+use crate::capability::{FsAccess, ResourceLimits, SecurityConfig};
+
+/// Something a manifest requests beyond a grant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CapabilityChange {
+    /// A `net_allow_list` entry that was not granted.
+    Network(String),
+    /// A granted `!` rule the manifest no longer has.
+    DroppedDeny(String),
+    /// `allow_private_networks`.
+    PrivateNetworks,
+    /// Wider filesystem access.
+    Filesystem {
+        granted: FsAccess,
+        requested: FsAccess,
+    },
+    /// An environment variable that was not granted.
+    EnvVar(String),
+    /// The host key/value store.
+    KeyValue,
+    /// A secret that was not granted.
+    Secret(String),
+    /// Secrets injected as environment variables.
+    SecretsAsEnv,
+    /// A resource limit above the granted one.
+    Limit {
+        name: &'static str,
+        granted: u64,
+        requested: u64,
+    },
+}
+
+impl fmt::Display for CapabilityChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Network(entry) => write!(f, "network: {entry}"),
+            Self::DroppedDeny(entry) => write!(f, "network: drops deny rule {entry}"),
+            Self::PrivateNetworks => write!(f, "network: private addresses"),
+            Self::Filesystem { granted, requested } => {
+                write!(f, "filesystem: {granted:?} -> {requested:?}")
+            }
+            Self::EnvVar(name) => write!(f, "env: {name}"),
+            Self::KeyValue => write!(f, "key/value store"),
+            Self::Secret(name) => write!(f, "secret: {name}"),
+            Self::SecretsAsEnv => write!(f, "secrets as env vars"),
+            Self::Limit {
+                name,
+                granted,
+                requested,
+            } => write!(f, "{name}: {granted} -> {requested}"),
+        }
+    }
+}
+
+/// Lists what `requested` asks for beyond `granted`, in manifest order.
+///
+/// An empty list means `requested` is covered by the grant.
+pub fn capability_diff(
+    granted: &SecurityConfig,
+    requested: &SecurityConfig,
+) -> Vec<CapabilityChange> {
+    let (allows, denies): (Vec<String>, Vec<String>) = requested
+        .net_allow_list
+        .iter()
+        .cloned()
+        .partition(|entry| !entry.starts_with('!'));
+    let granted_denies: Vec<String> = granted
+        .net_allow_list
+        .iter()
+        .filter(|entry| entry.starts_with('!'))
+        .cloned()
+        .collect();
+    let mut changes: Vec<_> = missing(&allows, &granted.net_allow_list)
+        .map(CapabilityChange::Network)
+        .collect();
+    changes.extend(missing(&granted_denies, &denies).map(CapabilityChange::DroppedDeny));
+    if requested.allow_private_networks && !granted.allow_private_networks {
+        changes.push(CapabilityChange::PrivateNetworks);
+    }
+    if requested.fs_access > granted.fs_access {
+        changes.push(CapabilityChange::Filesystem {
+            granted: granted.fs_access.clone(),
+            requested: requested.fs_access.clone(),
+        });
+    }
+    let env = missing(&requested.env_allow_list, &granted.env_allow_list);
+    changes.extend(env.map(CapabilityChange::EnvVar));
+    if requested.key_value && !granted.key_value {
+        changes.push(CapabilityChange::KeyValue);
+    }
+    let secrets = missing(&requested.secrets, &granted.secrets);
+    changes.extend(secrets.map(CapabilityChange::Secret));
+    if requested.secrets_as_env && !granted.secrets_as_env {
+        changes.push(CapabilityChange::SecretsAsEnv);
+    }
+    changes.extend(limit_changes(&granted.limits, &requested.limits));
+    changes
+}
+
+/// Entries of `requested` that are not in `granted`.
+fn missing<'a>(
+    requested: &'a [String],
+    granted: &'a [String],
+) -> impl Iterator<Item = String> + 'a {
+    requested.iter().filter(|e| !granted.contains(e)).cloned()
+}
+
+/// A named resource limit and how to read it.
+type Limit = (&'static str, fn(&ResourceLimits) -> u64);
+
+/// Limits of `requested` above those of `granted`.
+fn limit_changes(granted: &ResourceLimits, requested: &ResourceLimits) -> Vec<CapabilityChange> {
+    let limits: [Limit; 7] = [
+        ("max_memory_mb", |l| l.max_memory_mb.into()),
+        ("max_execution_ms", |l| l.max_execution_ms),
+        ("max_fuel", |l| l.max_fuel),
+        ("max_log_bytes", |l| l.max_log_bytes),
+        ("max_kv_bytes", |l| l.max_kv_bytes),
+        ("max_http_requests", |l| l.max_http_requests.into()),
+        ("max_egress_bytes", |l| l.max_egress_bytes),
+    ];
+    limits
+        .into_iter()
+        .filter(|(_, get)| get(requested) > get(granted))
+        .map(|(name, get)| CapabilityChange::Limit {
+            name,
+            granted: get(granted),
+            requested: get(requested),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "grant_tests.rs"]
+mod tests;
@@ -0,0 +1,92 @@
+//! Tests for capability grants.
+
+use super::*;
+
+fn config(net: &[&str]) -> SecurityConfig {
+    SecurityConfig {
+        net_allow_list: net.iter().map(|s| s.to_string()).collect(),
+        ..SecurityConfig::default()
+    }
+}
+
+#[test]
+fn identical_config_needs_no_consent() {
+    let granted = config(&["api.example.com", "!10.0.0.0/8"]);
+    assert!(capability_diff(&granted, &granted).is_empty());
+}
+
+#[test]
+fn narrower_request_needs_no_consent() {
+    let granted = SecurityConfig {
+        fs_access: FsAccess::Sandbox,
+        key_value: true,
+        ..config(&["a.example.com", "b.example.com"])
+    };
+    let requested = config(&["a.example.com"]);
+    assert!(capability_diff(&granted, &requested).is_empty());
+}
+
+#[test]
+fn new_hosts_and_dropped_denies_are_listed() {
+    let granted = config(&["a.example.com", "!10.0.0.0/8"]);
+    let requested = config(&["a.example.com", "b.example.com"]);
+
+    let changes = capability_diff(&granted, &requested);
+
+    assert_eq!(
+        changes,
+        [
+            CapabilityChange::Network("b.example.com".into()),
+            CapabilityChange::DroppedDeny("!10.0.0.0/8".into()),
+        ]
+    );
+}
+
+#[test]
+fn wider_access_and_new_grants_are_listed() {
+    let requested = SecurityConfig {
+        allow_private_networks: true,
+        fs_access: FsAccess::ReadOnly,
+        env_allow_list: vec!["HOME".into()],
+        key_value: true,
+        secrets: vec!["TOKEN".into()],
+        secrets_as_env: true,
+        ..SecurityConfig::default()
+    };
+
+    let changes = capability_diff(&SecurityConfig::default(), &requested);
+
+    assert_eq!(
+        changes,
+        [
+            CapabilityChange::PrivateNetworks,
+            CapabilityChange::Filesystem {
+                granted: FsAccess::None,
+                requested: FsAccess::ReadOnly,
+            },
+            CapabilityChange::EnvVar("HOME".into()),
+            CapabilityChange::KeyValue,
+            CapabilityChange::Secret("TOKEN".into()),
+            CapabilityChange::SecretsAsEnv,
+        ]
+    );
+}
+
+#[test]
+fn higher_limits_are_listed() {
+    let mut requested = SecurityConfig::default();
+    requested.limits.max_memory_mb = 128;
+    requested.limits.max_execution_ms = 1;
+
+    let changes = capability_diff(&SecurityConfig::default(), &requested);
+
+    assert_eq!(
+        changes,
+        [CapabilityChange::Limit {
+            name: "max_memory_mb",
+            granted: 64,
+            requested: 128,
+        }]
+    );
+    assert_eq!(changes[0].to_string(), "max_memory_mb: 64 -> 128");
+}
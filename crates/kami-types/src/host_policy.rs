@@ -0,0 +1,113 @@
+//! Host-wide policy an operator applies on top of every tool manifest.
+//!
+//! A manifest says what a tool asks for; the host policy says what this
+//! host is willing to give any tool. It can only narrow a manifest.
+
+use serde::{Deserialize, Serialize};
+
+use crate::capability::{FsAccess, ResourceLimits, SecurityConfig};
+
+/// Ceilings on resource limits; `None` keeps the manifest's value.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LimitCaps {
+    /// Maximum memory in megabytes.
+    pub max_memory_mb: Option<u32>,
+    /// Maximum execution time in milliseconds.
+    pub max_execution_ms: Option<u64>,
+    /// Maximum fuel (instruction budget).
+    pub max_fuel: Option<u64>,
+    /// Maximum outgoing HTTP requests per execution.
+    pub max_http_requests: Option<u32>,
+    /// Maximum bytes sent over HTTP per execution.
+    pub max_egress_bytes: Option<u64>,
+    /// Maximum bytes of guest log output kept per execution.
+    pub max_log_bytes: Option<u64>,
+    /// Maximum bytes a tool may hold in its key-value store.
+    pub max_kv_bytes: Option<u64>,
+}
+
+impl LimitCaps {
+    /// Lowers every limit above its ceiling.
+    pub fn apply(&self, limits: &mut ResourceLimits) {
+        cap(&mut limits.max_memory_mb, self.max_memory_mb);
+        cap(&mut limits.max_execution_ms, self.max_execution_ms);
+        cap(&mut limits.max_fuel, self.max_fuel);
+        cap(&mut limits.max_http_requests, self.max_http_requests);
+        cap(&mut limits.max_egress_bytes, self.max_egress_bytes);
+        cap(&mut limits.max_log_bytes, self.max_log_bytes);
+        cap(&mut limits.max_kv_bytes, self.max_kv_bytes);
+    }
+}
+
+fn cap<T: Ord + Copy>(value: &mut T, ceiling: Option<T>) {
+    if let Some(ceiling) = ceiling {
+        *value = (*value).min(ceiling);
+    }
+}
+
+/// Restrictions applied to every tool, whatever its manifest requests.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HostPolicy {
+    /// Destinations no tool may reach, in `net_allow_list` syntax
+    /// (`*.internal`, `10.0.0.0/8`, `metadata.google.internal`).
+    #[serde(default)]
+    pub deny_hosts: Vec<String>,
+    /// Refuses `allow_private_networks` to every tool.
+    #[serde(default)]
+    pub deny_private_networks: bool,
+    /// Widest filesystem access any tool gets.
+    #[serde(default)]
+    pub max_fs_access: Option<FsAccess>,
+    /// Environment variables no tool may read.
+    #[serde(default)]
+    pub deny_env: Vec<String>,
+    /// Ceilings for every tool.
+    #[serde(default)]
+    pub limits: LimitCaps,
+    /// Further ceilings for tools not signed by a trusted key.
+    #[serde(default)]
+    pub unsigned: LimitCaps,
+    /// Hex-encoded Ed25519 public keys whose signatures exempt a tool
+    /// from the `unsigned` ceilings. A key shipped in the manifest itself
+    /// only proves integrity, not trust.
+    #[serde(default)]
+    pub trusted_keys: Vec<String>,
+}
+
+impl HostPolicy {
+    /// Returns `config` narrowed by this policy.
+    ///
+    /// Denied hosts become `!` entries of a non-empty `net_allow_list`,
+    /// so they win over the manifest's allow rules. `signer` is the key the
+    /// tool's signature was verified against, if any.
+    pub fn restrict(&self, config: &SecurityConfig, signer: Option<&str>) -> SecurityConfig {
+        let mut out = config.clone();
+        if !out.net_allow_list.is_empty() {
+            let denies = self.deny_hosts.iter().map(|h| format!("!{h}"));
+            out.net_allow_list.extend(denies);
+        }
+        out.allow_private_networks &= !self.deny_private_networks;
+        if let Some(max) = &self.max_fs_access {
+            out.fs_access = out.fs_access.min(max.clone());
+        }
+        self.limits.apply(&mut out.limits);
+        if !signer.is_some_and(|key| self.trusts(key)) {
+            self.unsigned.apply(&mut out.limits);
+        }
+        out
+    }
+
+    /// Whether `key` is one of the operator's trusted signing keys.
+    pub fn trusts(&self, key: &str) -> bool {
+        let key = key.trim();
+        self.trusted_keys
+            .iter()
+            .any(|trusted| trusted.trim().eq_ignore_ascii_case(key))
+    }
+}
+
+#[cfg(test)]
+#[path = "host_policy_tests.rs"]
+mod tests;
@@ -0,0 +1,117 @@
+//! Tests for the host-wide policy.
+
+use super::*;
+
+fn networked() -> SecurityConfig {
+    SecurityConfig {
+        net_allow_list: vec!["*.example.com".to_string()],
+        allow_private_networks: true,
+        fs_access: FsAccess::Sandbox,
+        ..SecurityConfig::default()
+    }
+}
+
+#[test]
+fn empty_policy_keeps_manifest() {
+    let config = networked();
+    let out = HostPolicy::default().restrict(&config, None);
+    assert_eq!(out.net_allow_list, config.net_allow_list);
+    assert!(out.allow_private_networks);
+    assert_eq!(out.fs_access, FsAccess::Sandbox);
+    assert_eq!(out.limits.max_memory_mb, config.limits.max_memory_mb);
+}
+
+#[test]
+fn denied_hosts_become_deny_rules() {
+    let policy = HostPolicy {
+        deny_hosts: vec!["*.internal".to_string()],
+        ..HostPolicy::default()
+    };
+    let out = policy.restrict(&networked(), None);
+    assert_eq!(out.net_allow_list, ["*.example.com", "!*.internal"]);
+}
+
+#[test]
+fn denied_hosts_do_not_open_the_network() {
+    let policy = HostPolicy {
+        deny_hosts: vec!["*.internal".to_string()],
+        ..HostPolicy::default()
+    };
+    let out = policy.restrict(&SecurityConfig::default(), None);
+    assert!(out.net_allow_list.is_empty());
+}
+
+#[test]
+fn private_networks_and_fs_are_narrowed() {
+    let policy = HostPolicy {
+        deny_private_networks: true,
+        max_fs_access: Some(FsAccess::ReadOnly),
+        ..HostPolicy::default()
+    };
+    let out = policy.restrict(&networked(), None);
+    assert!(!out.allow_private_networks);
+    assert_eq!(out.fs_access, FsAccess::ReadOnly);
+}
+
+#[test]
+fn fs_ceiling_never_widens() {
+    let policy = HostPolicy {
+        max_fs_access: Some(FsAccess::Sandbox),
+        ..HostPolicy::default()
+    };
+    let out = policy.restrict(&SecurityConfig::default(), None);
+    assert_eq!(out.fs_access, FsAccess::None);
+}
+
+const TRUSTED: &str = "AB12cd";
+
+#[test]
+fn unsigned_caps_apply_only_to_trusted_signers() {
+    let policy = HostPolicy {
+        limits: LimitCaps {
+            max_memory_mb: Some(256),
+            ..LimitCaps::default()
+        },
+        unsigned: LimitCaps {
+            max_memory_mb: Some(32),
+            max_fuel: Some(1000),
+            ..LimitCaps::default()
+        },
+        trusted_keys: vec![TRUSTED.to_string()],
+        ..HostPolicy::default()
+    };
+    let mut config = SecurityConfig::default();
+    config.limits.max_memory_mb = 512;
+    let signed = policy.restrict(&config, Some("ab12CD"));
+    assert_eq!(signed.limits.max_memory_mb, 256);
+    assert_eq!(signed.limits.max_fuel, config.limits.max_fuel);
+    let unsigned = policy.restrict(&config, None);
+    assert_eq!(unsigned.limits.max_memory_mb, 32);
+    assert_eq!(unsigned.limits.max_fuel, 1000);
+    let self_signed = policy.restrict(&config, Some("ffff"));
+    assert_eq!(self_signed.limits.max_memory_mb, 32);
+    assert_eq!(self_signed.limits.max_fuel, 1000);
+}
+
+#[test]
+fn caps_never_raise_limits() {
+    let mut limits = ResourceLimits::default();
+    let caps = LimitCaps {
+        max_memory_mb: Some(4096),
+        max_http_requests: Some(1),
+        max_log_bytes: Some(1024),
+        max_kv_bytes: Some(u64::MAX),
+        ..LimitCaps::default()
+    };
+    caps.apply(&mut limits);
+    assert_eq!(limits.max_memory_mb, 64);
+    assert_eq!(limits.max_http_requests, 1);
+    assert_eq!(limits.max_log_bytes, 1024);
+    assert_eq!(limits.max_kv_bytes, 1024 * 1024);
+}
+
+#[test]
+fn fs_access_is_ordered() {
+    assert!(FsAccess::None < FsAccess::ReadOnly);
+    assert!(FsAccess::ReadOnly < FsAccess::Sandbox);
+}
@@ -4,15 +4,23 @@
 //! Zero external dependencies except `serde` for serialization.
 //! No filesystem I/O — `tool.toml` parsing is in `kami-config`.
 
+pub mod argument;
 pub mod capability;
+pub mod content;
 pub mod error;
 pub mod event;
+pub mod grant;
+pub mod host_policy;
 pub mod manifest;
 pub mod tool;
 pub mod version;
 
 // Re-exports for convenience.
+pub use argument::{ArgumentConstraints, ArgumentItems, ToolArgument};
 pub use capability::{Capability, FsAccess, ResourceLimits, SecurityConfig};
+pub use content::{joined_text, ContentPart, ResourceContents};
 pub use error::{DiagnosticError, ErrorKind, KamiError};
-pub use event::DomainEvent;
-pub use tool::{Tool, ToolArgument, ToolId, ToolManifest, ToolVersion};
+pub use event::{DomainEvent, SecretAccess};
+pub use grant::{capability_diff, CapabilityChange};
+pub use host_policy::{HostPolicy, LimitCaps};
+pub use tool::{Tool, ToolId, ToolManifest, ToolVersion};
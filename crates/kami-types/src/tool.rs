@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::str::FromStr;
 
+use crate::argument::ToolArgument;
 use crate::capability::SecurityConfig;
 use crate::error::KamiError;
 
@@ -68,24 +69,6 @@ impl ToolVersion {
     }
 }
 
-/// MCP argument definition for a tool.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ToolArgument {
-    /// Argument name.
-    pub name: String,
-    /// JSON Schema type (string, number, boolean, etc.).
-    #[serde(rename = "type")]
-    pub arg_type: String,
-    /// Human-readable description.
-    pub description: String,
-    /// Whether this argument is required.
-    #[serde(default)]
-    pub required: bool,
-    /// Default value if not required.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub default: Option<String>,
-}
-
 /// Complete tool manifest (parsed from tool.toml).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ToolManifest {
@@ -102,6 +85,9 @@ pub struct ToolManifest {
     /// Tool arguments.
     #[serde(default)]
     pub arguments: Vec<ToolArgument>,
+    /// Fields of the tool's structured result (empty: no output schema).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub output: Vec<ToolArgument>,
     /// Security configuration.
     pub security: SecurityConfig,
     /// SHA-256 hex digest of the WASM file (computed at install time).
@@ -0,0 +1,5 @@
+;; Prelude for `echo_after.wat` counting the local $n down from COUNT to zero.
+(local.set $n (i64.const {COUNT}))
+(loop $l
+  (local.set $n (i64.sub (local.get $n) (i64.const 1)))
+  (br_if $l (i64.ne (local.get $n) (i64.const 0))))
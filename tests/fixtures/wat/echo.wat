@@ -0,0 +1,21 @@
+(component
+  (core module $m
+    (memory (export "memory") 1)
+    (func (export "cabi_realloc") (param i32 i32 i32 i32) (result i32)
+      i32.const 256)
+    (func (export "run") (param $ptr i32) (param $len i32) (result i32)
+      (i32.store (i32.const 4096) (i32.const 0))
+      (i32.store (i32.const 4100) (local.get $ptr))
+      (i32.store (i32.const 4104) (local.get $len))
+      i32.const 4096)
+    (func (export "cabi_post_run") (param i32)))
+  (core instance $i (instantiate $m))
+  (func (export "run")
+    (param "input" string)
+    (result (result string (error string)))
+    (canon lift
+      (core func $i "run")
+      (memory $i "memory")
+      (realloc (func $i "cabi_realloc"))
+      (post-return (func $i "cabi_post_run"))))
+)
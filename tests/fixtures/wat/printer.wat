@@ -0,0 +1,60 @@
+;; Writes its input to the WASI stream STREAM (stdout or stderr) REPEAT
+;; times, runs END, then returns `Ok(input)`. Filled in by guest_output.rs.
+(component
+  (import "wasi:io/error@0.2.2" (instance $error
+    (export "error" (type (sub resource)))))
+  (alias export $error "error" (type $error-t))
+  (import "wasi:io/streams@0.2.2" (instance $streams
+    (export "output-stream" (type $os (sub resource)))
+    (alias outer 1 $error-t (type $err))
+    (export "error" (type $err-e (eq $err)))
+    (type $se (variant (case "last-operation-failed" (own $err-e)) (case "closed")))
+    (export "stream-error" (type $se-e (eq $se)))
+    (export "[method]output-stream.blocking-write-and-flush"
+      (func (param "self" (borrow $os)) (param "contents" (list u8))
+        (result (result (error $se-e)))))))
+  (alias export $streams "output-stream" (type $output-stream))
+  (import "wasi:cli/{STREAM}@0.2.2" (instance $cli
+    (alias outer 1 $output-stream (type $os))
+    (export "output-stream" (type $os-e (eq $os)))
+    (export "get-{STREAM}" (func (result (own $os-e))))))
+
+  (core module $libc (memory (export "memory") 1))
+  (core instance $libc (instantiate $libc))
+  (core func $get (canon lower (func $cli "get-{STREAM}")))
+  (core func $write (canon lower
+    (func $streams "[method]output-stream.blocking-write-and-flush")
+    (memory $libc "memory")))
+
+  (core module $m
+    (import "libc" "memory" (memory 1))
+    (import "host" "get" (func $get (result i32)))
+    (import "host" "write" (func $write (param i32 i32 i32 i32)))
+    (func (export "cabi_realloc") (param i32 i32 i32 i32) (result i32)
+      i32.const 256)
+    (func (export "run") (param $ptr i32) (param $len i32) (result i32)
+      (local $h i32) (local $n i32)
+      (local.set $h (call $get))
+      (local.set $n (i32.const {REPEAT}))
+      (loop $again
+        (call $write (local.get $h) (local.get $ptr) (local.get $len) (i32.const 8192))
+        (local.set $n (i32.sub (local.get $n) (i32.const 1)))
+        (br_if $again (local.get $n)))
+      {END}
+      (i32.store (i32.const 4096) (i32.const 0))
+      (i32.store (i32.const 4100) (local.get $ptr))
+      (i32.store (i32.const 4104) (local.get $len))
+      i32.const 4096)
+    (func (export "cabi_post_run") (param i32)))
+  (core instance $i (instantiate $m
+    (with "libc" (instance $libc))
+    (with "host" (instance (export "get" (func $get)) (export "write" (func $write))))))
+  (func (export "run")
+    (param "input" string)
+    (result (result string (error string)))
+    (canon lift
+      (core func $i "run")
+      (memory $libc "memory")
+      (realloc (func $i "cabi_realloc"))
+      (post-return (func $i "cabi_post_run"))))
+)
@@ -0,0 +1,40 @@
+(component
+  (import "kami:tool/progress@0.1.0" (instance $host
+    (export "report-progress" (func
+      (param "progress" f64) (param "total" (option f64)) (param "message" (option string))))))
+
+  (core module $libc
+    (memory (export "memory") 1)
+    (data (i32.const 64) "halfway"))
+  (core instance $libc (instantiate $libc))
+  (core func $report (canon lower (func $host "report-progress") (memory $libc "memory")))
+
+  (core module $m
+    (import "libc" "memory" (memory 1))
+    (import "host" "report" (func $report (param f64 i32 f64 i32 i32 i32)))
+    (func (export "cabi_realloc") (param i32 i32 i32 i32) (result i32)
+      i32.const 256)
+    (func (export "run") (param $ptr i32) (param $len i32) (result i32)
+      (call $report (f64.const 1) (i32.const 1) (f64.const 4)
+        (i32.const 0) (i32.const 0) (i32.const 0))
+      (call $report (f64.const 2) (i32.const 1) (f64.const 4)
+        (i32.const 1) (i32.const 64) (i32.const 7))
+      (call $report (f64.const nan) (i32.const 0) (f64.const 0)
+        (i32.const 0) (i32.const 0) (i32.const 0))
+      (i32.store (i32.const 4096) (i32.const 0))
+      (i32.store (i32.const 4100) (local.get $ptr))
+      (i32.store (i32.const 4104) (local.get $len))
+      i32.const 4096)
+    (func (export "cabi_post_run") (param i32)))
+  (core instance $i (instantiate $m
+    (with "libc" (instance $libc))
+    (with "host" (instance (export "report" (func $report))))))
+  (func (export "run")
+    (param "input" string)
+    (result (result string (error string)))
+    (canon lift
+      (core func $i "run")
+      (memory $libc "memory")
+      (realloc (func $i "cabi_realloc"))
+      (post-return (func $i "cabi_post_run"))))
+)
@@ -0,0 +1,19 @@
+(component
+  (core module $m
+    (memory (export "memory") 1)
+    (func (export "cabi_realloc") (param i32 i32 i32 i32) (result i32)
+      i32.const 256)
+    (func (export "run") (param i32 i32) (result i32)
+      (loop $l (br $l))
+      i32.const 0)
+    (func (export "cabi_post_run") (param i32)))
+  (core instance $i (instantiate $m))
+  (func (export "run")
+    (param "input" string)
+    (result (result string (error string)))
+    (canon lift
+      (core func $i "run")
+      (memory $i "memory")
+      (realloc (func $i "cabi_realloc"))
+      (post-return (func $i "cabi_post_run"))))
+)
@@ -0,0 +1,93 @@
+(component
+  (import "wasi:filesystem/types@0.2.0" (instance $types
+    (export "descriptor" (type $descriptor (sub resource)))
+    (type $error (enum "access" "would-block" "already" "bad-descriptor" "busy"
+      "deadlock" "quota" "exist" "file-too-large" "illegal-byte-sequence"
+      "in-progress" "interrupted" "invalid" "io" "is-directory" "loop"
+      "too-many-links" "message-size" "name-too-long" "no-device" "no-entry"
+      "no-lock" "insufficient-memory" "insufficient-space" "not-directory"
+      "not-empty" "not-recoverable" "unsupported" "no-tty" "no-such-device"
+      "overflow" "not-permitted" "pipe" "read-only" "invalid-seek"
+      "text-file-busy" "cross-device"))
+    (export "error-code" (type $error-code (eq $error)))
+    (type $path (flags "symlink-follow"))
+    (export "path-flags" (type $path-flags (eq $path)))
+    (type $open (flags "create" "directory" "exclusive" "truncate"))
+    (export "open-flags" (type $open-flags (eq $open)))
+    (type $desc (flags "read" "write" "file-integrity-sync" "data-integrity-sync"
+      "requested-write-sync" "mutate-directory"))
+    (export "descriptor-flags" (type $descriptor-flags (eq $desc)))
+    (export "[method]descriptor.open-at" (func
+      (param "self" (borrow $descriptor)) (param "path-flags" $path-flags)
+      (param "path" string) (param "open-flags" $open-flags)
+      (param "flags" $descriptor-flags)
+      (result (result (own $descriptor) (error $error-code)))))
+    (export "[method]descriptor.write" (func
+      (param "self" (borrow $descriptor)) (param "buffer" (list u8))
+      (param "offset" u64)
+      (result (result u64 (error $error-code)))))))
+  (alias export $types "descriptor" (type $descriptor))
+  (import "wasi:filesystem/preopens@0.2.0" (instance $preopens
+    (alias outer 1 $descriptor (type $outer))
+    (export "descriptor" (type $d (eq $outer)))
+    (export "get-directories" (func
+      (result (list (tuple (own $d) string)))))))
+
+  (core module $libc
+    (memory (export "memory") 1)
+    (data (i32.const 64) "kami")
+    (global $next (mut i32) (i32.const 8192))
+    (func (export "cabi_realloc") (param i32 i32 i32 i32) (result i32)
+      (local $ptr i32)
+      (local.set $ptr (i32.and
+        (i32.add (global.get $next) (i32.sub (local.get 2) (i32.const 1)))
+        (i32.sub (i32.const 0) (local.get 2))))
+      (global.set $next (i32.add (local.get $ptr) (local.get 3)))
+      local.get $ptr))
+  (core instance $libc (instantiate $libc))
+  (core func $get-directories (canon lower (func $preopens "get-directories")
+    (memory $libc "memory") (realloc (func $libc "cabi_realloc"))))
+  (core func $open-at (canon lower (func $types "[method]descriptor.open-at")
+    (memory $libc "memory")))
+  (core func $write (canon lower (func $types "[method]descriptor.write")
+    (memory $libc "memory")))
+
+  ;; `run(path)` creates `path` in the first preopened directory and writes
+  ;; "kami" to it, returning `Ok(path)`, or `Err(path)` if either step fails.
+  (core module $m
+    (import "libc" "memory" (memory 1))
+    (import "wasi" "get-directories" (func $get-directories (param i32)))
+    (import "wasi" "open-at" (func $open-at (param i32 i32 i32 i32 i32 i32 i32)))
+    (import "wasi" "write" (func $write (param i32 i32 i32 i64 i32)))
+    (func (export "run") (param $ptr i32) (param $len i32) (result i32)
+      (i32.store (i32.const 4100) (local.get $ptr))
+      (i32.store (i32.const 4104) (local.get $len))
+      (i32.store (i32.const 4096) (i32.const 1))
+      (call $get-directories (i32.const 16))
+      (if (i32.eqz (i32.load (i32.const 20)))
+        (then (return (i32.const 4096))))
+      ;; open-at(dir, no path flags, path, create, write)
+      (call $open-at (i32.load (i32.load (i32.const 16))) (i32.const 0)
+        (local.get $ptr) (local.get $len) (i32.const 1) (i32.const 2) (i32.const 32))
+      (if (i32.load8_u (i32.const 32))
+        (then (return (i32.const 4096))))
+      (call $write (i32.load (i32.const 36)) (i32.const 64) (i32.const 4)
+        (i64.const 0) (i32.const 48))
+      (i32.store (i32.const 4096) (i32.load8_u (i32.const 48)))
+      i32.const 4096)
+    (func (export "cabi_post_run") (param i32)))
+  (core instance $i (instantiate $m
+    (with "libc" (instance $libc))
+    (with "wasi" (instance
+      (export "get-directories" (func $get-directories))
+      (export "open-at" (func $open-at))
+      (export "write" (func $write))))))
+  (func (export "run")
+    (param "input" string)
+    (result (result string (error string)))
+    (canon lift
+      (core func $i "run")
+      (memory $libc "memory")
+      (realloc (func $libc "cabi_realloc"))
+      (post-return (func $i "cabi_post_run"))))
+)